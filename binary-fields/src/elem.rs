@@ -2,9 +2,9 @@ use crate::{BinaryFieldElement, BinaryPolynomial};
 use crate::poly::{BinaryPoly16, BinaryPoly32, BinaryPoly64, BinaryPoly128};
 
 // Irreducible polynomials (matching Julia implementation)
-const IRREDUCIBLE_16: u32 = 0x1002D;  // x^16 + x^5 + x^3 + x^2 + 1 (need to store in larger type)
-const IRREDUCIBLE_32: u64 = (1u64 << 32) | 0b11001 | (1 << 7) | (1 << 9) | (1 << 15);  // x^32 + Conway polynomial
-const IRREDUCIBLE_128: u128 = 0b10000111;  // x^128 + x^7 + x^2 + x + 1 (AES) - the x^128 is implicit
+pub(crate) const IRREDUCIBLE_16: u32 = 0x1002D;  // x^16 + x^5 + x^3 + x^2 + 1 (need to store in larger type)
+pub(crate) const IRREDUCIBLE_32: u64 = (1u64 << 32) | 0b11001 | (1 << 7) | (1 << 9) | (1 << 15);  // x^32 + Conway polynomial
+pub(crate) const IRREDUCIBLE_128: u128 = 0b10000111;  // x^128 + x^7 + x^2 + x + 1 (AES) - the x^128 is implicit
 
 macro_rules! impl_binary_elem {
     ($name:ident, $poly_type:ident, $poly_double:ident, $value_type:ty, $value_double:ty, $irreducible:expr, $bitsize:expr) => {
@@ -25,7 +25,7 @@ macro_rules! impl_binary_elem {
                 
                 // Find highest bit set in p
                 let mut high_bit = 0;
-                for i in (n..std::mem::size_of::<$value_double>() * 8).rev() {
+                for i in (n..core::mem::size_of::<$value_double>() * 8).rev() {
                     if (p >> i) & 1 == 1 {
                         high_bit = i;
                         break;
@@ -37,7 +37,7 @@ macro_rules! impl_binary_elem {
                     p ^= irr << (high_bit - n);
                     // Find new high bit
                     high_bit = 0;
-                    for i in (n..std::mem::size_of::<$value_double>() * 8).rev() {
+                    for i in (n..core::mem::size_of::<$value_double>() * 8).rev() {
                         if (p >> i) & 1 == 1 {
                             high_bit = i;
                             break;
@@ -55,6 +55,18 @@ macro_rules! impl_binary_elem {
         impl BinaryFieldElement for $name {
             type Poly = $poly_type;
 
+            const BYTES: usize = $bitsize / 8;
+
+            fn write_bytes(&self, out: &mut [u8]) {
+                out[..Self::BYTES].copy_from_slice(&self.0.value().to_le_bytes());
+            }
+
+            fn read_bytes(bytes: &[u8]) -> Self {
+                let mut buf = [0u8; $bitsize / 8];
+                buf.copy_from_slice(&bytes[..Self::BYTES]);
+                Self::from_value(<$value_type>::from_le_bytes(buf))
+            }
+
             fn zero() -> Self {
                 Self($poly_type::zero())
             }
@@ -132,6 +144,14 @@ macro_rules! impl_binary_elem {
 
                 result
             }
+
+            fn mul_slice(out: &mut [Self], other: &[Self]) {
+                crate::simd::mul_slice_on(crate::simd::backend(), out, other)
+            }
+
+            fn scale_slice(out: &mut [Self], scalar: Self) {
+                crate::simd::scale_slice_on(crate::simd::backend(), out, scalar)
+            }
         }
 
         impl From<$value_type> for $name {
@@ -177,6 +197,18 @@ impl BinaryElem128 {
 impl BinaryFieldElement for BinaryElem128 {
     type Poly = BinaryPoly128;
 
+    const BYTES: usize = 16;
+
+    fn write_bytes(&self, out: &mut [u8]) {
+        out[..Self::BYTES].copy_from_slice(&self.0.value().to_le_bytes());
+    }
+
+    fn read_bytes(bytes: &[u8]) -> Self {
+        let mut buf = [0u8; 16];
+        buf.copy_from_slice(&bytes[..Self::BYTES]);
+        Self::from_value(u128::from_le_bytes(buf))
+    }
+
     fn zero() -> Self {
         Self(BinaryPoly128::zero())
     }
@@ -270,6 +302,14 @@ impl BinaryFieldElement for BinaryElem128 {
 
         result
     }
+
+    fn mul_slice(out: &mut [Self], other: &[Self]) {
+        crate::simd::mul_slice_on(crate::simd::backend(), out, other)
+    }
+
+    fn scale_slice(out: &mut [Self], scalar: Self) {
+        crate::simd::scale_slice_on(crate::simd::backend(), out, scalar)
+    }
 }
 
 impl From<u128> for BinaryElem128 {
@@ -297,6 +337,18 @@ impl BinaryElem64 {
 impl BinaryFieldElement for BinaryElem64 {
     type Poly = BinaryPoly64;
 
+    const BYTES: usize = 8;
+
+    fn write_bytes(&self, out: &mut [u8]) {
+        out[..Self::BYTES].copy_from_slice(&self.0.value().to_le_bytes());
+    }
+
+    fn read_bytes(bytes: &[u8]) -> Self {
+        let mut buf = [0u8; 8];
+        buf.copy_from_slice(&bytes[..Self::BYTES]);
+        Self::from_value(u64::from_le_bytes(buf))
+    }
+
     fn zero() -> Self {
         Self(BinaryPoly64::zero())
     }
@@ -349,9 +401,99 @@ impl BinaryFieldElement for BinaryElem64 {
 }
 
 // Field embeddings for Ligerito
+//
+// GF(2^16) and GF(2^32) are subfields of GF(2^32) and GF(2^128), but their
+// polynomial bases are not: the embedding sends x to a root of the smaller
+// field's modulus. The roots below were found by splitting that modulus over
+// the larger field, and THETA_16_IN_128 is THETA_16_IN_32 embedded, so the
+// embeddings compose. Each embedding is GF(2)-linear and tabulated per byte.
+
+/// Root of `IRREDUCIBLE_16` in GF(2^32)
+const THETA_16_IN_32: u32 = 0x2cad_6fe6;
+
+/// Root of `IRREDUCIBLE_16` in GF(2^128)
+const THETA_16_IN_128: u128 = 0x302e_47d0_901a_c339_3761_915e_62bb_3105;
+
+/// Root of `IRREDUCIBLE_32` in GF(2^128)
+const THETA_32_IN_128: u128 = 0x117d_15d6_556e_3241_6e8f_ce84_757d_732e;
+
+const fn gf32_mul(mut a: u32, mut b: u32) -> u32 {
+    let mut r = 0;
+    while b != 0 {
+        if b & 1 == 1 {
+            r ^= a;
+        }
+        let carry = a >> 31;
+        a <<= 1;
+        if carry == 1 {
+            a ^= IRREDUCIBLE_32 as u32;
+        }
+        b >>= 1;
+    }
+    r
+}
+
+const fn gf128_mul(mut a: u128, mut b: u128) -> u128 {
+    let mut r = 0;
+    while b != 0 {
+        if b & 1 == 1 {
+            r ^= a;
+        }
+        let carry = a >> 127;
+        a <<= 1;
+        if carry == 1 {
+            a ^= IRREDUCIBLE_128;
+        }
+        b >>= 1;
+    }
+    r
+}
+
+/// `table[i][v]` is the image of byte `v` at byte position `i`
+const fn embedding_table_32<const N: usize>(theta: u32) -> [[u32; 256]; N] {
+    let mut table = [[0u32; 256]; N];
+    let mut power = 1u32;
+    let mut bit = 0;
+    while bit < 8 * N {
+        let mut v = 0;
+        while v < 256 {
+            if (v >> (bit % 8)) & 1 == 1 {
+                table[bit / 8][v] ^= power;
+            }
+            v += 1;
+        }
+        power = gf32_mul(power, theta);
+        bit += 1;
+    }
+    table
+}
+
+const fn embedding_table_128<const N: usize>(theta: u128) -> [[u128; 256]; N] {
+    let mut table = [[0u128; 256]; N];
+    let mut power = 1u128;
+    let mut bit = 0;
+    while bit < 8 * N {
+        let mut v = 0;
+        while v < 256 {
+            if (v >> (bit % 8)) & 1 == 1 {
+                table[bit / 8][v] ^= power;
+            }
+            v += 1;
+        }
+        power = gf128_mul(power, theta);
+        bit += 1;
+    }
+    table
+}
+
+static EMBED_16_IN_32: [[u32; 256]; 2] = embedding_table_32(THETA_16_IN_32);
+static EMBED_16_IN_128: [[u128; 256]; 2] = embedding_table_128(THETA_16_IN_128);
+static EMBED_32_IN_128: [[u128; 256]; 4] = embedding_table_128(THETA_32_IN_128);
+
 impl From<BinaryElem16> for BinaryElem32 {
     fn from(elem: BinaryElem16) -> Self {
-        BinaryElem32::from(elem.0.value() as u32)
+        let [lo, hi] = elem.0.value().to_le_bytes();
+        BinaryElem32::from(EMBED_16_IN_32[0][lo as usize] ^ EMBED_16_IN_32[1][hi as usize])
     }
 }
 
@@ -363,7 +505,8 @@ impl From<BinaryElem16> for BinaryElem64 {
 
 impl From<BinaryElem16> for BinaryElem128 {
     fn from(elem: BinaryElem16) -> Self {
-        BinaryElem128::from(elem.0.value() as u128)
+        let [lo, hi] = elem.0.value().to_le_bytes();
+        BinaryElem128::from(EMBED_16_IN_128[0][lo as usize] ^ EMBED_16_IN_128[1][hi as usize])
     }
 }
 
@@ -375,7 +518,11 @@ impl From<BinaryElem32> for BinaryElem64 {
 
 impl From<BinaryElem32> for BinaryElem128 {
     fn from(elem: BinaryElem32) -> Self {
-        BinaryElem128::from(elem.0.value() as u128)
+        let value = elem.0.value().to_le_bytes()
+            .iter()
+            .zip(EMBED_32_IN_128.iter())
+            .fold(0u128, |acc, (&b, table)| acc ^ table[b as usize]);
+        BinaryElem128::from(value)
     }
 }
 
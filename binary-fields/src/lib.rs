@@ -2,16 +2,21 @@
 //! Binary extension fields GF(2^n) implementation
 //! Mirrors the Julia BinaryFields module
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
 mod elem;
 mod poly;
-mod simd;
+pub mod simd;
 
 pub use elem::{BinaryElem16, BinaryElem32, BinaryElem64, BinaryElem128};
 pub use poly::{BinaryPoly16, BinaryPoly32, BinaryPoly64, BinaryPoly128, BinaryPoly256};
 
+/// Elements per stack buffer in the default butterfly implementations
+const BATCH_CHUNK: usize = 64;
+
 // Re-export traits
 pub trait BinaryFieldElement: Send + Sync + 
-    Sized + Copy + Clone + Default + PartialEq + Eq + std::fmt::Debug 
+    Sized + Copy + Clone + Default + PartialEq + Eq + core::fmt::Debug 
 {
     type Poly: BinaryPolynomial;
     
@@ -23,6 +28,66 @@ pub trait BinaryFieldElement: Send + Sync +
     fn mul(&self, other: &Self) -> Self;
     fn inv(&self) -> Self;
     fn pow(&self, exp: u64) -> Self;
+
+    /// Length of the canonical little-endian byte encoding
+    const BYTES: usize;
+
+    /// Write the canonical encoding into `out[..Self::BYTES]`
+    fn write_bytes(&self, out: &mut [u8]);
+
+    /// Read an element from `bytes[..Self::BYTES]`
+    ///
+    /// Every bit pattern is a reduced field element, so this cannot fail.
+    fn read_bytes(bytes: &[u8]) -> Self;
+
+    /// `out[i] += other[i]`
+    fn add_slice(out: &mut [Self], other: &[Self]) {
+        assert_eq!(out.len(), other.len(), "slice lengths differ");
+        for (o, x) in out.iter_mut().zip(other) {
+            *o = o.add(x);
+        }
+    }
+
+    /// `out[i] *= other[i]`, vectorized where [`simd::backend`] allows
+    fn mul_slice(out: &mut [Self], other: &[Self]) {
+        assert_eq!(out.len(), other.len(), "slice lengths differ");
+        for (o, x) in out.iter_mut().zip(other) {
+            *o = o.mul(x);
+        }
+    }
+
+    /// `out[i] *= scalar`, vectorized where [`simd::backend`] allows
+    fn scale_slice(out: &mut [Self], scalar: Self) {
+        for o in out.iter_mut() {
+            *o = o.mul(&scalar);
+        }
+    }
+
+    /// Additive FFT butterfly over two halves: `u += lambda * w; w += u`
+    fn fft_butterfly(u: &mut [Self], w: &mut [Self], lambda: Self) {
+        assert_eq!(u.len(), w.len(), "slice lengths differ");
+        let mut buf = [Self::zero(); BATCH_CHUNK];
+        for (u, w) in u.chunks_mut(BATCH_CHUNK).zip(w.chunks_mut(BATCH_CHUNK)) {
+            let t = &mut buf[..w.len()];
+            t.copy_from_slice(w);
+            Self::scale_slice(t, lambda);
+            Self::add_slice(u, t);
+            Self::add_slice(w, u);
+        }
+    }
+
+    /// Inverse of [`fft_butterfly`](Self::fft_butterfly): `w += u; u += lambda * w`
+    fn ifft_butterfly(u: &mut [Self], w: &mut [Self], lambda: Self) {
+        assert_eq!(u.len(), w.len(), "slice lengths differ");
+        let mut buf = [Self::zero(); BATCH_CHUNK];
+        for (u, w) in u.chunks_mut(BATCH_CHUNK).zip(w.chunks_mut(BATCH_CHUNK)) {
+            Self::add_slice(w, u);
+            let t = &mut buf[..w.len()];
+            t.copy_from_slice(w);
+            Self::scale_slice(t, lambda);
+            Self::add_slice(u, t);
+        }
+    }
     
     fn from_bits(bits: u64) -> Self {
         let mut result = Self::zero();
@@ -42,9 +107,9 @@ pub trait BinaryFieldElement: Send + Sync +
 }
 
 pub trait BinaryPolynomial: 
-    Sized + Copy + Clone + Default + PartialEq + Eq + std::fmt::Debug
+    Sized + Copy + Clone + Default + PartialEq + Eq + core::fmt::Debug
 {
-    type Value: Copy + Clone + std::fmt::Debug;
+    type Value: Copy + Clone + core::fmt::Debug;
     
     fn zero() -> Self;
     fn one() -> Self;
@@ -125,6 +190,21 @@ mod tests {
         assert_eq!(a.mul(&zero), zero);
     }
 
+    #[test]
+    fn test_byte_encoding_roundtrip() {
+        let a = BinaryElem32::from_value(0xDEADBEEF);
+        let mut buf = [0u8; 4];
+        a.write_bytes(&mut buf);
+        assert_eq!(buf, 0xDEADBEEFu32.to_le_bytes());
+        assert_eq!(BinaryElem32::read_bytes(&buf), a);
+
+        let b = BinaryElem128::from_value(0x0123456789ABCDEF_FEDCBA9876543210);
+        let mut buf = [0u8; 16];
+        b.write_bytes(&mut buf);
+        assert_eq!(BinaryElem128::read_bytes(&buf), b);
+        assert_eq!(BinaryElem128::BYTES, 16);
+    }
+
     #[test]
     fn test_field_inverse() {
         let a = BinaryElem16::from_value(0x1234);
@@ -257,4 +337,109 @@ mod tests {
         // r should have degree less than b
         assert!(r.value() < b.value() || r.value() == 0);
     }
+
+    fn check_batch_backends<F>(len: usize)
+    where
+        F: BinaryFieldElement + simd::WideKernel,
+        rand::distributions::Standard: rand::distributions::Distribution<F>,
+    {
+        use rand::{Rng, SeedableRng};
+        let mut rng = rand::rngs::StdRng::seed_from_u64(len as u64);
+
+        // edge values first, then random
+        let all_ones = F::read_bytes(&[0xff; 16]);
+        let mut a: Vec<F> = vec![F::zero(), F::one(), all_ones, all_ones];
+        let mut b: Vec<F> = vec![all_ones, all_ones, F::one(), all_ones];
+        a.extend((4..len).map(|_| rng.gen::<F>()));
+        b.extend((4..len).map(|_| rng.gen::<F>()));
+        let scalar: F = rng.gen();
+
+        let products: Vec<F> = a.iter().zip(&b).map(|(x, y)| x.mul(y)).collect();
+        let scaled: Vec<F> = a.iter().map(|x| x.mul(&scalar)).collect();
+
+        for backend in simd::Backend::ALL.into_iter().filter(|b| b.is_available()) {
+            let mut out = a.clone();
+            simd::mul_slice_on(backend, &mut out, &b);
+            assert_eq!(out, products, "mul_slice on {:?}", backend);
+
+            let mut out = a.clone();
+            simd::scale_slice_on(backend, &mut out, scalar);
+            assert_eq!(out, scaled, "scale_slice on {:?}", backend);
+        }
+    }
+
+    #[test]
+    fn test_batch_backends_bit_exact() {
+        // odd lengths leave a remainder after two-lane packing
+        for len in [4, 37, 256] {
+            check_batch_backends::<BinaryElem16>(len);
+            check_batch_backends::<BinaryElem32>(len);
+            check_batch_backends::<BinaryElem128>(len);
+        }
+    }
+
+    #[test]
+    fn test_carryless_mul_matches_shift_and_xor() {
+        use rand::{Rng, SeedableRng};
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+
+        for _ in 0..100 {
+            let (a, b): (u64, u64) = (rng.gen(), rng.gen());
+            let expected = (0..64)
+                .filter(|i| (b >> i) & 1 == 1)
+                .fold(0u128, |acc, i| acc ^ ((a as u128) << i));
+            let got = simd::carryless_mul(BinaryPoly64::from_value(a), BinaryPoly64::from_value(b));
+            assert_eq!(got.value(), expected);
+        }
+    }
+
+    #[test]
+    fn test_butterflies_match_scalar() {
+        use rand::{Rng, SeedableRng};
+        let mut rng = rand::rngs::StdRng::seed_from_u64(2);
+
+        // longer than one stack chunk
+        let n = 3 * BATCH_CHUNK / 2 + 1;
+        let u: Vec<BinaryElem128> = (0..n).map(|_| rng.gen()).collect();
+        let w: Vec<BinaryElem128> = (0..n).map(|_| rng.gen()).collect();
+        let lambda: BinaryElem128 = rng.gen();
+
+        let (mut u2, mut w2) = (u.clone(), w.clone());
+        BinaryElem128::fft_butterfly(&mut u2, &mut w2, lambda);
+        for i in 0..n {
+            let ui = u[i].add(&lambda.mul(&w[i]));
+            assert_eq!(u2[i], ui);
+            assert_eq!(w2[i], w[i].add(&ui));
+        }
+
+        BinaryElem128::ifft_butterfly(&mut u2, &mut w2, lambda);
+        assert_eq!(u2, u);
+        assert_eq!(w2, w);
+    }
+
+    #[test]
+    fn test_embeddings_are_field_homomorphisms() {
+        use rand::{Rng, SeedableRng};
+        let mut rng = rand::rngs::StdRng::seed_from_u64(3);
+
+        assert_eq!(BinaryElem128::from(BinaryElem32::one()), BinaryElem128::one());
+        assert_eq!(BinaryElem128::from(BinaryElem16::one()), BinaryElem128::one());
+        assert_eq!(BinaryElem32::from(BinaryElem16::one()), BinaryElem32::one());
+
+        for _ in 0..100 {
+            let a: BinaryElem32 = rng.gen();
+            let b: BinaryElem32 = rng.gen();
+            let (ea, eb) = (BinaryElem128::from(a), BinaryElem128::from(b));
+            assert_eq!(BinaryElem128::from(a.mul(&b)), ea.mul(&eb));
+            assert_eq!(BinaryElem128::from(a.add(&b)), ea.add(&eb));
+
+            let c = BinaryElem16::from_value(rng.gen());
+            let d = BinaryElem16::from_value(rng.gen());
+            assert_eq!(BinaryElem32::from(c.mul(&d)), BinaryElem32::from(c).mul(&BinaryElem32::from(d)));
+            assert_eq!(BinaryElem128::from(c.mul(&d)), BinaryElem128::from(c).mul(&BinaryElem128::from(d)));
+
+            // 16 -> 32 -> 128 agrees with 16 -> 128
+            assert_eq!(BinaryElem128::from(BinaryElem32::from(c)), BinaryElem128::from(c));
+        }
+    }
 }
@@ -30,7 +30,7 @@ macro_rules! impl_binary_poly {
             }
 
             pub fn split(&self) -> (Self, Self) {
-                let half_bits = std::mem::size_of::<$value_type>() * 4;
+                let half_bits = core::mem::size_of::<$value_type>() * 4;
                 let mask = ((1u64 << half_bits) - 1) as $value_type;
                 let lo = Self(self.0 & mask);
                 let hi = Self(self.0 >> half_bits);
@@ -68,7 +68,7 @@ macro_rules! impl_binary_poly {
                 let a = self.0;
                 let b = other.0;
                 
-                for i in 0..std::mem::size_of::<$value_type>() * 8 {
+                for i in 0..core::mem::size_of::<$value_type>() * 8 {
                     if (b >> i) & 1 == 1 {
                         result ^= a.wrapping_shl(i as u32);
                     }
@@ -87,14 +87,14 @@ macro_rules! impl_binary_poly {
                     return (quotient, remainder);
                 }
 
-                let divisor_bits = (std::mem::size_of::<$value_type>() * 8) as u32 - divisor.leading_zeros();
-                let mut remainder_bits = (std::mem::size_of::<$value_type>() * 8) as u32 - remainder.leading_zeros();
+                let divisor_bits = (core::mem::size_of::<$value_type>() * 8) as u32 - divisor.leading_zeros();
+                let mut remainder_bits = (core::mem::size_of::<$value_type>() * 8) as u32 - remainder.leading_zeros();
 
                 while remainder_bits >= divisor_bits && remainder.0 != 0 {
                     let shift = remainder_bits - divisor_bits;
                     quotient.0 |= 1 << shift;
                     remainder.0 ^= divisor.0 << shift;
-                    remainder_bits = (std::mem::size_of::<$value_type>() * 8) as u32 - remainder.leading_zeros();
+                    remainder_bits = (core::mem::size_of::<$value_type>() * 8) as u32 - remainder.leading_zeros();
                 }
 
                 (quotient, remainder)
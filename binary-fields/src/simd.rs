@@ -1,53 +1,402 @@
 // src/simd.rs
-#[allow(unused_imports)]
+//! Carry-less multiplication and batched field arithmetic
+//!
+//! Batch kernels select a backend at runtime: VPCLMULQDQ (two 128-bit lanes
+//! per AVX2 register) or PCLMULQDQ on x86_64, PMULL on aarch64, and a
+//! portable shift-and-xor fallback everywhere else. All backends reduce by
+//! the same irreducible polynomials as the scalar `mul`, so results are
+//! bit-identical. GFNI is not used: its multiply is fixed to GF(2^8) modulo
+//! the AES polynomial, which is not a subfield of any field here.
+//!
+//! Without `std` there is no runtime detection and the backend follows the
+//! compile-time target features.
+
+use crate::elem::{IRREDUCIBLE_16, IRREDUCIBLE_32, IRREDUCIBLE_128};
 use crate::poly::{BinaryPoly64, BinaryPoly128};
+use crate::{BinaryElem16, BinaryElem32, BinaryElem128, BinaryFieldElement};
 
-#[cfg(target_arch = "x86_64")]
-pub fn carryless_mul(a: BinaryPoly64, b: BinaryPoly64) -> BinaryPoly128 {
-    #[cfg(target_feature = "pclmulqdq")]
-    unsafe {
-        use core::arch::x86_64::*;
-        
-        let a_vec = _mm_set_epi64x(0, a.value() as i64);
-        let b_vec = _mm_set_epi64x(0, b.value() as i64);
-        
-        let result = _mm_clmulepi64_si128(a_vec, b_vec, 0x00);
-        
-        let lo = _mm_extract_epi64(result, 0) as u64;
-        let hi = _mm_extract_epi64(result, 1) as u64;
-        
-        BinaryPoly128::new(((hi as u128) << 64) | (lo as u128))
-    }
-    
-    #[cfg(not(target_feature = "pclmulqdq"))]
-    {
-        // Software fallback
-        let mut result = 0u128;
-        let a_val = a.value();
-        let b_val = b.value();
-        
-        for i in 0..64 {
-            if (b_val >> i) & 1 == 1 {
-                result ^= (a_val as u128) << i;
-            }
+/// Carry-less multiplication backend
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Backend {
+    /// Shift-and-xor on general purpose registers
+    Portable,
+    /// x86_64 PCLMULQDQ, one 64x64 product per instruction
+    Pclmul,
+    /// x86_64 VPCLMULQDQ with AVX2, two 64x64 products per instruction
+    Vpclmul,
+    /// aarch64 PMULL
+    Pmull,
+}
+
+impl Backend {
+    pub const ALL: [Backend; 4] = [Backend::Portable, Backend::Pclmul, Backend::Vpclmul, Backend::Pmull];
+
+    /// Whether this backend can run on the current CPU
+    pub fn is_available(self) -> bool {
+        match self {
+            Backend::Portable => true,
+            Backend::Pclmul => has_pclmul(),
+            Backend::Vpclmul => has_pclmul() && has_vpclmul(),
+            Backend::Pmull => has_pmull(),
         }
-        
-        BinaryPoly128::new(result)
     }
 }
 
+/// Fastest backend available on the current CPU
+pub fn backend() -> Backend {
+    [Backend::Vpclmul, Backend::Pclmul, Backend::Pmull]
+        .into_iter()
+        .find(|b| b.is_available())
+        .unwrap_or(Backend::Portable)
+}
+
+#[cfg(all(target_arch = "x86_64", feature = "std"))]
+fn has_pclmul() -> bool {
+    std::is_x86_feature_detected!("pclmulqdq")
+}
+
+#[cfg(all(target_arch = "x86_64", feature = "std"))]
+fn has_vpclmul() -> bool {
+    std::is_x86_feature_detected!("vpclmulqdq") && std::is_x86_feature_detected!("avx2")
+}
+
+#[cfg(all(target_arch = "x86_64", not(feature = "std")))]
+fn has_pclmul() -> bool {
+    cfg!(target_feature = "pclmulqdq")
+}
+
+#[cfg(all(target_arch = "x86_64", not(feature = "std")))]
+fn has_vpclmul() -> bool {
+    cfg!(all(target_feature = "vpclmulqdq", target_feature = "avx2"))
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+fn has_pclmul() -> bool {
+    false
+}
+
 #[cfg(not(target_arch = "x86_64"))]
+fn has_vpclmul() -> bool {
+    false
+}
+
+#[cfg(all(target_arch = "aarch64", feature = "std"))]
+fn has_pmull() -> bool {
+    std::arch::is_aarch64_feature_detected!("pmull")
+}
+
+#[cfg(all(target_arch = "aarch64", not(feature = "std")))]
+fn has_pmull() -> bool {
+    cfg!(target_feature = "aes")
+}
+
+#[cfg(not(target_arch = "aarch64"))]
+fn has_pmull() -> bool {
+    false
+}
+
 pub fn carryless_mul(a: BinaryPoly64, b: BinaryPoly64) -> BinaryPoly128 {
-    // Software fallback
+    #[cfg(all(target_arch = "x86_64", target_feature = "pclmulqdq"))]
+    return BinaryPoly128::new(unsafe { Pclmul::clmul(a.value(), b.value()) });
+
+    #[cfg(not(all(target_arch = "x86_64", target_feature = "pclmulqdq")))]
+    {
+        #[cfg(target_arch = "x86_64")]
+        if has_pclmul() {
+            return BinaryPoly128::new(unsafe { clmul_pclmul(a.value(), b.value()) });
+        }
+
+        BinaryPoly128::new(clmul_portable(a.value(), b.value()))
+    }
+}
+
+fn clmul_portable(a: u64, b: u64) -> u128 {
     let mut result = 0u128;
-    let a_val = a.value();
-    let b_val = b.value();
-    
     for i in 0..64 {
-        if (b_val >> i) & 1 == 1 {
-            result ^= (a_val as u128) << i;
+        if (b >> i) & 1 == 1 {
+            result ^= (a as u128) << i;
+        }
+    }
+    result
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "pclmulqdq")]
+unsafe fn clmul_pclmul(a: u64, b: u64) -> u128 {
+    Pclmul::clmul(a, b)
+}
+
+/// 64x64 -> 128 bit carry-less product
+///
+/// Implementations are `unsafe` because they may require CPU features the
+/// caller has checked for.
+pub(crate) trait Clmul {
+    unsafe fn clmul(a: u64, b: u64) -> u128;
+}
+
+struct Soft;
+
+impl Clmul for Soft {
+    #[inline(always)]
+    unsafe fn clmul(a: u64, b: u64) -> u128 {
+        clmul_portable(a, b)
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+struct Pclmul;
+
+#[cfg(target_arch = "x86_64")]
+impl Clmul for Pclmul {
+    #[inline(always)]
+    unsafe fn clmul(a: u64, b: u64) -> u128 {
+        use core::arch::x86_64::*;
+
+        let r = _mm_clmulepi64_si128(_mm_cvtsi64_si128(a as i64), _mm_cvtsi64_si128(b as i64), 0x00);
+        let lo = _mm_cvtsi128_si64(r) as u64;
+        let hi = _mm_cvtsi128_si64(_mm_unpackhi_epi64(r, r)) as u64;
+        ((hi as u128) << 64) | lo as u128
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+struct Pmull;
+
+#[cfg(target_arch = "aarch64")]
+impl Clmul for Pmull {
+    #[inline(always)]
+    unsafe fn clmul(a: u64, b: u64) -> u128 {
+        core::arch::aarch64::vmull_p64(a, b)
+    }
+}
+
+/// Fields whose product is a carry-less product reduced by folding the high
+/// half through the low terms of the irreducible polynomial
+pub(crate) trait ClmulField: BinaryFieldElement {
+    unsafe fn mul_with<C: Clmul>(self, other: Self) -> Self;
+}
+
+impl ClmulField for BinaryElem16 {
+    #[inline(always)]
+    unsafe fn mul_with<C: Clmul>(self, other: Self) -> Self {
+        const R: u64 = (IRREDUCIBLE_16 & 0xffff) as u64;
+        let p = C::clmul(self.poly().value() as u64, other.poly().value() as u64) as u64;
+        let t = C::clmul(p >> 16, R) as u64;
+        let u = C::clmul(t >> 16, R) as u64;
+        Self::from_value((p ^ t ^ u) as u16)
+    }
+}
+
+impl ClmulField for BinaryElem32 {
+    #[inline(always)]
+    unsafe fn mul_with<C: Clmul>(self, other: Self) -> Self {
+        const R: u64 = IRREDUCIBLE_32 & 0xffff_ffff;
+        let p = C::clmul(self.poly().value() as u64, other.poly().value() as u64) as u64;
+        let t = C::clmul(p >> 32, R) as u64;
+        let u = C::clmul(t >> 32, R) as u64;
+        Self::from_value((p ^ t ^ u) as u32)
+    }
+}
+
+impl ClmulField for BinaryElem128 {
+    #[inline(always)]
+    unsafe fn mul_with<C: Clmul>(self, other: Self) -> Self {
+        const R: u64 = IRREDUCIBLE_128 as u64;
+        let (a, b) = (self.poly().value(), other.poly().value());
+        let (a0, a1, b0, b1) = (a as u64, (a >> 64) as u64, b as u64, (b >> 64) as u64);
+
+        // 256-bit product as h * x^128 + l
+        let mid = C::clmul(a0, b1) ^ C::clmul(a1, b0);
+        let l = C::clmul(a0, b0) ^ (mid << 64);
+        let h = C::clmul(a1, b1) ^ (mid >> 64);
+
+        // x^128 = R, folded twice since h * R overflows by 7 bits
+        let t0 = C::clmul(h as u64, R);
+        let t1 = C::clmul((h >> 64) as u64, R);
+        let u = C::clmul((t1 >> 64) as u64, R);
+        Self::from_value(l ^ t0 ^ (t1 << 64) ^ u)
+    }
+}
+
+#[inline(always)]
+unsafe fn mul_slice_with<F: ClmulField, C: Clmul>(out: &mut [F], other: &[F]) {
+    for (o, x) in out.iter_mut().zip(other) {
+        *o = o.mul_with::<C>(*x);
+    }
+}
+
+#[inline(always)]
+unsafe fn scale_slice_with<F: ClmulField, C: Clmul>(out: &mut [F], scalar: F) {
+    for o in out.iter_mut() {
+        *o = o.mul_with::<C>(scalar);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "pclmulqdq")]
+unsafe fn mul_slice_pclmul<F: ClmulField>(out: &mut [F], other: &[F]) {
+    mul_slice_with::<F, Pclmul>(out, other)
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "pclmulqdq")]
+unsafe fn scale_slice_pclmul<F: ClmulField>(out: &mut [F], scalar: F) {
+    scale_slice_with::<F, Pclmul>(out, scalar)
+}
+
+#[cfg(target_arch = "aarch64")]
+#[target_feature(enable = "neon,aes")]
+unsafe fn mul_slice_pmull<F: ClmulField>(out: &mut [F], other: &[F]) {
+    mul_slice_with::<F, Pmull>(out, other)
+}
+
+#[cfg(target_arch = "aarch64")]
+#[target_feature(enable = "neon,aes")]
+unsafe fn scale_slice_pmull<F: ClmulField>(out: &mut [F], scalar: F) {
+    scale_slice_with::<F, Pmull>(out, scalar)
+}
+
+/// Two GF(2^128) products per AVX2 register, one element per 128-bit lane
+#[cfg(target_arch = "x86_64")]
+mod vpclmul {
+    use super::{mul_slice_pclmul, scale_slice_pclmul};
+    use crate::{BinaryElem128, BinaryFieldElement};
+    use crate::elem::IRREDUCIBLE_128;
+    use core::arch::x86_64::*;
+
+    #[inline(always)]
+    unsafe fn load(a: BinaryElem128, b: BinaryElem128) -> __m256i {
+        let (a, b) = (a.poly().value(), b.poly().value());
+        _mm256_set_epi64x((b >> 64) as i64, b as i64, (a >> 64) as i64, a as i64)
+    }
+
+    #[inline(always)]
+    unsafe fn store(out: &mut [BinaryElem128], v: __m256i) {
+        let mut w = [0u64; 4];
+        _mm256_storeu_si256(w.as_mut_ptr().cast(), v);
+        out[0] = BinaryElem128::from_value(((w[1] as u128) << 64) | w[0] as u128);
+        out[1] = BinaryElem128::from_value(((w[3] as u128) << 64) | w[2] as u128);
+    }
+
+    /// Lane-wise version of `BinaryElem128::mul_with`
+    #[inline(always)]
+    unsafe fn mul2(a: __m256i, b: __m256i) -> __m256i {
+        let r = _mm256_set1_epi64x(IRREDUCIBLE_128 as i64);
+
+        let mid = _mm256_xor_si256(
+            _mm256_clmulepi64_epi128(a, b, 0x01),
+            _mm256_clmulepi64_epi128(a, b, 0x10),
+        );
+        let l = _mm256_xor_si256(_mm256_clmulepi64_epi128(a, b, 0x00), _mm256_bslli_epi128(mid, 8));
+        let h = _mm256_xor_si256(_mm256_clmulepi64_epi128(a, b, 0x11), _mm256_bsrli_epi128(mid, 8));
+
+        let t0 = _mm256_clmulepi64_epi128(h, r, 0x00);
+        let t1 = _mm256_clmulepi64_epi128(h, r, 0x01);
+        let u = _mm256_clmulepi64_epi128(_mm256_bsrli_epi128(t1, 8), r, 0x00);
+        _mm256_xor_si256(_mm256_xor_si256(l, t0), _mm256_xor_si256(_mm256_bslli_epi128(t1, 8), u))
+    }
+
+    #[target_feature(enable = "pclmulqdq,vpclmulqdq,avx2")]
+    pub(super) unsafe fn mul_slice(out: &mut [BinaryElem128], other: &[BinaryElem128]) {
+        let mut out = out.chunks_exact_mut(2);
+        let mut other = other.chunks_exact(2);
+        for (o, x) in (&mut out).zip(&mut other) {
+            store(o, mul2(load(o[0], o[1]), load(x[0], x[1])));
+        }
+        mul_slice_pclmul(out.into_remainder(), other.remainder());
+    }
+
+    #[target_feature(enable = "pclmulqdq,vpclmulqdq,avx2")]
+    pub(super) unsafe fn scale_slice(out: &mut [BinaryElem128], scalar: BinaryElem128) {
+        let s = load(scalar, scalar);
+        let mut out = out.chunks_exact_mut(2);
+        for o in &mut out {
+            store(o, mul2(load(o[0], o[1]), s));
+        }
+        scale_slice_pclmul(out.into_remainder(), scalar);
+    }
+}
+
+/// Fields with a dedicated wide-register kernel
+pub(crate) trait WideKernel: ClmulField {
+    unsafe fn mul_slice_wide(out: &mut [Self], other: &[Self]);
+    unsafe fn scale_slice_wide(out: &mut [Self], scalar: Self);
+}
+
+macro_rules! narrow_wide_kernel {
+    ($($t:ty),*) => {$(
+        // no lane packing for small fields: one PCLMULQDQ per product
+        impl WideKernel for $t {
+            unsafe fn mul_slice_wide(out: &mut [Self], other: &[Self]) {
+                #[cfg(target_arch = "x86_64")]
+                mul_slice_pclmul(out, other);
+                #[cfg(not(target_arch = "x86_64"))]
+                mul_slice_with::<Self, Soft>(out, other);
+            }
+
+            unsafe fn scale_slice_wide(out: &mut [Self], scalar: Self) {
+                #[cfg(target_arch = "x86_64")]
+                scale_slice_pclmul(out, scalar);
+                #[cfg(not(target_arch = "x86_64"))]
+                scale_slice_with::<Self, Soft>(out, scalar);
+            }
+        }
+    )*};
+}
+
+narrow_wide_kernel!(BinaryElem16, BinaryElem32);
+
+impl WideKernel for BinaryElem128 {
+    unsafe fn mul_slice_wide(out: &mut [Self], other: &[Self]) {
+        #[cfg(target_arch = "x86_64")]
+        vpclmul::mul_slice(out, other);
+        #[cfg(not(target_arch = "x86_64"))]
+        mul_slice_with::<Self, Soft>(out, other);
+    }
+
+    unsafe fn scale_slice_wide(out: &mut [Self], scalar: Self) {
+        #[cfg(target_arch = "x86_64")]
+        vpclmul::scale_slice(out, scalar);
+        #[cfg(not(target_arch = "x86_64"))]
+        scale_slice_with::<Self, Soft>(out, scalar);
+    }
+}
+
+/// `out[i] *= other[i]` on the given backend
+///
+/// Panics if the backend is not available on this CPU.
+pub(crate) fn mul_slice_on<F: WideKernel>(backend: Backend, out: &mut [F], other: &[F]) {
+    assert_eq!(out.len(), other.len(), "slice lengths differ");
+    assert!(backend.is_available(), "{:?} is not available on this CPU", backend);
+
+    // SAFETY: the backend's CPU features were checked above
+    unsafe {
+        match backend {
+            Backend::Vpclmul => F::mul_slice_wide(out, other),
+            #[cfg(target_arch = "x86_64")]
+            Backend::Pclmul => mul_slice_pclmul(out, other),
+            #[cfg(target_arch = "aarch64")]
+            Backend::Pmull => mul_slice_pmull(out, other),
+            _ => mul_slice_with::<F, Soft>(out, other),
+        }
+    }
+}
+
+/// `out[i] *= scalar` on the given backend
+///
+/// Panics if the backend is not available on this CPU.
+pub(crate) fn scale_slice_on<F: WideKernel>(backend: Backend, out: &mut [F], scalar: F) {
+    assert!(backend.is_available(), "{:?} is not available on this CPU", backend);
+
+    // SAFETY: the backend's CPU features were checked above
+    unsafe {
+        match backend {
+            Backend::Vpclmul => F::scale_slice_wide(out, scalar),
+            #[cfg(target_arch = "x86_64")]
+            Backend::Pclmul => scale_slice_pclmul(out, scalar),
+            #[cfg(target_arch = "aarch64")]
+            Backend::Pmull => scale_slice_pmull(out, scalar),
+            _ => scale_slice_with::<F, Soft>(out, scalar),
         }
     }
-    
-    BinaryPoly128::new(result)
 }
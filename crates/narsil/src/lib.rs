@@ -113,6 +113,8 @@ pub mod scanner;
 pub mod reshare;
 pub mod shares;
 pub mod state;
+#[cfg(feature = "sled")]
+pub mod store;
 pub mod syndicate;
 pub mod traits;
 pub mod vss;
@@ -195,11 +197,15 @@ pub use relay::{
 #[cfg(feature = "std")]
 pub use relay::MultiRelayClient;
 
+// durable state backend
+#[cfg(feature = "sled")]
+pub use store::{SledBackend, StoreError, WriteBatch};
+
 // chain scanner for shielded wallets
 pub use scanner::{
     CompactBlock, CompactOutput, TreeUpdate, DecryptedNote,
     FullViewingKey, Scanner, ScannerConfig, ScanResult,
-    WitnessBuilder, SyncState, SyncStatus,
+    WitnessBuilder, TreeHasher, WitnessError, SyncState, SyncStatus,
 };
 
 // verifiable secret sharing for backup
@@ -257,7 +263,11 @@ pub use networks::{
 };
 pub use networks::polkadot::{PolkadotAddress, PolkadotTransaction, AssetHubAction, AssetHubActionBuilder};
 pub use networks::penumbra::{PenumbraAddress, PenumbraTransaction, PenumbraAction, PenumbraActionBuilder};
-pub use networks::zcash::{ZcashAddress, ZcashTransaction, ZcashAction, ZcashActionBuilder};
+pub use networks::zcash::{
+    ZcashAddress, ZcashTransaction, ZcashAction, ZcashActionBuilder, ZcashPayment, ZcashSpendPlan,
+};
+#[cfg(feature = "zcash-builder")]
+pub use networks::orchard_tx::{OrchardTxBuilder, UnsignedOrchardTx, SpendAuthRequest};
 pub use networks::cosmos::{CosmosAddress, CosmosTransaction, CosmosAction, CosmosActionBuilder, Coin};
 
 // offchain worker (network isolation boundary)
@@ -0,0 +1,732 @@
+//! lightwalletd client
+//!
+//! speaks the CompactTxStreamer grpc protocol (zcash/lightwalletd
+//! walletrpc/service.proto) used by every zcash light wallet:
+//!
+//! - `GetLatestBlock`: chain tip
+//! - `GetBlockRange`: compact blocks, streamed, fed to [`Scanner::scan_blocks`]
+//! - `SendTransaction`: broadcast a signed raw transaction
+//! - `GetTransaction`: raw transaction and mined height by txid
+//!
+//! messages are declared by hand with prost derives (only the subset we
+//! use), so no protoc is needed at build time. unknown fields such as
+//! sapling outputs are skipped by prost when decoding.
+//!
+//! # tree positions
+//!
+//! orchard note positions come from `chainMetadata.orchardCommitmentTreeSize`
+//! (the tree size after the block), which lightwalletd fills since v0.4.9.
+//!
+//! # txids
+//!
+//! [`TxHash`] values are txids in internal byte order, as they appear in
+//! `TxFilter.hash`. lightwalletd reports the txid of a sent transaction as
+//! display-order hex in `SendResponse.errorMessage`, which is reversed here.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use tonic::codec::ProstCodec;
+use tonic::codegen::http::uri::PathAndQuery;
+use tonic::transport::{Channel, Endpoint};
+use tonic::{Code, Request, Status};
+
+use crate::networks::zcash::ZcashError;
+use crate::scanner::{CompactBlock, CompactOutput, ScanResult, Scanner};
+use crate::traits::{TxHash, TxStatus};
+use crate::wire::Hash32;
+
+/// grpc service name
+pub const SERVICE: &str = "cash.z.wallet.sdk.rpc.CompactTxStreamer";
+
+const GET_LATEST_BLOCK: &str = "/cash.z.wallet.sdk.rpc.CompactTxStreamer/GetLatestBlock";
+const GET_BLOCK_RANGE: &str = "/cash.z.wallet.sdk.rpc.CompactTxStreamer/GetBlockRange";
+const SEND_TRANSACTION: &str = "/cash.z.wallet.sdk.rpc.CompactTxStreamer/SendTransaction";
+const GET_TRANSACTION: &str = "/cash.z.wallet.sdk.rpc.CompactTxStreamer/GetTransaction";
+
+/// CompactTxStreamer messages (package cash.z.wallet.sdk.rpc)
+pub mod proto {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    /// block height and hash
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct BlockId {
+        #[prost(uint64, tag = "1")]
+        pub height: u64,
+        #[prost(bytes = "vec", tag = "2")]
+        pub hash: Vec<u8>,
+    }
+
+    /// inclusive block range
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct BlockRange {
+        #[prost(message, optional, tag = "1")]
+        pub start: Option<BlockId>,
+        #[prost(message, optional, tag = "2")]
+        pub end: Option<BlockId>,
+    }
+
+    /// empty chain selector
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct ChainSpec {}
+
+    /// transaction lookup (by hash, or block + index)
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct TxFilter {
+        #[prost(message, optional, tag = "1")]
+        pub block: Option<BlockId>,
+        #[prost(uint64, tag = "2")]
+        pub index: u64,
+        #[prost(bytes = "vec", tag = "3")]
+        pub hash: Vec<u8>,
+    }
+
+    /// raw transaction; height 0 means mempool
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct RawTransaction {
+        #[prost(bytes = "vec", tag = "1")]
+        pub data: Vec<u8>,
+        #[prost(uint64, tag = "2")]
+        pub height: u64,
+    }
+
+    /// broadcast result; error_message holds the txid on success
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct SendResponse {
+        #[prost(int32, tag = "1")]
+        pub error_code: i32,
+        #[prost(string, tag = "2")]
+        pub error_message: String,
+    }
+
+    /// compact block
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct CompactBlock {
+        #[prost(uint32, tag = "1")]
+        pub proto_version: u32,
+        #[prost(uint64, tag = "2")]
+        pub height: u64,
+        #[prost(bytes = "vec", tag = "3")]
+        pub hash: Vec<u8>,
+        #[prost(bytes = "vec", tag = "4")]
+        pub prev_hash: Vec<u8>,
+        #[prost(uint32, tag = "5")]
+        pub time: u32,
+        #[prost(bytes = "vec", tag = "6")]
+        pub header: Vec<u8>,
+        #[prost(message, repeated, tag = "7")]
+        pub vtx: Vec<CompactTx>,
+        #[prost(message, optional, tag = "8")]
+        pub chain_metadata: Option<ChainMetadata>,
+    }
+
+    /// commitment tree sizes after the block
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct ChainMetadata {
+        #[prost(uint32, tag = "1")]
+        pub sapling_commitment_tree_size: u32,
+        #[prost(uint32, tag = "2")]
+        pub orchard_commitment_tree_size: u32,
+    }
+
+    /// compact transaction (sapling fields omitted)
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct CompactTx {
+        #[prost(uint64, tag = "1")]
+        pub index: u64,
+        #[prost(bytes = "vec", tag = "2")]
+        pub hash: Vec<u8>,
+        #[prost(uint32, tag = "3")]
+        pub fee: u32,
+        #[prost(message, repeated, tag = "6")]
+        pub actions: Vec<CompactOrchardAction>,
+    }
+
+    /// compact orchard action (52-byte ciphertext prefix)
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct CompactOrchardAction {
+        #[prost(bytes = "vec", tag = "1")]
+        pub nullifier: Vec<u8>,
+        #[prost(bytes = "vec", tag = "2")]
+        pub cmx: Vec<u8>,
+        #[prost(bytes = "vec", tag = "3")]
+        pub ephemeral_key: Vec<u8>,
+        #[prost(bytes = "vec", tag = "4")]
+        pub ciphertext: Vec<u8>,
+    }
+}
+
+fn hash32(bytes: &[u8]) -> Option<Hash32> {
+    bytes.try_into().ok()
+}
+
+fn grpc_error(status: Status) -> ZcashError {
+    ZcashError::Grpc(format!("{:?}: {}", status.code(), status.message()))
+}
+
+/// convert a lightwalletd compact block for the scanner
+///
+/// every orchard action becomes an output (its nullifier is the new note's
+/// rho) and reveals a spent nullifier. a block with a malformed action,
+/// without chain metadata, or without a 32-byte hash is refused: note
+/// positions come from the orchard tree size and every commitment must
+/// reach the witness tree, so guessing or skipping would corrupt every
+/// witness built on top.
+pub fn to_compact_block(block: &proto::CompactBlock) -> Result<CompactBlock, ZcashError> {
+    let malformed = |what: &str| ZcashError::MalformedBlock(format!("block {}: {}", block.height, what));
+
+    let hash = hash32(&block.hash).ok_or_else(|| malformed("hash is not 32 bytes"))?;
+    let actions: Vec<&proto::CompactOrchardAction> =
+        block.vtx.iter().flat_map(|tx| tx.actions.iter()).collect();
+    let tree_size = block
+        .chain_metadata
+        .as_ref()
+        .ok_or_else(|| malformed("missing chain metadata"))?
+        .orchard_commitment_tree_size as u64;
+    let first_position = tree_size
+        .checked_sub(actions.len() as u64)
+        .ok_or_else(|| malformed("orchard tree size is smaller than its actions"))?;
+
+    let mut outputs = Vec::with_capacity(actions.len());
+    let mut nullifiers = Vec::with_capacity(actions.len());
+    for (i, action) in actions.iter().enumerate() {
+        let (Some(nullifier), Some(commitment), Some(ephemeral_key)) = (
+            hash32(&action.nullifier),
+            hash32(&action.cmx),
+            hash32(&action.ephemeral_key),
+        ) else {
+            return Err(malformed(&format!("orchard action {} is malformed", i)));
+        };
+        nullifiers.push(nullifier);
+        outputs.push(CompactOutput {
+            commitment,
+            ephemeral_key,
+            ciphertext: action.ciphertext.clone(),
+            nullifier,
+            position: first_position + i as u64,
+        });
+    }
+
+    Ok(CompactBlock {
+        height: block.height,
+        hash,
+        outputs,
+        nullifiers,
+        tree_updates: None,
+    })
+}
+
+/// async CompactTxStreamer client
+#[derive(Clone)]
+pub struct LightwalletdClient {
+    grpc: tonic::client::Grpc<Channel>,
+}
+
+impl fmt::Debug for LightwalletdClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LightwalletdClient").finish_non_exhaustive()
+    }
+}
+
+impl LightwalletdClient {
+    /// connect to a lightwalletd endpoint (e.g. "https://zec.rocks:443")
+    pub async fn connect(endpoint: impl Into<String>) -> Result<Self, ZcashError> {
+        let channel = Endpoint::from_shared(endpoint.into())
+            .map_err(|e| ZcashError::Grpc(e.to_string()))?
+            .connect()
+            .await
+            .map_err(|e| ZcashError::Grpc(e.to_string()))?;
+        Ok(Self::from_channel(channel))
+    }
+
+    /// wrap an existing channel
+    pub fn from_channel(channel: Channel) -> Self {
+        Self { grpc: tonic::client::Grpc::new(channel) }
+    }
+
+    async fn ready(&mut self) -> Result<(), ZcashError> {
+        self.grpc.ready().await.map_err(|e| ZcashError::Grpc(e.to_string()))
+    }
+
+    async fn unary<Req, Resp>(&mut self, path: &'static str, request: Req) -> Result<Resp, Status>
+    where
+        Req: prost::Message + Send + Sync + 'static,
+        Resp: prost::Message + Default + Send + Sync + 'static,
+    {
+        self.grpc.ready().await.map_err(|e| Status::unavailable(e.to_string()))?;
+        let response = self
+            .grpc
+            .unary(Request::new(request), PathAndQuery::from_static(path), ProstCodec::default())
+            .await?;
+        Ok(response.into_inner())
+    }
+
+    /// chain tip
+    pub async fn latest_block(&mut self) -> Result<proto::BlockId, ZcashError> {
+        self.unary(GET_LATEST_BLOCK, proto::ChainSpec {}).await.map_err(grpc_error)
+    }
+
+    /// compact blocks in `start..=end`
+    pub async fn block_range(&mut self, start: u64, end: u64) -> Result<Vec<proto::CompactBlock>, ZcashError> {
+        self.ready().await?;
+        let range = proto::BlockRange {
+            start: Some(proto::BlockId { height: start, hash: Vec::new() }),
+            end: Some(proto::BlockId { height: end, hash: Vec::new() }),
+        };
+        let mut stream = self
+            .grpc
+            .server_streaming(
+                Request::new(range),
+                PathAndQuery::from_static(GET_BLOCK_RANGE),
+                ProstCodec::<proto::BlockRange, proto::CompactBlock>::default(),
+            )
+            .await
+            .map_err(grpc_error)?
+            .into_inner();
+
+        let mut blocks = Vec::new();
+        while let Some(block) = stream.message().await.map_err(grpc_error)? {
+            blocks.push(block);
+        }
+        Ok(blocks)
+    }
+
+    /// compact blocks in `start..=end`, converted for the scanner
+    pub async fn compact_blocks(&mut self, start: u64, end: u64) -> Result<Vec<CompactBlock>, ZcashError> {
+        self.block_range(start, end).await?.iter().map(to_compact_block).collect()
+    }
+
+    /// fetch `start..=end` and run it through the scanner
+    pub async fn scan(&mut self, scanner: &mut Scanner, start: u64, end: u64) -> Result<ScanResult, ZcashError> {
+        let blocks = self.compact_blocks(start, end).await?;
+        Ok(scanner.scan_blocks(&blocks))
+    }
+
+    /// broadcast a signed raw transaction, returning its txid
+    pub async fn send_transaction(&mut self, raw: Vec<u8>) -> Result<TxHash, ZcashError> {
+        let response: proto::SendResponse = self
+            .unary(SEND_TRANSACTION, proto::RawTransaction { data: raw, height: 0 })
+            .await
+            .map_err(grpc_error)?;
+        if response.error_code != 0 {
+            return Err(ZcashError::InvalidTransaction(format!(
+                "{} ({})",
+                response.error_message, response.error_code
+            )));
+        }
+
+        // display-order hex -> internal byte order
+        let mut txid: TxHash = hex::decode(response.error_message.trim().trim_matches('"'))
+            .ok()
+            .and_then(|b| b.try_into().ok())
+            .ok_or_else(|| ZcashError::Grpc(format!("unexpected send response: {}", response.error_message)))?;
+        txid.reverse();
+        Ok(txid)
+    }
+
+    /// raw transaction by txid, None if lightwalletd doesn't know it
+    pub async fn transaction(&mut self, txid: &TxHash) -> Result<Option<proto::RawTransaction>, ZcashError> {
+        let filter = proto::TxFilter { block: None, index: 0, hash: txid.to_vec() };
+        match self.unary(GET_TRANSACTION, filter).await {
+            Ok(tx) => Ok(Some(tx)),
+            Err(status) if matches!(status.code(), Code::NotFound | Code::InvalidArgument) => Ok(None),
+            Err(status) => Err(grpc_error(status)),
+        }
+    }
+
+    /// confirmation status of a transaction
+    pub async fn tx_status(&mut self, txid: &TxHash) -> Result<TxStatus, ZcashError> {
+        Ok(match self.transaction(txid).await? {
+            None => TxStatus::Unknown,
+            // mempool transactions report height 0 (older servers: u64::MAX)
+            Some(tx) if tx.height == 0 || tx.height == u64::MAX => TxStatus::Pending,
+            Some(tx) => TxStatus::Confirmed { height: tx.height },
+        })
+    }
+}
+
+/// lightwalletd client with its own single-threaded runtime, for the
+/// synchronous [`NetworkAdapter`](crate::traits::NetworkAdapter) interface
+///
+/// must not be used from inside another tokio runtime; async callers
+/// should use [`LightwalletdClient`] directly.
+pub struct BlockingLightwalletd {
+    runtime: tokio::runtime::Runtime,
+    client: LightwalletdClient,
+}
+
+impl fmt::Debug for BlockingLightwalletd {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BlockingLightwalletd").finish_non_exhaustive()
+    }
+}
+
+impl BlockingLightwalletd {
+    /// connect to a lightwalletd endpoint
+    pub fn connect(endpoint: impl Into<String>) -> Result<Self, ZcashError> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| ZcashError::Grpc(e.to_string()))?;
+        let client = runtime.block_on(LightwalletdClient::connect(endpoint))?;
+        Ok(Self { runtime, client })
+    }
+
+    /// run a request on the client
+    pub fn call<T, F>(&self, f: impl FnOnce(LightwalletdClient) -> F) -> Result<T, ZcashError>
+    where
+        F: core::future::Future<Output = Result<T, ZcashError>>,
+    {
+        // the channel is cheap to clone and shares the connection
+        self.runtime.block_on(f(self.client.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::networks::zcash::{ZcashAdapter, ZcashTransaction};
+    use crate::scanner::{FullViewingKey, ScannerConfig};
+    use crate::traits::NetworkAdapter;
+    use alloc::collections::BTreeMap;
+    use alloc::sync::Arc;
+    use alloc::vec;
+    use core::convert::Infallible;
+    use core::pin::Pin;
+    use core::task::{Context, Poll};
+    use sha2::{Digest, Sha256};
+    use std::sync::Mutex;
+    use tonic::codegen::{http, Body, BoxFuture, Service, StdError};
+    use tonic::server::{NamedService, ServerStreamingService, UnaryService};
+
+    /// in-memory chain served by the mock
+    #[derive(Default)]
+    struct MockChain {
+        blocks: Vec<proto::CompactBlock>,
+        /// txid -> (raw, height), height 0 while in mempool
+        txs: BTreeMap<TxHash, (Vec<u8>, u64)>,
+    }
+
+    impl MockChain {
+        /// append a block with `actions` orchard actions
+        fn mine(&mut self, actions: Vec<proto::CompactOrchardAction>) {
+            let height = 2_000_000 + self.blocks.len() as u64;
+            let prev = self.blocks.last();
+            let tree_size = prev
+                .and_then(|b| b.chain_metadata.as_ref())
+                .map_or(0, |m| m.orchard_commitment_tree_size)
+                + actions.len() as u32;
+            let block = proto::CompactBlock {
+                proto_version: 1,
+                height,
+                hash: vec![height as u8; 32],
+                prev_hash: prev.map(|b| b.hash.clone()).unwrap_or_default(),
+                time: 0,
+                header: vec![],
+                vtx: vec![proto::CompactTx { index: 0, hash: vec![0u8; 32], fee: 10_000, actions }],
+                chain_metadata: Some(proto::ChainMetadata {
+                    sapling_commitment_tree_size: 0,
+                    orchard_commitment_tree_size: tree_size,
+                }),
+            };
+            self.blocks.push(block);
+
+            // mempool transactions confirm in the new block
+            for (_, tx_height) in self.txs.values_mut().filter(|(_, h)| *h == 0) {
+                *tx_height = height;
+            }
+        }
+    }
+
+    /// in-process lightwalletd serving a [`MockChain`]
+    #[derive(Clone, Default)]
+    struct MockLightwalletd(Arc<Mutex<MockChain>>);
+
+    impl NamedService for MockLightwalletd {
+        const NAME: &'static str = SERVICE;
+    }
+
+    struct LatestBlock(MockLightwalletd);
+
+    impl UnaryService<proto::ChainSpec> for LatestBlock {
+        type Response = proto::BlockId;
+        type Future = BoxFuture<tonic::Response<proto::BlockId>, Status>;
+
+        fn call(&mut self, _: Request<proto::ChainSpec>) -> Self::Future {
+            let chain = self.0 .0.lock().unwrap();
+            let tip = chain.blocks.last().map(|b| proto::BlockId { height: b.height, hash: b.hash.clone() });
+            Box::pin(async move { tip.map(tonic::Response::new).ok_or_else(|| Status::unavailable("no blocks")) })
+        }
+    }
+
+    struct BlockRange(MockLightwalletd);
+
+    type BlockStream = Pin<Box<dyn tokio_stream::Stream<Item = Result<proto::CompactBlock, Status>> + Send>>;
+
+    impl ServerStreamingService<proto::BlockRange> for BlockRange {
+        type Response = proto::CompactBlock;
+        type ResponseStream = BlockStream;
+        type Future = BoxFuture<tonic::Response<BlockStream>, Status>;
+
+        fn call(&mut self, request: Request<proto::BlockRange>) -> Self::Future {
+            let range = request.into_inner();
+            let (start, end) = (range.start.map_or(0, |b| b.height), range.end.map_or(0, |b| b.height));
+            let blocks: Vec<_> = self.0 .0.lock().unwrap()
+                .blocks
+                .iter()
+                .filter(|b| b.height >= start && b.height <= end)
+                .cloned()
+                .map(Ok)
+                .collect();
+            Box::pin(async move {
+                let stream: BlockStream = Box::pin(tokio_stream::iter(blocks));
+                Ok(tonic::Response::new(stream))
+            })
+        }
+    }
+
+    struct SendTransaction(MockLightwalletd);
+
+    impl UnaryService<proto::RawTransaction> for SendTransaction {
+        type Response = proto::SendResponse;
+        type Future = BoxFuture<tonic::Response<proto::SendResponse>, Status>;
+
+        fn call(&mut self, request: Request<proto::RawTransaction>) -> Self::Future {
+            let raw = request.into_inner().data;
+            let response = if raw.is_empty() {
+                proto::SendResponse { error_code: -22, error_message: "TX decode failed".into() }
+            } else {
+                let txid: TxHash = Sha256::digest(Sha256::digest(&raw)).into();
+                self.0 .0.lock().unwrap().txs.insert(txid, (raw, 0));
+                let mut display = txid;
+                display.reverse();
+                proto::SendResponse { error_code: 0, error_message: hex::encode(display) }
+            };
+            Box::pin(async move { Ok(tonic::Response::new(response)) })
+        }
+    }
+
+    struct GetTransaction(MockLightwalletd);
+
+    impl UnaryService<proto::TxFilter> for GetTransaction {
+        type Response = proto::RawTransaction;
+        type Future = BoxFuture<tonic::Response<proto::RawTransaction>, Status>;
+
+        fn call(&mut self, request: Request<proto::TxFilter>) -> Self::Future {
+            let hash = request.into_inner().hash;
+            let found = hash32(&hash).and_then(|txid| self.0 .0.lock().unwrap().txs.get(&txid).cloned());
+            Box::pin(async move {
+                found
+                    .map(|(data, height)| tonic::Response::new(proto::RawTransaction { data, height }))
+                    .ok_or_else(|| Status::not_found("transaction not found"))
+            })
+        }
+    }
+
+    impl<B> Service<http::Request<B>> for MockLightwalletd
+    where
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+
+        fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let mock = self.clone();
+            match req.uri().path() {
+                GET_LATEST_BLOCK => Box::pin(async move {
+                    let mut grpc = tonic::server::Grpc::new(ProstCodec::default());
+                    Ok(grpc.unary(LatestBlock(mock), req).await)
+                }),
+                GET_BLOCK_RANGE => Box::pin(async move {
+                    let mut grpc = tonic::server::Grpc::new(ProstCodec::default());
+                    Ok(grpc.server_streaming(BlockRange(mock), req).await)
+                }),
+                SEND_TRANSACTION => Box::pin(async move {
+                    let mut grpc = tonic::server::Grpc::new(ProstCodec::default());
+                    Ok(grpc.unary(SendTransaction(mock), req).await)
+                }),
+                GET_TRANSACTION => Box::pin(async move {
+                    let mut grpc = tonic::server::Grpc::new(ProstCodec::default());
+                    Ok(grpc.unary(GetTransaction(mock), req).await)
+                }),
+                _ => Box::pin(async move { Ok(Status::unimplemented("").into_http()) }),
+            }
+        }
+    }
+
+    /// serve the mock on a random local port, returning its url
+    fn serve(mock: MockLightwalletd) -> (tokio::runtime::Runtime, String) {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let listener = runtime.block_on(tokio::net::TcpListener::bind("127.0.0.1:0")).unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        runtime.spawn(
+            tonic::transport::Server::builder()
+                .add_service(mock)
+                .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener)),
+        );
+        (runtime, url)
+    }
+
+    fn action(seed: u8) -> proto::CompactOrchardAction {
+        proto::CompactOrchardAction {
+            nullifier: vec![seed; 32],
+            cmx: vec![seed.wrapping_add(100); 32],
+            ephemeral_key: vec![seed.wrapping_add(200); 32],
+            ciphertext: vec![0u8; 52],
+        }
+    }
+
+    fn mock_chain() -> MockLightwalletd {
+        let mock = MockLightwalletd::default();
+        {
+            let mut chain = mock.0.lock().unwrap();
+            chain.mine(vec![action(1), action(2)]);
+            chain.mine(vec![]);
+            chain.mine(vec![action(3)]);
+        }
+        mock
+    }
+
+    #[test]
+    fn test_compact_block_conversion() {
+        let mut chain = MockChain::default();
+        chain.mine(vec![action(1)]);
+        chain.mine(vec![action(2), action(3)]);
+
+        let block = to_compact_block(&chain.blocks[1]).unwrap();
+        assert_eq!(block.height, 2_000_001);
+        assert_eq!(block.outputs.len(), 2);
+        assert_eq!(block.outputs[0].position, 1);
+        assert_eq!(block.outputs[1].position, 2);
+        assert_eq!(block.outputs[1].commitment, [103u8; 32]);
+        assert_eq!(block.outputs[1].nullifier, [3u8; 32]);
+        assert_eq!(block.nullifiers, vec![[2u8; 32], [3u8; 32]]);
+
+        // a malformed action would leave a hole in the witness tree
+        let mut bad = chain.blocks[0].clone();
+        bad.vtx[0].actions[0].cmx.truncate(31);
+        assert!(matches!(to_compact_block(&bad), Err(ZcashError::MalformedBlock(_))));
+
+        // positions cannot be placed without the tree size
+        let mut bad = chain.blocks[1].clone();
+        bad.chain_metadata = None;
+        assert!(matches!(to_compact_block(&bad), Err(ZcashError::MalformedBlock(_))));
+
+        let mut bad = chain.blocks[1].clone();
+        bad.chain_metadata.as_mut().unwrap().orchard_commitment_tree_size = 1;
+        assert!(matches!(to_compact_block(&bad), Err(ZcashError::MalformedBlock(_))));
+
+        let mut bad = chain.blocks[1].clone();
+        bad.hash.truncate(31);
+        assert!(matches!(to_compact_block(&bad), Err(ZcashError::MalformedBlock(_))));
+    }
+
+    #[tokio::test]
+    async fn test_client_block_range() {
+        let mock = mock_chain();
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(
+            tonic::transport::Server::builder()
+                .add_service(mock)
+                .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener)),
+        );
+
+        let mut client = LightwalletdClient::connect(url).await.unwrap();
+        assert_eq!(client.latest_block().await.unwrap().height, 2_000_002);
+
+        let blocks = client.compact_blocks(2_000_001, 2_000_002).await.unwrap();
+        assert_eq!(blocks.iter().map(|b| b.height).collect::<Vec<_>>(), vec![2_000_001, 2_000_002]);
+        assert_eq!(blocks[1].outputs[0].position, 2);
+    }
+
+    #[test]
+    fn test_adapter_against_mock() {
+        let mock = mock_chain();
+        let (_server, url) = serve(mock.clone());
+
+        let mut adapter = ZcashAdapter::mainnet(url);
+        assert!(!adapter.is_connected());
+        assert!(matches!(adapter.current_height(), Err(ZcashError::NotConnected)));
+        adapter.connect().unwrap();
+        assert!(adapter.is_connected());
+        assert_eq!(adapter.current_height().unwrap(), 2_000_002);
+
+        // scanned blocks reach the scanner, spends of our notes are seen
+        let fvk = FullViewingKey::zcash_orchard(vec![0u8; 96]);
+        let mut scanner = Scanner::new(fvk, ScannerConfig::zcash_orchard());
+        scanner.register_nullifiers([[3u8; 32]]);
+        let result = adapter.scan(&mut scanner, 2_000_000, 2_000_002).unwrap();
+        assert_eq!(result.blocks_scanned, 3);
+        assert_eq!(result.outputs_checked, 3);
+        assert_eq!(result.scan_height, 2_000_002);
+        assert_eq!(result.spent_nullifiers, vec![[3u8; 32]]);
+
+        // unsigned and unserialized transactions never leave the node
+        let mut tx = ZcashTransaction {
+            actions: vec![],
+            anchor: [0u8; 32],
+            binding_sig: None,
+            memo: None,
+            raw: Some(b"signed v5 tx".to_vec()),
+        };
+        assert!(matches!(adapter.submit(&tx), Err(ZcashError::NotSigned)));
+        tx.binding_sig = Some([1u8; 64]);
+
+        let txid = adapter.submit(&tx).unwrap();
+        let expected: TxHash = Sha256::digest(Sha256::digest(b"signed v5 tx")).into();
+        assert_eq!(txid, expected);
+        assert_eq!(adapter.tx_status(&txid).unwrap(), TxStatus::Pending);
+
+        mock.0.lock().unwrap().mine(vec![]);
+        assert_eq!(adapter.tx_status(&txid).unwrap(), TxStatus::Confirmed { height: 2_000_003 });
+        assert_eq!(adapter.tx_status(&[9u8; 32]).unwrap(), TxStatus::Unknown);
+
+        // lightwalletd rejections surface as invalid transactions
+        tx.raw = Some(vec![]);
+        assert!(matches!(adapter.submit(&tx), Err(ZcashError::InvalidTransaction(_))));
+        tx.raw = None;
+        assert!(matches!(adapter.submit(&tx), Err(ZcashError::InvalidTransaction(_))));
+    }
+
+    #[test]
+    fn test_adapter_refuses_malformed_action() {
+        let mock = mock_chain();
+        {
+            let mut chain = mock.0.lock().unwrap();
+            let mut bad = action(4);
+            bad.ephemeral_key.truncate(16);
+            chain.mine(vec![action(5), bad]);
+        }
+        let (_server, url) = serve(mock);
+
+        let mut adapter = ZcashAdapter::mainnet(url);
+        adapter.connect().unwrap();
+        let fvk = FullViewingKey::zcash_orchard(vec![0u8; 96]);
+        let mut scanner = Scanner::new(fvk, ScannerConfig::zcash_orchard());
+
+        // blocks before the bad one still scan, a range reaching it fails
+        assert_eq!(adapter.scan(&mut scanner, 2_000_000, 2_000_002).unwrap().blocks_scanned, 3);
+        assert!(matches!(
+            adapter.scan(&mut scanner, 2_000_002, 2_000_003),
+            Err(ZcashError::MalformedBlock(_))
+        ));
+    }
+
+    #[test]
+    fn test_adapter_connect_failure() {
+        // nothing listens on port 1
+        let mut adapter = ZcashAdapter::testnet("http://127.0.0.1:1");
+        assert!(matches!(adapter.connect(), Err(ZcashError::Grpc(_))));
+        assert!(!adapter.is_connected());
+    }
+}
@@ -29,6 +29,10 @@ pub mod polkadot;
 pub mod penumbra;
 pub mod zcash;
 pub mod cosmos;
+#[cfg(feature = "lightwalletd")]
+pub mod lightwalletd;
+#[cfg(feature = "zcash-builder")]
+pub mod orchard_tx;
 
 // re-export adapters
 pub use polkadot::PolkadotAdapter;
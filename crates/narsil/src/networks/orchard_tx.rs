@@ -0,0 +1,530 @@
+//! orchard transaction building for zcash syndicates
+//!
+//! turns a [`ZcashSpendPlan`] into a v5 transaction through the pczt
+//! pipeline: builder → creator → prover (halo2) → io finalizer. the
+//! syndicate has no spending key, so signing stops at the shielded sighash
+//! and the per-spend randomizers α. members run one redpallas frost round
+//! per spend and the aggregates are plugged back in as spendAuthSig.
+//!
+//! the fvk's ak is the syndicate's pallas group key, normalized to ỹ = 0 by
+//! `osst::redpallas::zcash::finalize_spend_auth` at dkg time.
+//!
+//! ```text
+//! ZcashSpendPlan ──build──▶ UnsignedOrchardTx ──sighash, α_i──▶ members
+//!                                  │                               │
+//!                                  │        sign_randomized        │
+//!                                  │    aggregate_randomized       │
+//!                                  ▼                               │
+//!                          apply_signatures ◀── [u8; 64] per spend ┘
+//!                                  │
+//!                                  ▼
+//!                          ZcashTransaction { raw, .. } ──▶ submit
+//! ```
+//!
+//! fees follow [`super::zcash::zip317`]; the builder checks the plan's fee
+//! against zcash_primitives' own zip-317 rule, so a mispriced plan fails
+//! here rather than at the mempool.
+
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use std::sync::OnceLock;
+
+use ff::PrimeField;
+use orchard::circuit::{ProvingKey, VerifyingKey};
+use orchard::keys::{FullViewingKey, Scope};
+use orchard::note::{ExtractedNoteCommitment, RandomSeed, Rho};
+use orchard::tree::{Anchor, MerkleHashOrchard, MerklePath};
+use orchard::value::NoteValue;
+use orchard::{Address, Note};
+use osst::{OsstCurve, OsstPoint, OsstScalar, PallasCurve};
+use pczt::roles::creator::Creator;
+use pczt::roles::io_finalizer::IoFinalizer;
+use pczt::roles::prover::Prover;
+use pczt::roles::signer::Signer;
+use pczt::roles::tx_extractor::TransactionExtractor;
+use rand_core::OsRng;
+use zcash_primitives::transaction::builder::{BuildConfig, Builder};
+use zcash_primitives::transaction::fees::zip317::FeeRule;
+use zcash_protocol::consensus::{BlockHeight, MainNetwork, TestNetwork};
+use zcash_protocol::memo::MemoBytes;
+use zcash_protocol::value::Zatoshis;
+
+use super::zcash::{OrchardAction, ZcashError, ZcashNetwork, ZcashSpendPlan, ZcashTransaction};
+use crate::wallet::{NoteWitness, ShieldedNote, SyndicateWallet};
+use crate::wire::Hash32;
+
+/// orchard commitment tree depth
+const ORCHARD_DEPTH: usize = 32;
+
+/// halo2 keys are expensive to build, share them per process
+fn proving_key() -> &'static ProvingKey {
+    static PK: OnceLock<ProvingKey> = OnceLock::new();
+    PK.get_or_init(ProvingKey::build)
+}
+
+fn verifying_key() -> &'static VerifyingKey {
+    static VK: OnceLock<VerifyingKey> = OnceLock::new();
+    VK.get_or_init(VerifyingKey::build)
+}
+
+fn build_err(context: &str, e: impl core::fmt::Debug) -> ZcashError {
+    ZcashError::Build(format!("{}: {:?}", context, e))
+}
+
+/// one spend awaiting a threshold signature
+#[derive(Clone, Debug)]
+pub struct SpendAuthRequest {
+    /// action index in the orchard bundle
+    pub action_index: usize,
+    /// spend auth randomizer α (rk = vk + [α]G_SpendAuth)
+    pub randomizer: [u8; 32],
+    /// nullifier revealed by this spend
+    pub nullifier: Hash32,
+}
+
+impl SpendAuthRequest {
+    /// α as a pallas scalar, for `osst::redpallas::zcash::RedPallasPackage::randomized`
+    pub fn randomizer_scalar(&self) -> Option<<PallasCurve as OsstCurve>::Scalar> {
+        <PallasCurve as OsstCurve>::Scalar::from_canonical_bytes(&self.randomizer)
+    }
+}
+
+/// proven transaction waiting for spend authorization signatures
+#[derive(Clone, Debug)]
+pub struct UnsignedOrchardTx {
+    /// serialized pczt (proofs included, spendAuthSigs missing)
+    pub pczt: Vec<u8>,
+    /// shielded sighash every spend signs
+    pub sighash: [u8; 32],
+    /// one request per real (non-dummy) spend
+    pub spends: Vec<SpendAuthRequest>,
+    /// zip-317 fee paid
+    pub fee: u64,
+}
+
+impl UnsignedOrchardTx {
+    /// message for the threshold signing round
+    pub fn signing_message(&self) -> &[u8] {
+        &self.sighash
+    }
+
+    /// plug in aggregated redpallas signatures (R || z) and extract the tx
+    ///
+    /// `signatures` parallels [`Self::spends`].
+    pub fn apply_signatures(&self, signatures: &[[u8; 64]]) -> Result<ZcashTransaction, ZcashError> {
+        use orchard::primitives::redpallas::{Signature, SpendAuth};
+
+        if signatures.len() != self.spends.len() {
+            return Err(ZcashError::NotSigned);
+        }
+
+        let pczt = pczt::Pczt::parse(&self.pczt).map_err(|e| build_err("pczt parse", e))?;
+        let mut signer = Signer::new(pczt).map_err(|e| build_err("signer", e))?;
+        for (spend, sig) in self.spends.iter().zip(signatures) {
+            signer
+                .apply_orchard_signature(spend.action_index, Signature::<SpendAuth>::from(*sig))
+                .map_err(|e| build_err("spend auth signature", e))?;
+        }
+
+        let tx = TransactionExtractor::new(signer.finish())
+            .with_orchard(verifying_key())
+            .extract()
+            .map_err(|e| build_err("extract", e))?;
+
+        let bundle = tx
+            .orchard_bundle()
+            .ok_or_else(|| ZcashError::Build("transaction has no orchard bundle".to_string()))?;
+        let actions = bundle
+            .actions()
+            .iter()
+            .map(|action| OrchardAction {
+                nullifier: action.nullifier().to_bytes(),
+                cmx: action.cmx().to_bytes(),
+                encrypted_note: action.encrypted_note().enc_ciphertext.to_vec(),
+            })
+            .collect();
+        let anchor = bundle.anchor().to_bytes();
+        let binding_sig = <[u8; 64]>::from(bundle.authorization().binding_signature());
+
+        let mut raw = Vec::new();
+        tx.write(&mut raw).map_err(|e| build_err("serialize", e))?;
+
+        Ok(ZcashTransaction {
+            actions,
+            anchor,
+            binding_sig: Some(binding_sig),
+            memo: None,
+            raw: Some(raw),
+        })
+    }
+}
+
+/// builds proven orchard transactions for a syndicate wallet
+pub struct OrchardTxBuilder {
+    fvk: FullViewingKey,
+    network: ZcashNetwork,
+}
+
+impl OrchardTxBuilder {
+    /// create builder from the syndicate's orchard fvk (ak || nk || rivk)
+    ///
+    /// `group_pubkey` is the syndicate's spend auth key; the fvk must carry
+    /// it as ak, or the threshold signatures would authorize nothing.
+    pub fn new(
+        fvk: &[u8; 96],
+        group_pubkey: &<PallasCurve as OsstCurve>::Point,
+        network: ZcashNetwork,
+    ) -> Result<Self, ZcashError> {
+        let ak = group_pubkey.compress();
+        if ak[31] >> 7 != 0 {
+            return Err(ZcashError::Build("group key has ỹ = 1, not a valid ak".to_string()));
+        }
+        if fvk[..32] != ak {
+            return Err(ZcashError::Build("fvk ak is not the syndicate group key".to_string()));
+        }
+        let fvk = FullViewingKey::from_bytes(fvk)
+            .ok_or_else(|| ZcashError::Build("invalid orchard fvk".to_string()))?;
+        Ok(Self { fvk, network })
+    }
+
+    /// build and prove the transaction for `plan`
+    ///
+    /// every selected note needs a cached witness in `wallet`, all at the
+    /// same anchor. proving takes seconds; run it off any async executor.
+    pub fn build(
+        &self,
+        wallet: &SyndicateWallet,
+        plan: &ZcashSpendPlan,
+        target_height: u32,
+    ) -> Result<UnsignedOrchardTx, ZcashError> {
+        if plan.notes.is_empty() {
+            return Err(ZcashError::InsufficientFunds);
+        }
+
+        let mut anchor = None;
+        let mut spends = Vec::with_capacity(plan.notes.len());
+        for note in &plan.notes {
+            let witness = wallet.get_witness(&note.commitment).ok_or_else(|| {
+                ZcashError::Build(format!("no witness for note at {}", note.position))
+            })?;
+            if *anchor.get_or_insert(witness.anchor) != witness.anchor {
+                return Err(ZcashError::Build("witnesses disagree on anchor".to_string()));
+            }
+            spends.push((self.note(note)?, merkle_path(witness)?));
+        }
+        let anchor = Option::from(Anchor::from_bytes(anchor.unwrap_or_default()))
+            .ok_or_else(|| ZcashError::Build("invalid anchor".to_string()))?;
+
+        let mut outputs = Vec::with_capacity(plan.outputs());
+        for payment in &plan.payments {
+            let receiver = payment.to.orchard.ok_or_else(|| {
+                ZcashError::InvalidTransaction("orchard receiver required".to_string())
+            })?;
+            let address = Option::from(Address::from_raw_address_bytes(&receiver))
+                .ok_or_else(|| ZcashError::InvalidTransaction("invalid orchard receiver".to_string()))?;
+            let memo = match &payment.memo {
+                Some(m) => MemoBytes::from_bytes(m).map_err(|e| build_err("memo", e))?,
+                None => MemoBytes::empty(),
+            };
+            outputs.push((Scope::External, address, payment.amount, memo));
+        }
+        if let Some(change) = plan.change {
+            let address = self.fvk.address_at(0u64, Scope::Internal);
+            outputs.push((Scope::Internal, address, change, MemoBytes::empty()));
+        }
+
+        let config = BuildConfig::Standard {
+            sapling_anchor: None,
+            orchard_anchor: Some(anchor),
+        };
+        let target = BlockHeight::from(target_height);
+
+        macro_rules! run_builder {
+            ($params:expr) => {{
+                let mut builder = Builder::new($params, target, config);
+                for (note, path) in &spends {
+                    builder
+                        .add_orchard_spend::<()>(self.fvk.clone(), *note, path.clone())
+                        .map_err(|e| build_err("orchard spend", e))?;
+                }
+                for (scope, address, amount, memo) in &outputs {
+                    let value = Zatoshis::from_u64(*amount).map_err(|e| build_err("output value", e))?;
+                    let ovk = self.fvk.to_ovk(*scope);
+                    builder
+                        .add_orchard_output::<()>(Some(ovk), *address, value, memo.clone())
+                        .map_err(|e| build_err("orchard output", e))?;
+                }
+                let fee = builder
+                    .get_fee(&FeeRule::standard())
+                    .map_err(|e| build_err("fee", e))?;
+                if u64::from(fee) != plan.fee {
+                    return Err(ZcashError::Build(format!(
+                        "plan fee {} does not match zip-317 fee {}",
+                        plan.fee,
+                        u64::from(fee)
+                    )));
+                }
+                builder
+                    .build_for_pczt(OsRng, &FeeRule::standard())
+                    .map_err(|e| build_err("build", e))?
+                    .pczt_parts
+            }};
+        }
+
+        let parts = match self.network {
+            ZcashNetwork::Mainnet => run_builder!(MainNetwork),
+            ZcashNetwork::Testnet => run_builder!(TestNetwork),
+        };
+
+        let mut requests = Vec::new();
+        if let Some(bundle) = &parts.orchard {
+            for (i, action) in bundle.actions().iter().enumerate() {
+                if action.spend().dummy_sk().is_some() {
+                    continue;
+                }
+                if let Some(alpha) = action.spend().alpha() {
+                    requests.push(SpendAuthRequest {
+                        action_index: i,
+                        randomizer: alpha.to_repr(),
+                        nullifier: action.spend().nullifier().to_bytes(),
+                    });
+                }
+            }
+        }
+
+        let pczt = Creator::build_from_parts(parts)
+            .ok_or_else(|| ZcashError::Build("pczt creator failed".to_string()))?;
+        let pczt = Prover::new(pczt)
+            .create_orchard_proof(proving_key())
+            .map_err(|e| build_err("orchard proof", e))?
+            .finish();
+        let pczt = IoFinalizer::new(pczt)
+            .finalize_io()
+            .map_err(|e| build_err("io finalizer", e))?;
+        let pczt = pczt.serialize();
+
+        // sighash is taken from the serialized form members will verify
+        let sighash = Signer::new(pczt::Pczt::parse(&pczt).map_err(|e| build_err("pczt parse", e))?)
+            .map_err(|e| build_err("signer", e))?
+            .shielded_sighash();
+
+        Ok(UnsignedOrchardTx {
+            pczt,
+            sighash,
+            spends: requests,
+            fee: plan.fee,
+        })
+    }
+
+    /// rebuild an orchard note from wallet fields, checking its commitment
+    fn note(&self, note: &ShieldedNote) -> Result<Note, ZcashError> {
+        let invalid = |what: &str| ZcashError::Build(format!("note at {}: {}", note.position, what));
+
+        let recipient: [u8; 43] = note
+            .recipient
+            .as_slice()
+            .try_into()
+            .map_err(|_| invalid("recipient must be 43 bytes"))?;
+        let recipient = Option::from(Address::from_raw_address_bytes(&recipient))
+            .ok_or_else(|| invalid("invalid recipient"))?;
+        let value = u64::try_from(note.amount).map_err(|_| invalid("value exceeds u64"))?;
+        let rho = Option::from(Rho::from_bytes(&note.rho)).ok_or_else(|| invalid("invalid rho"))?;
+        let rseed = Option::from(RandomSeed::from_bytes(note.rseed, &rho))
+            .ok_or_else(|| invalid("invalid rseed"))?;
+        let rebuilt: Note = Option::from(Note::from_parts(recipient, NoteValue::from_raw(value), rho, rseed))
+            .ok_or_else(|| invalid("invalid note"))?;
+
+        if ExtractedNoteCommitment::from(rebuilt.commitment()).to_bytes() != note.commitment {
+            return Err(invalid("commitment mismatch"));
+        }
+        Ok(rebuilt)
+    }
+}
+
+/// convert a cached witness into an orchard merkle path
+fn merkle_path(witness: &NoteWitness) -> Result<MerklePath, ZcashError> {
+    if witness.auth_path.len() != ORCHARD_DEPTH {
+        return Err(ZcashError::Build(format!(
+            "witness depth {} != {}",
+            witness.auth_path.len(),
+            ORCHARD_DEPTH
+        )));
+    }
+    let position = u32::try_from(witness.position)
+        .map_err(|_| ZcashError::Build("witness position exceeds u32".to_string()))?;
+
+    let auth_path = witness
+        .auth_path
+        .iter()
+        .map(|sibling| {
+            Option::from(MerkleHashOrchard::from_bytes(sibling))
+                .ok_or_else(|| ZcashError::Build("invalid witness node".to_string()))
+        })
+        .collect::<Result<Vec<MerkleHashOrchard>, _>>()?;
+    let auth_path: [MerkleHashOrchard; ORCHARD_DEPTH] = auth_path
+        .try_into()
+        .map_err(|_| ZcashError::Build("invalid witness path".to_string()))?;
+    Ok(MerklePath::from_parts(position, auth_path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::networks::zcash::{ZcashAddress, ZcashPayment};
+    use crate::scanner::WitnessBuilder;
+    use crate::wallet::ShieldedChain;
+    use orchard::keys::SpendingKey;
+    use osst::dkg::{Aggregator, Dealer, DkgState};
+    use osst::redpallas::zcash::{self as redpallas, SpendAuthShare};
+    use zcash_primitives::transaction::Transaction;
+    use zcash_protocol::consensus::BranchId;
+
+    type Point = <PallasCurve as OsstCurve>::Point;
+
+    const TARGET_HEIGHT: u32 = 3_000_000;
+
+    /// 2-of-3 pallas dkg, normalized to a valid ak
+    fn dkg() -> Vec<SpendAuthShare> {
+        let dealers: Vec<Dealer<Point>> = (1..=3).map(|i| Dealer::new(i, 2, &mut OsRng)).collect();
+        let mut state: DkgState<Point> = DkgState::new(0, 2, 3);
+        for dealer in &dealers {
+            state.submit_commitment(dealer.commitment().clone()).unwrap();
+        }
+        state.close_complaints().unwrap();
+        (1..=3)
+            .map(|j| {
+                let mut agg = Aggregator::new(j);
+                for dealer in &dealers {
+                    agg.add_subshare(dealer.generate_subshare(j), dealer.commitment()).unwrap();
+                }
+                redpallas::finalize_spend_auth(&state, &agg).unwrap()
+            })
+            .collect()
+    }
+
+    /// fvk with the group key as ak, nk and rivk from a throwaway spending key
+    fn syndicate_fvk(ak: &Point) -> [u8; 96] {
+        let sk = Option::<SpendingKey>::from(SpendingKey::from_bytes([7u8; 32])).unwrap();
+        let mut fvk = FullViewingKey::from(&sk).to_bytes();
+        fvk[..32].copy_from_slice(&ak.compress());
+        fvk
+    }
+
+    /// a note to `address` at position 0 of an otherwise empty tree
+    fn funded_wallet(fvk: &FullViewingKey, value: u64) -> SyndicateWallet {
+        let address = fvk.address_at(0u64, Scope::External);
+        let rho = Option::<Rho>::from(Rho::from_bytes(&[0u8; 32])).unwrap();
+        let (rseed_bytes, rseed) = (1u8..)
+            .find_map(|b| Option::from(RandomSeed::from_bytes([b; 32], &rho)).map(|r| ([b; 32], r)))
+            .unwrap();
+        let note: Note =
+            Option::from(Note::from_parts(address, NoteValue::from_raw(value), rho, rseed)).unwrap();
+        let cmx = ExtractedNoteCommitment::from(note.commitment()).to_bytes();
+
+        let mut tree = WitnessBuilder::orchard();
+        tree.append(0, cmx, true).unwrap();
+        let witness = tree.build_witness(0, tree.root()).unwrap();
+
+        let mut wallet = SyndicateWallet::new([1u8; 32], ShieldedChain::ZcashOrchard, Vec::new());
+        wallet.add_note(ShieldedNote {
+            commitment: cmx,
+            nullifier: note.nullifier(fvk).to_bytes(),
+            amount: value as u128,
+            asset: [0u8; 32],
+            position: 0,
+            height: 1,
+            memo: None,
+            spent: false,
+            rseed: rseed_bytes,
+            recipient: address.to_raw_address_bytes().to_vec(),
+            rho: rho.to_bytes(),
+        });
+        wallet.cache_witness(cmx, witness);
+        wallet
+    }
+
+    #[test]
+    fn test_builder_requires_group_key_as_ak() {
+        let keys = dkg();
+        let fvk = syndicate_fvk(&keys[0].ak);
+        assert!(OrchardTxBuilder::new(&fvk, &keys[0].ak, ZcashNetwork::Testnet).is_ok());
+
+        let other = dkg();
+        assert!(OrchardTxBuilder::new(&fvk, &other[0].ak, ZcashNetwork::Testnet).is_err());
+        // the un-normalized encoding of the same key is refused outright
+        assert!(OrchardTxBuilder::new(&fvk, &-keys[0].ak, ZcashNetwork::Testnet).is_err());
+    }
+
+    /// dkg key → fvk → proven tx → threshold spend auth → verified bundle
+    #[test]
+    fn test_threshold_spend_end_to_end() {
+        let keys = dkg();
+        let ak = keys[0].ak;
+        let fvk_bytes = syndicate_fvk(&ak);
+        let builder = OrchardTxBuilder::new(&fvk_bytes, &ak, ZcashNetwork::Testnet).unwrap();
+        let fvk = FullViewingKey::from_bytes(&fvk_bytes).unwrap();
+
+        let wallet = funded_wallet(&fvk, 200_000);
+        let payee = FullViewingKey::from(
+            &Option::<SpendingKey>::from(SpendingKey::from_bytes([9u8; 32])).unwrap(),
+        )
+        .address_at(0u64, Scope::External)
+        .to_raw_address_bytes();
+        let plan = ZcashSpendPlan::select(
+            &wallet,
+            vec![ZcashPayment { to: ZcashAddress::orchard(payee), amount: 50_000, memo: None }],
+        )
+        .unwrap();
+
+        let unsigned = builder.build(&wallet, &plan, TARGET_HEIGHT).unwrap();
+        assert_eq!(unsigned.spends.len(), 1);
+
+        // members 2 and 3 sign each spend under its rk
+        let signers = &keys[1..];
+        let signatures: Vec<[u8; 64]> = unsigned
+            .spends
+            .iter()
+            .map(|spend| {
+                let alpha = spend.randomizer_scalar().unwrap();
+                let (nonces, commitments): (Vec<_>, Vec<_>) =
+                    signers.iter().map(|k| redpallas::commit(k.share.index, &mut OsRng)).unzip();
+                let package = redpallas::RedPallasPackage::randomized(
+                    unsigned.signing_message().to_vec(),
+                    commitments,
+                    alpha,
+                )
+                .unwrap();
+                let shares: Vec<_> = signers
+                    .iter()
+                    .zip(nonces)
+                    .map(|(k, n)| redpallas::sign_randomized(&package, n, &k.share, &ak).unwrap())
+                    .collect();
+                let signature = redpallas::aggregate_randomized(
+                    &package,
+                    &shares,
+                    &ak,
+                    Some(&keys[0].verifier_shares),
+                )
+                .unwrap();
+                signature.to_bytes_vec().try_into().unwrap()
+            })
+            .collect();
+
+        let signed = unsigned.apply_signatures(&signatures).unwrap();
+
+        let branch = BranchId::for_height(&TestNetwork, BlockHeight::from(TARGET_HEIGHT));
+        let tx = Transaction::read(signed.raw.as_deref().unwrap(), branch).unwrap();
+        let bundle = tx.orchard_bundle().unwrap();
+        assert!(bundle.verify_proof(verifying_key()).is_ok());
+        for spend in &unsigned.spends {
+            let action = bundle.actions().iter().nth(spend.action_index).unwrap();
+            assert_eq!(action.nullifier().to_bytes(), spend.nullifier);
+            assert!(action.rk().verify(&unsigned.sighash, action.authorization()).is_ok());
+        }
+        assert!(bundle
+            .binding_validating_key()
+            .verify(&unsigned.sighash, bundle.authorization().binding_signature())
+            .is_ok());
+    }
+}
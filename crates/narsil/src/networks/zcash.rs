@@ -13,12 +13,34 @@
 //!
 //! syndicate full viewing key = osst_group_key
 //! each spend requires threshold signature authorization.
+//!
+//! # chain access
+//!
+//! with the `lightwalletd` feature the adapter talks to a lightwalletd
+//! server (see [`super::lightwalletd`]) once [`ZcashAdapter::connect`]ed.
+//! without it, or before connecting, chain queries fail with
+//! [`ZcashError::NotConnected`].
+//!
+//! # spending
+//!
+//! [`ZcashSpendPlan::select`] picks notes from the syndicate wallet and
+//! prices the transaction with the [`zip317`] conventional fee. with the
+//! `zcash-builder` feature, [`super::orchard_tx::OrchardTxBuilder`] turns the
+//! plan into a proven v5 transaction whose spend authorizations come from
+//! a redpallas frost round over the syndicate's pallas key.
 
 use alloc::format;
 use alloc::string::String;
 use alloc::vec::Vec;
+#[cfg(feature = "lightwalletd")]
+use alloc::sync::Arc;
 
+#[cfg(feature = "lightwalletd")]
+use super::lightwalletd::{BlockingLightwalletd, LightwalletdClient};
+#[cfg(feature = "lightwalletd")]
+use crate::scanner::{ScanResult, Scanner};
 use crate::traits::{NetworkAdapter, ActionBuilder, TxHash, TxStatus};
+use crate::wallet::{ShieldedChain, ShieldedNote, SyndicateWallet};
 use crate::wire::Hash32;
 
 /// zcash unified address
@@ -79,6 +101,8 @@ pub struct ZcashTransaction {
     pub binding_sig: Option<[u8; 64]>,
     /// memo (512 bytes max)
     pub memo: Option<Vec<u8>>,
+    /// serialized v5 transaction, set once built and signed
+    pub raw: Option<Vec<u8>>,
 }
 
 /// orchard action (spend + output combined)
@@ -99,6 +123,9 @@ pub struct ZcashAdapter {
     endpoint: String,
     /// network (mainnet/testnet)
     network: ZcashNetwork,
+    /// lightwalletd connection (after connect)
+    #[cfg(feature = "lightwalletd")]
+    lightwalletd: Option<Arc<BlockingLightwalletd>>,
 }
 
 /// zcash network type
@@ -114,6 +141,8 @@ impl ZcashAdapter {
         Self {
             endpoint: endpoint.into(),
             network: ZcashNetwork::Mainnet,
+            #[cfg(feature = "lightwalletd")]
+            lightwalletd: None,
         }
     }
 
@@ -122,6 +151,8 @@ impl ZcashAdapter {
         Self {
             endpoint: endpoint.into(),
             network: ZcashNetwork::Testnet,
+            #[cfg(feature = "lightwalletd")]
+            lightwalletd: None,
         }
     }
 
@@ -134,6 +165,27 @@ impl ZcashAdapter {
     pub fn network(&self) -> ZcashNetwork {
         self.network
     }
+
+    /// connect to the lightwalletd endpoint
+    #[cfg(feature = "lightwalletd")]
+    pub fn connect(&mut self) -> Result<(), ZcashError> {
+        self.lightwalletd = Some(Arc::new(BlockingLightwalletd::connect(self.endpoint.clone())?));
+        Ok(())
+    }
+
+    #[cfg(feature = "lightwalletd")]
+    fn call<T, F>(&self, f: impl FnOnce(LightwalletdClient) -> F) -> Result<T, ZcashError>
+    where
+        F: core::future::Future<Output = Result<T, ZcashError>>,
+    {
+        self.lightwalletd.as_ref().ok_or(ZcashError::NotConnected)?.call(f)
+    }
+
+    /// fetch compact blocks `start..=end` and feed them to the scanner
+    #[cfg(feature = "lightwalletd")]
+    pub fn scan(&self, scanner: &mut Scanner, start: u64, end: u64) -> Result<ScanResult, ZcashError> {
+        self.call(|mut client| async move { client.scan(scanner, start, end).await })
+    }
 }
 
 impl NetworkAdapter for ZcashAdapter {
@@ -150,31 +202,50 @@ impl NetworkAdapter for ZcashAdapter {
     }
 
     fn is_connected(&self) -> bool {
-        // TODO: check grpc connection to lightwalletd
-        true
+        #[cfg(feature = "lightwalletd")]
+        return self.lightwalletd.is_some();
+        #[cfg(not(feature = "lightwalletd"))]
+        false
     }
 
     fn submit(&self, tx: &Self::Transaction) -> Result<TxHash, Self::Error> {
         if tx.binding_sig.is_none() {
             return Err(ZcashError::NotSigned);
         }
-        // TODO: submit via lightwalletd
-        Ok([0u8; 32])
+        let raw = tx
+            .raw
+            .clone()
+            .ok_or_else(|| ZcashError::InvalidTransaction("transaction not serialized".into()))?;
+
+        #[cfg(feature = "lightwalletd")]
+        return self.call(|mut client| async move { client.send_transaction(raw).await });
+        #[cfg(not(feature = "lightwalletd"))]
+        {
+            let _ = raw;
+            Err(ZcashError::NotConnected)
+        }
     }
 
-    fn tx_status(&self, _hash: &TxHash) -> Result<TxStatus, Self::Error> {
-        // TODO: query lightwalletd
-        Ok(TxStatus::Unknown)
+    fn tx_status(&self, hash: &TxHash) -> Result<TxStatus, Self::Error> {
+        #[cfg(feature = "lightwalletd")]
+        return self.call(|mut client| async move { client.tx_status(hash).await });
+        #[cfg(not(feature = "lightwalletd"))]
+        {
+            let _ = hash;
+            Err(ZcashError::NotConnected)
+        }
     }
 
     fn current_height(&self) -> Result<u64, Self::Error> {
-        // TODO: query lightwalletd
-        Ok(0)
+        #[cfg(feature = "lightwalletd")]
+        return self.call(|mut client| async move { Ok(client.latest_block().await?.height) });
+        #[cfg(not(feature = "lightwalletd"))]
+        Err(ZcashError::NotConnected)
     }
 
-    fn estimate_fee(&self, _tx: &Self::Transaction) -> Result<u64, Self::Error> {
-        // zcash has fixed fee (currently 1000 zatoshi for orchard)
-        Ok(1000)
+    fn estimate_fee(&self, tx: &Self::Transaction) -> Result<u64, Self::Error> {
+        // orchard-only: every action is one logical action
+        Ok(zip317::conventional_fee(tx.actions.len() as u64))
     }
 }
 
@@ -194,6 +265,160 @@ pub enum ZcashError {
     Grpc(String),
     InvalidTransaction(String),
     InsufficientFunds,
+    /// transaction building or proving failed
+    Build(String),
+    /// a compact block the scanner cannot place in the note tree
+    MalformedBlock(String),
+}
+
+/// zip-317 conventional fee
+///
+/// fee = marginal_fee * max(grace_actions, logical_actions), where logical
+/// actions count transparent inputs/outputs by standard p2pkh size, sapling
+/// spends/outputs pairwise, and every orchard action once.
+pub mod zip317 {
+    /// fee per logical action (zatoshi)
+    pub const MARGINAL_FEE: u64 = 5_000;
+    /// logical actions covered by the minimum fee
+    pub const GRACE_ACTIONS: u64 = 2;
+    /// standard p2pkh transparent input size (bytes)
+    pub const P2PKH_STANDARD_INPUT_SIZE: u64 = 150;
+    /// standard p2pkh transparent output size (bytes)
+    pub const P2PKH_STANDARD_OUTPUT_SIZE: u64 = 34;
+
+    /// count logical actions for a transaction
+    pub fn logical_actions(
+        transparent_input_size: u64,
+        transparent_output_size: u64,
+        sapling_spends: u64,
+        sapling_outputs: u64,
+        orchard_actions: u64,
+    ) -> u64 {
+        let transparent = transparent_input_size
+            .div_ceil(P2PKH_STANDARD_INPUT_SIZE)
+            .max(transparent_output_size.div_ceil(P2PKH_STANDARD_OUTPUT_SIZE));
+        transparent + sapling_spends.max(sapling_outputs) + orchard_actions
+    }
+
+    /// conventional fee for a number of logical actions
+    pub fn conventional_fee(logical_actions: u64) -> u64 {
+        MARGINAL_FEE.saturating_mul(logical_actions.max(GRACE_ACTIONS))
+    }
+
+    /// orchard actions needed for a bundle (each pairs one spend with one output)
+    pub fn orchard_actions(spends: usize, outputs: usize) -> u64 {
+        spends.max(outputs) as u64
+    }
+
+    /// fee for an orchard-only transaction
+    pub fn orchard_fee(spends: usize, outputs: usize) -> u64 {
+        conventional_fee(logical_actions(0, 0, 0, 0, orchard_actions(spends, outputs)))
+    }
+}
+
+/// one orchard payment in a spend plan
+#[derive(Clone, Debug)]
+pub struct ZcashPayment {
+    /// recipient (must have an orchard receiver)
+    pub to: ZcashAddress,
+    /// amount in zatoshi
+    pub amount: u64,
+    /// memo (512 bytes max)
+    pub memo: Option<Vec<u8>>,
+}
+
+/// notes to spend, payments to make, and the fee/change that balance them
+#[derive(Clone, Debug)]
+pub struct ZcashSpendPlan {
+    /// selected notes
+    pub notes: Vec<ShieldedNote>,
+    /// outgoing payments
+    pub payments: Vec<ZcashPayment>,
+    /// change back to the wallet, `None` if the notes cover the payments exactly
+    pub change: Option<u64>,
+    /// zip-317 fee
+    pub fee: u64,
+}
+
+impl ZcashSpendPlan {
+    /// select notes from an orchard wallet to cover `payments` plus fee
+    ///
+    /// takes the largest notes first, so the plan needs as few actions as
+    /// possible. any surplus goes to a change output, which is priced in.
+    pub fn select(
+        wallet: &SyndicateWallet,
+        payments: Vec<ZcashPayment>,
+    ) -> Result<Self, ZcashError> {
+        if wallet.chain != ShieldedChain::ZcashOrchard {
+            return Err(ZcashError::InvalidTransaction("not an orchard wallet".into()));
+        }
+        if !wallet.can_spend() {
+            return Err(ZcashError::InvalidTransaction("wallet cannot spend".into()));
+        }
+        if payments.is_empty() {
+            return Err(ZcashError::InvalidTransaction("no payments".into()));
+        }
+        for payment in &payments {
+            if payment.to.orchard.is_none() {
+                return Err(ZcashError::InvalidTransaction("orchard receiver required".into()));
+            }
+            if payment.amount == 0 {
+                return Err(ZcashError::InvalidTransaction("amount must be > 0".into()));
+            }
+            if payment.memo.as_ref().is_some_and(|m| m.len() > 512) {
+                return Err(ZcashError::InvalidTransaction("memo too long (max 512 bytes)".into()));
+            }
+        }
+        let total = payments
+            .iter()
+            .try_fold(0u64, |acc, p| acc.checked_add(p.amount))
+            .ok_or_else(|| ZcashError::InvalidTransaction("payment total overflows".into()))?;
+
+        // orchard notes are always ZEC
+        let mut candidates: Vec<&ShieldedNote> = wallet
+            .spendable_notes()
+            .into_iter()
+            .filter(|n| n.asset == [0u8; 32])
+            .collect();
+        candidates.sort_by(|a, b| b.amount.cmp(&a.amount).then(a.position.cmp(&b.position)));
+
+        let mut notes = Vec::new();
+        let mut value = 0u64;
+        for note in candidates {
+            let amount = u64::try_from(note.amount)
+                .map_err(|_| ZcashError::InvalidTransaction("note value exceeds u64".into()))?;
+            value = value
+                .checked_add(amount)
+                .ok_or_else(|| ZcashError::InvalidTransaction("input total overflows".into()))?;
+            notes.push(note.clone());
+
+            let exact_fee = zip317::orchard_fee(notes.len(), payments.len());
+            if Some(value) == total.checked_add(exact_fee) {
+                return Ok(Self { notes, payments, change: None, fee: exact_fee });
+            }
+            let fee = zip317::orchard_fee(notes.len(), payments.len() + 1);
+            if let Some(change) = total.checked_add(fee).and_then(|need| value.checked_sub(need)) {
+                return Ok(Self { notes, payments, change: Some(change), fee });
+            }
+        }
+
+        Err(ZcashError::InsufficientFunds)
+    }
+
+    /// total value of selected notes
+    pub fn total_in(&self) -> u64 {
+        self.notes.iter().map(|n| n.amount as u64).sum()
+    }
+
+    /// number of outputs, including change
+    pub fn outputs(&self) -> usize {
+        self.payments.len() + usize::from(self.change.is_some())
+    }
+
+    /// orchard actions the transaction will carry
+    pub fn actions(&self) -> u64 {
+        zip317::orchard_actions(self.notes.len(), self.outputs())
+    }
 }
 
 /// zcash action types
@@ -437,6 +662,103 @@ mod tests {
         assert!(builder.validate(&long_memo).is_err());
     }
 
+    fn orchard_wallet(amounts: &[u64]) -> SyndicateWallet {
+        let mut wallet = SyndicateWallet::new([9u8; 32], ShieldedChain::ZcashOrchard, vec![0u8; 96]);
+        for (i, amount) in amounts.iter().enumerate() {
+            wallet.add_note(ShieldedNote {
+                commitment: [i as u8 + 1; 32],
+                nullifier: [i as u8 + 101; 32],
+                amount: *amount as u128,
+                asset: [0u8; 32],
+                position: i as u64,
+                height: 1000,
+                memo: None,
+                spent: false,
+                rseed: [0u8; 32],
+                recipient: vec![0u8; 43],
+                rho: [0u8; 32],
+            });
+        }
+        wallet
+    }
+
+    fn pay(amount: u64) -> ZcashPayment {
+        ZcashPayment { to: ZcashAddress::orchard([1u8; 43]), amount, memo: None }
+    }
+
+    #[test]
+    fn test_zip317_fees() {
+        // two grace actions cover small transactions
+        assert_eq!(zip317::orchard_fee(1, 1), 10_000);
+        assert_eq!(zip317::orchard_fee(1, 2), 10_000);
+        assert_eq!(zip317::orchard_fee(3, 2), 15_000);
+        assert_eq!(zip317::orchard_fee(2, 5), 25_000);
+
+        // transparent inputs round up by standard p2pkh size
+        assert_eq!(zip317::logical_actions(151, 34, 0, 0, 0), 2);
+        assert_eq!(zip317::logical_actions(150, 35 * 34, 0, 0, 0), 35);
+        assert_eq!(zip317::logical_actions(0, 0, 1, 3, 2), 5);
+        assert_eq!(zip317::conventional_fee(0), 10_000);
+        assert_eq!(zip317::conventional_fee(7), 35_000);
+
+        let adapter = ZcashAdapter::mainnet("https://lightwalletd.zcash.co");
+        let action = OrchardAction { nullifier: [0u8; 32], cmx: [0u8; 32], encrypted_note: Vec::new() };
+        let tx = ZcashTransaction {
+            actions: vec![action; 4],
+            anchor: [0u8; 32],
+            binding_sig: None,
+            memo: None,
+            raw: None,
+        };
+        assert_eq!(adapter.estimate_fee(&tx).unwrap(), 20_000);
+    }
+
+    #[test]
+    fn test_select_with_change() {
+        let wallet = orchard_wallet(&[50_000, 200_000, 30_000]);
+        let plan = ZcashSpendPlan::select(&wallet, vec![pay(100_000)]).unwrap();
+
+        // largest note alone covers it
+        assert_eq!(plan.notes.len(), 1);
+        assert_eq!(plan.notes[0].amount, 200_000);
+        assert_eq!(plan.fee, 10_000);
+        assert_eq!(plan.change, Some(90_000));
+        assert_eq!(plan.total_in(), 100_000 + plan.fee + 90_000);
+        assert_eq!(plan.actions(), 2);
+    }
+
+    #[test]
+    fn test_select_exact_and_multiple_notes() {
+        let wallet = orchard_wallet(&[60_000, 50_000]);
+        let plan = ZcashSpendPlan::select(&wallet, vec![pay(100_000)]).unwrap();
+        assert_eq!(plan.notes.len(), 2);
+        assert_eq!(plan.change, None);
+        assert_eq!(plan.fee, 10_000);
+
+        // three spends push the fee past the grace actions
+        let wallet = orchard_wallet(&[40_000, 40_000, 40_000]);
+        let plan = ZcashSpendPlan::select(&wallet, vec![pay(100_000)]).unwrap();
+        assert_eq!(plan.notes.len(), 3);
+        assert_eq!(plan.fee, 15_000);
+        assert_eq!(plan.change, Some(5_000));
+    }
+
+    #[test]
+    fn test_select_rejects() {
+        let wallet = orchard_wallet(&[50_000]);
+        assert!(matches!(
+            ZcashSpendPlan::select(&wallet, vec![pay(45_000)]),
+            Err(ZcashError::InsufficientFunds)
+        ));
+        assert!(ZcashSpendPlan::select(&wallet, vec![pay(0)]).is_err());
+
+        let transparent = ZcashPayment { to: ZcashAddress::transparent([3u8; 20]), amount: 1000, memo: None };
+        assert!(ZcashSpendPlan::select(&wallet, vec![transparent]).is_err());
+
+        let penumbra = SyndicateWallet::new([9u8; 32], ShieldedChain::Penumbra, vec![0u8; 64]);
+        assert!(ZcashSpendPlan::select(&penumbra, vec![pay(1000)]).is_err());
+    }
+
     #[test]
     fn test_unified_address() {
         let addr = ZcashAddress::orchard([2u8; 43]);
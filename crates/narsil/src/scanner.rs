@@ -28,11 +28,32 @@
 //! - **incremental**: scan from last known height (ongoing)
 //! - **targeted**: scan specific block range (recovery)
 //!
+//! # trial decryption
+//!
+//! - **penumbra**: ECDH of the ephemeral key with ivk, note KDF and
+//!   ChaCha20-Poly1305 over the full 176-byte note ciphertext, then the note
+//!   commitment is recomputed and compared. nullifiers are derived from nk,
+//!   the note commitment and its position in the TCT.
+//! - **orchard**: compact (52-byte) decryption with the external and
+//!   internal ivk, keyed to the action's nullifier (rho), with cmx
+//!   recomputed from the recovered note. nullifiers are derived from nk,
+//!   rho and psi of the reconstructed note, so no position is needed.
+//!
+//! # witnesses
+//!
+//! [`WitnessBuilder`] appends every commitment to an incremental frontier
+//! and keeps auth paths only for owned notes, checkpointed per block so a
+//! reorg can rewind. state persists through any [`StateBackend`].
+//!
 //! # feature flags
 //!
-//! - `zcash`: enables orchard trial decryption via zync-core
+//! - `penumbra`: enables penumbra trial decryption via penumbra-sdk and
+//!   poseidon377 TCT hashing
+//! - `zcash`: enables orchard trial decryption via the orchard crate and
+//!   sinsemilla tree hashing
 
-use alloc::collections::BTreeSet;
+use alloc::collections::{BTreeMap, BTreeSet, VecDeque};
+use alloc::format;
 use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
@@ -47,6 +68,32 @@ pub use zync_core::{
     OrchardFvk,
 };
 
+#[cfg(feature = "zcash")]
+use {
+    orchard::{
+        keys::{FullViewingKey as OrchardFullViewingKey, PreparedIncomingViewingKey, Scope},
+        note::{ExtractedNoteCommitment, Note as OrchardNote, Nullifier as OrchardNullifier, RandomSeed, Rho},
+        note_encryption::{CompactAction, OrchardDomain},
+        tree::MerkleHashOrchard,
+        value::NoteValue,
+        Address as OrchardAddress,
+    },
+    incrementalmerkletree::{Hashable, Level},
+    zcash_note_encryption::{try_compact_note_decryption, EphemeralKeyBytes, COMPACT_NOTE_SIZE},
+};
+
+#[cfg(feature = "penumbra")]
+use {
+    decaf377::Fq,
+    decaf377_rdsa::{SpendAuth, VerificationKey},
+    penumbra_sdk_keys::keys::{FullViewingKey as PenumbraFullViewingKey, NullifierKey},
+    penumbra_sdk_shielded_pool::{NoteCiphertext, NotePayload, StateCommitment},
+    penumbra_sdk_tct as tct,
+};
+
+use sha2::{Digest, Sha256};
+
+use crate::traits::StateBackend;
 use crate::wallet::{SyndicateWallet, ShieldedNote, NoteWitness, ShieldedChain};
 use crate::wire::Hash32;
 
@@ -72,8 +119,13 @@ pub struct CompactOutput {
     pub commitment: Hash32,
     /// ephemeral public key (for ECDH)
     pub ephemeral_key: [u8; 32],
-    /// encrypted ciphertext (compact form)
+    /// encrypted ciphertext
+    /// penumbra: full 176-byte note ciphertext
+    /// zcash orchard: first 52 bytes of enc_ciphertext (compact form)
     pub ciphertext: Vec<u8>,
+    /// nullifier revealed by the same orchard action (rho of the new note),
+    /// unused for penumbra
+    pub nullifier: Hash32,
     /// position in commitment tree
     pub position: u64,
 }
@@ -108,6 +160,10 @@ pub struct DecryptedNote {
     pub position: u64,
     /// rseed (for re-deriving note fields)
     pub rseed: [u8; 32],
+    /// raw recipient address (orchard: 43 bytes, penumbra: 80 bytes)
+    pub recipient: Vec<u8>,
+    /// orchard rho (nullifier of the action's spend), zero for penumbra
+    pub rho: Hash32,
 }
 
 /// full viewing key (chain-specific, but common interface)
@@ -149,53 +205,83 @@ impl FullViewingKey {
         }
     }
 
+    #[cfg(feature = "penumbra")]
+    fn try_decrypt_penumbra(&self, output: &CompactOutput) -> Option<DecryptedNote> {
+        let fvk = self.penumbra_fvk()?;
+
+        let payload = NotePayload {
+            note_commitment: StateCommitment::try_from(output.commitment).ok()?,
+            ephemeral_key: decaf377_ka::Public(output.ephemeral_key),
+            encrypted_note: NoteCiphertext(output.ciphertext.as_slice().try_into().ok()?),
+        };
+
+        // ECDH + KDF + AEAD, then recomputes the note commitment
+        let note = payload.trial_decrypt(&fvk)?;
+        let address_index = fvk.address_index(&note.address())?;
+
+        let nullifier = fvk.derive_nullifier(tct::Position::from(output.position), &payload.note_commitment);
+
+        Some(DecryptedNote {
+            commitment: output.commitment,
+            nullifier: nullifier.0.to_bytes(),
+            amount: u128::from(note.amount()),
+            asset: note.asset_id().0.to_bytes(),
+            address_index: address_index.account,
+            memo: None, // memo travels in the output body, not the note payload
+            position: output.position,
+            rseed: note.rseed().to_bytes(),
+            recipient: note.address().to_vec(),
+            rho: [0u8; 32],
+        })
+    }
+
+    #[cfg(not(feature = "penumbra"))]
     fn try_decrypt_penumbra(&self, output: &CompactOutput) -> Option<DecryptedNote> {
-        // TODO: actual penumbra decryption
-        // 1. ECDH with ephemeral_key and ivk
-        // 2. derive shared secret
-        // 3. decrypt ciphertext
-        // 4. parse note plaintext
-        // 5. derive nullifier
+        // penumbra decryption requires penumbra feature
         let _ = output;
         None
     }
 
-    #[cfg(feature = "zcash")]
-    fn try_decrypt_orchard(&self, output: &CompactOutput) -> Option<DecryptedNote> {
-        // parse FVK bytes to orchard FullViewingKey
-        // orchard FVK is 96 bytes: ak(32) || nk(32) || rivk(32)
-        if self.key_bytes.len() != 96 {
+    /// parse penumbra FVK from 64 bytes (ak || nk)
+    #[cfg(feature = "penumbra")]
+    fn penumbra_fvk(&self) -> Option<PenumbraFullViewingKey> {
+        if self.key_bytes.len() != 64 {
             return None;
         }
 
-        let fvk_bytes: [u8; 96] = self.key_bytes.as_slice().try_into().ok()?;
-        let orchard_fvk = OrchardFvk::from_bytes(&fvk_bytes);
-        let orchard_fvk = Option::from(orchard_fvk)?;
-
-        // convert CompactOutput to zync-core ScanAction
-        let scan_action = ZcashScanAction {
-            nullifier: [0u8; 32], // compact outputs don't include nullifier
-            cmx: output.commitment,
-            ephemeral_key: output.ephemeral_key,
-            enc_ciphertext: output.ciphertext.clone(),
-            block_height: 0, // filled by caller
-            position_in_block: output.position as u32,
-        };
+        let ak = VerificationKey::<SpendAuth>::try_from(&self.key_bytes[..32]).ok()?;
+        let nk_bytes: [u8; 32] = self.key_bytes[32..].try_into().ok()?;
+        let nk = NullifierKey(Fq::from_bytes_checked(&nk_bytes).ok()?);
 
-        // use zync-core scanner for trial decryption
-        let scanner = ZcashScanner::from_fvk(&orchard_fvk);
-        let zync_note = scanner.try_decrypt(&scan_action)?;
+        Some(PenumbraFullViewingKey::from_components(ak, nk))
+    }
+
+    #[cfg(feature = "zcash")]
+    fn try_decrypt_orchard(&self, output: &CompactOutput) -> Option<DecryptedNote> {
+        let fvk = self.orchard_fvk()?;
+
+        // external receives first, then change sent back to ourselves
+        let (scope, note, addr) = [Scope::External, Scope::Internal]
+            .into_iter()
+            .find_map(|scope| {
+                let ivk = PreparedIncomingViewingKey::new(&fvk.to_ivk(scope));
+                decrypt_orchard_action(&ivk, output).map(|(note, addr)| (scope, note, addr))
+            })?;
 
-        // convert to narsil DecryptedNote
         Some(DecryptedNote {
             commitment: output.commitment,
-            nullifier: zync_note.nullifier,
-            amount: zync_note.value as u128,
+            nullifier: note.nullifier(&fvk).to_bytes(),
+            amount: note.value().inner() as u128,
             asset: [0u8; 32], // orchard uses ZEC native asset
-            address_index: 0, // would need to derive from address
+            address_index: match scope {
+                Scope::External => 0,
+                Scope::Internal => 1,
+            },
             memo: None, // compact decryption doesn't include memo
             position: output.position,
-            rseed: [0u8; 32], // would need to extract from note
+            rseed: *note.rseed().as_bytes(),
+            recipient: addr.to_raw_address_bytes().to_vec(),
+            rho: note.rho().to_bytes(),
         })
     }
 
@@ -206,6 +292,13 @@ impl FullViewingKey {
         None
     }
 
+    /// parse orchard FVK from 96 bytes (ak || nk || rivk)
+    #[cfg(feature = "zcash")]
+    fn orchard_fvk(&self) -> Option<OrchardFullViewingKey> {
+        let fvk_bytes: [u8; 96] = self.key_bytes.as_slice().try_into().ok()?;
+        OrchardFullViewingKey::from_bytes(&fvk_bytes)
+    }
+
     fn try_decrypt_sapling(&self, output: &CompactOutput) -> Option<DecryptedNote> {
         // TODO: actual sapling decryption
         let _ = output;
@@ -213,12 +306,72 @@ impl FullViewingKey {
     }
 
     /// derive nullifier for a note
-    pub fn derive_nullifier(&self, note: &DecryptedNote) -> Hash32 {
-        // chain-specific nullifier derivation
-        // uses nullifier key component of FVK
-        let _ = note;
-        [0u8; 32] // TODO
+    ///
+    /// returns None if the key or the stored note fields don't parse, or
+    /// the chain's backend isn't compiled in
+    pub fn derive_nullifier(&self, note: &DecryptedNote) -> Option<Hash32> {
+        match self.chain {
+            ShieldedChain::Penumbra => self.derive_nullifier_penumbra(note),
+            ShieldedChain::ZcashOrchard => self.derive_nullifier_orchard(note),
+            ShieldedChain::ZcashSapling => None,
+        }
     }
+
+    /// nf = hash(nk, commitment, position)
+    #[cfg(feature = "penumbra")]
+    fn derive_nullifier_penumbra(&self, note: &DecryptedNote) -> Option<Hash32> {
+        let fvk = self.penumbra_fvk()?;
+        let commitment = StateCommitment::try_from(note.commitment).ok()?;
+        let nf = fvk.derive_nullifier(tct::Position::from(note.position), &commitment);
+        Some(nf.0.to_bytes())
+    }
+
+    #[cfg(not(feature = "penumbra"))]
+    fn derive_nullifier_penumbra(&self, _note: &DecryptedNote) -> Option<Hash32> {
+        None
+    }
+
+    /// nf = Extract(PRF_nk(rho) + psi) * K + cm, from the rebuilt note
+    #[cfg(feature = "zcash")]
+    fn derive_nullifier_orchard(&self, note: &DecryptedNote) -> Option<Hash32> {
+        let fvk = self.orchard_fvk()?;
+
+        let recipient: [u8; 43] = note.recipient.as_slice().try_into().ok()?;
+        let recipient = OrchardAddress::from_raw_address_bytes(&recipient).into_option()?;
+        let value = NoteValue::from_raw(u64::try_from(note.amount).ok()?);
+        let rho = Rho::from_bytes(&note.rho).into_option()?;
+        let rseed = RandomSeed::from_bytes(note.rseed, &rho).into_option()?;
+        let rebuilt = OrchardNote::from_parts(recipient, value, rho, rseed).into_option()?;
+
+        Some(rebuilt.nullifier(&fvk).to_bytes())
+    }
+
+    #[cfg(not(feature = "zcash"))]
+    fn derive_nullifier_orchard(&self, _note: &DecryptedNote) -> Option<Hash32> {
+        None
+    }
+}
+
+/// compact trial decryption of an orchard action under one ivk
+///
+/// the recomputed cmx must match the one on chain, so a malicious server
+/// can't hand us a note we can't spend
+#[cfg(feature = "zcash")]
+fn decrypt_orchard_action(
+    ivk: &PreparedIncomingViewingKey,
+    output: &CompactOutput,
+) -> Option<(OrchardNote, OrchardAddress)> {
+    let ct: [u8; COMPACT_NOTE_SIZE] = output.ciphertext.get(..COMPACT_NOTE_SIZE)?.try_into().ok()?;
+    let nf = OrchardNullifier::from_bytes(&output.nullifier).into_option()?;
+    let cmx = ExtractedNoteCommitment::from_bytes(&output.commitment).into_option()?;
+    let action = CompactAction::from_parts(nf, cmx, EphemeralKeyBytes(output.ephemeral_key), ct);
+    let domain = OrchardDomain::for_compact_action(&action);
+
+    let (note, addr) = try_compact_note_decryption(&domain, ivk, &action)?;
+    if ExtractedNoteCommitment::from(note.commitment()).to_bytes() != output.commitment {
+        return None;
+    }
+    Some((note, addr))
 }
 
 /// scanner configuration
@@ -360,6 +513,9 @@ impl Scanner {
                 height: result.scan_height,
                 memo: note.memo.clone(),
                 spent: false,
+                rseed: note.rseed,
+                recipient: note.recipient.clone(),
+                rho: note.rho,
             });
         }
 
@@ -383,62 +539,697 @@ impl Scanner {
     }
 }
 
+/// checkpoints kept for rewinding on reorg (orchard reorgs rarely go
+/// deeper than a few blocks, penumbra has instant finality)
+pub const MAX_CHECKPOINTS: usize = 100;
+
+/// witness state encoding version
+const WITNESS_VERSION: u8 = 1;
+
+/// node hash function of a note commitment tree
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TreeHasher {
+    /// sha256 over (height || children), for custom chains and tests
+    Sha256,
+    /// orchard sinsemilla (MerkleHashOrchard)
+    #[cfg(feature = "zcash")]
+    Sinsemilla,
+    /// penumbra TCT poseidon377, empty subtrees hash to zero at every height
+    #[cfg(feature = "penumbra")]
+    Poseidon377,
+}
+
+impl TreeHasher {
+    fn tag(&self) -> u8 {
+        match self {
+            Self::Sha256 => 0,
+            #[cfg(feature = "zcash")]
+            Self::Sinsemilla => 1,
+            #[cfg(feature = "penumbra")]
+            Self::Poseidon377 => 2,
+        }
+    }
+
+    fn from_tag(tag: u8) -> Option<Self> {
+        match tag {
+            0 => Some(Self::Sha256),
+            #[cfg(feature = "zcash")]
+            1 => Some(Self::Sinsemilla),
+            #[cfg(feature = "penumbra")]
+            2 => Some(Self::Poseidon377),
+            _ => None,
+        }
+    }
+
+    /// hash of the leaf holding `commitment`
+    /// returns None if the commitment isn't a canonical field element
+    pub fn leaf(&self, commitment: &Hash32) -> Option<Hash32> {
+        match self {
+            Self::Sha256 => Some(*commitment),
+            #[cfg(feature = "zcash")]
+            Self::Sinsemilla => {
+                // the leaf is cmx itself
+                Option::<MerkleHashOrchard>::from(MerkleHashOrchard::from_bytes(commitment))
+                    .map(|h| h.to_bytes())
+            }
+            #[cfg(feature = "penumbra")]
+            Self::Poseidon377 => {
+                let cm = Fq::from_bytes_checked(commitment).ok()?;
+                Some(poseidon377::hash_1(&tct_domain_separator(), cm).to_bytes())
+            }
+        }
+    }
+
+    /// hash of an internal node at `height` (leaves are height 0)
+    /// returns None if a child isn't a canonical node hash or the number of
+    /// children doesn't match the hasher's arity
+    pub fn node(&self, height: u8, children: &[Hash32]) -> Option<Hash32> {
+        match self {
+            Self::Sha256 => {
+                let mut hasher = Sha256::new();
+                hasher.update(b"narsil-tree-v1");
+                hasher.update([height]);
+                for child in children {
+                    hasher.update(child);
+                }
+                Some(hasher.finalize().into())
+            }
+            #[cfg(feature = "zcash")]
+            Self::Sinsemilla => {
+                let [left, right] = children else {
+                    return None;
+                };
+                let left = Option::<MerkleHashOrchard>::from(MerkleHashOrchard::from_bytes(left))?;
+                let right = Option::<MerkleHashOrchard>::from(MerkleHashOrchard::from_bytes(right))?;
+                let level = Level::from(height.checked_sub(1)?);
+                Some(MerkleHashOrchard::combine(level, &left, &right).to_bytes())
+            }
+            #[cfg(feature = "penumbra")]
+            Self::Poseidon377 => {
+                let [a, b, c, d] = children else {
+                    return None;
+                };
+                let fq = |h: &Hash32| Fq::from_bytes_checked(h).ok();
+                let ds = tct_domain_separator() + Fq::from(height as u64);
+                Some(poseidon377::hash_4(&ds, (fq(a)?, fq(b)?, fq(c)?, fq(d)?)).to_bytes())
+            }
+        }
+    }
+
+    /// whether `hash` can be a node of this tree
+    fn is_canonical(&self, hash: &Hash32) -> bool {
+        match self {
+            Self::Sha256 => true,
+            #[cfg(feature = "zcash")]
+            Self::Sinsemilla => MerkleHashOrchard::from_bytes(hash).is_some().into(),
+            #[cfg(feature = "penumbra")]
+            Self::Poseidon377 => Fq::from_bytes_checked(hash).is_ok(),
+        }
+    }
+
+    /// the (arity, depth) the chain's tree has, None if any shape will do
+    pub fn shape(&self) -> Option<(u8, u8)> {
+        match self {
+            Self::Sha256 => None,
+            #[cfg(feature = "zcash")]
+            Self::Sinsemilla => Some((2, 32)),
+            #[cfg(feature = "penumbra")]
+            Self::Poseidon377 => Some((4, 24)),
+        }
+    }
+
+    /// roots of empty subtrees at heights 0..=depth
+    fn empty_roots(&self, arity: usize, depth: u8) -> Vec<Hash32> {
+        let mut roots = match self {
+            #[cfg(feature = "zcash")]
+            Self::Sinsemilla => vec![MerkleHashOrchard::empty_leaf().to_bytes()],
+            _ => vec![[0u8; 32]],
+        };
+        for height in 1..=depth {
+            let below = roots[height as usize - 1];
+            roots.push(match self {
+                #[cfg(feature = "penumbra")]
+                Self::Poseidon377 => [0u8; 32],
+                _ => self.node(height, &vec![below; arity]).expect("empty roots are canonical"),
+            });
+        }
+        roots
+    }
+}
+
+/// TCT domain separator: blake2b("penumbra.tct") reduced into Fq
+#[cfg(feature = "penumbra")]
+fn tct_domain_separator() -> Fq {
+    Fq::from_le_bytes_mod_order(blake2b_simd::blake2b(b"penumbra.tct").as_bytes())
+}
+
+/// witness builder errors
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WitnessError {
+    /// commitment position is behind the tree
+    PositionBehind { position: u64, next: u64 },
+    /// position is past the tree's capacity
+    TreeFull,
+    /// commitment isn't a valid leaf for this tree's hash
+    InvalidCommitment,
+    /// computed root doesn't match the root reported by the chain
+    RootMismatch,
+    /// no checkpoint at or below the requested height
+    NoCheckpoint(u64),
+    /// stored witness state doesn't decode
+    Malformed,
+    /// state backend failed
+    Backend(String),
+}
+
+impl core::fmt::Display for WitnessError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::PositionBehind { position, next } => {
+                write!(f, "position {} is behind the tree (next {})", position, next)
+            }
+            Self::TreeFull => write!(f, "commitment tree is full"),
+            Self::InvalidCommitment => write!(f, "invalid note commitment"),
+            Self::RootMismatch => write!(f, "tree root mismatch"),
+            Self::NoCheckpoint(height) => write!(f, "no checkpoint at or below height {}", height),
+            Self::Malformed => write!(f, "malformed witness state"),
+            Self::Backend(e) => write!(f, "state backend error: {}", e),
+        }
+    }
+}
+
+/// auth path slots of a note we own
+#[derive(Clone, Debug)]
+struct TrackedNote {
+    /// note commitment
+    commitment: Hash32,
+    /// siblings bottom-up, (arity - 1) per height, filled as subtrees complete
+    siblings: Vec<Option<Hash32>>,
+}
+
+/// incremental tree state (frontier + tracked notes), cloned per checkpoint
+#[derive(Clone, Debug)]
+struct TreeState {
+    /// next leaf position
+    size: u64,
+    /// completed children of the open node at each height + 1,
+    /// plus the root once the tree is full
+    levels: Vec<Vec<Hash32>>,
+    /// notes we keep witnesses for, by position
+    tracked: BTreeMap<u64, TrackedNote>,
+}
+
 /// witness builder for creating spends
+///
+/// keeps an incremental merkle frontier (O(depth) hashes) plus an auth path
+/// for each note the syndicate owns, so notes stay spendable years later
+/// without rescanning. the tree is append-only; positions may jump ahead
+/// (penumbra TCT block and epoch boundaries) and skipped slots are empty.
+///
+/// - orchard: binary, depth 32, sinsemilla
+/// - penumbra: quaternary, depth 24, poseidon377 (the TCT)
+///
+/// without the `zcash` / `penumbra` feature the same tree shape is hashed
+/// with sha256, so witnesses are self-consistent but won't match the chain.
+#[derive(Clone, Debug)]
 pub struct WitnessBuilder {
-    /// commitment tree frontier
-    frontier: Vec<Hash32>,
+    /// node hash
+    hasher: TreeHasher,
+    /// children per node
+    arity: u8,
     /// tree depth
     depth: u8,
-    /// current position
-    position: u64,
+    /// empty subtree roots by height
+    empty: Vec<Hash32>,
+    /// current state
+    state: TreeState,
+    /// (height, state) after each checkpointed block, oldest first
+    checkpoints: VecDeque<(u64, TreeState)>,
 }
 
 impl WitnessBuilder {
-    /// create for penumbra (depth 24)
-    pub fn penumbra() -> Self {
+    /// create an empty tree with the given shape
+    ///
+    /// panics if arity < 2, the tree has more than 2^64 leaves, or the
+    /// hasher only hashes trees of another shape
+    pub fn new(hasher: TreeHasher, arity: u8, depth: u8) -> Self {
+        assert!(arity >= 2, "arity must be at least 2");
+        assert!(
+            (arity as u64).checked_pow(depth as u32).is_some(),
+            "tree too large"
+        );
+        assert!(
+            hasher.shape().is_none_or(|shape| shape == (arity, depth)),
+            "hasher does not fit the tree shape"
+        );
+
         Self {
-            frontier: Vec::new(),
-            depth: 24,
-            position: 0,
+            hasher,
+            arity,
+            depth,
+            empty: hasher.empty_roots(arity as usize, depth),
+            state: TreeState {
+                size: 0,
+                levels: vec![Vec::new(); depth as usize + 1],
+                tracked: BTreeMap::new(),
+            },
+            checkpoints: VecDeque::new(),
         }
     }
 
-    /// create for zcash orchard (depth 32)
+    /// create for penumbra (quaternary TCT, depth 24)
+    pub fn penumbra() -> Self {
+        #[cfg(feature = "penumbra")]
+        let hasher = TreeHasher::Poseidon377;
+        #[cfg(not(feature = "penumbra"))]
+        let hasher = TreeHasher::Sha256;
+        Self::new(hasher, 4, 24)
+    }
+
+    /// create for zcash orchard (binary, depth 32)
     pub fn orchard() -> Self {
-        Self {
-            frontier: Vec::new(),
-            depth: 32,
-            position: 0,
+        #[cfg(feature = "zcash")]
+        let hasher = TreeHasher::Sinsemilla;
+        #[cfg(not(feature = "zcash"))]
+        let hasher = TreeHasher::Sha256;
+        Self::new(hasher, 2, 32)
+    }
+
+    /// next leaf position
+    pub fn position(&self) -> u64 {
+        self.state.size
+    }
+
+    /// positions of notes with maintained witnesses
+    pub fn tracked(&self) -> impl Iterator<Item = u64> + '_ {
+        self.state.tracked.keys().copied()
+    }
+
+    /// leaves covered by a subtree at `height`
+    fn span(&self, height: u8) -> u64 {
+        (self.arity as u64).pow(height as u32)
+    }
+
+    /// append a commitment at `position`, keeping its witness if `track`
+    pub fn append(&mut self, position: u64, commitment: Hash32, track: bool) -> Result<(), WitnessError> {
+        if position < self.state.size {
+            return Err(WitnessError::PositionBehind { position, next: self.state.size });
+        }
+        if position >= self.span(self.depth) {
+            return Err(WitnessError::TreeFull);
+        }
+        let leaf = self.hasher.leaf(&commitment).ok_or(WitnessError::InvalidCommitment)?;
+
+        self.skip_to(position);
+
+        if track {
+            // the completed children left of each ancestor are its left siblings
+            let slots = self.arity as usize - 1;
+            let mut siblings = vec![None; self.depth as usize * slots];
+            for (height, level) in self.state.levels[..self.depth as usize].iter().enumerate() {
+                for (j, hash) in level.iter().enumerate() {
+                    siblings[height * slots + j] = Some(*hash);
+                }
+            }
+            self.state.tracked.insert(position, TrackedNote { commitment, siblings });
+        }
+
+        self.push_subtree(0, leaf);
+        Ok(())
+    }
+
+    /// append commitments in order and check the reported root
+    pub fn update(&mut self, tree_update: &TreeUpdate) -> Result<(), WitnessError> {
+        for commitment in &tree_update.commitments {
+            self.append(self.state.size, *commitment, false)?;
+        }
+        if self.root() != tree_update.root {
+            return Err(WitnessError::RootMismatch);
+        }
+        Ok(())
+    }
+
+    /// append every output of scanned blocks, tracking the notes we own,
+    /// and checkpoint at each block height
+    ///
+    /// block outputs must cover every commitment the chain inserted; a
+    /// block's `tree_updates` root, if present, is checked after it.
+    pub fn add_blocks(&mut self, blocks: &[CompactBlock], owned: &[DecryptedNote]) -> Result<(), WitnessError> {
+        for block in blocks {
+            for output in &block.outputs {
+                let track = owned
+                    .iter()
+                    .any(|n| n.position == output.position && n.commitment == output.commitment);
+                self.append(output.position, output.commitment, track)?;
+            }
+            if let Some(update) = &block.tree_updates {
+                if self.root() != update.root {
+                    return Err(WitnessError::RootMismatch);
+                }
+            }
+            self.checkpoint(block.height);
+        }
+        Ok(())
+    }
+
+    /// insert empty subtrees up to `position`, largest aligned first
+    fn skip_to(&mut self, position: u64) {
+        while self.state.size < position {
+            let size = self.state.size;
+            let height = (0..self.depth)
+                .rev()
+                .find(|&h| size.is_multiple_of(self.span(h)) && size + self.span(h) <= position)
+                .unwrap_or(0);
+            self.push_subtree(height, self.empty[height as usize]);
         }
     }
 
-    /// update with tree changes
-    pub fn update(&mut self, tree_update: &TreeUpdate) {
-        self.frontier = tree_update.frontier.clone();
-        self.position += tree_update.commitments.len() as u64;
+    /// push a completed subtree at `height` onto the frontier
+    fn push_subtree(&mut self, height: u8, hash: Hash32) {
+        let arity = self.arity as usize;
+        let mut index = self.state.size / self.span(height);
+        self.state.size += self.span(height);
+
+        let mut height = height;
+        let mut carry = hash;
+        loop {
+            self.record_sibling(height, index, carry);
+
+            let level = &mut self.state.levels[height as usize];
+            level.push(carry);
+            if height == self.depth || level.len() < arity {
+                break;
+            }
+            // every stored hash came out of leaf() or node(), or was
+            // checked by from_bytes
+            carry = self.hasher.node(height + 1, level).expect("frontier nodes are canonical");
+            level.clear();
+            height += 1;
+            index /= arity as u64;
+        }
+    }
+
+    /// store a completed node as the right sibling of tracked notes' ancestors
+    fn record_sibling(&mut self, height: u8, index: u64, hash: Hash32) {
+        let arity = self.arity as u64;
+        let span = self.span(height);
+        let slots = self.arity as usize - 1;
+
+        for (position, note) in self.state.tracked.iter_mut() {
+            let own = position / span;
+            if own / arity == index / arity && index > own {
+                let k = (index % arity) as usize - 1;
+                note.siblings[height as usize * slots + k] = Some(hash);
+            }
+        }
+    }
+
+    /// hash of the partially filled node at each height (None if empty)
+    fn open_roots(&self, state: &TreeState) -> Vec<Option<Hash32>> {
+        let mut roots = vec![None; self.depth as usize + 1];
+        for height in 1..=self.depth as usize {
+            let below = &state.levels[height - 1];
+            if below.is_empty() && roots[height - 1].is_none() {
+                continue;
+            }
+            let mut children = below.clone();
+            children.extend(roots[height - 1]);
+            children.resize(self.arity as usize, self.empty[height - 1]);
+            roots[height] = self.hasher.node(height as u8, &children);
+        }
+        roots
+    }
+
+    fn root_of(&self, state: &TreeState) -> Hash32 {
+        let depth = self.depth as usize;
+        match state.levels[depth].first() {
+            Some(full) => *full,
+            None => self.open_roots(state)[depth].unwrap_or(self.empty[depth]),
+        }
+    }
+
+    /// get current tree root
+    pub fn root(&self) -> Hash32 {
+        self.root_of(&self.state)
+    }
+
+    /// record the current state as of `height`, replacing any at or above it
+    pub fn checkpoint(&mut self, height: u64) {
+        while self.checkpoints.back().is_some_and(|(h, _)| *h >= height) {
+            self.checkpoints.pop_back();
+        }
+        self.checkpoints.push_back((height, self.state.clone()));
+        while self.checkpoints.len() > MAX_CHECKPOINTS {
+            self.checkpoints.pop_front();
+        }
+    }
+
+    /// rewind to the latest checkpoint at or below `height` (after a reorg)
+    ///
+    /// returns the checkpoint height. notes appended after it are dropped
+    /// and must be rescanned.
+    pub fn rewind(&mut self, height: u64) -> Result<u64, WitnessError> {
+        while self.checkpoints.back().is_some_and(|(h, _)| *h > height) {
+            self.checkpoints.pop_back();
+        }
+        let (checkpoint, state) = self.checkpoints.back().ok_or(WitnessError::NoCheckpoint(height))?;
+        self.state = state.clone();
+        Ok(*checkpoint)
+    }
+
+    /// stop maintaining the witness for a note (e.g. once spent)
+    pub fn forget(&mut self, position: u64) -> bool {
+        self.state.tracked.remove(&position).is_some()
     }
 
     /// build witness for a note at given position
+    ///
+    /// `anchor` must be the current root or the root at a retained
+    /// checkpoint; the auth path is the one valid under that anchor.
     pub fn build_witness(&self, note_position: u64, anchor: Hash32) -> Option<NoteWitness> {
-        if note_position >= self.position {
-            return None; // note not yet in tree
+        let state = if self.root() == anchor {
+            &self.state
+        } else {
+            self.checkpoints
+                .iter()
+                .rev()
+                .map(|(_, s)| s)
+                .find(|s| self.root_of(s) == anchor)?
+        };
+        let note = state.tracked.get(&note_position)?;
+
+        let arity = self.arity as u64;
+        let slots = self.arity as usize - 1;
+        let open = self.open_roots(state);
+
+        let mut auth_path = Vec::with_capacity(note.siblings.len());
+        for height in 0..self.depth {
+            let span = self.span(height);
+            let own = note_position / span;
+            let base = own - own % arity;
+            let mut k = 0;
+            for idx in base..base + arity {
+                if idx == own {
+                    continue;
+                }
+                let sibling = match note.siblings[height as usize * slots + k] {
+                    Some(hash) => hash,
+                    // still filling, or not started yet
+                    None if idx == state.size / span && !state.size.is_multiple_of(span) => {
+                        open[height as usize].expect("open subtree")
+                    }
+                    None => self.empty[height as usize],
+                };
+                auth_path.push(sibling);
+                k += 1;
+            }
         }
 
-        // TODO: actual merkle path computation
-        // requires storing intermediate nodes or recomputing from frontier
-
         Some(NoteWitness {
-            commitment: [0u8; 32], // would be filled by caller
-            auth_path: vec![[0u8; 32]; self.depth as usize],
+            commitment: note.commitment,
+            auth_path,
             position: note_position,
             anchor,
         })
     }
 
-    /// get current tree root
-    pub fn root(&self) -> Hash32 {
-        // TODO: compute from frontier
-        [0u8; 32]
+    /// recompute the root from a witness and compare with its anchor
+    pub fn verify_witness(&self, witness: &NoteWitness) -> bool {
+        let slots = self.arity as usize - 1;
+        if witness.auth_path.len() != self.depth as usize * slots {
+            return false;
+        }
+        let Some(mut current) = self.hasher.leaf(&witness.commitment) else {
+            return false;
+        };
+
+        for height in 0..self.depth {
+            let slot = ((witness.position / self.span(height)) % self.arity as u64) as usize;
+            let siblings = &witness.auth_path[height as usize * slots..(height as usize + 1) * slots];
+            let mut children = siblings.to_vec();
+            children.insert(slot, current);
+            let Some(parent) = self.hasher.node(height + 1, &children) else {
+                return false;
+            };
+            current = parent;
+        }
+        current == witness.anchor
+    }
+
+    /// serialize tree, tracked notes and checkpoints
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = vec![WITNESS_VERSION, self.hasher.tag(), self.arity, self.depth];
+        Self::encode_state(&self.state, &mut buf);
+        buf.extend_from_slice(&(self.checkpoints.len() as u32).to_le_bytes());
+        for (height, state) in &self.checkpoints {
+            buf.extend_from_slice(&height.to_le_bytes());
+            Self::encode_state(state, &mut buf);
+        }
+        buf
+    }
+
+    fn encode_state(state: &TreeState, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&state.size.to_le_bytes());
+        for level in &state.levels {
+            buf.push(level.len() as u8);
+            for hash in level {
+                buf.extend_from_slice(hash);
+            }
+        }
+        buf.extend_from_slice(&(state.tracked.len() as u32).to_le_bytes());
+        for (position, note) in &state.tracked {
+            buf.extend_from_slice(&position.to_le_bytes());
+            buf.extend_from_slice(&note.commitment);
+            for slot in &note.siblings {
+                match slot {
+                    Some(hash) => {
+                        buf.push(1);
+                        buf.extend_from_slice(hash);
+                    }
+                    None => buf.push(0),
+                }
+            }
+        }
+    }
+
+    /// deserialize from bytes
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut r = ByteReader { bytes, offset: 0 };
+        if r.u8()? != WITNESS_VERSION {
+            return None;
+        }
+        let hasher = TreeHasher::from_tag(r.u8()?)?;
+        let (arity, depth) = (r.u8()?, r.u8()?);
+        if arity < 2
+            || (arity as u64).checked_pow(depth as u32).is_none()
+            || hasher.shape().is_some_and(|shape| shape != (arity, depth))
+        {
+            return None;
+        }
+
+        let mut builder = Self::new(hasher, arity, depth);
+        builder.state = builder.decode_state(&mut r)?;
+        let count = r.u32()?;
+        for _ in 0..count {
+            let height = r.u64()?;
+            let state = builder.decode_state(&mut r)?;
+            builder.checkpoints.push_back((height, state));
+        }
+        (r.offset == bytes.len()).then_some(builder)
+    }
+
+    fn decode_state(&self, r: &mut ByteReader<'_>) -> Option<TreeState> {
+        let size = r.u64()?;
+        if size > self.span(self.depth) {
+            return None;
+        }
+        // the frontier holds one completed child per base-arity digit of size
+        let mut levels = Vec::with_capacity(self.depth as usize + 1);
+        for height in 0..=self.depth {
+            let len = r.u8()? as u64;
+            let digit = if height == self.depth {
+                (size == self.span(self.depth)) as u64
+            } else {
+                (size / self.span(height)) % self.arity as u64
+            };
+            if len != digit {
+                return None;
+            }
+            levels.push((0..len).map(|_| self.node_hash(r)).collect::<Option<Vec<_>>>()?);
+        }
+
+        let slots = self.depth as usize * (self.arity as usize - 1);
+        let mut tracked = BTreeMap::new();
+        for _ in 0..r.u32()? {
+            let position = r.u64()?;
+            if position >= size {
+                return None;
+            }
+            let commitment = r.hash()?;
+            self.hasher.leaf(&commitment)?;
+            let siblings = (0..slots)
+                .map(|_| match r.u8()? {
+                    0 => Some(None),
+                    1 => self.node_hash(r).map(Some),
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>()?;
+            tracked.insert(position, TrackedNote { commitment, siblings });
+        }
+
+        Some(TreeState { size, levels, tracked })
+    }
+
+    /// a stored hash, refused unless it can be a node of this tree
+    fn node_hash(&self, r: &mut ByteReader<'_>) -> Option<Hash32> {
+        r.hash().filter(|hash| self.hasher.is_canonical(hash))
+    }
+
+    /// persist through a state backend's witness keyspace
+    pub fn save<B: StateBackend>(&self, backend: &B, syndicate_id: &Hash32) -> Result<(), B::Error> {
+        backend.save_witness(syndicate_id, &self.to_bytes())
+    }
+
+    /// restore from a state backend (None if nothing was saved)
+    pub fn load<B: StateBackend>(backend: &B, syndicate_id: &Hash32) -> Result<Option<Self>, WitnessError> {
+        let bytes = backend
+            .load_witness(syndicate_id)
+            .map_err(|e| WitnessError::Backend(format!("{:?}", e)))?;
+        match bytes {
+            Some(bytes) => Self::from_bytes(&bytes).map(Some).ok_or(WitnessError::Malformed),
+            None => Ok(None),
+        }
+    }
+}
+
+/// cursor over encoded witness state
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl ByteReader<'_> {
+    fn take(&mut self, n: usize) -> Option<&[u8]> {
+        let end = self.offset.checked_add(n)?;
+        let slice = self.bytes.get(self.offset..end)?;
+        self.offset = end;
+        Some(slice)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.take(1).map(|b| b[0])
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.take(4).map(|b| u32::from_le_bytes(b.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        self.take(8).map(|b| u64::from_le_bytes(b.try_into().unwrap()))
+    }
+
+    fn hash(&mut self) -> Option<Hash32> {
+        self.take(32).map(|b| b.try_into().unwrap())
     }
 }
 
@@ -519,6 +1310,7 @@ mod tests {
                     commitment: [(height as u8).wrapping_add(i as u8); 32],
                     ephemeral_key: [0u8; 32],
                     ciphertext: vec![0u8; 52],
+                    nullifier: [0u8; 32],
                     position: height * 100 + i as u64,
                 })
                 .collect(),
@@ -605,4 +1397,499 @@ mod tests {
         let builder = WitnessBuilder::penumbra();
         assert_eq!(builder.depth, 24);
     }
+
+    /// root of the full tree, hashing every node from the leaves up
+    fn naive_root(hasher: TreeHasher, arity: u8, depth: u8, leaves: &BTreeMap<u64, Hash32>) -> Hash32 {
+        let count = (arity as u64).pow(depth as u32);
+        let mut level: Vec<Hash32> = (0..count)
+            .map(|i| leaves.get(&i).map(|c| hasher.leaf(c).unwrap()).unwrap_or([0u8; 32]))
+            .collect();
+        for height in 1..=depth {
+            level = level
+                .chunks(arity as usize)
+                .map(|children| hasher.node(height, children).unwrap())
+                .collect();
+        }
+        level[0]
+    }
+
+    fn commitment(i: u64) -> Hash32 {
+        let mut c = [0u8; 32];
+        c[..8].copy_from_slice(&(i + 1).to_le_bytes());
+        c
+    }
+
+    #[test]
+    fn test_witness_root_matches_full_tree() {
+        for (arity, depth) in [(2u8, 6u8), (4, 3)] {
+            let mut builder = WitnessBuilder::new(TreeHasher::Sha256, arity, depth);
+            let mut leaves = BTreeMap::new();
+            assert_eq!(builder.root(), naive_root(TreeHasher::Sha256, arity, depth, &leaves));
+
+            // sequential inserts, then a gap (e.g. penumbra block boundary)
+            for position in (0..11).chain(16..19).chain([37, 63]) {
+                builder.append(position, commitment(position), false).unwrap();
+                leaves.insert(position, commitment(position));
+                assert_eq!(builder.root(), naive_root(TreeHasher::Sha256, arity, depth, &leaves));
+            }
+            assert_eq!(builder.position(), 64);
+            assert_eq!(builder.append(64, commitment(64), false), Err(WitnessError::TreeFull));
+        }
+    }
+
+    #[test]
+    fn test_witness_tracks_owned_notes() {
+        for (arity, depth) in [(2u8, 6u8), (4, 3)] {
+            let mut builder = WitnessBuilder::new(TreeHasher::Sha256, arity, depth);
+            let owned = [0u64, 5, 6, 21, 40];
+
+            for position in 0..50 {
+                builder.append(position, commitment(position), owned.contains(&position)).unwrap();
+
+                // every tracked witness stays valid under the current root
+                for &note in owned.iter().filter(|&&p| p <= position) {
+                    let witness = builder.build_witness(note, builder.root()).unwrap();
+                    assert_eq!(witness.commitment, commitment(note));
+                    assert_eq!(witness.auth_path.len(), depth as usize * (arity as usize - 1));
+                    assert!(builder.verify_witness(&witness));
+                }
+            }
+
+            // untracked notes and unknown anchors have no witness
+            assert!(builder.build_witness(1, builder.root()).is_none());
+            assert!(builder.build_witness(5, [9u8; 32]).is_none());
+
+            assert!(builder.forget(5));
+            assert!(builder.build_witness(5, builder.root()).is_none());
+            assert_eq!(builder.tracked().collect::<Vec<_>>(), vec![0, 6, 21, 40]);
+        }
+    }
+
+    #[test]
+    fn test_witness_checkpoint_and_rewind() {
+        let mut builder = WitnessBuilder::new(TreeHasher::Sha256, 2, 6);
+        let mut blocks = Vec::new();
+        for height in 0..4u64 {
+            let outputs = (0..3)
+                .map(|i| CompactOutput {
+                    commitment: commitment(height * 3 + i),
+                    ephemeral_key: [0u8; 32],
+                    ciphertext: vec![],
+                    nullifier: [0u8; 32],
+                    position: height * 3 + i,
+                })
+                .collect();
+            blocks.push(CompactBlock { height, hash: [0u8; 32], outputs, nullifiers: vec![], tree_updates: None });
+        }
+        let owned = DecryptedNote {
+            commitment: commitment(4),
+            nullifier: [0u8; 32],
+            amount: 1,
+            asset: [0u8; 32],
+            address_index: 0,
+            memo: None,
+            position: 4,
+            rseed: [0u8; 32],
+            recipient: vec![],
+            rho: [0u8; 32],
+        };
+
+        builder.add_blocks(&blocks[..2], core::slice::from_ref(&owned)).unwrap();
+        let anchor = builder.root();
+        builder.add_blocks(&blocks[2..], &[]).unwrap();
+        assert_ne!(builder.root(), anchor);
+
+        // witness against an older anchor still verifies
+        let old = builder.build_witness(4, anchor).unwrap();
+        assert!(builder.verify_witness(&old));
+        let new = builder.build_witness(4, builder.root()).unwrap();
+        assert!(builder.verify_witness(&new));
+        assert_ne!(old.auth_path, new.auth_path);
+
+        // reorg back to height 1, then replay
+        let head = builder.root();
+        assert_eq!(builder.rewind(1), Ok(1));
+        assert_eq!(builder.root(), anchor);
+        assert_eq!(builder.position(), 6);
+        builder.add_blocks(&blocks[2..], &[]).unwrap();
+        assert_eq!(builder.root(), head);
+
+        assert_eq!(builder.rewind(0), Ok(0));
+        assert_eq!(builder.tracked().count(), 0);
+
+        let mut empty = WitnessBuilder::new(TreeHasher::Sha256, 2, 6);
+        assert_eq!(empty.rewind(10), Err(WitnessError::NoCheckpoint(10)));
+    }
+
+    #[test]
+    fn test_witness_update_checks_root() {
+        let mut reference = WitnessBuilder::new(TreeHasher::Sha256, 4, 3);
+        for position in 0..5 {
+            reference.append(position, commitment(position), false).unwrap();
+        }
+
+        let mut builder = WitnessBuilder::new(TreeHasher::Sha256, 4, 3);
+        let mut update = TreeUpdate {
+            commitments: (0..5).map(commitment).collect(),
+            frontier: vec![],
+            root: reference.root(),
+        };
+        builder.update(&update).unwrap();
+        assert_eq!(builder.root(), reference.root());
+
+        update.commitments = vec![commitment(5)];
+        assert_eq!(builder.update(&update), Err(WitnessError::RootMismatch));
+
+        assert_eq!(
+            builder.append(2, commitment(2), false),
+            Err(WitnessError::PositionBehind { position: 2, next: 6 })
+        );
+    }
+
+    #[test]
+    fn test_witness_persistence() {
+        use core::cell::RefCell;
+
+        #[derive(Default)]
+        struct MemBackend {
+            state: RefCell<BTreeMap<Hash32, Vec<u8>>>,
+            witnesses: RefCell<BTreeMap<Hash32, Vec<u8>>>,
+        }
+
+        impl StateBackend for MemBackend {
+            type Error = ();
+
+            fn load(&self, id: &Hash32) -> Result<Option<Vec<u8>>, ()> {
+                Ok(self.state.borrow().get(id).cloned())
+            }
+
+            fn save(&self, id: &Hash32, data: &[u8]) -> Result<(), ()> {
+                self.state.borrow_mut().insert(*id, data.to_vec());
+                Ok(())
+            }
+
+            fn delete(&self, id: &Hash32) -> Result<(), ()> {
+                self.state.borrow_mut().remove(id);
+                self.witnesses.borrow_mut().remove(id);
+                Ok(())
+            }
+
+            fn list(&self) -> Result<Vec<Hash32>, ()> {
+                Ok(self.state.borrow().keys().copied().collect())
+            }
+
+            fn load_witness(&self, id: &Hash32) -> Result<Option<Vec<u8>>, ()> {
+                Ok(self.witnesses.borrow().get(id).cloned())
+            }
+
+            fn save_witness(&self, id: &Hash32, data: &[u8]) -> Result<(), ()> {
+                self.witnesses.borrow_mut().insert(*id, data.to_vec());
+                Ok(())
+            }
+        }
+
+        let mut builder = WitnessBuilder::new(TreeHasher::Sha256, 4, 3);
+        for position in 0..9 {
+            builder.append(position, commitment(position), position % 3 == 0).unwrap();
+            builder.checkpoint(position);
+        }
+
+        let backend = MemBackend::default();
+        let id = [7u8; 32];
+        assert!(WitnessBuilder::load(&backend, &id).unwrap().is_none());
+        backend.save(&id, b"syndicate state").unwrap();
+        builder.save(&backend, &id).unwrap();
+        assert_eq!(backend.list(), Ok(vec![id]));
+        assert_eq!(backend.load(&id), Ok(Some(b"syndicate state".to_vec())));
+
+        let mut restored = WitnessBuilder::load(&backend, &id).unwrap().unwrap();
+        assert_eq!(restored.to_bytes(), builder.to_bytes());
+        assert_eq!(restored.root(), builder.root());
+        let witness = restored.build_witness(6, restored.root()).unwrap();
+        assert!(restored.verify_witness(&witness));
+
+        // restored tree keeps growing identically
+        for position in 9..20 {
+            builder.append(position, commitment(position), false).unwrap();
+            restored.append(position, commitment(position), false).unwrap();
+        }
+        assert_eq!(restored.root(), builder.root());
+        assert_eq!(restored.rewind(4), Ok(4));
+
+        let bytes = builder.to_bytes();
+        assert!(WitnessBuilder::from_bytes(&bytes[..bytes.len() - 1]).is_none());
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(WitnessBuilder::from_bytes(&trailing).is_none());
+
+        // a frontier that doesn't match the tree size is refused
+        let mut skewed = bytes.clone();
+        skewed[4] += 1;
+        assert!(WitnessBuilder::from_bytes(&skewed).is_none());
+
+        backend.save_witness(&id, b"junk").unwrap();
+        assert_eq!(WitnessBuilder::load(&backend, &id).err(), Some(WitnessError::Malformed));
+
+        // deleting the syndicate takes its witnesses with it
+        backend.delete(&id).unwrap();
+        assert!(WitnessBuilder::load(&backend, &id).unwrap().is_none());
+    }
+}
+
+#[cfg(all(test, feature = "penumbra"))]
+mod penumbra_tests {
+    use super::*;
+    use penumbra_sdk_asset::{Value, STAKING_TOKEN_ASSET_ID};
+    use penumbra_sdk_keys::keys::{AddressIndex, SpendKey, SpendKeyBytes};
+    use penumbra_sdk_num::Amount;
+    use penumbra_sdk_shielded_pool::Note;
+    use rand::rngs::OsRng;
+
+    fn syndicate_fvk(seed: u8) -> (PenumbraFullViewingKey, FullViewingKey) {
+        let sk = SpendKey::from(SpendKeyBytes([seed; 32]));
+        let fvk = sk.full_viewing_key().clone();
+        let mut key_bytes = fvk.spend_verification_key().to_bytes().to_vec();
+        key_bytes.extend_from_slice(&fvk.nullifier_key().0.to_bytes());
+        (fvk, FullViewingKey::penumbra(key_bytes))
+    }
+
+    fn output_to(fvk: &PenumbraFullViewingKey, account: u32, amount: u64, position: u64) -> (Note, CompactOutput) {
+        let (address, _) = fvk.payment_address(AddressIndex::new(account));
+        let value = Value { amount: Amount::from(amount), asset_id: *STAKING_TOKEN_ASSET_ID };
+        let note = Note::generate(&mut OsRng, &address, value);
+        let epk = note.ephemeral_secret_key().diversified_public(&note.diversified_generator());
+
+        let output = CompactOutput {
+            commitment: note.commit().0.to_bytes(),
+            ephemeral_key: epk.0,
+            ciphertext: note.encrypt().0.to_vec(),
+            nullifier: [0u8; 32],
+            position,
+        };
+        (note, output)
+    }
+
+    #[test]
+    fn test_penumbra_trial_decrypt() {
+        let (pfvk, fvk) = syndicate_fvk(7);
+        let (note, output) = output_to(&pfvk, 3, 1_000_000, 42);
+
+        let decrypted = fvk.try_decrypt(&output).expect("our note");
+        assert_eq!(decrypted.amount, 1_000_000);
+        assert_eq!(decrypted.asset, STAKING_TOKEN_ASSET_ID.0.to_bytes());
+        assert_eq!(decrypted.address_index, 3);
+        assert_eq!(decrypted.rseed, note.rseed().to_bytes());
+        assert_eq!(decrypted.recipient, note.address().to_vec());
+        assert_eq!(decrypted.position, 42);
+
+        let expected = pfvk.derive_nullifier(tct::Position::from(42u64), &note.commit());
+        assert_eq!(decrypted.nullifier, expected.0.to_bytes());
+        assert_eq!(fvk.derive_nullifier(&decrypted), Some(decrypted.nullifier));
+    }
+
+    #[test]
+    fn test_penumbra_rejects_foreign_and_tampered() {
+        let (pfvk, fvk) = syndicate_fvk(7);
+        let (other, _) = syndicate_fvk(8);
+
+        let (_, foreign) = output_to(&other, 0, 5, 0);
+        assert!(fvk.try_decrypt(&foreign).is_none());
+
+        let (_, output) = output_to(&pfvk, 0, 5, 0);
+        let mut tampered = output.clone();
+        tampered.ciphertext[10] ^= 1;
+        assert!(fvk.try_decrypt(&tampered).is_none());
+
+        // ciphertext decrypts but doesn't open the claimed commitment
+        let (_, unrelated) = output_to(&pfvk, 0, 6, 1);
+        let mut swapped = output.clone();
+        swapped.commitment = unrelated.commitment;
+        assert!(fvk.try_decrypt(&swapped).is_none());
+
+        assert!(FullViewingKey::penumbra(vec![0u8; 32]).try_decrypt(&output).is_none());
+    }
+
+    #[test]
+    fn test_penumbra_witness_root_matches_tct() {
+        let (pfvk, _) = syndicate_fvk(7);
+        let mut tree = tct::Tree::new();
+        let mut builder = WitnessBuilder::penumbra();
+        assert_eq!(builder.root(), Fq::from(tree.root()).to_bytes());
+
+        for position in 0..21u64 {
+            let (note, output) = output_to(&pfvk, 0, position + 1, position);
+            let keep = position % 5 == 0;
+            let witness = if keep { tct::Witness::Keep } else { tct::Witness::Forget };
+            tree.insert(witness, note.commit()).unwrap();
+            builder.append(position, output.commitment, keep).unwrap();
+            assert_eq!(builder.root(), Fq::from(tree.root()).to_bytes(), "root after {position}");
+        }
+
+        // auth paths of kept notes open against the tct anchor
+        let anchor = Fq::from(tree.root()).to_bytes();
+        for position in [0u64, 5, 10, 15, 20] {
+            let witness = builder.build_witness(position, anchor).unwrap();
+            assert!(builder.verify_witness(&witness));
+        }
+
+        // non-canonical hashes from outside are refused, not hashed
+        let mut forged = builder.build_witness(5, anchor).unwrap();
+        forged.auth_path[0] = [0xff; 32];
+        assert!(!builder.verify_witness(&forged));
+
+        let bytes = builder.to_bytes();
+        let mut noncanonical = bytes.clone();
+        noncanonical[13..45].copy_from_slice(&[0xff; 32]);
+        assert!(WitnessBuilder::from_bytes(&noncanonical).is_none());
+        let mut reshaped = bytes.clone();
+        reshaped[2] = 2;
+        assert!(WitnessBuilder::from_bytes(&reshaped).is_none());
+    }
+
+    #[test]
+    fn test_penumbra_scan_and_spend() {
+        let (pfvk, fvk) = syndicate_fvk(7);
+        let (other, _) = syndicate_fvk(8);
+        let mut scanner = Scanner::new(fvk, ScannerConfig::penumbra());
+
+        let (_, ours) = output_to(&pfvk, 0, 77, 1);
+        let (_, theirs) = output_to(&other, 0, 88, 2);
+        let block = CompactBlock {
+            height: 10,
+            hash: [10u8; 32],
+            outputs: vec![theirs, ours],
+            nullifiers: vec![],
+            tree_updates: None,
+        };
+
+        let result = scanner.scan_blocks(&[block]);
+        assert_eq!(result.outputs_checked, 2);
+        assert_eq!(result.new_notes.len(), 1);
+        assert_eq!(result.new_notes[0].amount, 77);
+
+        let spend = CompactBlock {
+            height: 11,
+            hash: [11u8; 32],
+            outputs: vec![],
+            nullifiers: vec![result.new_notes[0].nullifier],
+            tree_updates: None,
+        };
+        let result = scanner.scan_blocks(&[spend]);
+        assert_eq!(result.spent_nullifiers.len(), 1);
+    }
+}
+
+#[cfg(all(test, feature = "zcash"))]
+mod orchard_tests {
+    use super::*;
+    use orchard::keys::SpendingKey;
+    use orchard::note_encryption::OrchardNoteEncryption;
+    use zcash_note_encryption::Domain;
+
+    fn syndicate_fvk(seed: u8) -> (OrchardFullViewingKey, FullViewingKey) {
+        let sk = SpendingKey::from_bytes([seed; 32]).unwrap();
+        let fvk = OrchardFullViewingKey::from(&sk);
+        let key = FullViewingKey::zcash_orchard(fvk.to_bytes().to_vec());
+        (fvk, key)
+    }
+
+    /// encrypt a note to `fvk` the way a wallet's builder would
+    fn action_to(fvk: &OrchardFullViewingKey, scope: Scope, value: u64, position: u64) -> (OrchardNote, CompactOutput) {
+        // nullifier revealed by the same action; small values are canonical
+        let mut nf = [0u8; 32];
+        nf[..8].copy_from_slice(&(position + 1).to_le_bytes());
+        let rho = Rho::from_bytes(&nf).unwrap();
+        let rseed = RandomSeed::from_bytes([position as u8 ^ 0x5a; 32], &rho).unwrap();
+        let recipient = fvk.address_at(7u32, scope);
+        let note = OrchardNote::from_parts(recipient, NoteValue::from_raw(value), rho, rseed).unwrap();
+
+        let encryptor = OrchardNoteEncryption::new(Some(fvk.to_ovk(scope)), note, [0u8; 512]);
+        let enc = encryptor.encrypt_note_plaintext();
+
+        let output = CompactOutput {
+            commitment: ExtractedNoteCommitment::from(note.commitment()).to_bytes(),
+            ephemeral_key: OrchardDomain::epk_bytes(encryptor.epk()).0,
+            ciphertext: enc[..COMPACT_NOTE_SIZE].to_vec(),
+            nullifier: nf,
+            position,
+        };
+        (note, output)
+    }
+
+    #[test]
+    fn test_orchard_trial_decrypt() {
+        let (ofvk, fvk) = syndicate_fvk(7);
+        let (note, output) = action_to(&ofvk, Scope::External, 250_000, 9);
+
+        let decrypted = fvk.try_decrypt(&output).expect("our note");
+        assert_eq!(decrypted.amount, 250_000);
+        assert_eq!(decrypted.address_index, 0);
+        assert_eq!(decrypted.rseed, *note.rseed().as_bytes());
+        assert_eq!(decrypted.rho, output.nullifier);
+        assert_eq!(decrypted.recipient, note.recipient().to_raw_address_bytes().to_vec());
+        assert_eq!(decrypted.nullifier, note.nullifier(&ofvk).to_bytes());
+        assert_eq!(fvk.derive_nullifier(&decrypted), Some(decrypted.nullifier));
+
+        // change outputs decrypt with the internal ivk
+        let (_, change) = action_to(&ofvk, Scope::Internal, 1, 10);
+        assert_eq!(fvk.try_decrypt(&change).unwrap().address_index, 1);
+    }
+
+    #[test]
+    fn test_orchard_rejects_foreign_and_tampered() {
+        let (ofvk, fvk) = syndicate_fvk(7);
+        let (other, _) = syndicate_fvk(8);
+
+        let (_, foreign) = action_to(&other, Scope::External, 5, 0);
+        assert!(fvk.try_decrypt(&foreign).is_none());
+
+        let (_, output) = action_to(&ofvk, Scope::External, 5, 0);
+
+        // rho is bound into the note, so the wrong action nullifier fails
+        let mut wrong_rho = output.clone();
+        wrong_rho.nullifier[0] ^= 1;
+        assert!(fvk.try_decrypt(&wrong_rho).is_none());
+
+        let mut tampered = output.clone();
+        tampered.ciphertext[0] ^= 1;
+        assert!(fvk.try_decrypt(&tampered).is_none());
+
+        let mut short = output.clone();
+        short.ciphertext.truncate(40);
+        assert!(fvk.try_decrypt(&short).is_none());
+    }
+
+    #[test]
+    fn test_orchard_commitment_tree() {
+        use incrementalmerkletree::frontier::Frontier;
+
+        let mut builder = WitnessBuilder::orchard();
+        assert_eq!(builder.root(), MerkleHashOrchard::empty_root(Level::from(32)).to_bytes());
+
+        let (ofvk, _) = syndicate_fvk(7);
+        let mut frontier = Frontier::<MerkleHashOrchard, 32>::empty();
+        for position in 0..5 {
+            let (_, output) = action_to(&ofvk, Scope::External, 1, position);
+            builder.append(position, output.commitment, position == 2).unwrap();
+            frontier.append(MerkleHashOrchard::from_bytes(&output.commitment).unwrap());
+        }
+        assert_eq!(builder.root(), frontier.root().to_bytes());
+
+        let witness = builder.build_witness(2, builder.root()).unwrap();
+        assert!(builder.verify_witness(&witness));
+
+        // untrusted paths and stored state that aren't pallas field
+        // elements are refused, not hashed
+        let mut forged = witness.clone();
+        forged.auth_path[3] = [0xff; 32];
+        assert!(!builder.verify_witness(&forged));
+
+        let bytes = builder.to_bytes();
+        let mut noncanonical = bytes.clone();
+        noncanonical[13..45].copy_from_slice(&[0xff; 32]);
+        assert!(WitnessBuilder::from_bytes(&noncanonical).is_none());
+        let mut reshaped = bytes.clone();
+        reshaped[2] = 4;
+        assert!(WitnessBuilder::from_bytes(&reshaped).is_none());
+    }
 }
@@ -0,0 +1,681 @@
+//! durable state backend on sled
+//!
+//! a member node that restarts mid-round must come back with the same
+//! pending proposals, replay sequence numbers and collected contributions
+//! it had before, otherwise it re-accepts replayed envelopes or drops
+//! contributions it already acknowledged.
+//!
+//! # layout
+//!
+//! ```text
+//! meta           schema_version                     -> u32 le
+//! state          syndicate_id                       -> syndicate state
+//! proposals      syndicate_id || proposal_id (be)   -> proposal bytes
+//! replay         syndicate_id || sender             -> sequence u64 le
+//! contributions  syndicate_id || round_id || member -> contribution bytes
+//! witnesses      syndicate_id                       -> note witness state
+//! ```
+//!
+//! # atomicity
+//!
+//! all writes go through a [`WriteBatch`] committed as one sled transaction
+//! across every tree and flushed before [`SledBackend::commit`] returns. a
+//! crash leaves either the whole batch or none of it on disk, so handling a
+//! message (store contribution + bump sender sequence) is all-or-nothing.
+//!
+//! # schema versioning
+//!
+//! the schema version is written on first open. opening a database written
+//! by a newer version fails with [`StoreError::UnsupportedSchema`] instead
+//! of misreading it; older versions are migrated in place on open.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::string::{String, ToString};
+use std::sync::Mutex;
+use std::vec::Vec;
+
+use sled::transaction::{ConflictableTransactionError, TransactionError, TransactionalTree};
+use sled::{Db, Transactional, Tree};
+
+use crate::replay::ReplayValidator;
+use crate::traits::StateBackend;
+use crate::wire::{Hash32, ProposalId};
+
+/// current on-disk schema version
+pub const SCHEMA_VERSION: u32 = 1;
+
+const SCHEMA_KEY: &[u8] = b"schema_version";
+
+/// store errors
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StoreError {
+    /// sled i/o or corruption error
+    Db(String),
+    /// database written by an unknown schema version
+    UnsupportedSchema { found: u32, supported: u32 },
+    /// stored value has the wrong shape
+    Malformed(&'static str),
+}
+
+impl core::fmt::Display for StoreError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Db(e) => write!(f, "database error: {}", e),
+            Self::UnsupportedSchema { found, supported } => {
+                write!(f, "schema version {} not supported (max {})", found, supported)
+            }
+            Self::Malformed(what) => write!(f, "malformed {}", what),
+        }
+    }
+}
+
+impl From<sled::Error> for StoreError {
+    fn from(e: sled::Error) -> Self {
+        Self::Db(e.to_string())
+    }
+}
+
+impl From<TransactionError<StoreError>> for StoreError {
+    fn from(e: TransactionError<StoreError>) -> Self {
+        match e {
+            TransactionError::Abort(e) => e,
+            TransactionError::Storage(e) => e.into(),
+        }
+    }
+}
+
+/// tree a batch operation targets
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Keyspace {
+    State,
+    Proposals,
+    Replay,
+    Contributions,
+    Witnesses,
+}
+
+/// single batched operation
+#[derive(Clone, Debug)]
+enum Op {
+    /// insert or overwrite
+    Put(Keyspace, Vec<u8>, Vec<u8>),
+    /// remove a key
+    Delete(Keyspace, Vec<u8>),
+    /// raise a sender's sequence (never lowers it)
+    Sequence(Vec<u8>, u64),
+    /// remove every key under a prefix
+    DeletePrefix(Keyspace, Vec<u8>),
+}
+
+/// set of writes applied atomically by [`SledBackend::commit`]
+#[derive(Clone, Debug, Default)]
+pub struct WriteBatch {
+    ops: Vec<Op>,
+}
+
+impl WriteBatch {
+    /// create empty batch
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// number of queued operations
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    /// check if batch is empty
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// save syndicate state
+    pub fn put_state(&mut self, syndicate_id: &Hash32, state: &[u8]) -> &mut Self {
+        self.ops.push(Op::Put(Keyspace::State, syndicate_id.to_vec(), state.to_vec()));
+        self
+    }
+
+    /// save a syndicate's note witness state
+    pub fn put_witness(&mut self, syndicate_id: &Hash32, witness: &[u8]) -> &mut Self {
+        self.ops.push(Op::Put(Keyspace::Witnesses, syndicate_id.to_vec(), witness.to_vec()));
+        self
+    }
+
+    /// delete syndicate state and everything stored under the syndicate
+    pub fn delete_syndicate(&mut self, syndicate_id: &Hash32) -> &mut Self {
+        self.ops.push(Op::Delete(Keyspace::State, syndicate_id.to_vec()));
+        self.ops.push(Op::Delete(Keyspace::Witnesses, syndicate_id.to_vec()));
+        for keyspace in [Keyspace::Proposals, Keyspace::Replay, Keyspace::Contributions] {
+            self.ops.push(Op::DeletePrefix(keyspace, syndicate_id.to_vec()));
+        }
+        self
+    }
+
+    /// save a pending proposal
+    pub fn put_proposal(&mut self, syndicate_id: &Hash32, id: ProposalId, proposal: &[u8]) -> &mut Self {
+        self.ops.push(Op::Put(Keyspace::Proposals, proposal_key(syndicate_id, id), proposal.to_vec()));
+        self
+    }
+
+    /// remove a proposal (executed, rejected or expired)
+    pub fn remove_proposal(&mut self, syndicate_id: &Hash32, id: ProposalId) -> &mut Self {
+        self.ops.push(Op::Delete(Keyspace::Proposals, proposal_key(syndicate_id, id)));
+        self
+    }
+
+    /// record the highest sequence seen from a sender
+    pub fn record_sequence(&mut self, syndicate_id: &Hash32, sender: &Hash32, sequence: u64) -> &mut Self {
+        self.ops.push(Op::Sequence(concat(&[syndicate_id, sender]), sequence));
+        self
+    }
+
+    /// forget a sender (member left)
+    pub fn remove_sender(&mut self, syndicate_id: &Hash32, sender: &Hash32) -> &mut Self {
+        self.ops.push(Op::Delete(Keyspace::Replay, concat(&[syndicate_id, sender])));
+        self
+    }
+
+    /// save a member's contribution to a round
+    pub fn put_contribution(
+        &mut self,
+        syndicate_id: &Hash32,
+        round_id: &Hash32,
+        member: &Hash32,
+        contribution: &[u8],
+    ) -> &mut Self {
+        let key = concat(&[syndicate_id, round_id, member]);
+        self.ops.push(Op::Put(Keyspace::Contributions, key, contribution.to_vec()));
+        self
+    }
+
+    /// drop every contribution to a round (finalized or abandoned)
+    pub fn clear_round(&mut self, syndicate_id: &Hash32, round_id: &Hash32) -> &mut Self {
+        self.ops.push(Op::DeletePrefix(Keyspace::Contributions, concat(&[syndicate_id, round_id])));
+        self
+    }
+}
+
+fn concat(parts: &[&Hash32]) -> Vec<u8> {
+    parts.iter().flat_map(|p| p.iter().copied()).collect()
+}
+
+fn proposal_key(syndicate_id: &Hash32, id: ProposalId) -> Vec<u8> {
+    let mut key = syndicate_id.to_vec();
+    key.extend_from_slice(&id.to_be_bytes());
+    key
+}
+
+fn hash_at(bytes: &[u8], offset: usize) -> Result<Hash32, StoreError> {
+    bytes
+        .get(offset..offset + 32)
+        .and_then(|b| b.try_into().ok())
+        .ok_or(StoreError::Malformed("key"))
+}
+
+fn decode_sequence(bytes: &[u8]) -> Result<u64, StoreError> {
+    bytes
+        .try_into()
+        .map(u64::from_le_bytes)
+        .map_err(|_| StoreError::Malformed("sequence"))
+}
+
+/// durable syndicate state on an embedded sled database
+pub struct SledBackend {
+    db: Db,
+    state: Tree,
+    proposals: Tree,
+    replay: Tree,
+    contributions: Tree,
+    witnesses: Tree,
+    /// serializes commits so prefix deletes see a stable key set
+    write_lock: Mutex<()>,
+}
+
+impl SledBackend {
+    /// open (or create) a database at `path`
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, StoreError> {
+        // every commit flushes, so the background flusher isn't needed
+        Self::from_db(sled::Config::new().path(path).flush_every_ms(None).open()?)
+    }
+
+    /// open a throwaway in-memory database (tests, ephemeral nodes)
+    pub fn temporary() -> Result<Self, StoreError> {
+        Self::from_db(sled::Config::new().temporary(true).open()?)
+    }
+
+    fn from_db(db: Db) -> Result<Self, StoreError> {
+        let meta = db.open_tree("meta")?;
+        match meta.get(SCHEMA_KEY)? {
+            None => {
+                meta.insert(SCHEMA_KEY, &SCHEMA_VERSION.to_le_bytes())?;
+                db.flush()?;
+            }
+            Some(bytes) => {
+                let found = bytes
+                    .as_ref()
+                    .try_into()
+                    .map(u32::from_le_bytes)
+                    .map_err(|_| StoreError::Malformed("schema version"))?;
+                if found > SCHEMA_VERSION || found == 0 {
+                    return Err(StoreError::UnsupportedSchema { found, supported: SCHEMA_VERSION });
+                }
+                // migrations from older versions run here, one step at a time
+            }
+        }
+
+        Ok(Self {
+            state: db.open_tree("state")?,
+            proposals: db.open_tree("proposals")?,
+            replay: db.open_tree("replay")?,
+            contributions: db.open_tree("contributions")?,
+            witnesses: db.open_tree("witnesses")?,
+            db,
+            write_lock: Mutex::new(()),
+        })
+    }
+
+    /// on-disk schema version
+    pub fn schema_version(&self) -> Result<u32, StoreError> {
+        let bytes = self
+            .db
+            .open_tree("meta")?
+            .get(SCHEMA_KEY)?
+            .ok_or(StoreError::Malformed("schema version"))?;
+        bytes
+            .as_ref()
+            .try_into()
+            .map(u32::from_le_bytes)
+            .map_err(|_| StoreError::Malformed("schema version"))
+    }
+
+    fn tree(&self, keyspace: Keyspace) -> &Tree {
+        match keyspace {
+            Keyspace::State => &self.state,
+            Keyspace::Proposals => &self.proposals,
+            Keyspace::Replay => &self.replay,
+            Keyspace::Contributions => &self.contributions,
+            Keyspace::Witnesses => &self.witnesses,
+        }
+    }
+
+    /// apply a batch atomically and flush it to disk
+    pub fn commit(&self, batch: &WriteBatch) -> Result<(), StoreError> {
+        if batch.is_empty() {
+            return Ok(());
+        }
+        let _guard = self.write_lock.lock().unwrap_or_else(|e| e.into_inner());
+
+        // transactions can't scan, so expand prefix deletes up front
+        let mut ops = Vec::with_capacity(batch.ops.len());
+        for op in &batch.ops {
+            match op {
+                Op::DeletePrefix(keyspace, prefix) => {
+                    for key in self.tree(*keyspace).scan_prefix(prefix).keys() {
+                        ops.push(Op::Delete(*keyspace, key?.to_vec()));
+                    }
+                }
+                op => ops.push(op.clone()),
+            }
+        }
+
+        (&self.state, &self.proposals, &self.replay, &self.contributions, &self.witnesses).transaction(
+            |(state, proposals, replay, contributions, witnesses)| {
+                let tree = |keyspace: Keyspace| -> &TransactionalTree {
+                    match keyspace {
+                        Keyspace::State => state,
+                        Keyspace::Proposals => proposals,
+                        Keyspace::Replay => replay,
+                        Keyspace::Contributions => contributions,
+                        Keyspace::Witnesses => witnesses,
+                    }
+                };
+                for op in &ops {
+                    match op {
+                        Op::Put(keyspace, key, value) => {
+                            tree(*keyspace).insert(key.as_slice(), value.as_slice())?;
+                        }
+                        Op::Delete(keyspace, key) => {
+                            tree(*keyspace).remove(key.as_slice())?;
+                        }
+                        Op::Sequence(key, sequence) => {
+                            let current = match replay.get(key)? {
+                                Some(bytes) => Some(
+                                    decode_sequence(&bytes).map_err(ConflictableTransactionError::Abort)?,
+                                ),
+                                None => None,
+                            };
+                            if current.is_none_or(|current| *sequence > current) {
+                                replay.insert(key.as_slice(), &sequence.to_le_bytes())?;
+                            }
+                        }
+                        Op::DeletePrefix(..) => unreachable!("expanded above"),
+                    }
+                }
+                Ok(())
+            },
+        )?;
+
+        self.db.flush()?;
+        Ok(())
+    }
+
+    /// pending proposals of a syndicate, by id
+    pub fn proposals(&self, syndicate_id: &Hash32) -> Result<Vec<(ProposalId, Vec<u8>)>, StoreError> {
+        self.proposals
+            .scan_prefix(syndicate_id)
+            .map(|entry| {
+                let (key, value) = entry?;
+                let id = key
+                    .get(32..)
+                    .and_then(|b| b.try_into().ok())
+                    .map(ProposalId::from_be_bytes)
+                    .ok_or(StoreError::Malformed("key"))?;
+                Ok((id, value.to_vec()))
+            })
+            .collect()
+    }
+
+    /// highest sequence seen per sender
+    pub fn sequences(&self, syndicate_id: &Hash32) -> Result<BTreeMap<Hash32, u64>, StoreError> {
+        self.replay
+            .scan_prefix(syndicate_id)
+            .map(|entry| {
+                let (key, value) = entry?;
+                Ok((hash_at(&key, 32)?, decode_sequence(&value)?))
+            })
+            .collect()
+    }
+
+    /// restore persisted senders and sequences into a validator
+    pub fn restore_replay(&self, syndicate_id: &Hash32, validator: &mut ReplayValidator) -> Result<(), StoreError> {
+        for (sender, sequence) in self.sequences(syndicate_id)? {
+            validator.add_sender(sender);
+            validator.record(&sender, sequence);
+        }
+        Ok(())
+    }
+
+    /// contributions collected for a round, by member
+    pub fn contributions(&self, syndicate_id: &Hash32, round_id: &Hash32) -> Result<Vec<(Hash32, Vec<u8>)>, StoreError> {
+        self.contributions
+            .scan_prefix(concat(&[syndicate_id, round_id]))
+            .map(|entry| {
+                let (key, value) = entry?;
+                Ok((hash_at(&key, 64)?, value.to_vec()))
+            })
+            .collect()
+    }
+}
+
+impl StateBackend for SledBackend {
+    type Error = StoreError;
+
+    fn load(&self, syndicate_id: &Hash32) -> Result<Option<Vec<u8>>, Self::Error> {
+        Ok(self.state.get(syndicate_id)?.map(|v| v.to_vec()))
+    }
+
+    fn save(&self, syndicate_id: &Hash32, state: &[u8]) -> Result<(), Self::Error> {
+        self.commit(WriteBatch::new().put_state(syndicate_id, state))
+    }
+
+    fn delete(&self, syndicate_id: &Hash32) -> Result<(), Self::Error> {
+        self.commit(WriteBatch::new().delete_syndicate(syndicate_id))
+    }
+
+    fn list(&self) -> Result<Vec<Hash32>, Self::Error> {
+        self.state
+            .iter()
+            .keys()
+            .map(|key| hash_at(&key?, 0))
+            .collect()
+    }
+
+    fn load_witness(&self, syndicate_id: &Hash32) -> Result<Option<Vec<u8>>, Self::Error> {
+        Ok(self.witnesses.get(syndicate_id)?.map(|v| v.to_vec()))
+    }
+
+    fn save_witness(&self, syndicate_id: &Hash32, witness: &[u8]) -> Result<(), Self::Error> {
+        self.commit(WriteBatch::new().put_witness(syndicate_id, witness))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::replay::ReplayCheck;
+    use crate::wire::{Envelope, MessagePayload, SyncRequest};
+    use std::io::{BufRead, BufReader};
+    use std::process::{Command, Stdio};
+
+    const SYNDICATE: Hash32 = [1u8; 32];
+    const MEMBERS: [Hash32; 3] = [[10u8; 32], [11u8; 32], [12u8; 32]];
+
+    /// env var handing the database path to the crash child
+    const CRASH_DIR: &str = "NARSIL_STORE_CRASH_DIR";
+
+    fn round_id(round: u64) -> Hash32 {
+        let mut id = [0u8; 32];
+        id[..8].copy_from_slice(&round.to_be_bytes());
+        id
+    }
+
+    #[test]
+    fn test_state_backend_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let other = [2u8; 32];
+        {
+            let store = SledBackend::open(dir.path()).unwrap();
+            store.save(&SYNDICATE, b"state v1").unwrap();
+            store.save(&other, b"other").unwrap();
+            store.save(&SYNDICATE, b"state v2").unwrap();
+            assert_eq!(store.schema_version().unwrap(), SCHEMA_VERSION);
+        }
+
+        let store = SledBackend::open(dir.path()).unwrap();
+        assert_eq!(store.load(&SYNDICATE).unwrap(), Some(b"state v2".to_vec()));
+        assert_eq!(store.list().unwrap(), vec![SYNDICATE, other]);
+
+        store.delete(&other).unwrap();
+        assert_eq!(store.load(&other).unwrap(), None);
+        assert_eq!(store.list().unwrap(), vec![SYNDICATE]);
+    }
+
+    #[test]
+    fn test_witness_keyspace() {
+        let store = SledBackend::temporary().unwrap();
+        store.save(&SYNDICATE, b"state").unwrap();
+        store.save_witness(&SYNDICATE, b"witness").unwrap();
+
+        // witnesses neither list as syndicates nor clobber their state
+        assert_eq!(store.list().unwrap(), vec![SYNDICATE]);
+        assert_eq!(store.load(&SYNDICATE).unwrap(), Some(b"state".to_vec()));
+        assert_eq!(store.load_witness(&SYNDICATE).unwrap(), Some(b"witness".to_vec()));
+
+        store.delete(&SYNDICATE).unwrap();
+        assert_eq!(store.load_witness(&SYNDICATE).unwrap(), None);
+        assert!(store.list().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_batch_keyspaces() {
+        let store = SledBackend::temporary().unwrap();
+        let round = round_id(7);
+
+        let mut batch = WriteBatch::new();
+        batch
+            .put_proposal(&SYNDICATE, 2, b"p2")
+            .put_proposal(&SYNDICATE, 1, b"p1")
+            .record_sequence(&SYNDICATE, &MEMBERS[0], 5)
+            .put_contribution(&SYNDICATE, &round, &MEMBERS[1], b"c1")
+            .put_contribution(&SYNDICATE, &round, &MEMBERS[0], b"c0");
+        assert_eq!(batch.len(), 5);
+        store.commit(&batch).unwrap();
+
+        assert_eq!(store.proposals(&SYNDICATE).unwrap(), vec![(1, b"p1".to_vec()), (2, b"p2".to_vec())]);
+        assert_eq!(
+            store.contributions(&SYNDICATE, &round).unwrap(),
+            vec![(MEMBERS[0], b"c0".to_vec()), (MEMBERS[1], b"c1".to_vec())]
+        );
+
+        // sequences never move backwards
+        store.commit(WriteBatch::new().record_sequence(&SYNDICATE, &MEMBERS[0], 3)).unwrap();
+        assert_eq!(store.sequences(&SYNDICATE).unwrap()[&MEMBERS[0]], 5);
+
+        let mut batch = WriteBatch::new();
+        batch.remove_proposal(&SYNDICATE, 1).clear_round(&SYNDICATE, &round);
+        store.commit(&batch).unwrap();
+        assert_eq!(store.proposals(&SYNDICATE).unwrap().len(), 1);
+        assert!(store.contributions(&SYNDICATE, &round).unwrap().is_empty());
+
+        // other syndicates are untouched by a delete
+        let other = [2u8; 32];
+        store.commit(WriteBatch::new().put_proposal(&other, 1, b"x")).unwrap();
+        store.delete(&SYNDICATE).unwrap();
+        assert!(store.proposals(&SYNDICATE).unwrap().is_empty());
+        assert!(store.sequences(&SYNDICATE).unwrap().is_empty());
+        assert_eq!(store.proposals(&other).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_restore_replay_validator() {
+        let dir = tempfile::tempdir().unwrap();
+        let state_hash = [9u8; 32];
+        {
+            let store = SledBackend::open(dir.path()).unwrap();
+            store.commit(WriteBatch::new().record_sequence(&SYNDICATE, &MEMBERS[0], 4)).unwrap();
+        }
+
+        let store = SledBackend::open(dir.path()).unwrap();
+        let mut validator = ReplayValidator::new(state_hash);
+        store.restore_replay(&SYNDICATE, &mut validator).unwrap();
+        assert_eq!(validator.next_sequence(&MEMBERS[0]), Some(5));
+
+        // an envelope replayed after restart is rejected
+        let envelope = Envelope {
+            version: 1,
+            syndicate_id: SYNDICATE,
+            state_hash,
+            sequence: 4,
+            payload: MessagePayload::SyncRequest(SyncRequest {
+                current_state_hash: [0u8; 32],
+                current_sequence: 0,
+            }),
+            signature: [0u8; 64],
+        };
+        assert!(matches!(
+            validator.validate(&envelope, &MEMBERS[0]),
+            ReplayCheck::DuplicateSequence { .. }
+        ));
+    }
+
+    #[test]
+    fn test_schema_version_checked() {
+        let dir = tempfile::tempdir().unwrap();
+        {
+            let db = sled::open(dir.path()).unwrap();
+            db.open_tree("meta").unwrap().insert(SCHEMA_KEY, &(SCHEMA_VERSION + 1).to_le_bytes()).unwrap();
+            db.flush().unwrap();
+        }
+        assert_eq!(
+            SledBackend::open(dir.path()).err(),
+            Some(StoreError::UnsupportedSchema { found: SCHEMA_VERSION + 1, supported: SCHEMA_VERSION })
+        );
+    }
+
+    /// one round: proposal, every member's contribution, their sequences
+    /// and the state pointer, all in one batch
+    fn round_batch(round: u64) -> WriteBatch {
+        let mut batch = WriteBatch::new();
+        batch.put_proposal(&SYNDICATE, round, &round.to_le_bytes());
+        for member in &MEMBERS {
+            batch
+                .put_contribution(&SYNDICATE, &round_id(round), member, &[0xab; 64])
+                .record_sequence(&SYNDICATE, member, round + 1);
+        }
+        batch.put_state(&SYNDICATE, &round.to_le_bytes());
+        batch
+    }
+
+    /// check the store holds exactly rounds 0..=last, each complete
+    fn check_consistent(store: &SledBackend) -> Option<u64> {
+        let last = store.load(&SYNDICATE).unwrap().map(|b| u64::from_le_bytes(b.try_into().unwrap()))?;
+
+        let proposals = store.proposals(&SYNDICATE).unwrap();
+        assert_eq!(proposals.len() as u64, last + 1);
+        for (i, (id, _)) in proposals.iter().enumerate() {
+            assert_eq!(*id, i as u64);
+            assert_eq!(store.contributions(&SYNDICATE, &round_id(*id)).unwrap().len(), MEMBERS.len());
+        }
+        assert!(store.contributions(&SYNDICATE, &round_id(last + 1)).unwrap().is_empty());
+
+        let sequences = store.sequences(&SYNDICATE).unwrap();
+        assert!(MEMBERS.iter().all(|m| sequences[m] == last + 1));
+        Some(last)
+    }
+
+    /// crash child: commits rounds forever, reporting each after commit
+    /// returns. only runs when spawned by `test_crash_recovery`.
+    #[test]
+    fn crash_writer() {
+        let Ok(dir) = std::env::var(CRASH_DIR) else {
+            return;
+        };
+        let store = SledBackend::open(dir).unwrap();
+        let mut round = check_consistent(&store).map_or(0, |last| last + 1);
+        loop {
+            store.commit(&round_batch(round)).unwrap();
+            println!("committed {}", round);
+            round += 1;
+        }
+    }
+
+    #[test]
+    fn test_crash_recovery() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut acknowledged = None;
+
+        for kill_after in [5u64, 17, 40] {
+            let mut child = Command::new(std::env::current_exe().unwrap())
+                .args(["store::tests::crash_writer", "--exact", "--nocapture", "--test-threads=1"])
+                .env(CRASH_DIR, dir.path())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::null())
+                .spawn()
+                .unwrap();
+
+            // kill the writer while it is in the middle of later rounds
+            let stdout = BufReader::new(child.stdout.take().unwrap());
+            for line in stdout.lines() {
+                let line = line.unwrap();
+                if let Some(round) = line.strip_prefix("committed ") {
+                    let round: u64 = round.parse().unwrap();
+                    acknowledged = Some(round);
+                    if round >= kill_after {
+                        child.kill().unwrap();
+                        break;
+                    }
+                }
+            }
+            child.wait().unwrap();
+
+            // every acknowledged round survived, and nothing is half-written
+            let store = SledBackend::open(dir.path()).unwrap();
+            let last = check_consistent(&store).expect("rounds persisted");
+            assert!(last >= acknowledged.unwrap());
+        }
+    }
+
+    #[test]
+    fn test_uncommitted_batch_leaves_no_trace() {
+        let dir = tempfile::tempdir().unwrap();
+        {
+            let store = SledBackend::open(dir.path()).unwrap();
+            store.commit(&round_batch(0)).unwrap();
+            // round 1 is built but the node dies before committing
+            let _pending = round_batch(1);
+        }
+        let store = SledBackend::open(dir.path()).unwrap();
+        assert_eq!(check_consistent(&store), Some(0));
+    }
+}
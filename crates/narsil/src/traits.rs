@@ -207,11 +207,20 @@ pub trait StateBackend {
     /// save state for syndicate
     fn save(&self, syndicate_id: &Hash32, state: &[u8]) -> Result<(), Self::Error>;
 
-    /// delete state
+    /// delete state, including the syndicate's note witnesses
     fn delete(&self, syndicate_id: &Hash32) -> Result<(), Self::Error>;
 
     /// list all syndicate ids
     fn list(&self) -> Result<Vec<Hash32>, Self::Error>;
+
+    /// load note witness state for syndicate
+    fn load_witness(&self, syndicate_id: &Hash32) -> Result<Option<Vec<u8>>, Self::Error>;
+
+    /// save note witness state for syndicate
+    ///
+    /// witnesses live in their own keyspace: they never show up in `list`
+    /// and never overwrite syndicate state
+    fn save_witness(&self, syndicate_id: &Hash32, witness: &[u8]) -> Result<(), Self::Error>;
 }
 
 /// relay backend trait
@@ -453,6 +462,8 @@ mod tests {
         fn save(&self, _id: &Hash32, _state: &[u8]) -> Result<(), Self::Error> { Ok(()) }
         fn delete(&self, _id: &Hash32) -> Result<(), Self::Error> { Ok(()) }
         fn list(&self) -> Result<Vec<Hash32>, Self::Error> { Ok(vec![]) }
+        fn load_witness(&self, _id: &Hash32) -> Result<Option<Vec<u8>>, Self::Error> { Ok(None) }
+        fn save_witness(&self, _id: &Hash32, _witness: &[u8]) -> Result<(), Self::Error> { Ok(()) }
     }
 
     struct TransferAction;
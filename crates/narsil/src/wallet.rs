@@ -67,6 +67,12 @@ pub struct ShieldedNote {
     pub memo: Option<Vec<u8>>,
     /// spent status
     pub spent: bool,
+    /// rseed (for re-deriving note fields when spending)
+    pub rseed: [u8; 32],
+    /// raw recipient address (orchard: 43 bytes, penumbra: 80 bytes)
+    pub recipient: Vec<u8>,
+    /// orchard rho, zero for penumbra
+    pub rho: Hash32,
 }
 
 /// merkle witness for spending a note
@@ -505,6 +511,9 @@ mod tests {
             height: 1000,
             memo: None,
             spent: false,
+            rseed: [0u8; 32],
+            recipient: Vec::new(),
+            rho: [0u8; 32],
         }
     }
 
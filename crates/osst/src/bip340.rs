@@ -0,0 +1,1027 @@
+//! BIP340 FROST ciphersuite for Bitcoin Taproot
+//!
+//! Taproot key-path spends verify BIP340 Schnorr signatures against a 32-byte
+//! x-only public key. Both the key and the nonce commitment are implicitly
+//! the point with even y, and the challenge is a SHA-256 tagged hash:
+//!
+//!   e = SHA256(tag || tag || R_x || Q_x || msg) mod n,  tag = SHA256("BIP0340/challenge")
+//!
+//! A FROST group key and a group commitment each have odd y half the time.
+//! Rather than re-running DKG until the key is even, [`bitcoin::TaprootKey`]
+//! tracks the signs needed to normalize the internal key P, apply the BIP341
+//! tweak Q = P + tG, and normalize Q. Signers fold these signs into their
+//! share and negate their nonces when the group commitment R is odd, so the
+//! aggregate (R_x, z) is an ordinary BIP340 signature under Q_x.
+//!
+//! # Tweaking
+//!
+//! The tweak t is added once by the aggregator, not by the signers: with
+//! g_P, g_Q ∈ {1, -1} the normalizing signs, signer i responds with
+//! λ_i · c · g_P · g_Q · s_i and the aggregator adds c · g_Q · t. The
+//! signature then verifies under g_Q · (g_P · Y + t·G), the even-y output key.
+//!
+//! # References
+//!
+//! - BIP340: Schnorr Signatures for secp256k1
+//! - BIP341: Taproot, SegWit version 1 spending rules
+//! - FROST (Komlo & Goldberg, SAC 2020, RFC 9591)
+
+#[cfg(feature = "secp256k1")]
+pub mod bitcoin {
+    extern crate alloc;
+    use alloc::collections::BTreeMap;
+    use alloc::vec::Vec;
+
+    use crate::curve::{OsstPoint, OsstScalar};
+    use crate::error::OsstError;
+    use crate::frost::{self, Nonces, SignatureShare, SigningCommitments};
+    use crate::lagrange::compute_lagrange_coefficients;
+    use crate::SecretShare;
+
+    use k256::elliptic_curve::ops::Reduce;
+    use k256::elliptic_curve::sec1::ToEncodedPoint;
+    use k256::{FieldBytes, ProjectivePoint, Scalar, U256};
+    use sha2::{Digest, Sha256};
+
+    // ========================================================================
+    // BIP340 primitives
+    // ========================================================================
+
+    /// hash_tag(x) = SHA256(SHA256(tag) || SHA256(tag) || x)
+    fn tagged_hash(tag: &[u8], chunks: &[&[u8]]) -> [u8; 32] {
+        let tag_hash = Sha256::digest(tag);
+        let mut h = Sha256::new();
+        h.update(tag_hash);
+        h.update(tag_hash);
+        for chunk in chunks {
+            h.update(chunk);
+        }
+        h.finalize().into()
+    }
+
+    /// Interpret 32 big-endian bytes as an integer mod n
+    fn reduce(bytes: &[u8; 32]) -> Scalar {
+        <Scalar as Reduce<U256>>::reduce_bytes(&FieldBytes::from(*bytes))
+    }
+
+    fn has_even_y(point: &ProjectivePoint) -> bool {
+        point.to_affine().to_encoded_point(true).as_bytes()[0] == 0x02
+    }
+
+    /// 1 if the point has even y, -1 otherwise
+    fn even_y_sign(point: &ProjectivePoint) -> Scalar {
+        if has_even_y(point) {
+            Scalar::ONE
+        } else {
+            -Scalar::ONE
+        }
+    }
+
+    /// The point with x coordinate `x` and even y, if one exists
+    fn lift_x(x: &[u8; 32]) -> Option<ProjectivePoint> {
+        ProjectivePoint::decompress(x)
+    }
+
+    /// e = hash_BIP0340/challenge(R_x || P_x || m) mod n
+    fn bip340_challenge(r_x: &[u8; 32], p_x: &[u8; 32], message: &[u8]) -> Scalar {
+        reduce(&tagged_hash(b"BIP0340/challenge", &[r_x, p_x, message]))
+    }
+
+    /// Binding factor for BIP340 FROST
+    ///
+    /// Tagged SHA-256 over the output key, message, full commitment list and
+    /// adaptor point (empty when there is none), so a package cannot be
+    /// replayed against a different tweak or adaptor.
+    fn bip340_binding_factor(
+        index: u32,
+        output_key: &[u8; 32],
+        message: &[u8],
+        encoded_commitments: &[u8],
+        adaptor: &[u8],
+    ) -> Scalar {
+        reduce(&tagged_hash(
+            b"FROST/BIP340/rho",
+            &[
+                &index.to_le_bytes(),
+                output_key,
+                &(message.len() as u64).to_le_bytes(),
+                message,
+                encoded_commitments,
+                &(adaptor.len() as u64).to_le_bytes(),
+                adaptor,
+            ],
+        ))
+    }
+
+    // ========================================================================
+    // Keys
+    // ========================================================================
+
+    /// A FROST group key prepared for BIP340 signing
+    ///
+    /// Holds the even-y internal key P, the optional BIP341 tweak t, and the
+    /// even-y output key Q that signatures verify under.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct TaprootKey {
+        internal: ProjectivePoint,
+        output: ProjectivePoint,
+        tweak: Scalar,
+        /// g_P · g_Q, folded into every signer's secret share
+        share_sign: Scalar,
+        /// g_Q, applied to the tweak by the aggregator
+        tweak_sign: Scalar,
+        output_odd: bool,
+    }
+
+    impl TaprootKey {
+        /// Untweaked BIP340 key: Q = P = ±Y with even y.
+        pub fn new(group_pubkey: &ProjectivePoint) -> Result<Self, OsstError> {
+            Self::with_tweak(group_pubkey, Scalar::ZERO)
+        }
+
+        /// BIP341 output key for a key-path spend.
+        ///
+        /// t = hash_TapTweak(P_x || merkle_root), or hash_TapTweak(P_x) when
+        /// the output commits to no script tree.
+        pub fn taproot(
+            group_pubkey: &ProjectivePoint,
+            merkle_root: Option<&[u8; 32]>,
+        ) -> Result<Self, OsstError> {
+            let p_x = group_pubkey.compress();
+            let root: &[u8] = match merkle_root {
+                Some(root) => root,
+                None => &[],
+            };
+            let tweak = tagged_hash(b"TapTweak", &[&p_x, root]);
+            // BIP341 rejects a tweak hash >= n rather than reducing it
+            let tweak = Scalar::from_canonical_bytes(&tweak).ok_or(OsstError::InvalidResponse)?;
+            Self::with_tweak(group_pubkey, tweak)
+        }
+
+        /// Q = ±(P + t·G) for an arbitrary tweak t.
+        pub fn with_tweak(
+            group_pubkey: &ProjectivePoint,
+            tweak: Scalar,
+        ) -> Result<Self, OsstError> {
+            if *group_pubkey == ProjectivePoint::IDENTITY {
+                return Err(OsstError::InvalidCommitment);
+            }
+            let internal_sign = even_y_sign(group_pubkey);
+            let internal = group_pubkey.mul_scalar(&internal_sign);
+
+            let tweaked = internal.add(&ProjectivePoint::GENERATOR.mul_scalar(&tweak));
+            if tweaked == ProjectivePoint::IDENTITY {
+                return Err(OsstError::InvalidCommitment);
+            }
+            let output_odd = !has_even_y(&tweaked);
+            let tweak_sign = even_y_sign(&tweaked);
+
+            Ok(Self {
+                internal,
+                output: tweaked.mul_scalar(&tweak_sign),
+                tweak,
+                share_sign: internal_sign.mul(&tweak_sign),
+                tweak_sign,
+                output_odd,
+            })
+        }
+
+        /// x-only internal key P_x.
+        pub fn internal_key(&self) -> [u8; 32] {
+            self.internal.compress()
+        }
+
+        /// x-only output key Q_x, the key signatures verify under.
+        pub fn output_key(&self) -> [u8; 32] {
+            self.output.compress()
+        }
+
+        /// Whether P + t·G had odd y (the BIP341 control block parity bit).
+        pub fn output_parity(&self) -> bool {
+            self.output_odd
+        }
+
+        /// The tweak t (zero for an untweaked key).
+        pub fn tweak(&self) -> &Scalar {
+            &self.tweak
+        }
+    }
+
+    // ========================================================================
+    // BIP340 FROST signing (wraps generic FROST with BIP340 hashes)
+    // ========================================================================
+
+    /// BIP340 signing package bound to one output key.
+    pub struct Bip340Package {
+        key: TaprootKey,
+        message: Vec<u8>,
+        commitments: BTreeMap<u32, SigningCommitments<ProjectivePoint>>,
+        encoded_commitments: Vec<u8>,
+        /// adaptor point T for pre-signing
+        adaptor: Option<ProjectivePoint>,
+    }
+
+    impl Bip340Package {
+        pub fn new(
+            key: TaprootKey,
+            message: Vec<u8>,
+            commitments: Vec<SigningCommitments<ProjectivePoint>>,
+        ) -> Result<Self, OsstError> {
+            let mut map = BTreeMap::new();
+            for c in commitments {
+                if c.index == 0 {
+                    return Err(OsstError::InvalidIndex);
+                }
+                if map.contains_key(&c.index) {
+                    return Err(OsstError::DuplicateIndex(c.index));
+                }
+                map.insert(c.index, c);
+            }
+            if map.is_empty() {
+                return Err(OsstError::EmptyContributions);
+            }
+
+            let encoded = encode_commitments(&map);
+
+            Ok(Self {
+                key,
+                message,
+                commitments: map,
+                encoded_commitments: encoded,
+                adaptor: None,
+            })
+        }
+
+        /// Package for a pre-signature under adaptor point T.
+        ///
+        /// T is hashed into every binding factor and the challenge is taken
+        /// over x(R + T), so the completed signature is a BIP340 signature.
+        pub fn with_adaptor(
+            key: TaprootKey,
+            message: Vec<u8>,
+            commitments: Vec<SigningCommitments<ProjectivePoint>>,
+            adaptor: ProjectivePoint,
+        ) -> Result<Self, OsstError> {
+            if adaptor == ProjectivePoint::IDENTITY {
+                return Err(OsstError::InvalidCommitment);
+            }
+            let mut package = Self::new(key, message, commitments)?;
+            package.adaptor = Some(adaptor);
+            Ok(package)
+        }
+
+        pub fn key(&self) -> &TaprootKey {
+            &self.key
+        }
+
+        pub fn adaptor(&self) -> Option<&ProjectivePoint> {
+            self.adaptor.as_ref()
+        }
+
+        pub fn signer_indices(&self) -> Vec<u32> {
+            self.commitments.keys().copied().collect()
+        }
+
+        pub fn num_signers(&self) -> usize {
+            self.commitments.len()
+        }
+
+        fn binding_factor(&self, index: u32) -> Scalar {
+            let adaptor = self
+                .adaptor
+                .as_ref()
+                .map(|t| t.compress_vec())
+                .unwrap_or_default();
+            bip340_binding_factor(
+                index,
+                &self.key.output_key(),
+                &self.message,
+                &self.encoded_commitments,
+                &adaptor,
+            )
+        }
+
+        /// R = Σ (D_i + ρ_i · E_i), before even-y normalization.
+        fn group_commitment(&self) -> ProjectivePoint {
+            let mut r = ProjectivePoint::IDENTITY;
+            for c in self.commitments.values() {
+                let rho = self.binding_factor(c.index);
+                let bound = c.binding.mul_scalar(&rho);
+                r = r.add(&c.hiding);
+                r = r.add(&bound);
+            }
+            r
+        }
+
+        /// The nonce point the signature carries: R, or R + T when pre-signing.
+        fn nonce(&self, group_commitment: &ProjectivePoint) -> ProjectivePoint {
+            match &self.adaptor {
+                Some(adaptor) => group_commitment.add(adaptor),
+                None => *group_commitment,
+            }
+        }
+
+        fn challenge(&self, nonce: &ProjectivePoint) -> Scalar {
+            bip340_challenge(&nonce.compress(), &self.key.output_key(), &self.message)
+        }
+    }
+
+    /// Full 33-byte encodings, so the binding factor also commits to y parity.
+    fn encode_commitments(
+        commitments: &BTreeMap<u32, SigningCommitments<ProjectivePoint>>,
+    ) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(commitments.len() * 70);
+        for c in commitments.values() {
+            buf.extend_from_slice(&c.index.to_le_bytes());
+            buf.extend_from_slice(&c.hiding.compress_vec());
+            buf.extend_from_slice(&c.binding.compress_vec());
+        }
+        buf
+    }
+
+    /// Round 1: generate nonces and commitments (same as generic FROST).
+    pub fn commit<R: rand_core::RngCore + rand_core::CryptoRng>(
+        index: u32,
+        rng: &mut R,
+    ) -> (Nonces<Scalar>, SigningCommitments<ProjectivePoint>) {
+        frost::commit::<ProjectivePoint, R>(index, rng)
+    }
+
+    /// Round 2: produce a BIP340 signature share.
+    ///
+    /// z_i = g_R · (d_i + ρ_i · e_i) + λ_i · c · g_P · g_Q · s_i
+    pub fn sign(
+        package: &Bip340Package,
+        nonces: Nonces<Scalar>,
+        share: &SecretShare<Scalar>,
+    ) -> Result<SignatureShare<Scalar>, OsstError> {
+        if package.adaptor.is_some() {
+            return Err(OsstError::InvalidCommitment);
+        }
+        sign_share(package, nonces, share)
+    }
+
+    fn sign_share(
+        package: &Bip340Package,
+        nonces: Nonces<Scalar>,
+        share: &SecretShare<Scalar>,
+    ) -> Result<SignatureShare<Scalar>, OsstError> {
+        if !package.commitments.contains_key(&share.index) {
+            return Err(OsstError::InvalidIndex);
+        }
+
+        let rho = package.binding_factor(share.index);
+        let nonce = package.nonce(&package.group_commitment());
+        if nonce == ProjectivePoint::IDENTITY {
+            return Err(OsstError::InvalidCommitment);
+        }
+        let challenge = package.challenge(&nonce);
+
+        let indices = package.signer_indices();
+        let lagrange = compute_lagrange_coefficients::<Scalar>(&indices)?;
+        let my_pos = indices
+            .iter()
+            .position(|&i| i == share.index)
+            .ok_or(OsstError::InvalidIndex)?;
+        let lambda = &lagrange[my_pos];
+
+        let secret = share.scalar().mul(&package.key.share_sign);
+
+        // an odd R is signed as -R, which negates the nonces:
+        // -(d_i + ρ_i·e_i + λ_i·c·(-s)) = -(d_i + ρ_i·e_i) + λ_i·c·s
+        let response = if has_even_y(&nonce) {
+            nonces.compute_response(&rho, lambda, &challenge, &secret)
+        } else {
+            nonces
+                .compute_response(&rho, lambda, &challenge, &secret.neg())
+                .neg()
+        };
+
+        Ok(SignatureShare {
+            index: share.index,
+            response,
+        })
+    }
+
+    /// Aggregate signature shares into a 64-byte BIP340 signature R_x || z.
+    pub fn aggregate(
+        package: &Bip340Package,
+        shares: &[SignatureShare<Scalar>],
+        verifier_shares: Option<&BTreeMap<u32, ProjectivePoint>>,
+    ) -> Result<[u8; 64], OsstError> {
+        if package.adaptor.is_some() {
+            return Err(OsstError::InvalidCommitment);
+        }
+        let (group_commitment, z) = combine(package, shares, verifier_shares)?;
+
+        let mut sig = [0u8; 64];
+        sig[..32].copy_from_slice(&group_commitment.compress());
+        sig[32..].copy_from_slice(&OsstScalar::to_bytes(&z));
+        Ok(sig)
+    }
+
+    /// (R, Σ z_i + c · g_Q · t) after optional share verification
+    fn combine(
+        package: &Bip340Package,
+        shares: &[SignatureShare<Scalar>],
+        verifier_shares: Option<&BTreeMap<u32, ProjectivePoint>>,
+    ) -> Result<(ProjectivePoint, Scalar), OsstError> {
+        if shares.len() < package.num_signers() {
+            return Err(OsstError::InsufficientContributions {
+                got: shares.len(),
+                need: package.num_signers(),
+            });
+        }
+
+        let group_commitment = package.group_commitment();
+        let nonce = package.nonce(&group_commitment);
+        if nonce == ProjectivePoint::IDENTITY {
+            return Err(OsstError::InvalidCommitment);
+        }
+        let challenge = package.challenge(&nonce);
+        let nonce_sign = even_y_sign(&nonce);
+
+        // optional share verification:
+        // z_i·G == g_R · (D_i + ρ_i·E_i) + λ_i · c · g_P · g_Q · Y_i
+        if let Some(vshares) = verifier_shares {
+            let indices = package.signer_indices();
+            let lagrange = compute_lagrange_coefficients::<Scalar>(&indices)?;
+
+            for share in shares {
+                let pos = indices
+                    .iter()
+                    .position(|&i| i == share.index)
+                    .ok_or(OsstError::InvalidIndex)?;
+
+                let yi = vshares
+                    .get(&share.index)
+                    .ok_or(OsstError::InvalidIndex)?;
+
+                let rho = package.binding_factor(share.index);
+                let comm = package
+                    .commitments
+                    .get(&share.index)
+                    .ok_or(OsstError::InvalidIndex)?;
+
+                let weight = lagrange[pos]
+                    .mul(&challenge)
+                    .mul(&package.key.share_sign);
+                let lhs = ProjectivePoint::GENERATOR.mul_scalar(&share.response);
+                let rhs = comm
+                    .hiding
+                    .add(&comm.binding.mul_scalar(&rho))
+                    .mul_scalar(&nonce_sign)
+                    .add(&yi.mul_scalar(&weight));
+
+                if lhs != rhs {
+                    return Err(OsstError::InvalidResponse);
+                }
+            }
+        }
+
+        // z = Σ z_i + c · g_Q · t
+        let mut z = challenge
+            .mul(&package.key.tweak_sign)
+            .mul(&package.key.tweak);
+        for share in shares {
+            z = z.add(&share.response);
+        }
+        Ok((group_commitment, z))
+    }
+
+    /// Verify a BIP340 signature against an x-only public key.
+    pub fn verify_signature(public_key: &[u8; 32], message: &[u8], signature: &[u8; 64]) -> bool {
+        let p = match lift_x(public_key) {
+            Some(p) => p,
+            None => return false,
+        };
+        let r_x: [u8; 32] = signature[..32].try_into().expect("32 bytes");
+        let s_bytes: [u8; 32] = signature[32..].try_into().expect("32 bytes");
+        let s = match Scalar::from_canonical_bytes(&s_bytes) {
+            Some(s) => s,
+            None => return false,
+        };
+
+        let e = bip340_challenge(&r_x, public_key, message);
+        let r = ProjectivePoint::GENERATOR
+            .mul_scalar(&s)
+            .add(&p.mul_scalar(&e.neg()));
+
+        // x(R) is always < p, so a non-canonical r_x never matches
+        r != ProjectivePoint::IDENTITY && has_even_y(&r) && r.compress() == r_x
+    }
+
+    // ========================================================================
+    // Adaptor signatures
+    // ========================================================================
+
+    /// BIP340 pre-signature (R, T, ẑ) under adaptor point T.
+    ///
+    /// The signature nonce is N = g_N · (R + T) with g_N the sign making N
+    /// even, and ẑ·G == g_N · R + e · Q. Adding g_N · t gives a BIP340
+    /// signature (N_x, ẑ + g_N · t) under Q_x.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Bip340PreSignature {
+        /// FROST group commitment R (without the adaptor)
+        pub r: ProjectivePoint,
+        /// Adaptor point T = t·G
+        pub adaptor: ProjectivePoint,
+        /// Aggregate response ẑ
+        pub z: Scalar,
+    }
+
+    impl Bip340PreSignature {
+        fn nonce_sign(&self) -> Scalar {
+            even_y_sign(&self.r.add(&self.adaptor))
+        }
+
+        /// Check ẑ·G == g_N · R + e · Q with e over x(R + T).
+        pub fn verify(&self, output_key: &[u8; 32], message: &[u8]) -> bool {
+            let q = match lift_x(output_key) {
+                Some(q) => q,
+                None => return false,
+            };
+            let nonce = self.r.add(&self.adaptor);
+            if nonce == ProjectivePoint::IDENTITY {
+                return false;
+            }
+            let e = bip340_challenge(&nonce.compress(), output_key, message);
+            let lhs = ProjectivePoint::GENERATOR.mul_scalar(&self.z);
+            let rhs = self
+                .r
+                .mul_scalar(&self.nonce_sign())
+                .add(&q.mul_scalar(&e));
+            lhs == rhs
+        }
+
+        /// Complete into the 64-byte BIP340 signature.
+        ///
+        /// # Errors
+        ///
+        /// Returns `InvalidResponse` if t·G != T.
+        pub fn complete(&self, secret: &Scalar) -> Result<[u8; 64], OsstError> {
+            if ProjectivePoint::GENERATOR.mul_scalar(secret) != self.adaptor {
+                return Err(OsstError::InvalidResponse);
+            }
+            let z = self.z.add(&self.nonce_sign().mul(secret));
+
+            let mut sig = [0u8; 64];
+            sig[..32].copy_from_slice(&self.r.add(&self.adaptor).compress());
+            sig[32..].copy_from_slice(&OsstScalar::to_bytes(&z));
+            Ok(sig)
+        }
+
+        /// Recover t = g_N · (z − ẑ) from the completed signature.
+        pub fn extract(&self, signature: &[u8; 64]) -> Option<Scalar> {
+            if signature[..32] != self.r.add(&self.adaptor).compress() {
+                return None;
+            }
+            let z_bytes: [u8; 32] = signature[32..].try_into().expect("32 bytes");
+            let z = Scalar::from_canonical_bytes(&z_bytes)?;
+            let secret = z.sub(&self.z).mul(&self.nonce_sign());
+            if ProjectivePoint::GENERATOR.mul_scalar(&secret) != self.adaptor {
+                return None;
+            }
+            Some(secret)
+        }
+    }
+
+    /// Round 2 against the package's adaptor point T: as [`sign`] with the
+    /// challenge and nonce parity taken over R + T.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidCommitment` if the package has no adaptor point.
+    pub fn sign_adaptor(
+        package: &Bip340Package,
+        nonces: Nonces<Scalar>,
+        share: &SecretShare<Scalar>,
+    ) -> Result<SignatureShare<Scalar>, OsstError> {
+        if package.adaptor.is_none() {
+            return Err(OsstError::InvalidCommitment);
+        }
+        sign_share(package, nonces, share)
+    }
+
+    /// Aggregate adaptor shares into a [`Bip340PreSignature`].
+    pub fn aggregate_adaptor(
+        package: &Bip340Package,
+        shares: &[SignatureShare<Scalar>],
+        verifier_shares: Option<&BTreeMap<u32, ProjectivePoint>>,
+    ) -> Result<Bip340PreSignature, OsstError> {
+        let adaptor = *package.adaptor.as_ref().ok_or(OsstError::InvalidCommitment)?;
+        let (r, z) = combine(package, shares, verifier_shares)?;
+        Ok(Bip340PreSignature { r, adaptor, z })
+    }
+
+    // ========================================================================
+    // Single-signer BIP340
+    // ========================================================================
+
+    /// x-only public key for a secret key.
+    pub fn public_key(secret: &Scalar) -> [u8; 32] {
+        ProjectivePoint::GENERATOR.mul_scalar(secret).compress()
+    }
+
+    /// BIP340 default signing with auxiliary randomness.
+    ///
+    /// Deterministic given `aux_rand`; matches the BIP340 test vectors. Used
+    /// for single-key Taproot spends alongside threshold keys.
+    pub fn sign_single(
+        secret: &Scalar,
+        message: &[u8],
+        aux_rand: &[u8; 32],
+    ) -> Result<[u8; 64], OsstError> {
+        if *secret == Scalar::ZERO {
+            return Err(OsstError::InvalidResponse);
+        }
+        let p = ProjectivePoint::GENERATOR.mul_scalar(secret);
+        let d = secret.mul(&even_y_sign(&p));
+        let p_x = p.compress();
+
+        let mut t = OsstScalar::to_bytes(&d);
+        let aux_hash = tagged_hash(b"BIP0340/aux", &[aux_rand]);
+        for (b, a) in t.iter_mut().zip(aux_hash.iter()) {
+            *b ^= a;
+        }
+
+        let k = reduce(&tagged_hash(b"BIP0340/nonce", &[&t, &p_x, message]));
+        if k == Scalar::ZERO {
+            return Err(OsstError::ZeroChallenge);
+        }
+        let r = ProjectivePoint::GENERATOR.mul_scalar(&k);
+        let k = k.mul(&even_y_sign(&r));
+        let r_x = r.compress();
+
+        let e = bip340_challenge(&r_x, &p_x, message);
+        let s = k.add(&e.mul(&d));
+
+        let mut sig = [0u8; 64];
+        sig[..32].copy_from_slice(&r_x);
+        sig[32..].copy_from_slice(&OsstScalar::to_bytes(&s));
+        Ok(sig)
+    }
+}
+
+#[cfg(all(test, feature = "secp256k1"))]
+mod tests {
+    use super::bitcoin::*;
+    use crate::curve::{OsstPoint, OsstScalar};
+    use crate::SecretShare;
+    use alloc::collections::BTreeMap;
+    use k256::{ProjectivePoint, Scalar};
+    use rand::rngs::OsRng;
+
+    fn h32(s: &str) -> [u8; 32] {
+        hex::decode(s).unwrap().try_into().unwrap()
+    }
+
+    fn h64(s: &str) -> [u8; 64] {
+        hex::decode(s).unwrap().try_into().unwrap()
+    }
+
+    fn shamir_split(secret: &Scalar, n: u32, t: u32) -> Vec<SecretShare<Scalar>> {
+        let mut rng = OsRng;
+        let mut coeffs = vec![*secret];
+        for _ in 1..t {
+            coeffs.push(Scalar::random(&mut rng));
+        }
+        (1..=n)
+            .map(|i| {
+                let x = Scalar::from_u32(i);
+                let mut y = Scalar::zero();
+                let mut x_pow = Scalar::one();
+                for c in &coeffs {
+                    y = y.add(&c.mul(&x_pow));
+                    x_pow = x_pow.mul(&x);
+                }
+                SecretShare::new(i, y)
+            })
+            .collect()
+    }
+
+    /// Run a t-of-n FROST session and return the BIP340 signature
+    fn threshold_sign(
+        key: &TaprootKey,
+        shares: &[SecretShare<Scalar>],
+        message: &[u8],
+        verifier_shares: Option<&BTreeMap<u32, ProjectivePoint>>,
+    ) -> [u8; 64] {
+        let mut rng = OsRng;
+        let mut nonces_vec = Vec::new();
+        let mut comms = Vec::new();
+        for s in shares {
+            let (n, c) = commit(s.index, &mut rng);
+            nonces_vec.push(n);
+            comms.push(c);
+        }
+        let package = Bip340Package::new(key.clone(), message.to_vec(), comms).unwrap();
+        let sig_shares: Vec<_> = shares
+            .iter()
+            .zip(nonces_vec)
+            .map(|(s, n)| sign(&package, n, s).unwrap())
+            .collect();
+        aggregate(&package, &sig_shares, verifier_shares).unwrap()
+    }
+
+    // (secret key, public key, aux_rand, message, signature) from BIP340
+    const SIGN_VECTORS: &[(&str, &str, &str, &str, &str)] = &[
+        (
+            "0000000000000000000000000000000000000000000000000000000000000003",
+            "F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "E907831F80848D1069A5371B402410364BDF1C5F8307B0084C55F1CE2DCA821525F66A4A85EA8B71E482A74F382D2CE5EBEEE8FDB2172F477DF4900D310536C0",
+        ),
+        (
+            "B7E151628AED2A6ABF7158809CF4F3C762E7160F38B4DA56A784D9045190CFEF",
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "6896BD60EEAE296DB48A229FF71DFE071BDE413E6D43F917DC8DCF8C78DE33418906D11AC976ABCCB20B091292BFF4EA897EFCB639EA871CFA95F6DE339E4B0A",
+        ),
+        (
+            "C90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74020BBEA63B14E5C9",
+            "DD308AFEC5777E13121FA72B9CC1B7CC0139715309B086C960E18FD969774EB8",
+            "C87AA53824B4D7AE2EB035A2B5BBBCCC080E76CDC6D1692C4B0B62D798E6D906",
+            "7E2D58D8B3BCDF1ABADEC7829054F90DDA9805AAB56C77333024B9D0A508B75C",
+            "5831AAEED7B44BB74E5EAB94BA9D4294C49BCF2A60728D8B4C200F50DD313C1BAB745879A5AD954A72C45A91C3A51D3C7ADEA98D82F8481E0E1E03674A6F3FB7",
+        ),
+        (
+            "0B432B2677937381AEF05BB02A66ECD012773062CF3FA2549E44F58ED2401710",
+            "25D1DFF95105F5253C4022F628A996AD3A0D95FBF21D468A1B33F8C160D8F517",
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF",
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF",
+            "7EB0509757E246F19449885651611CB965ECC1A187DD51B64FDA1EDC9637D5EC97582B9CB13DB3933705B32BA982AF5AF25FD78881EBB32771FC5922EFC66EA3",
+        ),
+    ];
+
+    // (public key, message, signature, valid) from BIP340
+    const VERIFY_VECTORS: &[(&str, &str, &str, bool)] = &[
+        // valid, R_x has leading zero bytes
+        (
+            "D69C3509BB99E412E68B0FE8544E72837DFA30746D8BE2AA65975F29D22DC7B9",
+            "4DF3C3F68FCC83B27E9D42C90431A72499F17875C81A599B566C9889B9696703",
+            "00000000000000000000003B78CE563F89A0ED9414F5AA28AD0D96D6795F9C6376AFB1548AF603B3EB45C9F8207DEE1060CB71C04E80F593060B07D28308D7F4",
+            true,
+        ),
+        // public key not on curve
+        (
+            "EEFDEA4CDB677750A420FEE807EACF21EB9898AE79B9768766E4FAA04A2D4A34",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E17776969E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B",
+            false,
+        ),
+        // has_even_y(R) is false
+        (
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "FFF97BD5755EEEA420453A14355235D382F6472F8568A18B2F057A14602975563CC27944640AC607CD107AE10923D9EF7A73C643E166BE5EBEAFA34B1AC553E2",
+            false,
+        ),
+        // negated message
+        (
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "1FA62E331EDBC21C394792D2AB1100A7B432B013DF3F6FF4F99FCB33E0E1515F28890B3EDB6E7189B630448B515CE4F8622A954CFE545735AAEA5134FCCDB2BD",
+            false,
+        ),
+        // negated s value
+        (
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E177769961764B3AA9B2FFCB6EF947B6887A226E8D7C93E00C5ED0C1834FF0D0C2E6DA6",
+            false,
+        ),
+        // sG - eP is infinite (x(inf) as 0)
+        (
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "0000000000000000000000000000000000000000000000000000000000000000123DDA8328AF9C23A94C1FEECFD123BA4FB73476F0D594DCB65C6425BD186051",
+            false,
+        ),
+        // sG - eP is infinite (x(inf) as 1)
+        (
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "00000000000000000000000000000000000000000000000000000000000000017615FBAF5AE28864013C099742DEADB4DBA87F11AC6754F93780D5A1837CF197",
+            false,
+        ),
+        // sig[0:32] is not an x coordinate on the curve
+        (
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "4A298DACAE57395A15D0795DDBFD1DCB564DA82B0F269BC70A74F8220429BA1D69E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B",
+            false,
+        ),
+        // sig[0:32] is equal to the field size
+        (
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F69E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B",
+            false,
+        ),
+        // sig[32:64] is equal to the curve order
+        (
+            "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E177769FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+            false,
+        ),
+        // public key exceeds the field size
+        (
+            "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC30",
+            "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+            "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E17776969E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B",
+            false,
+        ),
+    ];
+
+    #[test]
+    fn test_bip340_sign_vectors() {
+        for (i, (sk, pk, aux, msg, sig)) in SIGN_VECTORS.iter().enumerate() {
+            let secret = Scalar::from_canonical_bytes(&h32(sk)).unwrap();
+            assert_eq!(public_key(&secret), h32(pk), "vector {} public key", i);
+
+            let produced = sign_single(&secret, &h32(msg), &h32(aux)).unwrap();
+            assert_eq!(produced, h64(sig), "vector {} signature", i);
+            assert!(verify_signature(&h32(pk), &h32(msg), &produced));
+        }
+    }
+
+    #[test]
+    fn test_bip340_verify_vectors() {
+        for (i, (pk, msg, sig, valid)) in VERIFY_VECTORS.iter().enumerate() {
+            assert_eq!(
+                verify_signature(&h32(pk), &h32(msg), &h64(sig)),
+                *valid,
+                "verify vector {}",
+                i + 4
+            );
+        }
+    }
+
+    #[test]
+    fn test_threshold_bip340_untweaked() {
+        let mut rng = OsRng;
+        let secret = Scalar::random(&mut rng);
+        let group_pubkey = ProjectivePoint::GENERATOR.mul_scalar(&secret);
+        let shares = shamir_split(&secret, 5, 3);
+
+        let key = TaprootKey::new(&group_pubkey).unwrap();
+        assert_eq!(key.output_key(), group_pubkey.compress());
+        assert_eq!(key.internal_key(), key.output_key());
+
+        let message = b"taproot key path spend";
+        let sig = threshold_sign(&key, &shares[1..4], message, None);
+        assert!(verify_signature(&key.output_key(), message, &sig));
+        assert!(!verify_signature(&key.output_key(), b"wrong", &sig));
+    }
+
+    #[test]
+    fn test_threshold_bip340_odd_group_key() {
+        let mut rng = OsRng;
+        // find a secret whose public key has odd y
+        let (secret, group_pubkey) = loop {
+            let s = Scalar::random(&mut rng);
+            let y = ProjectivePoint::GENERATOR.mul_scalar(&s);
+            if y.compress_vec()[0] == 0x03 {
+                break (s, y);
+            }
+        };
+        let shares = shamir_split(&secret, 3, 2);
+        let key = TaprootKey::new(&group_pubkey).unwrap();
+
+        let verifiers: BTreeMap<u32, ProjectivePoint> = shares
+            .iter()
+            .map(|s| (s.index, ProjectivePoint::GENERATOR.mul_scalar(s.scalar())))
+            .collect();
+
+        // many sessions so both parities of R are exercised
+        for round in 0u8..8 {
+            let message = [round; 32];
+            let sig = threshold_sign(&key, &shares[..2], &message, Some(&verifiers));
+            assert!(verify_signature(&key.output_key(), &message, &sig));
+        }
+    }
+
+    #[test]
+    fn test_threshold_bip340_taproot_tweak() {
+        let mut rng = OsRng;
+        let secret = Scalar::random(&mut rng);
+        let group_pubkey = ProjectivePoint::GENERATOR.mul_scalar(&secret);
+        let shares = shamir_split(&secret, 3, 2);
+
+        let verifiers: BTreeMap<u32, ProjectivePoint> = shares
+            .iter()
+            .map(|s| (s.index, ProjectivePoint::GENERATOR.mul_scalar(s.scalar())))
+            .collect();
+
+        for root in [None, Some([7u8; 32])] {
+            let key = TaprootKey::taproot(&group_pubkey, root.as_ref()).unwrap();
+            assert_ne!(key.output_key(), key.internal_key());
+
+            // output key matches a single signer holding the tweaked secret
+            let mut d = secret;
+            if group_pubkey.compress_vec()[0] == 0x03 {
+                d = d.neg();
+            }
+            let tweaked = d.add(key.tweak());
+            assert_eq!(public_key(&tweaked), key.output_key());
+            let parity = ProjectivePoint::GENERATOR.mul_scalar(&tweaked).compress_vec()[0];
+            assert_eq!(key.output_parity(), parity == 0x03);
+
+            let message = b"tweaked output";
+            let sig = threshold_sign(&key, &[shares[0].clone(), shares[2].clone()], message, Some(&verifiers));
+            assert!(verify_signature(&key.output_key(), message, &sig));
+            assert!(!verify_signature(&key.internal_key(), message, &sig));
+        }
+    }
+
+    /// Completed pre-signatures verify under k256's BIP340 verifier, across
+    /// both parities of R + T and a tweaked output key
+    #[test]
+    fn test_threshold_bip340_adaptor() {
+        use k256::schnorr;
+
+        let mut rng = OsRng;
+        let secret = Scalar::random(&mut rng);
+        let group_pubkey = ProjectivePoint::GENERATOR.mul_scalar(&secret);
+        let shares = shamir_split(&secret, 3, 2);
+        let key = TaprootKey::taproot(&group_pubkey, None).unwrap();
+        let verifiers: BTreeMap<u32, ProjectivePoint> = shares
+            .iter()
+            .map(|s| (s.index, ProjectivePoint::GENERATOR.mul_scalar(s.scalar())))
+            .collect();
+        let chain_key = schnorr::VerifyingKey::from_bytes(&key.output_key()).unwrap();
+
+        for round in 0u8..8 {
+            let message = [round; 32];
+            let t = Scalar::random(&mut rng);
+            let adaptor = ProjectivePoint::GENERATOR.mul_scalar(&t);
+
+            let mut nonces_vec = Vec::new();
+            let mut comms = Vec::new();
+            for s in &shares[..2] {
+                let (n, c) = commit(s.index, &mut rng);
+                nonces_vec.push(n);
+                comms.push(c);
+            }
+            let package =
+                Bip340Package::with_adaptor(key.clone(), message.to_vec(), comms, adaptor).unwrap();
+            let sig_shares: Vec<_> = shares[..2]
+                .iter()
+                .zip(nonces_vec)
+                .map(|(s, n)| sign_adaptor(&package, n, s).unwrap())
+                .collect();
+            assert!(matches!(
+                aggregate(&package, &sig_shares, None),
+                Err(crate::OsstError::InvalidCommitment)
+            ));
+            let presig = aggregate_adaptor(&package, &sig_shares, Some(&verifiers)).unwrap();
+            assert!(presig.verify(&key.output_key(), &message));
+            assert!(matches!(
+                presig.complete(&Scalar::random(&mut rng)),
+                Err(crate::OsstError::InvalidResponse)
+            ));
+
+            let sig = presig.complete(&t).unwrap();
+            assert!(verify_signature(&key.output_key(), &message, &sig));
+            assert_eq!(presig.extract(&sig), Some(t));
+
+            let chain_sig = schnorr::Signature::try_from(&sig[..]).unwrap();
+            assert!(chain_key.verify_raw(&message, &chain_sig).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_bad_share_rejected() {
+        let mut rng = OsRng;
+        let secret = Scalar::random(&mut rng);
+        let group_pubkey = ProjectivePoint::GENERATOR.mul_scalar(&secret);
+        let shares = shamir_split(&secret, 3, 2);
+        let key = TaprootKey::taproot(&group_pubkey, None).unwrap();
+
+        let verifiers: BTreeMap<u32, ProjectivePoint> = shares
+            .iter()
+            .map(|s| (s.index, ProjectivePoint::GENERATOR.mul_scalar(s.scalar())))
+            .collect();
+
+        let mut nonces_vec = Vec::new();
+        let mut comms = Vec::new();
+        for s in &shares[..2] {
+            let (n, c) = commit(s.index, &mut rng);
+            nonces_vec.push(n);
+            comms.push(c);
+        }
+        let package = Bip340Package::new(key, b"msg".to_vec(), comms).unwrap();
+        let mut sig_shares: Vec<_> = shares[..2]
+            .iter()
+            .zip(nonces_vec)
+            .map(|(s, n)| sign(&package, n, s).unwrap())
+            .collect();
+        sig_shares[1].response = sig_shares[1].response.add(&Scalar::one());
+
+        assert!(aggregate(&package, &sig_shares, Some(&verifiers)).is_err());
+    }
+}
@@ -302,8 +302,10 @@ pub mod pallas {
             <Point as Group>::identity()
         }
 
+        /// Orchard's spend authorization base point rather than pasta's
+        /// `Group::generator()`, so pallas keys are valid RedPallas keys
         fn generator() -> Self {
-            <Point as Group>::generator()
+            crate::redpallas::zcash::spend_auth_basepoint()
         }
 
         fn mul_scalar(&self, scalar: &Self::Scalar) -> Self {
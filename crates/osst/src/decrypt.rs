@@ -0,0 +1,918 @@
+//! Threshold decryption under an OSST group key
+//!
+//! Anyone can encrypt to the group public key Y = g^s, and decrypting
+//! takes t custodians. Ciphertexts follow TDH2 (Shoup-Gennaro): alongside
+//! the ephemeral point R = g^r the sender publishes R̄ = ḡ^r, for a second
+//! generator ḡ of unknown discrete log, and a proof that log_g R = log_ḡ R̄
+//! whose challenge hashes the label and the body. Custodians check that
+//! proof before touching their secret share, so a mauled ciphertext, or one
+//! moved under another label, is refused rather than decrypted.
+//!
+//! Custodian i publishes a decryption share D_i = R^{x_i} together with a
+//! Chaum-Pedersen proof that log_R D_i = log_g Y_i, where Y_i = g^{x_i} is
+//! its verification share from the DKG. The proof's challenge includes the
+//! ciphertext digest, so a share only verifies for the ciphertext and label
+//! it was issued for. Any t valid shares interpolate to the shared point
+//! Σ λ_i·D_i = R^s = Y^r, and s is never reconstructed.
+//!
+//! # Schemes
+//!
+//! - [`Ciphertext`]: ElGamal on group elements, (R, M + Y^r)
+//! - [`EciesCiphertext`] (feature `ecies`): hybrid encryption of arbitrary
+//!   bytes with ChaCha20-Poly1305 keyed by H(Y || R || Y^r), with the AEAD
+//!   associated data as the label
+//!
+//! Both work over every [`OsstPoint`] backend and implement
+//! [`ThresholdCiphertext`].
+//!
+//! # Example
+//!
+//! ```ignore
+//! use osst::decrypt::{DecryptionShare, EciesCiphertext};
+//!
+//! // sender
+//! let ct = EciesCiphertext::encrypt(&group_pubkey, b"memo", b"case-7", &mut rng);
+//!
+//! // each custodian, after checking the ciphertext proof
+//! let share = DecryptionShare::new(&secret_share, &ct, b"case-7", &mut rng)?;
+//!
+//! // anyone holding t shares and the verification shares
+//! let memo = ct.decrypt(&group_pubkey, &shares, t, b"case-7", Some(&verification_shares))?;
+//! ```
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use sha2::{Digest, Sha512};
+
+use crate::curve::{OsstPoint, OsstScalar};
+use crate::error::OsstError;
+use crate::lagrange::compute_lagrange_coefficients;
+use crate::SecretShare;
+
+// ============================================================================
+// Ciphertext Validity
+// ============================================================================
+
+/// Ephemeral key of a ciphertext: R = g^r, R̄ = ḡ^r and a proof that both
+/// share the exponent r, bound to the ciphertext's label and body
+///
+/// Size: 2 * point size + 64 bytes (128 for 32-byte points)
+#[derive(Clone, Debug, PartialEq)]
+pub struct EphemeralKey<P: OsstPoint> {
+    /// Ephemeral point R = g^r
+    pub point: P,
+    /// Twin point R̄ = ḡ^r
+    pub twin: P,
+    /// Proof challenge e
+    pub proof_challenge: P::Scalar,
+    /// Proof response f = w + e * r
+    pub proof_response: P::Scalar,
+}
+
+impl<P: OsstPoint> EphemeralKey<P> {
+    fn new<R: rand_core::RngCore + rand_core::CryptoRng>(
+        r: &P::Scalar,
+        label: &[u8],
+        body: &[u8],
+        rng: &mut R,
+    ) -> Self {
+        let g_bar = twin_generator::<P>();
+        let point = P::generator().mul_scalar(r);
+        let twin = g_bar.mul_scalar(r);
+
+        let mut w = P::Scalar::random(rng);
+        let commitments = (P::generator().mul_scalar(&w), g_bar.mul_scalar(&w));
+        let proof_challenge = ephemeral_challenge(label, body, &point, &twin, &commitments);
+        let proof_response = w.add(&proof_challenge.mul(r));
+        w.zeroize();
+
+        Self {
+            point,
+            twin,
+            proof_challenge,
+            proof_response,
+        }
+    }
+
+    /// Check the proof that log_g R = log_ḡ R̄ under `label` and `body`
+    pub fn verify(&self, label: &[u8], body: &[u8]) -> bool {
+        let f = self.proof_response.clone();
+        let neg_e = self.proof_challenge.neg();
+        let commitments = (
+            P::multiscalar_mul(
+                &[f.clone(), neg_e.clone()],
+                &[P::generator(), self.point.clone()],
+            ),
+            P::multiscalar_mul(&[f, neg_e], &[twin_generator::<P>(), self.twin.clone()]),
+        );
+
+        ephemeral_challenge(label, body, &self.point, &self.twin, &commitments)
+            == self.proof_challenge
+    }
+
+    /// Serialized size in bytes
+    #[inline]
+    pub fn byte_size() -> usize {
+        2 * P::COMPRESSED_SIZE + 64
+    }
+
+    /// Serialize: [R][R̄][e:32][f:32]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(Self::byte_size());
+        buf.extend_from_slice(&self.point.compress_vec());
+        buf.extend_from_slice(&self.twin.compress_vec());
+        buf.extend_from_slice(&self.proof_challenge.to_bytes());
+        buf.extend_from_slice(&self.proof_response.to_bytes());
+        buf
+    }
+
+    /// Deserialize from bytes
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, OsstError> {
+        if bytes.len() != Self::byte_size() {
+            return Err(OsstError::InvalidCommitment);
+        }
+
+        let (points, scalars) = bytes.split_at(2 * P::COMPRESSED_SIZE);
+        let (r, r_bar) = points.split_at(P::COMPRESSED_SIZE);
+        let scalar = |b: &[u8]| {
+            P::Scalar::from_canonical_bytes(&b.try_into().unwrap()).ok_or(OsstError::InvalidResponse)
+        };
+
+        Ok(Self {
+            point: P::decompress_slice(r).ok_or(OsstError::InvalidCommitment)?,
+            twin: P::decompress_slice(r_bar).ok_or(OsstError::InvalidCommitment)?,
+            proof_challenge: scalar(&scalars[..32])?,
+            proof_response: scalar(&scalars[32..])?,
+        })
+    }
+}
+
+/// A ciphertext under the group key that custodians can issue decryption
+/// shares for
+pub trait ThresholdCiphertext<P: OsstPoint> {
+    /// Ephemeral key and its proof
+    fn ephemeral(&self) -> &EphemeralKey<P>;
+
+    /// Encoded body the ephemeral key proof is bound to
+    fn body_bytes(&self) -> Vec<u8>;
+
+    /// Check the ephemeral key proof under `label`
+    fn verify(&self, label: &[u8]) -> bool {
+        self.ephemeral().verify(label, &self.body_bytes())
+    }
+
+    /// Hash of the ciphertext and label, bound into every decryption share
+    fn digest(&self, label: &[u8]) -> [u8; 64] {
+        let mut h = Sha512::new();
+        h.update(b"osst-tdh2-digest-v1");
+        absorb(&mut h, label);
+        absorb(&mut h, &self.body_bytes());
+        h.update(self.ephemeral().to_bytes());
+        h.finalize().into()
+    }
+}
+
+/// Second generator ḡ: the first hash-to-bytes output in the
+/// "osst-tdh2-generator-v1" sequence that decodes to a non-identity point
+fn twin_generator<P: OsstPoint>() -> P {
+    (0u32..)
+        .find_map(|counter| {
+            let mut h = Sha512::new();
+            h.update(b"osst-tdh2-generator-v1");
+            h.update(counter.to_le_bytes());
+            let hash: [u8; 64] = h.finalize().into();
+            let candidate: [u8; 32] = hash[..32].try_into().unwrap();
+            P::decompress(&candidate).filter(|p| *p != P::identity())
+        })
+        .expect("some counter decodes to a point")
+}
+
+/// Challenge: e = H("osst-tdh2-v1" || L || body || R || R̄ || g^w || ḡ^w),
+/// with L and body length-prefixed
+fn ephemeral_challenge<P: OsstPoint>(
+    label: &[u8],
+    body: &[u8],
+    point: &P,
+    twin: &P,
+    commitments: &(P, P),
+) -> P::Scalar {
+    let mut h = Sha512::new();
+    h.update(b"osst-tdh2-v1");
+    absorb(&mut h, label);
+    absorb(&mut h, body);
+    h.update(point.compress_vec());
+    h.update(twin.compress_vec());
+    h.update(commitments.0.compress_vec());
+    h.update(commitments.1.compress_vec());
+    let hash: [u8; 64] = h.finalize().into();
+    P::Scalar::from_bytes_wide(&hash)
+}
+
+fn absorb(h: &mut Sha512, bytes: &[u8]) {
+    h.update((bytes.len() as u64).to_le_bytes());
+    h.update(bytes);
+}
+
+// ============================================================================
+// Decryption Shares
+// ============================================================================
+
+/// One custodian's partial decryption D_i = R^{x_i}, with a proof of
+/// correctness against its verification share
+///
+/// Size: 4 + 3 * point size + 32 bytes (132 for 32-byte points)
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecryptionShare<P: OsstPoint> {
+    /// Custodian's index (1-indexed)
+    pub index: u32,
+    /// Partial decryption D_i = R^{x_i}
+    pub share: P,
+    /// Proof commitments (g^w, R^w)
+    pub proof_commitments: (P, P),
+    /// Proof response z = w + e * x_i
+    pub proof_response: P::Scalar,
+}
+
+impl<P: OsstPoint> DecryptionShare<P> {
+    /// Partially decrypt `ciphertext` under `label`
+    ///
+    /// Fails with `InvalidCiphertext`, before the secret share is used, if
+    /// the ciphertext's proof does not verify under `label`.
+    pub fn new<C: ThresholdCiphertext<P>, R: rand_core::RngCore + rand_core::CryptoRng>(
+        secret: &SecretShare<P::Scalar>,
+        ciphertext: &C,
+        label: &[u8],
+        rng: &mut R,
+    ) -> Result<Self, OsstError> {
+        if !ciphertext.verify(label) {
+            return Err(OsstError::InvalidCiphertext);
+        }
+        let digest = ciphertext.digest(label);
+        let ephemeral = &ciphertext.ephemeral().point;
+
+        let x = secret.scalar();
+        let share = ephemeral.mul_scalar(x);
+        let verification_share: P = secret.public_share();
+
+        let mut w = P::Scalar::random(rng);
+        let proof_commitments = (P::generator().mul_scalar(&w), ephemeral.mul_scalar(&w));
+
+        let e = decryption_challenge(
+            &digest,
+            secret.index,
+            ephemeral,
+            &verification_share,
+            &share,
+            &proof_commitments,
+        );
+        let proof_response = w.add(&e.mul(x));
+        w.zeroize();
+
+        Ok(Self {
+            index: secret.index,
+            share,
+            proof_commitments,
+            proof_response,
+        })
+    }
+
+    /// Check the proof that log_R D_i = log_g Y_i for `ciphertext` under
+    /// `label`
+    pub fn verify<C: ThresholdCiphertext<P>>(
+        &self,
+        ciphertext: &C,
+        label: &[u8],
+        verification_share: &P,
+    ) -> bool {
+        self.verify_digest(
+            &ciphertext.ephemeral().point,
+            &ciphertext.digest(label),
+            verification_share,
+        )
+    }
+
+    fn verify_digest(&self, ephemeral: &P, digest: &[u8; 64], verification_share: &P) -> bool {
+        if self.index == 0 {
+            return false;
+        }
+
+        let e = decryption_challenge(
+            digest,
+            self.index,
+            ephemeral,
+            verification_share,
+            &self.share,
+            &self.proof_commitments,
+        );
+        let z = &self.proof_response;
+        let (a_g, a_r) = &self.proof_commitments;
+
+        P::generator().mul_scalar(z) == a_g.add(&verification_share.mul_scalar(&e))
+            && ephemeral.mul_scalar(z) == a_r.add(&self.share.mul_scalar(&e))
+    }
+
+    /// Serialized size in bytes
+    #[inline]
+    pub fn byte_size() -> usize {
+        4 + 3 * P::COMPRESSED_SIZE + 32
+    }
+
+    /// Serialize: [index:4][D_i][g^w][R^w][z:32]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(Self::byte_size());
+        buf.extend_from_slice(&self.index.to_le_bytes());
+        buf.extend_from_slice(&self.share.compress_vec());
+        buf.extend_from_slice(&self.proof_commitments.0.compress_vec());
+        buf.extend_from_slice(&self.proof_commitments.1.compress_vec());
+        buf.extend_from_slice(&self.proof_response.to_bytes());
+        buf
+    }
+
+    /// Deserialize from bytes
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, OsstError> {
+        if bytes.len() != Self::byte_size() {
+            return Err(OsstError::InvalidCommitment);
+        }
+
+        let index = u32::from_le_bytes(bytes[0..4].try_into().unwrap());
+        if index == 0 {
+            return Err(OsstError::InvalidIndex);
+        }
+
+        let mut points = bytes[4..bytes.len() - 32]
+            .chunks_exact(P::COMPRESSED_SIZE)
+            .map(|b| P::decompress_slice(b).ok_or(OsstError::InvalidCommitment));
+        let share = points.next().unwrap()?;
+        let proof_commitments = (points.next().unwrap()?, points.next().unwrap()?);
+
+        let response_bytes: [u8; 32] = bytes[bytes.len() - 32..].try_into().unwrap();
+        let proof_response =
+            P::Scalar::from_canonical_bytes(&response_bytes).ok_or(OsstError::InvalidResponse)?;
+
+        Ok(Self {
+            index,
+            share,
+            proof_commitments,
+            proof_response,
+        })
+    }
+}
+
+/// Challenge: e = H("osst-decrypt-v2" || digest || i || R || Y_i || D_i || A)
+fn decryption_challenge<P: OsstPoint>(
+    digest: &[u8; 64],
+    index: u32,
+    ephemeral: &P,
+    verification_share: &P,
+    share: &P,
+    proof_commitments: &(P, P),
+) -> P::Scalar {
+    let mut h = Sha512::new();
+    h.update(b"osst-decrypt-v2");
+    h.update(digest);
+    h.update(index.to_le_bytes());
+    h.update(ephemeral.compress_vec());
+    h.update(verification_share.compress_vec());
+    h.update(share.compress_vec());
+    h.update(proof_commitments.0.compress_vec());
+    h.update(proof_commitments.1.compress_vec());
+    let hash: [u8; 64] = h.finalize().into();
+    P::Scalar::from_bytes_wide(&hash)
+}
+
+/// Interpolate decryption shares of `ciphertext` to the shared point
+/// R^s = Y^r
+///
+/// The ciphertext's proof is always checked under `label`. With
+/// `verifier_shares`, every share's proof is checked against Y_i first;
+/// without it the result is only as good as the shares.
+///
+/// # Errors
+///
+/// - `InvalidCiphertext` if the ciphertext's proof fails
+/// - `EmptyContributions` / `InsufficientContributions` for fewer than
+///   `threshold` shares
+/// - `DuplicateIndex` / `InvalidIndex` for bad indices, including an
+///   index missing from `verifier_shares`
+/// - `InvalidResponse` if a share proof fails
+pub fn combine_shares<P: OsstPoint, C: ThresholdCiphertext<P>>(
+    ciphertext: &C,
+    label: &[u8],
+    shares: &[DecryptionShare<P>],
+    threshold: u32,
+    verifier_shares: Option<&BTreeMap<u32, P>>,
+) -> Result<P, OsstError> {
+    if !ciphertext.verify(label) {
+        return Err(OsstError::InvalidCiphertext);
+    }
+    if shares.is_empty() {
+        return Err(OsstError::EmptyContributions);
+    }
+    if shares.len() < threshold as usize {
+        return Err(OsstError::InsufficientContributions {
+            got: shares.len(),
+            need: threshold as usize,
+        });
+    }
+
+    let indices: Vec<u32> = shares.iter().map(|s| s.index).collect();
+    let lambdas: Vec<P::Scalar> = compute_lagrange_coefficients(&indices)?;
+
+    if let Some(vshares) = verifier_shares {
+        let ephemeral = &ciphertext.ephemeral().point;
+        let digest = ciphertext.digest(label);
+        for share in shares {
+            let yi = vshares.get(&share.index).ok_or(OsstError::InvalidIndex)?;
+            if !share.verify_digest(ephemeral, &digest, yi) {
+                return Err(OsstError::InvalidResponse);
+            }
+        }
+    }
+
+    let points: Vec<P> = shares.iter().map(|s| s.share.clone()).collect();
+    Ok(P::multiscalar_mul(&lambdas, &points))
+}
+
+// ============================================================================
+// ElGamal
+// ============================================================================
+
+/// ElGamal ciphertext of a group element: (R, C) = (g^r, M + Y^r)
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ciphertext<P: OsstPoint> {
+    /// Ephemeral key R = g^r with its proof
+    pub ephemeral: EphemeralKey<P>,
+    /// Masked message C = M + Y^r
+    pub body: P,
+}
+
+impl<P: OsstPoint> Ciphertext<P> {
+    /// Encrypt the group element `message` to `group_pubkey` under `label`
+    pub fn encrypt<R: rand_core::RngCore + rand_core::CryptoRng>(
+        group_pubkey: &P,
+        message: &P,
+        label: &[u8],
+        rng: &mut R,
+    ) -> Self {
+        let mut r = P::Scalar::random(rng);
+        let body = message.add(&group_pubkey.mul_scalar(&r));
+        let ephemeral = EphemeralKey::new(&r, label, &body.compress_vec(), rng);
+        r.zeroize();
+
+        Self { ephemeral, body }
+    }
+
+    /// Remove the mask given the shared point Y^r
+    pub fn open(&self, shared: &P) -> P {
+        self.body.add(&shared.mul_scalar(&P::Scalar::one().neg()))
+    }
+
+    /// Combine `shares` (see [`combine_shares`]) and recover M
+    pub fn decrypt(
+        &self,
+        shares: &[DecryptionShare<P>],
+        threshold: u32,
+        label: &[u8],
+        verifier_shares: Option<&BTreeMap<u32, P>>,
+    ) -> Result<P, OsstError> {
+        let shared = combine_shares(self, label, shares, threshold, verifier_shares)?;
+        Ok(self.open(&shared))
+    }
+
+    /// Serialized size in bytes
+    #[inline]
+    pub fn byte_size() -> usize {
+        EphemeralKey::<P>::byte_size() + P::COMPRESSED_SIZE
+    }
+
+    /// Serialize: [ephemeral key][C]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(Self::byte_size());
+        buf.extend_from_slice(&self.ephemeral.to_bytes());
+        buf.extend_from_slice(&self.body.compress_vec());
+        buf
+    }
+
+    /// Deserialize from bytes
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, OsstError> {
+        if bytes.len() != Self::byte_size() {
+            return Err(OsstError::InvalidCommitment);
+        }
+        let (ephemeral, c) = bytes.split_at(EphemeralKey::<P>::byte_size());
+        Ok(Self {
+            ephemeral: EphemeralKey::from_bytes(ephemeral)?,
+            body: P::decompress_slice(c).ok_or(OsstError::InvalidCommitment)?,
+        })
+    }
+}
+
+impl<P: OsstPoint> ThresholdCiphertext<P> for Ciphertext<P> {
+    fn ephemeral(&self) -> &EphemeralKey<P> {
+        &self.ephemeral
+    }
+
+    fn body_bytes(&self) -> Vec<u8> {
+        self.body.compress_vec()
+    }
+}
+
+// ============================================================================
+// ECIES
+// ============================================================================
+
+#[cfg(feature = "ecies")]
+pub use ecies::EciesCiphertext;
+
+#[cfg(feature = "ecies")]
+mod ecies {
+    use super::*;
+    use chacha20poly1305::{
+        aead::{Aead, KeyInit, Payload},
+        ChaCha20Poly1305, Nonce,
+    };
+
+    /// Poly1305 tag length
+    const TAG_SIZE: usize = 16;
+
+    /// Hybrid ciphertext: ephemeral key R = g^r and the ChaCha20-Poly1305
+    /// encryption of the plaintext under a key derived from Y^r
+    ///
+    /// Every ciphertext has a fresh R, so key and nonce are single-use. The
+    /// associated data doubles as the TDH2 label.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct EciesCiphertext<P: OsstPoint> {
+        /// Ephemeral key R = g^r with its proof
+        pub ephemeral: EphemeralKey<P>,
+        /// AEAD ciphertext with the 16-byte tag appended
+        pub ciphertext: Vec<u8>,
+    }
+
+    impl<P: OsstPoint> EciesCiphertext<P> {
+        /// Encrypt `plaintext` to `group_pubkey`, authenticating `aad`
+        pub fn encrypt<R: rand_core::RngCore + rand_core::CryptoRng>(
+            group_pubkey: &P,
+            plaintext: &[u8],
+            aad: &[u8],
+            rng: &mut R,
+        ) -> Self {
+            let mut r = P::Scalar::random(rng);
+            let point = P::generator().mul_scalar(&r);
+            let shared = group_pubkey.mul_scalar(&r);
+
+            let (cipher, nonce) = derive_cipher(group_pubkey, &point, &shared);
+            let ciphertext = cipher
+                .encrypt(&nonce, Payload { msg: plaintext, aad })
+                .expect("chacha20poly1305 encryption is infallible for in-memory buffers");
+            let ephemeral = EphemeralKey::new(&r, aad, &ciphertext, rng);
+            r.zeroize();
+
+            Self {
+                ephemeral,
+                ciphertext,
+            }
+        }
+
+        /// Decrypt given the shared point Y^r
+        ///
+        /// Fails with `DecryptionFailed` if the tag does not verify.
+        pub fn open(&self, group_pubkey: &P, shared: &P, aad: &[u8]) -> Result<Vec<u8>, OsstError> {
+            let (cipher, nonce) = derive_cipher(group_pubkey, &self.ephemeral.point, shared);
+            cipher
+                .decrypt(
+                    &nonce,
+                    Payload {
+                        msg: &self.ciphertext,
+                        aad,
+                    },
+                )
+                .map_err(|_| OsstError::DecryptionFailed)
+        }
+
+        /// Combine `shares` (see [`combine_shares`]) and decrypt
+        pub fn decrypt(
+            &self,
+            group_pubkey: &P,
+            shares: &[DecryptionShare<P>],
+            threshold: u32,
+            aad: &[u8],
+            verifier_shares: Option<&BTreeMap<u32, P>>,
+        ) -> Result<Vec<u8>, OsstError> {
+            let shared = combine_shares(self, aad, shares, threshold, verifier_shares)?;
+            self.open(group_pubkey, &shared, aad)
+        }
+
+        /// Serialize: [ephemeral key][ciphertext || tag]
+        pub fn to_bytes(&self) -> Vec<u8> {
+            let mut buf = Vec::with_capacity(EphemeralKey::<P>::byte_size() + self.ciphertext.len());
+            buf.extend_from_slice(&self.ephemeral.to_bytes());
+            buf.extend_from_slice(&self.ciphertext);
+            buf
+        }
+
+        /// Deserialize from bytes
+        pub fn from_bytes(bytes: &[u8]) -> Result<Self, OsstError> {
+            if bytes.len() < EphemeralKey::<P>::byte_size() + TAG_SIZE {
+                return Err(OsstError::InvalidCommitment);
+            }
+            let (ephemeral, ciphertext) = bytes.split_at(EphemeralKey::<P>::byte_size());
+            Ok(Self {
+                ephemeral: EphemeralKey::from_bytes(ephemeral)?,
+                ciphertext: ciphertext.to_vec(),
+            })
+        }
+    }
+
+    impl<P: OsstPoint> ThresholdCiphertext<P> for EciesCiphertext<P> {
+        fn ephemeral(&self) -> &EphemeralKey<P> {
+            &self.ephemeral
+        }
+
+        fn body_bytes(&self) -> Vec<u8> {
+            self.ciphertext.clone()
+        }
+    }
+
+    /// (key || nonce) = H("osst-ecies-v1" || Y || R || Y^r)[..44]
+    fn derive_cipher<P: OsstPoint>(
+        group_pubkey: &P,
+        ephemeral: &P,
+        shared: &P,
+    ) -> (ChaCha20Poly1305, Nonce) {
+        let mut h = Sha512::new();
+        h.update(b"osst-ecies-v1");
+        h.update(group_pubkey.compress_vec());
+        h.update(ephemeral.compress_vec());
+        h.update(shared.compress_vec());
+        let mut okm: [u8; 64] = h.finalize().into();
+
+        let cipher = ChaCha20Poly1305::new_from_slice(&okm[..32]).expect("32-byte key");
+        let mut nonce = Nonce::default();
+        nonce.copy_from_slice(&okm[32..44]);
+        okm.iter_mut().for_each(|b| *b = 0);
+
+        (cipher, nonce)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::OsRng;
+
+    const LABEL: &[u8] = b"ballot-7";
+
+    /// Shamir-split a random secret, returning (Y, shares, {i: Y_i})
+    fn deal<P: OsstPoint>(n: u32, t: u32) -> (P, Vec<SecretShare<P::Scalar>>, BTreeMap<u32, P>) {
+        let mut rng = OsRng;
+        let coeffs: Vec<P::Scalar> = (0..t).map(|_| P::Scalar::random(&mut rng)).collect();
+        let group_pubkey = P::generator().mul_scalar(&coeffs[0]);
+
+        let shares: Vec<SecretShare<P::Scalar>> = (1..=n)
+            .map(|i| {
+                let x = P::Scalar::from_u32(i);
+                let y = coeffs
+                    .iter()
+                    .rev()
+                    .fold(P::Scalar::zero(), |acc, c| acc.mul(&x).add(c));
+                SecretShare::new(i, y)
+            })
+            .collect();
+        let vshares = shares
+            .iter()
+            .map(|s| (s.index, s.public_share::<P>()))
+            .collect();
+
+        (group_pubkey, shares, vshares)
+    }
+
+    fn elgamal_roundtrip<P: OsstPoint>() {
+        let mut rng = OsRng;
+        let (group_pubkey, shares, vshares) = deal::<P>(5, 3);
+
+        let message = P::generator().mul_scalar(&P::Scalar::random(&mut rng));
+        let ct = Ciphertext::encrypt(&group_pubkey, &message, LABEL, &mut rng);
+        let bytes = ct.to_bytes();
+        assert_eq!(bytes.len(), Ciphertext::<P>::byte_size());
+        let ct = Ciphertext::<P>::from_bytes(&bytes).unwrap();
+        assert!(ct.verify(LABEL));
+
+        let partials: Vec<DecryptionShare<P>> = shares
+            .iter()
+            .map(|s| DecryptionShare::new(s, &ct, LABEL, &mut rng).unwrap())
+            .collect();
+        for d in &partials {
+            assert!(d.verify(&ct, LABEL, &vshares[&d.index]));
+            let bytes = d.to_bytes();
+            assert_eq!(bytes.len(), DecryptionShare::<P>::byte_size());
+            assert_eq!(DecryptionShare::from_bytes(&bytes).unwrap(), *d);
+        }
+
+        // any t-subset decrypts
+        for subset in [&partials[0..3], &partials[2..5], &partials[1..5]] {
+            assert_eq!(ct.decrypt(subset, 3, LABEL, Some(&vshares)).unwrap(), message);
+        }
+
+        assert_eq!(
+            ct.decrypt(&partials[0..2], 3, LABEL, Some(&vshares)),
+            Err(OsstError::InsufficientContributions { got: 2, need: 3 })
+        );
+    }
+
+    fn refuses_invalid_ciphertexts<P: OsstPoint>() {
+        let mut rng = OsRng;
+        let (group_pubkey, shares, vshares) = deal::<P>(3, 2);
+
+        let message = P::generator().mul_scalar(&P::Scalar::random(&mut rng));
+        let ct = Ciphertext::encrypt(&group_pubkey, &message, LABEL, &mut rng);
+        let partials: Vec<DecryptionShare<P>> = shares[0..2]
+            .iter()
+            .map(|s| DecryptionShare::new(s, &ct, LABEL, &mut rng).unwrap())
+            .collect();
+
+        // mauling the body (M + Δ) or moving the ciphertext under another
+        // label breaks the proof, so no custodian issues a share for it
+        let mut mauled = ct.clone();
+        mauled.body = mauled.body.add(&P::generator());
+        assert!(!mauled.verify(LABEL));
+        assert_eq!(
+            DecryptionShare::new(&shares[0], &mauled, LABEL, &mut rng),
+            Err(OsstError::InvalidCiphertext)
+        );
+        assert_eq!(
+            DecryptionShare::new(&shares[0], &ct, b"ballot-8", &mut rng),
+            Err(OsstError::InvalidCiphertext)
+        );
+
+        // and combining refuses it even with shares in hand
+        assert_eq!(
+            mauled.decrypt(&partials, 2, LABEL, None),
+            Err(OsstError::InvalidCiphertext)
+        );
+        assert_eq!(
+            ct.decrypt(&partials, 2, b"ballot-8", Some(&vshares)),
+            Err(OsstError::InvalidCiphertext)
+        );
+
+        // a twin point off ḡ^r, or a proof for another ephemeral, fails too
+        let mut skewed = ct.clone();
+        skewed.ephemeral.twin = skewed.ephemeral.twin.add(&P::generator());
+        assert!(!skewed.verify(LABEL));
+        let other = Ciphertext::encrypt(&group_pubkey, &message, LABEL, &mut rng);
+        let mut spliced = ct.clone();
+        spliced.ephemeral.point = other.ephemeral.point;
+        assert!(!spliced.verify(LABEL));
+        assert!(Ciphertext::<P>::from_bytes(&skewed.to_bytes()).is_ok_and(|c| !c.verify(LABEL)));
+    }
+
+    #[cfg(feature = "ecies")]
+    fn ecies_roundtrip<P: OsstPoint>() {
+        let mut rng = OsRng;
+        let (group_pubkey, shares, vshares) = deal::<P>(4, 3);
+        let memo = b"encrypted evidence for arbitration";
+
+        let ct = EciesCiphertext::encrypt(&group_pubkey, memo, b"case-7", &mut rng);
+        let ct = EciesCiphertext::<P>::from_bytes(&ct.to_bytes()).unwrap();
+        assert_eq!(ct.ciphertext.len(), memo.len() + 16);
+
+        let partials: Vec<DecryptionShare<P>> = shares[1..4]
+            .iter()
+            .map(|s| DecryptionShare::new(s, &ct, b"case-7", &mut rng).unwrap())
+            .collect();
+
+        let opened = ct
+            .decrypt(&group_pubkey, &partials, 3, b"case-7", Some(&vshares))
+            .unwrap();
+        assert_eq!(opened, memo);
+
+        // wrong associated data or a tampered body is refused by the
+        // ciphertext proof before any share is combined
+        assert_eq!(
+            ct.decrypt(&group_pubkey, &partials, 3, b"case-8", Some(&vshares)),
+            Err(OsstError::InvalidCiphertext)
+        );
+        let mut tampered = ct.clone();
+        tampered.ciphertext[0] ^= 1;
+        assert_eq!(
+            DecryptionShare::new(&shares[0], &tampered, b"case-7", &mut rng),
+            Err(OsstError::InvalidCiphertext)
+        );
+        assert_eq!(
+            tampered.decrypt(&group_pubkey, &partials, 3, b"case-7", Some(&vshares)),
+            Err(OsstError::InvalidCiphertext)
+        );
+
+        // the AEAD tag still covers the associated data
+        let shared = combine_shares(&ct, b"case-7", &partials, 3, Some(&vshares)).unwrap();
+        assert_eq!(
+            ct.open(&group_pubkey, &shared, b"case-8"),
+            Err(OsstError::DecryptionFailed)
+        );
+    }
+
+    fn rejects_bad_shares<P: OsstPoint>() {
+        let mut rng = OsRng;
+        let (group_pubkey, shares, vshares) = deal::<P>(5, 3);
+
+        let message = P::generator().mul_scalar(&P::Scalar::random(&mut rng));
+        let ct = Ciphertext::encrypt(&group_pubkey, &message, LABEL, &mut rng);
+        let mut partials: Vec<DecryptionShare<P>> = shares[0..3]
+            .iter()
+            .map(|s| DecryptionShare::new(s, &ct, LABEL, &mut rng).unwrap())
+            .collect();
+
+        // a share for another ciphertext does not verify against this one
+        let other = Ciphertext::encrypt(&group_pubkey, &message, LABEL, &mut rng);
+        let stale = DecryptionShare::new(&shares[0], &other, LABEL, &mut rng).unwrap();
+        assert!(!stale.verify(&ct, LABEL, &vshares[&1]));
+
+        // even when the sender reuses r, so both ciphertexts share R
+        let r = P::Scalar::random(&mut rng);
+        let reuse = |m: &P, rng: &mut OsRng| {
+            let body = m.add(&group_pubkey.mul_scalar(&r));
+            Ciphertext {
+                ephemeral: EphemeralKey::new(&r, LABEL, &body.compress_vec(), rng),
+                body,
+            }
+        };
+        let first = reuse(&message, &mut rng);
+        let second = reuse(&P::generator(), &mut rng);
+        assert_eq!(first.ephemeral.point, second.ephemeral.point);
+        assert!(first.verify(LABEL) && second.verify(LABEL));
+        let replayed = DecryptionShare::new(&shares[0], &first, LABEL, &mut rng).unwrap();
+        assert!(replayed.verify(&first, LABEL, &vshares[&1]));
+        assert!(!replayed.verify(&second, LABEL, &vshares[&1]));
+
+        // nor does a share claimed under the wrong verification share
+        assert!(!partials[0].verify(&ct, LABEL, &vshares[&2]));
+
+        // a corrupted share is caught with verification shares, and silently
+        // yields garbage without them
+        partials[1].share = partials[1].share.add(&P::generator());
+        assert_eq!(
+            ct.decrypt(&partials, 3, LABEL, Some(&vshares)),
+            Err(OsstError::InvalidResponse)
+        );
+        assert_ne!(ct.decrypt(&partials, 3, LABEL, None).unwrap(), message);
+
+        let dup = [partials[0].clone(), partials[0].clone(), partials[2].clone()];
+        assert_eq!(
+            ct.decrypt(&dup, 3, LABEL, None),
+            Err(OsstError::DuplicateIndex(1))
+        );
+
+        let mut missing = vshares.clone();
+        missing.remove(&3);
+        partials[1] = DecryptionShare::new(&shares[1], &ct, LABEL, &mut rng).unwrap();
+        assert_eq!(
+            ct.decrypt(&partials, 3, LABEL, Some(&missing)),
+            Err(OsstError::InvalidIndex)
+        );
+    }
+
+    #[cfg(feature = "ristretto255")]
+    #[test]
+    fn test_ristretto_threshold_elgamal() {
+        elgamal_roundtrip::<curve25519_dalek::ristretto::RistrettoPoint>();
+        refuses_invalid_ciphertexts::<curve25519_dalek::ristretto::RistrettoPoint>();
+        rejects_bad_shares::<curve25519_dalek::ristretto::RistrettoPoint>();
+    }
+
+    #[cfg(feature = "pallas")]
+    #[test]
+    fn test_pallas_threshold_elgamal() {
+        elgamal_roundtrip::<pasta_curves::pallas::Point>();
+        refuses_invalid_ciphertexts::<pasta_curves::pallas::Point>();
+        rejects_bad_shares::<pasta_curves::pallas::Point>();
+    }
+
+    #[cfg(feature = "secp256k1")]
+    #[test]
+    fn test_secp256k1_threshold_elgamal() {
+        elgamal_roundtrip::<k256::ProjectivePoint>();
+        refuses_invalid_ciphertexts::<k256::ProjectivePoint>();
+        rejects_bad_shares::<k256::ProjectivePoint>();
+    }
+
+    #[cfg(all(feature = "ecies", feature = "ristretto255"))]
+    #[test]
+    fn test_ristretto_threshold_ecies() {
+        ecies_roundtrip::<curve25519_dalek::ristretto::RistrettoPoint>();
+    }
+
+    #[cfg(all(feature = "ecies", feature = "pallas"))]
+    #[test]
+    fn test_pallas_threshold_ecies() {
+        ecies_roundtrip::<pasta_curves::pallas::Point>();
+    }
+
+    #[cfg(all(feature = "ecies", feature = "secp256k1"))]
+    #[test]
+    fn test_secp256k1_threshold_ecies() {
+        ecies_roundtrip::<k256::ProjectivePoint>();
+    }
+}
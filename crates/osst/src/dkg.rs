@@ -20,6 +20,31 @@
 //! 4. Participant j verifies each sub-share against commitments
 //! 5. Participant j's final share: s_j = sum_i(f_i(j))
 //! 6. Group public key: Y = sum_i(C_{i,0}) = g^{sum_i(f_i(0))}
+//!
+//! # Weighted participants
+//!
+//! With a [`WeightTable`], participant p deals once but receives f_i(j) for
+//! every index j it owns, and collects them with a [`WeightedAggregator`].
+//! The resulting [`WeightedShare`] counts for p's weight in
+//! [`weighted::verify`](crate::weighted::verify) and
+//! [`frost::sign_weighted`](crate::frost::sign_weighted).
+//!
+//! # Complaints
+//!
+//! A sub-share that fails step 4 does not stall the ceremony. Player j
+//! publishes a [`Complaint`] against dealer i, and dealer i must answer by
+//! revealing f_i(j) in public. When [`DkgState::close_complaints`] ends the
+//! round, every dealer that never committed or has a complaint without a
+//! verifying answer is disqualified. Steps 5 and 6 then sum over the
+//! remaining dealers only, which every observer of the round computes
+//! identically.
+//!
+//! Revealed sub-shares are public, so a dealer with t answered complaints has
+//! exposed its secret; the group secret stays hidden as long as one qualified
+//! dealer is honest. Only the accused dealer may answer, and a wrong answer
+//! leaves the complaint open rather than settling it, so nobody else can get
+//! an honest dealer disqualified. Authenticating the sender of complaints and
+//! answers is left to the transport.
 
 use alloc::vec;
 use alloc::vec::Vec;
@@ -28,6 +53,50 @@ use core::marker::PhantomData;
 use crate::curve::{OsstPoint, OsstScalar};
 use crate::error::OsstError;
 use crate::reshare::{DealerCommitment, SubShare};
+use crate::weighted::{WeightTable, WeightedShare};
+use crate::SecretShare;
+
+// ============================================================================
+// Complaints
+// ============================================================================
+
+/// Accusation by a player that a dealer's sub-share failed verification
+///
+/// Size: 8 bytes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Complaint {
+    /// Player whose sub-share was missing or invalid
+    pub accuser: u32,
+    /// Dealer that sent it
+    pub accused: u32,
+}
+
+impl Complaint {
+    #[inline]
+    pub fn new(accuser: u32, accused: u32) -> Self {
+        assert!(accuser > 0, "accuser must be 1-indexed");
+        assert!(accused > 0, "accused must be 1-indexed");
+        Self { accuser, accused }
+    }
+
+    pub fn to_bytes(&self) -> [u8; 8] {
+        let mut buf = [0u8; 8];
+        buf[0..4].copy_from_slice(&self.accuser.to_le_bytes());
+        buf[4..8].copy_from_slice(&self.accused.to_le_bytes());
+        buf
+    }
+
+    pub fn from_bytes(bytes: &[u8; 8]) -> Result<Self, OsstError> {
+        let accuser = u32::from_le_bytes(bytes[0..4].try_into().unwrap());
+        let accused = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+
+        if accuser == 0 || accused == 0 {
+            return Err(OsstError::InvalidIndex);
+        }
+
+        Ok(Self { accuser, accused })
+    }
+}
 
 // ============================================================================
 // Dealer
@@ -106,6 +175,18 @@ impl<P: OsstPoint> Dealer<P> {
             .map(|j| self.generate_subshare(j))
             .collect()
     }
+
+    /// Generate sub-shares for every index a weighted party owns
+    ///
+    /// Returns `None` if `party` is not in `table`.
+    pub fn generate_weighted_subshares(
+        &self,
+        table: &WeightTable,
+        party: u32,
+    ) -> Option<Vec<SubShare<P::Scalar>>> {
+        let range = table.indices(party)?;
+        Some(range.map(|j| self.generate_subshare(j)).collect())
+    }
 }
 
 impl<P: OsstPoint> core::fmt::Debug for Dealer<P> {
@@ -194,6 +275,20 @@ impl<P: OsstPoint> Aggregator<P> {
         Ok(true)
     }
 
+    /// Drop sub-shares from dealers outside `qualified`.
+    ///
+    /// Call with [`DkgState::qualified_dealers`] once complaints are closed,
+    /// then finalize with `qualified.len()` dealers.
+    pub fn retain_dealers(&mut self, qualified: &[u32]) {
+        let (subshares, constant_commitments) = core::mem::take(&mut self.subshares)
+            .into_iter()
+            .zip(core::mem::take(&mut self.constant_commitments))
+            .filter(|((idx, _), _)| qualified.contains(idx))
+            .unzip();
+        self.subshares = subshares;
+        self.constant_commitments = constant_commitments;
+    }
+
     /// Derive group public key: Y = sum(C_{i,0})
     pub fn derive_group_key(&self) -> P {
         let mut key = P::identity();
@@ -230,6 +325,107 @@ impl<P: OsstPoint> core::fmt::Debug for Aggregator<P> {
     }
 }
 
+// ============================================================================
+// Weighted aggregator
+// ============================================================================
+
+/// DKG aggregator for a weighted party: one [`Aggregator`] per owned index.
+///
+/// Every party deals once, with a polynomial of degree T − 1 for weight
+/// threshold T, so dealer indices are party indices. Verification shares
+/// are per index, see [`DkgState::derive_verification_share`].
+pub struct WeightedAggregator<P: OsstPoint> {
+    party: u32,
+    aggregators: Vec<Aggregator<P>>,
+}
+
+impl<P: OsstPoint> WeightedAggregator<P> {
+    /// Returns `InvalidIndex` if `party` is not in `table`.
+    pub fn new(table: &WeightTable, party: u32) -> Result<Self, OsstError> {
+        let range = table.indices(party).ok_or(OsstError::InvalidIndex)?;
+        Ok(Self {
+            party,
+            aggregators: range.map(Aggregator::new).collect(),
+        })
+    }
+
+    #[inline]
+    pub fn party(&self) -> u32 {
+        self.party
+    }
+
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.aggregators[0].count()
+    }
+
+    /// Add one dealer's sub-shares for all of this party's indices, in order.
+    ///
+    /// Either all are verified and added or none is. Returns Ok(false) if
+    /// the dealer was already added.
+    pub fn add_subshares(
+        &mut self,
+        subshares: Vec<SubShare<P::Scalar>>,
+        commitment: &DealerCommitment<P>,
+    ) -> Result<bool, OsstError> {
+        if subshares.len() != self.aggregators.len() {
+            return Err(OsstError::InvalidIndex);
+        }
+        for (subshare, aggregator) in subshares.iter().zip(&self.aggregators) {
+            if subshare.player_index != aggregator.player_index()
+                || subshare.dealer_index != commitment.dealer_index
+            {
+                return Err(OsstError::InvalidIndex);
+            }
+            if !commitment.verify_subshare(subshare.player_index, subshare.value()) {
+                return Err(OsstError::InvalidResponse);
+            }
+        }
+
+        let mut added = false;
+        for (subshare, aggregator) in subshares.into_iter().zip(&mut self.aggregators) {
+            added = aggregator.add_subshare(subshare, commitment)?;
+        }
+        Ok(added)
+    }
+
+    /// Drop sub-shares from dealers outside `qualified`.
+    pub fn retain_dealers(&mut self, qualified: &[u32]) {
+        for aggregator in &mut self.aggregators {
+            aggregator.retain_dealers(qualified);
+        }
+    }
+
+    /// Derive group public key: Y = sum(C_{i,0})
+    pub fn derive_group_key(&self) -> P {
+        self.aggregators[0].derive_group_key()
+    }
+
+    /// Aggregate the final share for each owned index
+    pub fn finalize(
+        &self,
+        table: &WeightTable,
+        num_dealers: u32,
+    ) -> Result<WeightedShare<P::Scalar>, OsstError> {
+        let shares = self
+            .aggregators
+            .iter()
+            .map(|a| Ok(SecretShare::new(a.player_index(), a.finalize(num_dealers)?)))
+            .collect::<Result<Vec<_>, OsstError>>()?;
+        WeightedShare::new(self.party, table, shares)
+    }
+}
+
+impl<P: OsstPoint> core::fmt::Debug for WeightedAggregator<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("dkg::WeightedAggregator")
+            .field("party", &self.party)
+            .field("weight", &self.aggregators.len())
+            .field("count", &self.count())
+            .finish()
+    }
+}
+
 // ============================================================================
 // On-chain coordination
 // ============================================================================
@@ -237,7 +433,12 @@ impl<P: OsstPoint> core::fmt::Debug for Aggregator<P> {
 /// DKG round state for on-chain coordination.
 ///
 /// Tracks commitments from all participants. Once all n commitments are in,
-/// players can verify sub-shares and derive their final shares.
+/// players can verify sub-shares and derive their final shares. If any
+/// complaint is filed, key derivation waits for [`close_complaints`] and
+/// then uses only the [`qualified_dealers`].
+///
+/// [`close_complaints`]: DkgState::close_complaints
+/// [`qualified_dealers`]: DkgState::qualified_dealers
 #[derive(Clone, Debug)]
 pub struct DkgState<P: OsstPoint> {
     /// Epoch being generated
@@ -248,6 +449,14 @@ pub struct DkgState<P: OsstPoint> {
     pub num_participants: u32,
     /// Collected commitments (indexed by dealer_index - 1)
     pub commitments: Vec<Option<DealerCommitment<P>>>,
+    /// Complaints in submission order
+    pub complaints: Vec<Complaint>,
+    /// Verified sub-shares revealed in answer to complaints
+    pub justifications: Vec<SubShare<P::Scalar>>,
+    /// Disqualified dealers, ascending
+    pub disqualified: Vec<u32>,
+    /// Whether the complaint round has ended
+    pub complaints_closed: bool,
 }
 
 impl<P: OsstPoint> DkgState<P> {
@@ -257,6 +466,10 @@ impl<P: OsstPoint> DkgState<P> {
             threshold,
             num_participants,
             commitments: vec![None; num_participants as usize],
+            complaints: Vec::new(),
+            justifications: Vec::new(),
+            disqualified: Vec::new(),
+            complaints_closed: false,
         }
     }
 
@@ -265,6 +478,10 @@ impl<P: OsstPoint> DkgState<P> {
         &mut self,
         commitment: DealerCommitment<P>,
     ) -> Result<bool, OsstError> {
+        if self.complaints_closed {
+            return Err(OsstError::WrongPhase);
+        }
+
         let idx = commitment
             .dealer_index
             .checked_sub(1)
@@ -296,17 +513,149 @@ impl<P: OsstPoint> DkgState<P> {
         self.commitment_count() == self.num_participants as usize
     }
 
-    /// Derive group public key from all commitments: Y = sum(C_{i,0})
-    pub fn derive_group_key(&self) -> Result<P, OsstError> {
-        if !self.is_complete() {
+    /// Submit a complaint. Returns true if new, false if duplicate or the
+    /// accused dealer is already disqualified.
+    pub fn submit_complaint(&mut self, complaint: Complaint) -> Result<bool, OsstError> {
+        if self.complaints_closed {
+            return Err(OsstError::WrongPhase);
+        }
+        if complaint.accuser == 0
+            || complaint.accuser > self.num_participants
+            || complaint.accused == 0
+            || complaint.accused > self.num_participants
+            || complaint.accuser == complaint.accused
+        {
+            return Err(OsstError::InvalidIndex);
+        }
+        if self.commitment(complaint.accused).is_none() {
+            return Err(OsstError::InvalidCommitment);
+        }
+
+        if self.is_disqualified(complaint.accused) || self.complaints.contains(&complaint) {
+            return Ok(false);
+        }
+
+        self.complaints.push(complaint);
+        Ok(true)
+    }
+
+    /// Submit a dealer's answer to a complaint: the disputed sub-share.
+    ///
+    /// `sender` is the authenticated participant the answer came from and
+    /// must be the accused dealer. Returns true if the sub-share verifies
+    /// against the dealer's commitment, which settles the complaint. Returns
+    /// false if it does not; the complaint stays open, and the dealer is
+    /// disqualified at [`close_complaints`](Self::close_complaints) unless a
+    /// verifying answer arrives first.
+    pub fn submit_justification(
+        &mut self,
+        sender: u32,
+        subshare: SubShare<P::Scalar>,
+    ) -> Result<bool, OsstError> {
+        if self.complaints_closed {
+            return Err(OsstError::WrongPhase);
+        }
+
+        if subshare.player_index == 0
+            || subshare.player_index > self.num_participants
+            || subshare.dealer_index == 0
+            || subshare.dealer_index > self.num_participants
+        {
+            return Err(OsstError::InvalidIndex);
+        }
+
+        if sender != subshare.dealer_index {
+            return Err(OsstError::InvalidIndex);
+        }
+
+        let complaint = Complaint::new(subshare.player_index, subshare.dealer_index);
+        if !self.complaints.contains(&complaint) {
+            return Err(OsstError::InvalidIndex);
+        }
+        if self.is_disqualified(complaint.accused) {
+            return Ok(false);
+        }
+        if self.justification(complaint).is_some() {
+            return Ok(true);
+        }
+
+        let commitment = self
+            .commitment(complaint.accused)
+            .ok_or(OsstError::InvalidCommitment)?;
+
+        if !commitment.verify_subshare(complaint.accuser, subshare.value()) {
+            return Ok(false);
+        }
+
+        self.justifications.push(subshare);
+        Ok(true)
+    }
+
+    /// Sub-share revealed in answer to `complaint`, if any.
+    ///
+    /// The accuser adds it to its [`Aggregator`] in place of the bad one.
+    pub fn justification(&self, complaint: Complaint) -> Option<&SubShare<P::Scalar>> {
+        self.justifications.iter().find(|s| {
+            s.player_index == complaint.accuser && s.dealer_index == complaint.accused
+        })
+    }
+
+    /// End the complaint round.
+    ///
+    /// Disqualifies every dealer without a commitment or with a complaint
+    /// that no verifying sub-share answered, and returns the disqualified dealers. Fails if fewer than
+    /// `threshold` dealers remain; the ceremony must then restart.
+    pub fn close_complaints(&mut self) -> Result<&[u32], OsstError> {
+        if self.complaints_closed {
+            return Err(OsstError::WrongPhase);
+        }
+        self.complaints_closed = true;
+
+        for dealer in 1..=self.num_participants {
+            if self.commitment(dealer).is_none() {
+                self.disqualify(dealer);
+            }
+        }
+
+        let unanswered: Vec<u32> = self
+            .complaints
+            .iter()
+            .filter(|c| self.justification(**c).is_none())
+            .map(|c| c.accused)
+            .collect();
+        for dealer in unanswered {
+            self.disqualify(dealer);
+        }
+
+        let qualified = self.qualified_dealers().len();
+        if qualified < self.threshold as usize {
             return Err(OsstError::InsufficientContributions {
-                got: self.commitment_count(),
-                need: self.num_participants as usize,
+                got: qualified,
+                need: self.threshold as usize,
             });
         }
 
+        Ok(&self.disqualified)
+    }
+
+    /// True if `dealer_index` has been disqualified
+    pub fn is_disqualified(&self, dealer_index: u32) -> bool {
+        self.disqualified.binary_search(&dealer_index).is_ok()
+    }
+
+    /// Dealers with a commitment that are not disqualified, ascending
+    pub fn qualified_dealers(&self) -> Vec<u32> {
+        self.qualified_commitments()
+            .map(|c| c.dealer_index)
+            .collect()
+    }
+
+    /// Derive group public key from all commitments: Y = sum(C_{i,0})
+    pub fn derive_group_key(&self) -> Result<P, OsstError> {
+        self.check_settled()?;
+
         let mut key = P::identity();
-        for commitment in self.commitments.iter().flatten() {
+        for commitment in self.qualified_commitments() {
             key = key.add(commitment.share_commitment());
         }
 
@@ -323,15 +672,10 @@ impl<P: OsstPoint> DkgState<P> {
         if player_index == 0 {
             return Err(OsstError::InvalidIndex);
         }
-        if !self.is_complete() {
-            return Err(OsstError::InsufficientContributions {
-                got: self.commitment_count(),
-                need: self.num_participants as usize,
-            });
-        }
+        self.check_settled()?;
 
         let mut vshare = P::identity();
-        for commitment in self.commitments.iter().flatten() {
+        for commitment in self.qualified_commitments() {
             vshare = vshare.add(&commitment.evaluate_at(player_index));
         }
 
@@ -355,6 +699,42 @@ impl<P: OsstPoint> DkgState<P> {
     pub fn get_commitments(&self) -> Vec<&DealerCommitment<P>> {
         self.commitments.iter().filter_map(|c| c.as_ref()).collect()
     }
+
+    fn commitment(&self, dealer_index: u32) -> Option<&DealerCommitment<P>> {
+        let idx = dealer_index.checked_sub(1)? as usize;
+        self.commitments.get(idx)?.as_ref()
+    }
+
+    fn qualified_commitments(&self) -> impl Iterator<Item = &DealerCommitment<P>> {
+        self.commitments
+            .iter()
+            .flatten()
+            .filter(|c| !self.is_disqualified(c.dealer_index))
+    }
+
+    fn disqualify(&mut self, dealer_index: u32) {
+        if let Err(pos) = self.disqualified.binary_search(&dealer_index) {
+            self.disqualified.insert(pos, dealer_index);
+        }
+    }
+
+    /// Keys are final once the complaint round is closed, or when every
+    /// dealer committed and nobody complained
+    fn check_settled(&self) -> Result<(), OsstError> {
+        if self.complaints_closed {
+            return Ok(());
+        }
+        if !self.is_complete() {
+            return Err(OsstError::InsufficientContributions {
+                got: self.commitment_count(),
+                need: self.num_participants as usize,
+            });
+        }
+        if !self.complaints.is_empty() {
+            return Err(OsstError::WrongPhase);
+        }
+        Ok(())
+    }
 }
 
 // ============================================================================
@@ -528,6 +908,250 @@ mod tests {
 
         assert!(verify(&group_key, &contributions, t, payload).unwrap());
     }
+
+    #[test]
+    fn test_dkg_complaint_answered() {
+        let mut rng = OsRng;
+        let n = 4u32;
+        let t = 3u32;
+
+        let dealers: Vec<Dealer<RistrettoPoint>> =
+            (1..=n).map(|i| Dealer::new(i, t, &mut rng)).collect();
+
+        let mut state: DkgState<RistrettoPoint> = DkgState::new(1, t, n);
+        for dealer in &dealers {
+            state.submit_commitment(dealer.commitment().clone()).unwrap();
+        }
+
+        // player 1 receives garbage from dealer 3 and complains
+        let mut agg: Aggregator<RistrettoPoint> = Aggregator::new(1);
+        let bad = SubShare::new(3, 1, Scalar::random(&mut rng));
+        assert!(agg.add_subshare(bad, dealers[2].commitment()).is_err());
+
+        let complaint = Complaint::new(1, 3);
+        assert!(state.submit_complaint(complaint).unwrap());
+        assert!(!state.submit_complaint(complaint).unwrap());
+        assert!(matches!(state.derive_group_key(), Err(OsstError::WrongPhase)));
+
+        // dealer 3 reveals the real sub-share, which player 1 then uses
+        assert!(state.submit_justification(3, dealers[2].generate_subshare(1)).unwrap());
+        assert!(state.close_complaints().unwrap().is_empty());
+
+        for dealer in &dealers {
+            let subshare = match state.justification(Complaint::new(1, dealer.index())) {
+                Some(revealed) => revealed.clone(),
+                None => dealer.generate_subshare(1),
+            };
+            agg.add_subshare(subshare, dealer.commitment()).unwrap();
+        }
+        let share = agg.finalize(n).unwrap();
+
+        assert_eq!(agg.derive_group_key(), state.derive_group_key().unwrap());
+        assert_eq!(
+            RistrettoPoint::generator().mul_scalar(&share),
+            state.derive_verification_share(1).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_dkg_faulty_dealers_disqualified() {
+        let mut rng = OsRng;
+        let n = 6u32;
+        let t = 3u32;
+
+        let dealers: Vec<Dealer<RistrettoPoint>> =
+            (1..=n).map(|i| Dealer::new(i, t, &mut rng)).collect();
+
+        // dealer 6 never commits
+        let mut state: DkgState<RistrettoPoint> = DkgState::new(1, t, n);
+        for dealer in &dealers[..5] {
+            state.submit_commitment(dealer.commitment().clone()).unwrap();
+        }
+
+        // dealer 2 ignores its complaint, dealer 4 answers with a wrong value
+        state.submit_complaint(Complaint::new(1, 2)).unwrap();
+        state.submit_complaint(Complaint::new(5, 4)).unwrap();
+        let wrong = SubShare::new(4, 5, Scalar::random(&mut rng));
+        assert!(!state.submit_justification(4, wrong).unwrap());
+        assert!(!state.is_disqualified(4));
+
+        assert_eq!(state.close_complaints().unwrap(), &[2, 4, 6]);
+        let qualified = state.qualified_dealers();
+        assert_eq!(qualified, vec![1, 3, 5]);
+
+        let group_key = state.derive_group_key().unwrap();
+        let mut shares = Vec::new();
+        for j in 1..=n {
+            let mut agg: Aggregator<RistrettoPoint> = Aggregator::new(j);
+            for dealer in &dealers[..5] {
+                if j == 1 && dealer.index() == 2 {
+                    continue;
+                }
+                agg.add_subshare(dealer.generate_subshare(j), dealer.commitment())
+                    .unwrap();
+            }
+            agg.retain_dealers(&qualified);
+            assert_eq!(agg.derive_group_key(), group_key);
+            shares.push(SecretShare::new(j, agg.finalize(qualified.len() as u32).unwrap()));
+        }
+
+        let payload = b"dkg with disqualified dealers";
+        let contributions: Vec<Contribution<RistrettoPoint>> = [1, 3, 5]
+            .iter()
+            .map(|&i| shares[i].contribute(&mut rng, payload))
+            .collect();
+        assert!(verify(&group_key, &contributions, t, payload).unwrap());
+    }
+
+    /// Nobody but the accused dealer can answer a complaint, and a bad
+    /// answer does not settle it either way
+    #[test]
+    fn test_dkg_justification_from_dealer_only() {
+        let mut rng = OsRng;
+        let dealers: Vec<Dealer<RistrettoPoint>> =
+            (1..=4).map(|i| Dealer::new(i, 3, &mut rng)).collect();
+
+        let mut state: DkgState<RistrettoPoint> = DkgState::new(1, 3, 4);
+        for dealer in &dealers {
+            state.submit_commitment(dealer.commitment().clone()).unwrap();
+        }
+        let complaint = Complaint::new(1, 2);
+        state.submit_complaint(complaint).unwrap();
+
+        // a third party and the accuser both try to answer for dealer 2
+        let garbage = SubShare::new(2, 1, Scalar::random(&mut rng));
+        for sender in [3, 1] {
+            assert!(matches!(
+                state.submit_justification(sender, garbage.clone()),
+                Err(OsstError::InvalidIndex)
+            ));
+            assert!(matches!(
+                state.submit_justification(sender, dealers[1].generate_subshare(1)),
+                Err(OsstError::InvalidIndex)
+            ));
+        }
+
+        // dealer 2's own wrong answer leaves the complaint open
+        assert!(!state.submit_justification(2, garbage).unwrap());
+        assert!(!state.is_disqualified(2));
+        assert!(state.justification(complaint).is_none());
+
+        // and its real sub-share still settles it
+        assert!(state.submit_justification(2, dealers[1].generate_subshare(1)).unwrap());
+        assert!(state.close_complaints().unwrap().is_empty());
+        let revealed = state.justification(complaint).unwrap();
+        assert_eq!(revealed.value(), dealers[1].generate_subshare(1).value());
+    }
+
+    #[test]
+    fn test_dkg_complaint_validation() {
+        let mut rng = OsRng;
+        let dealers: Vec<Dealer<RistrettoPoint>> =
+            (1..=3).map(|i| Dealer::new(i, 2, &mut rng)).collect();
+
+        let mut state: DkgState<RistrettoPoint> = DkgState::new(1, 2, 3);
+        for dealer in &dealers[..2] {
+            state.submit_commitment(dealer.commitment().clone()).unwrap();
+        }
+
+        let complaint = Complaint::new(2, 1);
+        assert_eq!(Complaint::from_bytes(&complaint.to_bytes()).unwrap(), complaint);
+        assert!(Complaint::from_bytes(&[0u8; 8]).is_err());
+
+        // self-accusation, out of range, and no commitment to dispute
+        assert!(state.submit_complaint(Complaint::new(1, 1)).is_err());
+        assert!(state.submit_complaint(Complaint::new(4, 1)).is_err());
+        assert!(matches!(
+            state.submit_complaint(Complaint::new(1, 3)),
+            Err(OsstError::InvalidCommitment)
+        ));
+
+        // justification without a complaint
+        assert!(state.submit_justification(1, dealers[0].generate_subshare(3)).is_err());
+
+        // malformed indices are rejected, not asserted on
+        for (dealer, player) in [(0, 1), (1, 0), (4, 1), (1, 4)] {
+            let mut subshare = dealers[0].generate_subshare(1);
+            subshare.dealer_index = dealer;
+            subshare.player_index = player;
+            assert!(matches!(
+                state.submit_justification(dealer, subshare),
+                Err(OsstError::InvalidIndex)
+            ));
+        }
+
+        // two of three dealers remain, enough for t = 2
+        assert_eq!(state.close_complaints().unwrap(), &[3]);
+        assert!(matches!(state.submit_complaint(complaint), Err(OsstError::WrongPhase)));
+        assert!(matches!(
+            state.submit_commitment(dealers[2].commitment().clone()),
+            Err(OsstError::WrongPhase)
+        ));
+        assert!(state.close_complaints().is_err());
+
+        // too few dealers left
+        let mut state: DkgState<RistrettoPoint> = DkgState::new(1, 2, 3);
+        state.submit_commitment(dealers[0].commitment().clone()).unwrap();
+        assert!(matches!(
+            state.close_complaints(),
+            Err(OsstError::InsufficientContributions { got: 1, need: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_weighted_dkg() {
+        let mut rng = OsRng;
+        let table = WeightTable::new(&[3, 1, 2]);
+        let n = table.num_parties();
+        let t = 4u32;
+
+        // every party deals once, in weight units
+        let dealers: Vec<Dealer<RistrettoPoint>> =
+            (1..=n).map(|i| Dealer::new(i, t, &mut rng)).collect();
+
+        let mut state: DkgState<RistrettoPoint> = DkgState::new(0, t, n);
+        for dealer in &dealers {
+            state.submit_commitment(dealer.commitment().clone()).unwrap();
+        }
+
+        let mut parties = Vec::new();
+        for p in 1..=n {
+            let mut agg: WeightedAggregator<RistrettoPoint> =
+                WeightedAggregator::new(&table, p).unwrap();
+            for dealer in &dealers {
+                let subshares = dealer.generate_weighted_subshares(&table, p).unwrap();
+                assert!(agg.add_subshares(subshares, dealer.commitment()).unwrap());
+            }
+            assert_eq!(agg.derive_group_key(), state.derive_group_key().unwrap());
+            parties.push(agg.finalize(&table, n).unwrap());
+        }
+
+        // shares match the on-chain verification shares, index by index
+        for party in &parties {
+            for (index, y) in party.public_shares::<RistrettoPoint>() {
+                assert_eq!(y, state.derive_verification_share(index).unwrap());
+            }
+        }
+
+        // parties 1 and 2 meet weight 4
+        let group_key = state.derive_group_key().unwrap();
+        let payload = b"weighted dkg";
+        let contributions: Vec<_> = parties[..2]
+            .iter()
+            .map(|p| p.contribute::<RistrettoPoint, _>(&mut rng, payload))
+            .collect();
+        assert!(crate::weighted::verify(&group_key, &table, &contributions, t, payload).unwrap());
+
+        // a bad sub-share rejects the whole batch
+        let mut agg: WeightedAggregator<RistrettoPoint> = WeightedAggregator::new(&table, 3).unwrap();
+        let mut subshares = dealers[0].generate_weighted_subshares(&table, 3).unwrap();
+        subshares[1] = SubShare::new(1, subshares[1].player_index, Scalar::ONE);
+        assert_eq!(
+            agg.add_subshares(subshares, dealers[0].commitment()),
+            Err(OsstError::InvalidResponse)
+        );
+        assert_eq!(agg.count(), 0);
+    }
 }
 
 #[cfg(all(test, feature = "pallas"))]
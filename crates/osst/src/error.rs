@@ -28,6 +28,18 @@ pub enum OsstError {
 
     /// Index out of valid range (must be > 0)
     InvalidIndex,
+
+    /// Message or query not valid in the current protocol phase
+    WrongPhase,
+
+    /// Ciphertext failed authentication under the recovered key
+    DecryptionFailed,
+
+    /// Ciphertext's proof of well-formedness does not verify
+    InvalidCiphertext,
+
+    /// Signing package carries a message the signer did not agree to sign
+    MessageMismatch,
 }
 
 impl fmt::Display for OsstError {
@@ -43,6 +55,10 @@ impl fmt::Display for OsstError {
             Self::InvalidResponse => write!(f, "invalid response scalar"),
             Self::LagrangeError => write!(f, "lagrange coefficient computation failed"),
             Self::InvalidIndex => write!(f, "index must be greater than 0"),
+            Self::WrongPhase => write!(f, "not allowed in the current protocol phase"),
+            Self::DecryptionFailed => write!(f, "ciphertext failed authentication"),
+            Self::InvalidCiphertext => write!(f, "ciphertext proof does not verify"),
+            Self::MessageMismatch => write!(f, "signing package carries a different message"),
         }
     }
 }
@@ -49,20 +49,57 @@
 //! - **Share verification** allows detecting a misbehaving signer before
 //!   aggregation, given their public verification share `Y_i = g^{s_i}`.
 //!
+//! # Adaptor signatures
+//!
+//! [`sign_adaptor`] and [`aggregate_adaptor`] run the same two rounds
+//! against an adaptor point T = g^t whose discrete log the signers need not
+//! know. T is fixed in the [`SigningPackage`] and hashed into every binding
+//! factor, so a session's nonces commit to T as well as the message. The
+//! challenge is taken over R + T, so the output [`PreSignature`]
+//! (R, T, ẑ) satisfies g^ẑ == R + c · Y but is not a valid signature.
+//! Whoever knows t can [`complete`](PreSignature::complete) it into the
+//! signature (R + T, ẑ + t), and anyone holding both can
+//! [`extract`](PreSignature::extract) t = z − ẑ. Publishing the completed
+//! signature (e.g. on-chain) therefore reveals t, which is what atomic swaps
+//! and escrow payouts key on.
+//!
+//! These complete into signatures under the session's [`Ciphersuite`], not
+//! under a chain's own scheme. For Zcash Orchard and Taproot outputs use
+//! `redpallas::zcash::sign_adaptor` and `bip340::bitcoin::sign_adaptor`,
+//! whose completed signatures are RedPallas and BIP340 signatures.
+//!
+//! # Weighted signers
+//!
+//! A party holding several share indices of a [`WeightTable`] signs once:
+//! [`sign_weighted`] folds all of its shares into one [`SignatureShare`]
+//! under its party index, and [`aggregate_weighted`] checks it against the
+//! matching sum of verification shares.
+//!
 //! # Ciphersuite
 //!
-//! This module uses SHA-512 with domain separation for the binding factor
-//! and challenge computations. For zcash RedPallas compatibility, a
-//! ciphersuite adapter would override the challenge hash to match zcash's
-//! BLAKE2b-based construction.
+//! [`sign`], [`aggregate`], [`verify_share`] and [`verify_signature`] use
+//! the [`Osst`] ciphersuite: SHA-512 with domain separation for the binding
+//! factor and challenge computations. The `*_with` variants take a
+//! [`Ciphersuite`] type parameter instead, so the same shares and commitments
+//! can produce RFC 9591 signatures that interoperate with other FROST
+//! implementations:
+//!
+//! - [`Ristretto255Sha512`]: FROST(ristretto255, SHA-512)
+//! - [`Secp256k1Sha256`]: FROST(secp256k1, SHA-256)
+//!
+//! Signer index i is the RFC identifier `Scalar(i)`. RFC signatures encode R
+//! with its full element encoding, see [`Signature::to_bytes_vec`]. For zcash
+//! RedPallas and Bitcoin BIP340, see the `redpallas` and `bip340` adapters.
 
 use alloc::collections::BTreeMap;
+use alloc::vec;
 use alloc::vec::Vec;
 use sha2::{Digest, Sha512};
 
 use crate::curve::{OsstPoint, OsstScalar};
 use crate::error::OsstError;
 use crate::lagrange::compute_lagrange_coefficients;
+use crate::weighted::{WeightTable, WeightedShare};
 use crate::SecretShare;
 
 // ============================================================================
@@ -163,8 +200,8 @@ impl<P: OsstPoint> SigningCommitments<P> {
 pub struct SigningPackage<P: OsstPoint> {
     message: Vec<u8>,
     commitments: BTreeMap<u32, SigningCommitments<P>>,
-    /// cached encoded commitments for binding factor computation
-    encoded_commitments: Vec<u8>,
+    /// Adaptor point T for pre-signing, bound into every ρ_i
+    adaptor: Option<P>,
 }
 
 impl<P: OsstPoint> SigningPackage<P> {
@@ -189,15 +226,30 @@ impl<P: OsstPoint> SigningPackage<P> {
             return Err(OsstError::EmptyContributions);
         }
 
-        let encoded = encode_commitments(&map);
-
         Ok(Self {
             message,
             commitments: map,
-            encoded_commitments: encoded,
+            adaptor: None,
         })
     }
 
+    /// Signing package for a pre-signature under adaptor point T.
+    ///
+    /// T enters every binding factor, so commitments collected for one
+    /// adaptor point cannot be reused in a session under another.
+    pub fn with_adaptor(
+        message: Vec<u8>,
+        commitments: Vec<SigningCommitments<P>>,
+        adaptor: P,
+    ) -> Result<Self, OsstError> {
+        if adaptor == P::identity() {
+            return Err(OsstError::InvalidCommitment);
+        }
+        let mut package = Self::new(message, commitments)?;
+        package.adaptor = Some(adaptor);
+        Ok(package)
+    }
+
     /// The message being signed.
     #[inline]
     pub fn message(&self) -> &[u8] {
@@ -220,34 +272,39 @@ impl<P: OsstPoint> SigningPackage<P> {
         self.commitments.get(&index)
     }
 
-    /// Compute the binding factor for signer i.
+    /// The adaptor point T, if this package is for a pre-signature.
+    #[inline]
+    pub fn adaptor(&self) -> Option<&P> {
+        self.adaptor.as_ref()
+    }
+
+    /// Compute the binding factor ρ_i of every signer under ciphersuite `C`.
     ///
-    /// ρ_i = H("frost-binding-v1" || index || message || encoded_commitments)
-    fn binding_factor(&self, index: u32) -> P::Scalar {
-        compute_binding_factor::<P::Scalar>(
-            index,
-            &self.message,
-            &self.encoded_commitments,
-        )
+    /// Adaptor packages hash "frost-adaptor-v1" || T || m in place of m, so
+    /// ρ_i commits to T as well as the message.
+    fn binding_factors<C: Ciphersuite<P>>(&self, group_pubkey: &P) -> BTreeMap<u32, P::Scalar> {
+        match &self.adaptor {
+            None => C::binding_factors(group_pubkey, &self.message, &self.commitments),
+            Some(adaptor) => {
+                let mut bound = b"frost-adaptor-v1".to_vec();
+                bound.extend_from_slice(&adaptor.compress_vec());
+                bound.extend_from_slice(&self.message);
+                C::binding_factors(group_pubkey, &bound, &self.commitments)
+            }
+        }
     }
 
     /// Compute the group commitment R = Σ (D_i + ρ_i · E_i).
-    fn group_commitment(&self) -> P {
+    fn group_commitment(&self, binding_factors: &BTreeMap<u32, P::Scalar>) -> P {
         let mut r = P::identity();
-        for (_, c) in &self.commitments {
-            let rho = self.binding_factor(c.index);
+        for (index, c) in &self.commitments {
             // D_i + ρ_i · E_i
-            let bound = c.binding.mul_scalar(&rho);
+            let bound = c.binding.mul_scalar(&binding_factors[index]);
             r = r.add(&c.hiding);
             r = r.add(&bound);
         }
         r
     }
-
-    /// Compute the Schnorr challenge c = H("frost-challenge-v1" || R || Y || m).
-    fn challenge(&self, group_commitment: &P, group_pubkey: &P) -> P::Scalar {
-        compute_challenge::<P>(group_commitment, group_pubkey, &self.message)
-    }
 }
 
 /// A single signer's share of the aggregate signature.
@@ -322,6 +379,29 @@ impl<P: OsstPoint> Signature<P> {
             .ok_or(OsstError::InvalidResponse)?;
         Ok(Self { r, z })
     }
+
+    /// Serialize with the full point encoding: [R:COMPRESSED_SIZE][z:32].
+    ///
+    /// This is the RFC 9591 signature encoding; for secp256k1 it keeps the
+    /// y parity of R that [`to_bytes`](Self::to_bytes) drops.
+    pub fn to_bytes_vec(&self) -> Vec<u8> {
+        let mut buf = self.r.compress_vec();
+        buf.extend_from_slice(&self.z.to_bytes());
+        buf
+    }
+
+    /// Deserialize from [`to_bytes_vec`](Self::to_bytes_vec) output.
+    pub fn from_bytes_slice(bytes: &[u8]) -> Result<Self, OsstError> {
+        if bytes.len() != P::COMPRESSED_SIZE + 32 {
+            return Err(OsstError::InvalidCommitment);
+        }
+        let (r_bytes, z_bytes) = bytes.split_at(P::COMPRESSED_SIZE);
+        let r = P::decompress_slice(r_bytes).ok_or(OsstError::InvalidCommitment)?;
+        let z_bytes: [u8; 32] = z_bytes.try_into().unwrap();
+        let z = P::Scalar::from_canonical_bytes(&z_bytes)
+            .ok_or(OsstError::InvalidResponse)?;
+        Ok(Self { r, z })
+    }
 }
 
 // ============================================================================
@@ -381,6 +461,285 @@ fn compute_challenge<P: OsstPoint>(
     P::Scalar::from_bytes_wide(&hash)
 }
 
+// ============================================================================
+// Ciphersuites
+// ============================================================================
+
+/// Hash functions that parameterize FROST.
+///
+/// Implemented by the unit structs [`Osst`], [`Ristretto255Sha512`] and
+/// [`Secp256k1Sha256`] and passed to the `*_with` protocol functions as a
+/// type parameter.
+pub trait Ciphersuite<P: OsstPoint> {
+    /// Binding factor ρ_i for every signer in the commitment list.
+    fn binding_factors(
+        group_pubkey: &P,
+        message: &[u8],
+        commitments: &BTreeMap<u32, SigningCommitments<P>>,
+    ) -> BTreeMap<u32, P::Scalar>;
+
+    /// Schnorr challenge c = H(R, Y, m).
+    fn challenge(group_commitment: &P, group_pubkey: &P, message: &[u8]) -> P::Scalar;
+
+    /// Nonce derived from 32 fresh random bytes and the signer's secret share,
+    /// so a weak RNG alone does not leak the share.
+    fn nonce(randomness: &[u8; 32], secret: &P::Scalar) -> P::Scalar;
+}
+
+/// The crate's own ciphersuite: SHA-512 with "frost-*-v1" domain separation.
+///
+/// Binding factors cover the signer index, message and commitment list but
+/// not the group public key.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Osst;
+
+impl<P: OsstPoint> Ciphersuite<P> for Osst {
+    fn binding_factors(
+        _group_pubkey: &P,
+        message: &[u8],
+        commitments: &BTreeMap<u32, SigningCommitments<P>>,
+    ) -> BTreeMap<u32, P::Scalar> {
+        let encoded = encode_commitments(commitments);
+        commitments
+            .keys()
+            .map(|&i| (i, compute_binding_factor::<P::Scalar>(i, message, &encoded)))
+            .collect()
+    }
+
+    fn challenge(group_commitment: &P, group_pubkey: &P, message: &[u8]) -> P::Scalar {
+        compute_challenge::<P>(group_commitment, group_pubkey, message)
+    }
+
+    fn nonce(randomness: &[u8; 32], secret: &P::Scalar) -> P::Scalar {
+        let mut h = Sha512::new();
+        h.update(b"frost-nonce-v1");
+        h.update(randomness);
+        h.update(secret.to_bytes());
+        let hash: [u8; 64] = h.finalize().into();
+        P::Scalar::from_bytes_wide(&hash)
+    }
+}
+
+/// RFC 9591 §4.4–4.5 binding factors, given the ciphersuite's H1, H4, H5.
+///
+/// ρ_i = H1(Y || H4(m) || H5(encode_group_commitment_list(B)) || i)
+fn rfc9591_binding_factors<P: OsstPoint>(
+    h1: fn(&[u8]) -> P::Scalar,
+    h4: fn(&[u8]) -> Vec<u8>,
+    h5: fn(&[u8]) -> Vec<u8>,
+    group_pubkey: &P,
+    message: &[u8],
+    commitments: &BTreeMap<u32, SigningCommitments<P>>,
+) -> BTreeMap<u32, P::Scalar> {
+    let mut encoded = Vec::new();
+    for (&i, c) in commitments {
+        encoded.extend_from_slice(&P::Scalar::from_u32(i).to_bytes());
+        encoded.extend_from_slice(&c.hiding.compress_vec());
+        encoded.extend_from_slice(&c.binding.compress_vec());
+    }
+
+    let mut prefix = group_pubkey.compress_vec();
+    prefix.extend_from_slice(&h4(message));
+    prefix.extend_from_slice(&h5(&encoded));
+
+    commitments
+        .keys()
+        .map(|&i| {
+            let mut input = prefix.clone();
+            input.extend_from_slice(&P::Scalar::from_u32(i).to_bytes());
+            (i, h1(&input))
+        })
+        .collect()
+}
+
+/// RFC 9591 challenge: c = H2(R || Y || m).
+fn rfc9591_challenge<P: OsstPoint>(
+    h2: fn(&[u8]) -> P::Scalar,
+    group_commitment: &P,
+    group_pubkey: &P,
+    message: &[u8],
+) -> P::Scalar {
+    let mut input = group_commitment.compress_vec();
+    input.extend_from_slice(&group_pubkey.compress_vec());
+    input.extend_from_slice(message);
+    h2(&input)
+}
+
+/// RFC 9591 nonce_generate: H3(randomness || SerializeScalar(secret)).
+fn rfc9591_nonce<S: OsstScalar>(h3: fn(&[u8]) -> S, randomness: &[u8; 32], secret: &S) -> S {
+    let mut input = [0u8; 64];
+    input[..32].copy_from_slice(randomness);
+    input[32..].copy_from_slice(&secret.to_bytes());
+    h3(&input)
+}
+
+/// FROST(ristretto255, SHA-512), RFC 9591 §6.2.
+#[cfg(feature = "ristretto255")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Ristretto255Sha512;
+
+#[cfg(feature = "ristretto255")]
+mod ristretto255_sha512 {
+    use super::*;
+    use curve25519_dalek::{ristretto::RistrettoPoint, scalar::Scalar};
+
+    const CONTEXT: &[u8] = b"FROST-RISTRETTO255-SHA512-v1";
+
+    fn hash(label: &[u8], m: &[u8]) -> [u8; 64] {
+        let mut h = Sha512::new();
+        h.update(CONTEXT);
+        h.update(label);
+        h.update(m);
+        h.finalize().into()
+    }
+
+    fn h1(m: &[u8]) -> Scalar {
+        Scalar::from_bytes_wide(&hash(b"rho", m))
+    }
+
+    fn h2(m: &[u8]) -> Scalar {
+        Scalar::from_bytes_wide(&hash(b"chal", m))
+    }
+
+    fn h3(m: &[u8]) -> Scalar {
+        Scalar::from_bytes_wide(&hash(b"nonce", m))
+    }
+
+    fn h4(m: &[u8]) -> Vec<u8> {
+        hash(b"msg", m).to_vec()
+    }
+
+    fn h5(m: &[u8]) -> Vec<u8> {
+        hash(b"com", m).to_vec()
+    }
+
+    impl Ciphersuite<RistrettoPoint> for Ristretto255Sha512 {
+        fn binding_factors(
+            group_pubkey: &RistrettoPoint,
+            message: &[u8],
+            commitments: &BTreeMap<u32, SigningCommitments<RistrettoPoint>>,
+        ) -> BTreeMap<u32, Scalar> {
+            rfc9591_binding_factors(h1, h4, h5, group_pubkey, message, commitments)
+        }
+
+        fn challenge(
+            group_commitment: &RistrettoPoint,
+            group_pubkey: &RistrettoPoint,
+            message: &[u8],
+        ) -> Scalar {
+            rfc9591_challenge(h2, group_commitment, group_pubkey, message)
+        }
+
+        fn nonce(randomness: &[u8; 32], secret: &Scalar) -> Scalar {
+            rfc9591_nonce(h3, randomness, secret)
+        }
+    }
+}
+
+/// FROST(secp256k1, SHA-256), RFC 9591 §6.5.
+#[cfg(feature = "secp256k1")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Secp256k1Sha256;
+
+#[cfg(feature = "secp256k1")]
+mod secp256k1_sha256 {
+    use super::*;
+    use k256::{ProjectivePoint, Scalar};
+    use sha2::Sha256;
+
+    const CONTEXT: &[u8] = b"FROST-secp256k1-SHA256-v1";
+
+    /// hash_to_field from RFC 9380 with expand_message_xmd(SHA-256), L = 48
+    fn hash_to_scalar(label: &[u8], m: &[u8]) -> Scalar {
+        let dst_len = (CONTEXT.len() + label.len()) as u8;
+        let dst_prime = |h: &mut Sha256| {
+            h.update(CONTEXT);
+            h.update(label);
+            h.update([dst_len]);
+        };
+
+        let mut h = Sha256::new();
+        h.update([0u8; 64]);
+        h.update(m);
+        h.update(48u16.to_be_bytes());
+        h.update([0u8]);
+        dst_prime(&mut h);
+        let b0: [u8; 32] = h.finalize().into();
+
+        let mut h = Sha256::new();
+        h.update(b0);
+        h.update([1u8]);
+        dst_prime(&mut h);
+        let b1: [u8; 32] = h.finalize().into();
+
+        let mut mixed = b0;
+        for (x, y) in mixed.iter_mut().zip(b1.iter()) {
+            *x ^= y;
+        }
+        let mut h = Sha256::new();
+        h.update(mixed);
+        h.update([2u8]);
+        dst_prime(&mut h);
+        let b2: [u8; 32] = h.finalize().into();
+
+        // 48 uniform bytes, big-endian, left-padded to 64 for the wide reduction
+        let mut wide = [0u8; 64];
+        wide[16..48].copy_from_slice(&b1);
+        wide[48..].copy_from_slice(&b2[..16]);
+        Scalar::from_bytes_wide(&wide)
+    }
+
+    fn hash(label: &[u8], m: &[u8]) -> Vec<u8> {
+        let mut h = Sha256::new();
+        h.update(CONTEXT);
+        h.update(label);
+        h.update(m);
+        h.finalize().to_vec()
+    }
+
+    fn h1(m: &[u8]) -> Scalar {
+        hash_to_scalar(b"rho", m)
+    }
+
+    fn h2(m: &[u8]) -> Scalar {
+        hash_to_scalar(b"chal", m)
+    }
+
+    fn h3(m: &[u8]) -> Scalar {
+        hash_to_scalar(b"nonce", m)
+    }
+
+    fn h4(m: &[u8]) -> Vec<u8> {
+        hash(b"msg", m)
+    }
+
+    fn h5(m: &[u8]) -> Vec<u8> {
+        hash(b"com", m)
+    }
+
+    impl Ciphersuite<ProjectivePoint> for Secp256k1Sha256 {
+        fn binding_factors(
+            group_pubkey: &ProjectivePoint,
+            message: &[u8],
+            commitments: &BTreeMap<u32, SigningCommitments<ProjectivePoint>>,
+        ) -> BTreeMap<u32, Scalar> {
+            rfc9591_binding_factors(h1, h4, h5, group_pubkey, message, commitments)
+        }
+
+        fn challenge(
+            group_commitment: &ProjectivePoint,
+            group_pubkey: &ProjectivePoint,
+            message: &[u8],
+        ) -> Scalar {
+            rfc9591_challenge(h2, group_commitment, group_pubkey, message)
+        }
+
+        fn nonce(randomness: &[u8; 32], secret: &Scalar) -> Scalar {
+            rfc9591_nonce(h3, randomness, secret)
+        }
+    }
+}
+
 // ============================================================================
 // Protocol
 // ============================================================================
@@ -407,6 +766,49 @@ pub fn commit<P: OsstPoint, R: rand_core::RngCore + rand_core::CryptoRng>(
     (Nonces { hiding, binding }, commitments)
 }
 
+/// Round 1 with hedged nonces: d_i, e_i = C::nonce(random, s_i).
+///
+/// This is RFC 9591 `commit`. The returned [`Nonces`] are single-use,
+/// exactly as for [`commit`].
+pub fn commit_with<C, P, R>(
+    share: &SecretShare<P::Scalar>,
+    rng: &mut R,
+) -> (Nonces<P::Scalar>, SigningCommitments<P>)
+where
+    C: Ciphersuite<P>,
+    P: OsstPoint,
+    R: rand_core::RngCore + rand_core::CryptoRng,
+{
+    let mut hiding_randomness = [0u8; 32];
+    let mut binding_randomness = [0u8; 32];
+    rng.fill_bytes(&mut hiding_randomness);
+    rng.fill_bytes(&mut binding_randomness);
+    commit_from_randomness::<C, P>(share, &hiding_randomness, &binding_randomness)
+}
+
+/// Round 1 from caller-supplied randomness.
+///
+/// Deterministic, for reproducing test vectors. The randomness MUST be
+/// fresh for every session; use [`commit_with`] otherwise.
+pub fn commit_from_randomness<C: Ciphersuite<P>, P: OsstPoint>(
+    share: &SecretShare<P::Scalar>,
+    hiding_randomness: &[u8; 32],
+    binding_randomness: &[u8; 32],
+) -> (Nonces<P::Scalar>, SigningCommitments<P>) {
+    assert!(share.index > 0, "signer index must be 1-indexed");
+
+    let hiding = C::nonce(hiding_randomness, share.scalar());
+    let binding = C::nonce(binding_randomness, share.scalar());
+
+    let commitments = SigningCommitments {
+        index: share.index,
+        hiding: P::generator().mul_scalar(&hiding),
+        binding: P::generator().mul_scalar(&binding),
+    };
+
+    (Nonces { hiding, binding }, commitments)
+}
+
 /// Round 2: produce a signature share.
 ///
 /// Consumes the nonces to prevent reuse. Computes:
@@ -427,41 +829,18 @@ pub fn sign<P: OsstPoint>(
     share: &SecretShare<P::Scalar>,
     group_pubkey: &P,
 ) -> Result<SignatureShare<P::Scalar>, OsstError> {
-    // verify our index is in the signing set
-    if package.get_commitments(share.index).is_none() {
-        return Err(OsstError::InvalidIndex);
-    }
-
-    // binding factor for this signer
-    let rho = package.binding_factor(share.index);
-
-    // group commitment R
-    let group_commitment = package.group_commitment();
-
-    // challenge c = H(R, Y, m)
-    let challenge = package.challenge(&group_commitment, group_pubkey);
-
-    // lagrange coefficient λ_i for this signer in the signing set
-    let indices = package.signer_indices();
-    let lagrange = compute_lagrange_coefficients::<P::Scalar>(&indices)?;
-    let my_pos = indices
-        .iter()
-        .position(|&i| i == share.index)
-        .ok_or(OsstError::InvalidIndex)?;
-    let lambda = &lagrange[my_pos];
-
-    // z_i = d_i + ρ_i · e_i + λ_i · c · s_i
-    let response = nonces
-        .hiding
-        .add(&rho.mul(&nonces.binding))
-        .add(&lambda.mul(&challenge).mul(share.scalar()));
-
-    // nonces dropped here, zeroized
+    sign_with::<Osst, P>(package, nonces, share, group_pubkey)
+}
 
-    Ok(SignatureShare {
-        index: share.index,
-        response,
-    })
+/// [`sign`] under ciphersuite `C`.
+pub fn sign_with<C: Ciphersuite<P>, P: OsstPoint>(
+    package: &SigningPackage<P>,
+    nonces: Nonces<P::Scalar>,
+    share: &SecretShare<P::Scalar>,
+    group_pubkey: &P,
+) -> Result<SignatureShare<P::Scalar>, OsstError> {
+    let session = Session::new::<C>(package, group_pubkey, false)?;
+    session.respond(share.index, nonces, core::slice::from_ref(share))
 }
 
 /// Aggregate signature shares into a standard Schnorr signature.
@@ -482,60 +861,231 @@ pub fn aggregate<P: OsstPoint>(
     group_pubkey: &P,
     verifier_shares: Option<&BTreeMap<u32, P>>,
 ) -> Result<Signature<P>, OsstError> {
-    if shares.len() < package.num_signers() {
-        return Err(OsstError::InsufficientContributions {
-            got: shares.len(),
-            need: package.num_signers(),
-        });
+    aggregate_with::<Osst, P>(package, shares, group_pubkey, verifier_shares)
+}
+
+/// [`aggregate`] under ciphersuite `C`.
+pub fn aggregate_with<C: Ciphersuite<P>, P: OsstPoint>(
+    package: &SigningPackage<P>,
+    shares: &[SignatureShare<P::Scalar>],
+    group_pubkey: &P,
+    verifier_shares: Option<&BTreeMap<u32, P>>,
+) -> Result<Signature<P>, OsstError> {
+    let session = Session::new::<C>(package, group_pubkey, false)?;
+    let z = session.combine(package, shares, verifier_shares)?;
+
+    Ok(Signature {
+        r: session.group_commitment,
+        z,
+    })
+}
+
+/// Verify one signature share against the signer's verification share
+/// `Y_i = g^{s_i}`.
+///
+/// # Errors
+///
+/// Returns `InvalidIndex` if the share's index is not in the package.
+pub fn verify_share<P: OsstPoint>(
+    package: &SigningPackage<P>,
+    share: &SignatureShare<P::Scalar>,
+    group_pubkey: &P,
+    verification_share: &P,
+) -> Result<bool, OsstError> {
+    verify_share_with::<Osst, P>(package, share, group_pubkey, verification_share)
+}
+
+/// [`verify_share`] under ciphersuite `C`.
+pub fn verify_share_with<C: Ciphersuite<P>, P: OsstPoint>(
+    package: &SigningPackage<P>,
+    share: &SignatureShare<P::Scalar>,
+    group_pubkey: &P,
+    verification_share: &P,
+) -> Result<bool, OsstError> {
+    let session = Session::new::<C>(package, group_pubkey, false)?;
+    let verifier_shares = BTreeMap::from([(share.index, verification_share.clone())]);
+
+    session.share_is_valid(package, share, &verifier_shares)
+}
+
+/// Per-session values shared by signing, share verification and aggregation.
+struct Session<P: OsstPoint> {
+    binding_factors: BTreeMap<u32, P::Scalar>,
+    /// R = Σ (D_i + ρ_i · E_i)
+    group_commitment: P,
+    /// c = H(R + T, Y, m), with T the package's adaptor point or the identity
+    challenge: P::Scalar,
+    /// Share indices each signer answers for: its own, or its weighted range
+    members: BTreeMap<u32, Vec<u32>>,
+    /// λ_k over the union of all signers' share indices
+    lagrange: BTreeMap<u32, P::Scalar>,
+}
+
+impl<P: OsstPoint> Session<P> {
+    /// Session where each signer answers for its own index.
+    ///
+    /// Refuses a package whose adaptor point does not match `adaptor`, so a
+    /// pre-signing package never yields a plain signature share and vice versa.
+    fn new<C: Ciphersuite<P>>(
+        package: &SigningPackage<P>,
+        group_pubkey: &P,
+        adaptor: bool,
+    ) -> Result<Self, OsstError> {
+        if package.adaptor.is_some() != adaptor {
+            return Err(OsstError::InvalidCommitment);
+        }
+        let members = package
+            .signer_indices()
+            .into_iter()
+            .map(|i| (i, vec![i]))
+            .collect();
+        Self::with_members::<C>(package, group_pubkey, members)
     }
 
-    let group_commitment = package.group_commitment();
-    let challenge = package.challenge(&group_commitment, group_pubkey);
+    /// Session where signer p answers for the indices `table` assigns it.
+    fn weighted<C: Ciphersuite<P>>(
+        package: &SigningPackage<P>,
+        group_pubkey: &P,
+        table: &WeightTable,
+    ) -> Result<Self, OsstError> {
+        if package.adaptor.is_some() {
+            return Err(OsstError::InvalidCommitment);
+        }
+        let members = package
+            .signer_indices()
+            .into_iter()
+            .map(|p| {
+                let range = table.indices(p).ok_or(OsstError::InvalidIndex)?;
+                Ok((p, range.collect()))
+            })
+            .collect::<Result<_, OsstError>>()?;
+        Self::with_members::<C>(package, group_pubkey, members)
+    }
+
+    fn with_members<C: Ciphersuite<P>>(
+        package: &SigningPackage<P>,
+        group_pubkey: &P,
+        members: BTreeMap<u32, Vec<u32>>,
+    ) -> Result<Self, OsstError> {
+        let indices: Vec<u32> = members.values().flatten().copied().collect();
+        let lagrange = indices
+            .iter()
+            .copied()
+            .zip(compute_lagrange_coefficients::<P::Scalar>(&indices)?)
+            .collect();
+
+        let binding_factors = package.binding_factors::<C>(group_pubkey);
+        let group_commitment = package.group_commitment(&binding_factors);
+        let nonce = match &package.adaptor {
+            Some(adaptor) => group_commitment.add(adaptor),
+            None => group_commitment.clone(),
+        };
+        let challenge = C::challenge(&nonce, group_pubkey, &package.message);
+        Ok(Self {
+            binding_factors,
+            group_commitment,
+            challenge,
+            members,
+            lagrange,
+        })
+    }
 
-    // optionally verify each share
-    if let Some(vshares) = verifier_shares {
-        let indices = package.signer_indices();
-        let lagrange = compute_lagrange_coefficients::<P::Scalar>(&indices)?;
+    /// z_i = d_i + ρ_i · e_i + c · Σ_k λ_k · s_k over signer i's shares
+    fn respond(
+        &self,
+        index: u32,
+        nonces: Nonces<P::Scalar>,
+        shares: &[SecretShare<P::Scalar>],
+    ) -> Result<SignatureShare<P::Scalar>, OsstError> {
+        // verify our index is in the signing set
+        let rho = self
+            .binding_factors
+            .get(&index)
+            .ok_or(OsstError::InvalidIndex)?;
+
+        // and that we hold exactly the shares it answers for
+        let members = self.members.get(&index).ok_or(OsstError::InvalidIndex)?;
+        if !shares.iter().map(|s| s.index).eq(members.iter().copied()) {
+            return Err(OsstError::InvalidIndex);
+        }
 
+        let mut secret = P::Scalar::zero();
         for share in shares {
-            let pos = indices
-                .iter()
-                .position(|&i| i == share.index)
-                .ok_or(OsstError::InvalidIndex)?;
-
-            let yi = vshares
-                .get(&share.index)
-                .ok_or(OsstError::InvalidIndex)?;
-
-            let rho = package.binding_factor(share.index);
-            let comm = package
-                .get_commitments(share.index)
-                .ok_or(OsstError::InvalidIndex)?;
-
-            // expected: g^{z_i} == D_i + ρ_i·E_i + λ_i·c·Y_i
-            let lhs = P::generator().mul_scalar(&share.response);
-
-            let rhs = comm
-                .hiding
-                .add(&comm.binding.mul_scalar(&rho))
-                .add(&yi.mul_scalar(&lagrange[pos].mul(&challenge)));
-
-            if lhs != rhs {
-                return Err(OsstError::InvalidResponse);
-            }
+            secret = secret.add(&self.lagrange[&share.index].mul(share.scalar()));
         }
+
+        let response = nonces
+            .hiding
+            .add(&rho.mul(&nonces.binding))
+            .add(&self.challenge.mul(&secret));
+
+        // nonces dropped here, zeroized
+
+        Ok(SignatureShare { index, response })
     }
 
-    // z = Σ z_i
-    let mut z = P::Scalar::zero();
-    for share in shares {
-        z = z.add(&share.response);
+    /// z = Σ z_i, verifying each share first if `verifier_shares` is given.
+    fn combine(
+        &self,
+        package: &SigningPackage<P>,
+        shares: &[SignatureShare<P::Scalar>],
+        verifier_shares: Option<&BTreeMap<u32, P>>,
+    ) -> Result<P::Scalar, OsstError> {
+        if shares.len() < package.num_signers() {
+            return Err(OsstError::InsufficientContributions {
+                got: shares.len(),
+                need: package.num_signers(),
+            });
+        }
+
+        // optionally verify each share
+        if let Some(vshares) = verifier_shares {
+            for share in shares {
+                if !self.share_is_valid(package, share, vshares)? {
+                    return Err(OsstError::InvalidResponse);
+                }
+            }
+        }
+
+        let mut z = P::Scalar::zero();
+        for share in shares {
+            z = z.add(&share.response);
+        }
+        Ok(z)
     }
 
-    Ok(Signature {
-        r: group_commitment,
-        z,
-    })
+    /// g^{z_i} == D_i + ρ_i·E_i + c · Σ_k λ_k·Y_k over signer i's shares
+    fn share_is_valid(
+        &self,
+        package: &SigningPackage<P>,
+        share: &SignatureShare<P::Scalar>,
+        verifier_shares: &BTreeMap<u32, P>,
+    ) -> Result<bool, OsstError> {
+        let members = self
+            .members
+            .get(&share.index)
+            .ok_or(OsstError::InvalidIndex)?;
+
+        let rho = &self.binding_factors[&share.index];
+        let comm = package
+            .get_commitments(share.index)
+            .ok_or(OsstError::InvalidIndex)?;
+
+        let mut key = P::identity();
+        for k in members {
+            let yk = verifier_shares.get(k).ok_or(OsstError::InvalidIndex)?;
+            key = key.add(&yk.mul_scalar(&self.lagrange[k]));
+        }
+
+        let lhs = P::generator().mul_scalar(&share.response);
+
+        let rhs = comm
+            .hiding
+            .add(&comm.binding.mul_scalar(rho))
+            .add(&key.mul_scalar(&self.challenge));
+
+        Ok(lhs == rhs)
+    }
 }
 
 /// Verify a standard Schnorr signature against a group public key.
@@ -546,7 +1096,16 @@ pub fn verify_signature<P: OsstPoint>(
     message: &[u8],
     signature: &Signature<P>,
 ) -> bool {
-    let challenge = compute_challenge::<P>(&signature.r, group_pubkey, message);
+    verify_signature_with::<Osst, P>(group_pubkey, message, signature)
+}
+
+/// [`verify_signature`] under ciphersuite `C`.
+pub fn verify_signature_with<C: Ciphersuite<P>, P: OsstPoint>(
+    group_pubkey: &P,
+    message: &[u8],
+    signature: &Signature<P>,
+) -> bool {
+    let challenge = C::challenge(&signature.r, group_pubkey, message);
 
     // lhs = g^z
     let lhs = P::generator().mul_scalar(&signature.z);
@@ -557,6 +1116,240 @@ pub fn verify_signature<P: OsstPoint>(
     lhs == rhs
 }
 
+// ============================================================================
+// Weighted signers
+// ============================================================================
+
+/// Round 2 for a weighted party: one share that counts for its whole weight.
+///
+/// The party commits once with [`commit`] under its party index. With Q the
+/// union of the signing parties' share indices, its response is
+///
+/// ```text
+/// z_p = d_p + ρ_p · e_p + c · Σ_{k ∈ p} λ_k · s_k
+/// ```
+///
+/// with λ_k the Lagrange coefficients over Q.
+///
+/// # Errors
+///
+/// Returns `InvalidIndex` if the party is not in the package or `table`.
+pub fn sign_weighted<P: OsstPoint>(
+    package: &SigningPackage<P>,
+    nonces: Nonces<P::Scalar>,
+    share: &WeightedShare<P::Scalar>,
+    group_pubkey: &P,
+    table: &WeightTable,
+) -> Result<SignatureShare<P::Scalar>, OsstError> {
+    sign_weighted_with::<Osst, P>(package, nonces, share, group_pubkey, table)
+}
+
+/// [`sign_weighted`] under ciphersuite `C`.
+pub fn sign_weighted_with<C: Ciphersuite<P>, P: OsstPoint>(
+    package: &SigningPackage<P>,
+    nonces: Nonces<P::Scalar>,
+    share: &WeightedShare<P::Scalar>,
+    group_pubkey: &P,
+    table: &WeightTable,
+) -> Result<SignatureShare<P::Scalar>, OsstError> {
+    let session = Session::weighted::<C>(package, group_pubkey, table)?;
+    session.respond(share.party, nonces, share.shares())
+}
+
+/// Aggregate weighted signature shares into a standard Schnorr signature.
+///
+/// `verifier_shares` maps share indices (not parties) to g^{s_k}, as
+/// derived by [`DkgState::derive_verification_share`](crate::dkg::DkgState::derive_verification_share).
+/// A party's share is checked against Σ_{k ∈ p} λ_k · Y_k.
+///
+/// # Errors
+///
+/// As [`aggregate`], plus `InvalidIndex` if a signer is not in `table`.
+pub fn aggregate_weighted<P: OsstPoint>(
+    package: &SigningPackage<P>,
+    shares: &[SignatureShare<P::Scalar>],
+    group_pubkey: &P,
+    table: &WeightTable,
+    verifier_shares: Option<&BTreeMap<u32, P>>,
+) -> Result<Signature<P>, OsstError> {
+    aggregate_weighted_with::<Osst, P>(package, shares, group_pubkey, table, verifier_shares)
+}
+
+/// [`aggregate_weighted`] under ciphersuite `C`.
+pub fn aggregate_weighted_with<C: Ciphersuite<P>, P: OsstPoint>(
+    package: &SigningPackage<P>,
+    shares: &[SignatureShare<P::Scalar>],
+    group_pubkey: &P,
+    table: &WeightTable,
+    verifier_shares: Option<&BTreeMap<u32, P>>,
+) -> Result<Signature<P>, OsstError> {
+    let session = Session::weighted::<C>(package, group_pubkey, table)?;
+    let z = session.combine(package, shares, verifier_shares)?;
+
+    Ok(Signature {
+        r: session.group_commitment,
+        z,
+    })
+}
+
+// ============================================================================
+// Adaptor signatures
+// ============================================================================
+
+/// Threshold pre-signature under adaptor point T.
+///
+/// Verifies with [`verify_presignature`]; becomes a [`Signature`] once the
+/// discrete log of T is added to the response.
+#[derive(Clone, Debug)]
+pub struct PreSignature<P: OsstPoint> {
+    /// FROST group commitment R (without the adaptor).
+    pub r: P,
+    /// Adaptor point T = g^t.
+    pub adaptor: P,
+    /// Aggregate response ẑ.
+    pub z: P::Scalar,
+}
+
+impl<P: OsstPoint> PreSignature<P> {
+    /// Complete into a signature (R + T, ẑ + t).
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidResponse` if g^t != T.
+    pub fn complete(&self, secret: &P::Scalar) -> Result<Signature<P>, OsstError> {
+        if P::generator().mul_scalar(secret) != self.adaptor {
+            return Err(OsstError::InvalidResponse);
+        }
+        Ok(Signature {
+            r: self.r.add(&self.adaptor),
+            z: self.z.add(secret),
+        })
+    }
+
+    /// Recover t = z − ẑ from the completed signature.
+    ///
+    /// Returns `None` if `signature` was not completed from this
+    /// pre-signature.
+    pub fn extract(&self, signature: &Signature<P>) -> Option<P::Scalar> {
+        if signature.r != self.r.add(&self.adaptor) {
+            return None;
+        }
+        let secret = signature.z.sub(&self.z);
+        if P::generator().mul_scalar(&secret) != self.adaptor {
+            return None;
+        }
+        Some(secret)
+    }
+
+    /// Serialize: [R:COMPRESSED_SIZE][T:COMPRESSED_SIZE][ẑ:32]
+    pub fn to_bytes_vec(&self) -> Vec<u8> {
+        let mut buf = self.r.compress_vec();
+        buf.extend_from_slice(&self.adaptor.compress_vec());
+        buf.extend_from_slice(&self.z.to_bytes());
+        buf
+    }
+
+    /// Deserialize from [`to_bytes_vec`](Self::to_bytes_vec) output.
+    pub fn from_bytes_slice(bytes: &[u8]) -> Result<Self, OsstError> {
+        let n = P::COMPRESSED_SIZE;
+        if bytes.len() != 2 * n + 32 {
+            return Err(OsstError::InvalidCommitment);
+        }
+        let r = P::decompress_slice(&bytes[..n]).ok_or(OsstError::InvalidCommitment)?;
+        let adaptor =
+            P::decompress_slice(&bytes[n..2 * n]).ok_or(OsstError::InvalidCommitment)?;
+        let z_bytes: [u8; 32] = bytes[2 * n..].try_into().unwrap();
+        let z = P::Scalar::from_canonical_bytes(&z_bytes)
+            .ok_or(OsstError::InvalidResponse)?;
+        Ok(Self { r, adaptor, z })
+    }
+}
+
+/// Round 2 against the package's adaptor point T:
+/// z_i = d_i + ρ_i · e_i + λ_i · c · s_i with c = H(R + T, Y, m).
+///
+/// The package must come from [`SigningPackage::with_adaptor`]; T is part of
+/// every binding factor, so all signers sign under the same T.
+///
+/// # Errors
+///
+/// Returns `InvalidCommitment` if the package has no adaptor point.
+pub fn sign_adaptor<P: OsstPoint>(
+    package: &SigningPackage<P>,
+    nonces: Nonces<P::Scalar>,
+    share: &SecretShare<P::Scalar>,
+    group_pubkey: &P,
+) -> Result<SignatureShare<P::Scalar>, OsstError> {
+    sign_adaptor_with::<Osst, P>(package, nonces, share, group_pubkey)
+}
+
+/// [`sign_adaptor`] under ciphersuite `C`.
+pub fn sign_adaptor_with<C: Ciphersuite<P>, P: OsstPoint>(
+    package: &SigningPackage<P>,
+    nonces: Nonces<P::Scalar>,
+    share: &SecretShare<P::Scalar>,
+    group_pubkey: &P,
+) -> Result<SignatureShare<P::Scalar>, OsstError> {
+    let session = Session::new::<C>(package, group_pubkey, true)?;
+    session.respond(share.index, nonces, core::slice::from_ref(share))
+}
+
+/// Aggregate adaptor signature shares into a [`PreSignature`].
+///
+/// `verifier_shares` works as in [`aggregate`].
+pub fn aggregate_adaptor<P: OsstPoint>(
+    package: &SigningPackage<P>,
+    shares: &[SignatureShare<P::Scalar>],
+    group_pubkey: &P,
+    verifier_shares: Option<&BTreeMap<u32, P>>,
+) -> Result<PreSignature<P>, OsstError> {
+    aggregate_adaptor_with::<Osst, P>(package, shares, group_pubkey, verifier_shares)
+}
+
+/// [`aggregate_adaptor`] under ciphersuite `C`.
+pub fn aggregate_adaptor_with<C: Ciphersuite<P>, P: OsstPoint>(
+    package: &SigningPackage<P>,
+    shares: &[SignatureShare<P::Scalar>],
+    group_pubkey: &P,
+    verifier_shares: Option<&BTreeMap<u32, P>>,
+) -> Result<PreSignature<P>, OsstError> {
+    let session = Session::new::<C>(package, group_pubkey, true)?;
+    let z = session.combine(package, shares, verifier_shares)?;
+
+    Ok(PreSignature {
+        r: session.group_commitment,
+        adaptor: package.adaptor.clone().ok_or(OsstError::InvalidCommitment)?,
+        z,
+    })
+}
+
+/// Verify a pre-signature: g^ẑ == R + H(R + T, Y, m) · Y
+///
+/// A valid pre-signature guarantees that adding log_g(T) yields a valid
+/// signature, so a counterparty can lock funds against T before it learns t.
+pub fn verify_presignature<P: OsstPoint>(
+    group_pubkey: &P,
+    message: &[u8],
+    presignature: &PreSignature<P>,
+) -> bool {
+    verify_presignature_with::<Osst, P>(group_pubkey, message, presignature)
+}
+
+/// [`verify_presignature`] under ciphersuite `C`.
+pub fn verify_presignature_with<C: Ciphersuite<P>, P: OsstPoint>(
+    group_pubkey: &P,
+    message: &[u8],
+    presignature: &PreSignature<P>,
+) -> bool {
+    let nonce = presignature.r.add(&presignature.adaptor);
+    let challenge = C::challenge(&nonce, group_pubkey, message);
+
+    let lhs = P::generator().mul_scalar(&presignature.z);
+    let rhs = presignature.r.add(&group_pubkey.mul_scalar(&challenge));
+
+    lhs == rhs
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -886,6 +1679,346 @@ mod tests {
             SigningPackage::<RistrettoPoint>::new(b"test".to_vec(), vec![c1, c2]);
         assert!(matches!(result, Err(OsstError::DuplicateIndex(1))));
     }
+
+    /// Run one adaptor session with signers 1..=t
+    fn presign(
+        shares: &[SecretShare<Scalar>],
+        group_pubkey: &RistrettoPoint,
+        adaptor: &RistrettoPoint,
+        message: &[u8],
+    ) -> PreSignature<RistrettoPoint> {
+        let mut rng = OsRng;
+        let mut nonces_vec = Vec::new();
+        let mut commitments_vec = Vec::new();
+        for s in shares {
+            let (n, c) = commit::<RistrettoPoint, _>(s.index, &mut rng);
+            nonces_vec.push(n);
+            commitments_vec.push(c);
+        }
+        let package =
+            SigningPackage::with_adaptor(message.to_vec(), commitments_vec, *adaptor).unwrap();
+
+        let vshares: BTreeMap<u32, RistrettoPoint> =
+            shares.iter().map(|s| (s.index, public_share(s))).collect();
+        let sig_shares: Vec<_> = shares
+            .iter()
+            .zip(nonces_vec)
+            .map(|(s, n)| sign_adaptor(&package, n, s, group_pubkey).unwrap())
+            .collect();
+        aggregate_adaptor(&package, &sig_shares, group_pubkey, Some(&vshares)).unwrap()
+    }
+
+    #[test]
+    fn test_adaptor_complete_and_extract() {
+        let mut rng = OsRng;
+        let secret = Scalar::random(&mut rng);
+        let group_pubkey = RistrettoPoint::generator().mul_scalar(&secret);
+        let shares = shamir_split(&secret, 5, 3);
+        let message = b"payout: seat 2 wins the pot";
+
+        let t = Scalar::random(&mut rng);
+        let adaptor = RistrettoPoint::generator().mul_scalar(&t);
+
+        let presig = presign(&shares[..3], &group_pubkey, &adaptor, message);
+        assert!(verify_presignature(&group_pubkey, message, &presig));
+        assert!(!verify_presignature(&group_pubkey, b"other payout", &presig));
+
+        // the pre-signature alone is not a signature
+        let unadapted = Signature {
+            r: presig.r,
+            z: presig.z,
+        };
+        assert!(!verify_signature(&group_pubkey, message, &unadapted));
+
+        let signature = presig.complete(&t).unwrap();
+        assert!(verify_signature(&group_pubkey, message, &signature));
+        assert_eq!(presig.extract(&signature), Some(t));
+
+        let decoded = PreSignature::<RistrettoPoint>::from_bytes_slice(&presig.to_bytes_vec()).unwrap();
+        assert!(verify_presignature(&group_pubkey, message, &decoded));
+    }
+
+    #[test]
+    fn test_adaptor_rejects_wrong_secret() {
+        let mut rng = OsRng;
+        let secret = Scalar::random(&mut rng);
+        let group_pubkey = RistrettoPoint::generator().mul_scalar(&secret);
+        let shares = shamir_split(&secret, 3, 2);
+
+        let t = Scalar::random(&mut rng);
+        let adaptor = RistrettoPoint::generator().mul_scalar(&t);
+        let presig = presign(&shares[..2], &group_pubkey, &adaptor, b"swap");
+
+        assert!(matches!(
+            presig.complete(&Scalar::random(&mut rng)),
+            Err(OsstError::InvalidResponse)
+        ));
+
+        // a signature for an unrelated session reveals nothing
+        let other = presign(&shares[..2], &group_pubkey, &adaptor, b"swap")
+            .complete(&t)
+            .unwrap();
+        assert_eq!(presig.extract(&other), None);
+    }
+
+    #[test]
+    fn test_adaptor_share_under_wrong_point_detected() {
+        let mut rng = OsRng;
+        let secret = Scalar::random(&mut rng);
+        let group_pubkey = RistrettoPoint::generator().mul_scalar(&secret);
+        let shares = shamir_split(&secret, 3, 2);
+        let adaptor = RistrettoPoint::generator().mul_scalar(&Scalar::random(&mut rng));
+        let other = RistrettoPoint::generator().mul_scalar(&Scalar::random(&mut rng));
+
+        let mut nonces_vec = Vec::new();
+        let mut commitments_vec = Vec::new();
+        for s in &shares[..2] {
+            let (n, c) = commit::<RistrettoPoint, _>(s.index, &mut rng);
+            nonces_vec.push(n);
+            commitments_vec.push(c);
+        }
+        let package =
+            SigningPackage::with_adaptor(b"swap".to_vec(), commitments_vec.clone(), adaptor).unwrap();
+        // same commitments relayed under another adaptor point
+        let swapped = SigningPackage::with_adaptor(b"swap".to_vec(), commitments_vec, other).unwrap();
+        let mut nonces_iter = nonces_vec.into_iter();
+        let sig_shares = vec![
+            sign_adaptor(&package, nonces_iter.next().unwrap(), &shares[0], &group_pubkey).unwrap(),
+            sign_adaptor(&swapped, nonces_iter.next().unwrap(), &shares[1], &group_pubkey).unwrap(),
+        ];
+
+        let vshares: BTreeMap<u32, RistrettoPoint> =
+            shares.iter().map(|s| (s.index, public_share(s))).collect();
+        let result = aggregate_adaptor(&package, &sig_shares, &group_pubkey, Some(&vshares));
+        assert!(matches!(result, Err(OsstError::InvalidResponse)));
+    }
+
+    #[test]
+    fn test_adaptor_point_binds_nonces() {
+        let mut rng = OsRng;
+        let secret = Scalar::random(&mut rng);
+        let group_pubkey = RistrettoPoint::generator().mul_scalar(&secret);
+        let adaptor = RistrettoPoint::generator().mul_scalar(&Scalar::random(&mut rng));
+        let other = RistrettoPoint::generator().mul_scalar(&Scalar::random(&mut rng));
+
+        let commitments: Vec<_> = (1..=2)
+            .map(|i| commit::<RistrettoPoint, _>(i, &mut rng).1)
+            .collect();
+        let plain = SigningPackage::new(b"swap".to_vec(), commitments.clone()).unwrap();
+        let under_t = SigningPackage::with_adaptor(b"swap".to_vec(), commitments.clone(), adaptor)
+            .unwrap();
+        let under_other =
+            SigningPackage::with_adaptor(b"swap".to_vec(), commitments, other).unwrap();
+
+        // ρ_i and therefore R depend on T
+        let rho = under_t.binding_factors::<Osst>(&group_pubkey);
+        assert_ne!(rho, plain.binding_factors::<Osst>(&group_pubkey));
+        assert_ne!(rho, under_other.binding_factors::<Osst>(&group_pubkey));
+        assert_eq!(under_t.adaptor(), Some(&adaptor));
+
+        assert!(matches!(
+            SigningPackage::with_adaptor(b"swap".to_vec(), vec![], <RistrettoPoint as OsstPoint>::identity()),
+            Err(OsstError::InvalidCommitment)
+        ));
+
+        // plain and adaptor entry points refuse each other's packages
+        let share = SecretShare::new(1, secret);
+        let (nonces, _) = commit::<RistrettoPoint, _>(1, &mut rng);
+        assert!(matches!(
+            sign(&under_t, nonces, &share, &group_pubkey),
+            Err(OsstError::InvalidCommitment)
+        ));
+        let (nonces, _) = commit::<RistrettoPoint, _>(1, &mut rng);
+        assert!(matches!(
+            sign_adaptor(&plain, nonces, &share, &group_pubkey),
+            Err(OsstError::InvalidCommitment)
+        ));
+    }
+
+    fn scalar(hex_str: &str) -> Scalar {
+        let bytes: [u8; 32] = hex::decode(hex_str).unwrap().try_into().unwrap();
+        Scalar::from_canonical_bytes(bytes).unwrap()
+    }
+
+    fn bytes32(hex_str: &str) -> [u8; 32] {
+        hex::decode(hex_str).unwrap().try_into().unwrap()
+    }
+
+    /// RFC 9591 Appendix E.2: FROST(ristretto255, SHA-512), signers 1 and 3
+    #[test]
+    fn test_rfc9591_ristretto255_vector() {
+        let secret = scalar("1b25a55e463cfd15cf14a5d3acc3d15053f08da49c8afcf3ab265f2ebc4f970b");
+        let group_pubkey = RistrettoPoint::generator().mul_scalar(&secret);
+        assert_eq!(
+            hex::encode(group_pubkey.compress_vec()),
+            "e2a62f39eede11269e3bd5a7d97554f5ca384f9f6d3dd9c3c0d05083c7254f57"
+        );
+
+        let s1 = SecretShare::new(
+            1,
+            scalar("5c3430d391552f6e60ecdc093ff9f6f4488756aa6cebdbad75a768010b8f830e"),
+        );
+        let s3 = SecretShare::new(
+            3,
+            scalar("f17e505f0e2581c6acfe54d3846a622834b5e7b50cad9a2109a97ba7a80d5c04"),
+        );
+
+        let (n1, c1) = commit_from_randomness::<Ristretto255Sha512, RistrettoPoint>(
+            &s1,
+            &bytes32("f595a133b4d95c6e1f79887220c8b275ce6277e7f68a6640e1e7140f9be2fb5c"),
+            &bytes32("34dd1001360e3513cb37bebfabe7be4a32c5bb91ba19fbd4360d039111f0fbdc"),
+        );
+        let (n3, c3) = commit_from_randomness::<Ristretto255Sha512, RistrettoPoint>(
+            &s3,
+            &bytes32("daa0cf42a32617786d390e0c7edfbf2efbd428037069357b5173ae61d6dd5d5e"),
+            &bytes32("b4387e72b2e4108ce4168931cc2c7fcce5f345a5297368952c18b5fc8473f050"),
+        );
+        assert_eq!(
+            hex::encode(c1.hiding.compress_vec()),
+            "965def4d0958398391fc06d8c2d72932608b1e6255226de4fb8d972dac15fd57"
+        );
+        assert_eq!(
+            hex::encode(c1.binding.compress_vec()),
+            "ec5170920660820007ae9e1d363936659ef622f99879898db86e5bf1d5bf2a14"
+        );
+        assert_eq!(
+            hex::encode(c3.hiding.compress_vec()),
+            "480e06e3de182bf83489c45d7441879932fd7b434a26af41455756264fbd5d6e"
+        );
+        assert_eq!(
+            hex::encode(c3.binding.compress_vec()),
+            "3064746dfd3c1862ef58fc68c706da287dd925066865ceacc816b3a28c7b363b"
+        );
+
+        let package = SigningPackage::new(b"test".to_vec(), vec![c1, c3]).unwrap();
+        let z1 = sign_with::<Ristretto255Sha512, _>(&package, n1, &s1, &group_pubkey).unwrap();
+        let z3 = sign_with::<Ristretto255Sha512, _>(&package, n3, &s3, &group_pubkey).unwrap();
+        assert_eq!(
+            hex::encode(z1.response.to_bytes()),
+            "9285f875923ce7e0c491a592e9ea1865ec1b823ead4854b48c8a46287749ee09"
+        );
+        assert_eq!(
+            hex::encode(z3.response.to_bytes()),
+            "7cb211fe0e3d59d25db6e36b3fb32344794139602a7b24f1ae0dc4e26ad7b908"
+        );
+
+        let vshares: BTreeMap<u32, RistrettoPoint> =
+            [(1, public_share(&s1)), (3, public_share(&s3))].into_iter().collect();
+        let signature = aggregate_with::<Ristretto255Sha512, _>(
+            &package,
+            &[z1, z3],
+            &group_pubkey,
+            Some(&vshares),
+        )
+        .unwrap();
+        assert_eq!(
+            hex::encode(signature.to_bytes_vec()),
+            "fc45655fbc66bbffad654ea4ce5fdae253a49a64ace25d9adb62010dd9fb2555\
+             2164141787162e5b4cab915b4aa45d94655dbb9ed7c378a53b980a0be220a802"
+        );
+        assert!(verify_signature_with::<Ristretto255Sha512, _>(
+            &group_pubkey,
+            b"test",
+            &signature
+        ));
+    }
+
+    #[test]
+    fn test_ciphersuites_are_domain_separated() {
+        let mut rng = OsRng;
+        let secret = Scalar::random(&mut rng);
+        let group_pubkey = RistrettoPoint::generator().mul_scalar(&secret);
+        let shares = shamir_split(&secret, 3, 2);
+        let message = b"interop";
+
+        let mut nonces = Vec::new();
+        let mut comms = Vec::new();
+        for s in &shares[..2] {
+            let (n, c) = commit_with::<Ristretto255Sha512, RistrettoPoint, _>(s, &mut rng);
+            nonces.push(n);
+            comms.push(c);
+        }
+        let package = SigningPackage::new(message.to_vec(), comms).unwrap();
+        let sig_shares: Vec<_> = shares[..2]
+            .iter()
+            .zip(nonces)
+            .map(|(s, n)| sign_with::<Ristretto255Sha512, _>(&package, n, s, &group_pubkey).unwrap())
+            .collect();
+
+        // a share for one ciphersuite is not valid under the other
+        assert!(verify_share_with::<Ristretto255Sha512, _>(
+            &package,
+            &sig_shares[0],
+            &group_pubkey,
+            &public_share(&shares[0])
+        )
+        .unwrap());
+        assert!(!verify_share(&package, &sig_shares[0], &group_pubkey, &public_share(&shares[0])).unwrap());
+
+        let signature =
+            aggregate_with::<Ristretto255Sha512, _>(&package, &sig_shares, &group_pubkey, None).unwrap();
+        assert!(verify_signature_with::<Ristretto255Sha512, _>(&group_pubkey, message, &signature));
+        assert!(!verify_signature(&group_pubkey, message, &signature));
+    }
+
+    #[test]
+    fn test_weighted_frost() {
+        let mut rng = OsRng;
+        let secret = Scalar::random(&mut rng);
+        let group_pubkey = RistrettoPoint::generator().mul_scalar(&secret);
+
+        // weights 3, 1, 2 over one 4-of-6 sharing
+        let table = WeightTable::new(&[3, 1, 2]);
+        let mut shares = shamir_split(&secret, 6, 4).into_iter();
+        let parties: Vec<WeightedShare<Scalar>> = (1..=3)
+            .map(|p| {
+                let owned = shares.by_ref().take(table.weight(p).unwrap() as usize).collect();
+                WeightedShare::new(p, &table, owned).unwrap()
+            })
+            .collect();
+        let verifier_shares: BTreeMap<u32, RistrettoPoint> = parties
+            .iter()
+            .flat_map(|p| p.public_shares::<RistrettoPoint>())
+            .collect();
+        let message = b"weighted spend";
+
+        // parties 1 and 3 carry weight 5: two commitments, two shares
+        let signers = [&parties[0], &parties[2]];
+        let mut nonces = Vec::new();
+        let mut comms = Vec::new();
+        for p in signers {
+            let (n, c) = commit::<RistrettoPoint, _>(p.party, &mut rng);
+            nonces.push(n);
+            comms.push(c);
+        }
+        let package = SigningPackage::new(message.to_vec(), comms).unwrap();
+
+        let mut sig_shares: Vec<_> = signers
+            .iter()
+            .zip(nonces)
+            .map(|(p, n)| sign_weighted(&package, n, p, &group_pubkey, &table).unwrap())
+            .collect();
+
+        let signature =
+            aggregate_weighted(&package, &sig_shares, &group_pubkey, &table, Some(&verifier_shares))
+                .unwrap();
+        assert!(verify_signature(&group_pubkey, message, &signature));
+
+        // a tampered share is caught against the party's summed key
+        sig_shares[1].response += Scalar::ONE;
+        assert!(matches!(
+            aggregate_weighted(&package, &sig_shares, &group_pubkey, &table, Some(&verifier_shares)),
+            Err(OsstError::InvalidResponse)
+        ));
+
+        // a party signing with someone else's shares is rejected
+        let (n, c) = commit::<RistrettoPoint, _>(2, &mut rng);
+        let package = SigningPackage::new(message.to_vec(), vec![c]).unwrap();
+        assert!(matches!(
+            sign_weighted(&package, n, &parties[0], &group_pubkey, &table),
+            Err(OsstError::InvalidIndex)
+        ));
+    }
 }
 
 #[cfg(all(test, feature = "pallas"))]
@@ -1032,4 +2165,155 @@ mod pallas_tests {
             "DKG + FROST should produce valid signature"
         );
     }
+
+    #[test]
+    fn test_pallas_adaptor() {
+        let mut rng = OsRng;
+        let secret = <Scalar as Field>::random(&mut rng);
+        let group_key = Point::generator().mul_scalar(&secret);
+        let shares = shamir_split(&secret, 3, 2);
+        let message = b"escrow payout";
+
+        let t = <Scalar as Field>::random(&mut rng);
+        let adaptor = Point::generator().mul_scalar(&t);
+
+        let mut nonces_vec = Vec::new();
+        let mut commitments_vec = Vec::new();
+        for s in &shares[..2] {
+            let (n, c) = commit::<Point, _>(s.index, &mut rng);
+            nonces_vec.push(n);
+            commitments_vec.push(c);
+        }
+        let package =
+            SigningPackage::with_adaptor(message.to_vec(), commitments_vec, adaptor).unwrap();
+        let sig_shares: Vec<_> = shares[..2]
+            .iter()
+            .zip(nonces_vec)
+            .map(|(s, n)| sign_adaptor(&package, n, s, &group_key).unwrap())
+            .collect();
+        let presig = aggregate_adaptor(&package, &sig_shares, &group_key, None).unwrap();
+
+        assert!(verify_presignature(&group_key, message, &presig));
+        let signature = presig.complete(&t).unwrap();
+        assert!(verify_signature(&group_key, message, &signature));
+        assert_eq!(presig.extract(&signature), Some(t));
+    }
+}
+
+#[cfg(all(test, feature = "secp256k1"))]
+mod secp256k1_tests {
+    use super::*;
+    use k256::{ProjectivePoint, Scalar};
+
+    fn scalar(hex_str: &str) -> Scalar {
+        let bytes: [u8; 32] = hex::decode(hex_str).unwrap().try_into().unwrap();
+        Scalar::from_canonical_bytes(&bytes).unwrap()
+    }
+
+    fn bytes32(hex_str: &str) -> [u8; 32] {
+        hex::decode(hex_str).unwrap().try_into().unwrap()
+    }
+
+    /// RFC 9591 Appendix E.5: FROST(secp256k1, SHA-256), signers 1 and 3
+    #[test]
+    fn test_rfc9591_secp256k1_vector() {
+        let secret = scalar("0d004150d27c3bf2a42f312683d35fac7394b1e9e318249c1bfe7f0795a83114");
+        let group_pubkey = ProjectivePoint::GENERATOR.mul_scalar(&secret);
+        assert_eq!(
+            hex::encode(group_pubkey.compress_vec()),
+            "02f37c34b66ced1fb51c34a90bdae006901f10625cc06c4f64663b0eae87d87b4f"
+        );
+
+        let s1 = SecretShare::new(
+            1,
+            scalar("08f89ffe80ac94dcb920c26f3f46140bfc7f95b493f8310f5fc1ea2b01f4254c"),
+        );
+        let s3 = SecretShare::new(
+            3,
+            scalar("00e95d59dd0d46b0e303e500b62b7ccb0e555d49f5b849f5e748c071da8c0dbc"),
+        );
+
+        let (n1, c1) = commit_from_randomness::<Secp256k1Sha256, ProjectivePoint>(
+            &s1,
+            &bytes32("7ea5ed09af19f6ff21040c07ec2d2adbd35b759da5a401d4c99dd26b82391cb2"),
+            &bytes32("47acab018f116020c10cb9b9abdc7ac10aae1b48ca6e36dc15acb6ec9be5cdc5"),
+        );
+        let (n3, c3) = commit_from_randomness::<Secp256k1Sha256, ProjectivePoint>(
+            &s3,
+            &bytes32("e6cc56ccbd0502b3f6f831d91e2ebd01c4de0479e0191b66895a4ffd9b68d544"),
+            &bytes32("7203d55eb82a5ca0d7d83674541ab55f6e76f1b85391d2c13706a89a064fd5b9"),
+        );
+        assert_eq!(
+            hex::encode(c1.hiding.compress_vec()),
+            "03c699af97d26bb4d3f05232ec5e1938c12f1e6ae97643c8f8f11c9820303f1904"
+        );
+        assert_eq!(
+            hex::encode(c1.binding.compress_vec()),
+            "02fa2aaccd51b948c9dc1a325d77226e98a5a3fe65fe9ba213761a60123040a45e"
+        );
+        assert_eq!(
+            hex::encode(c3.hiding.compress_vec()),
+            "03077507ba327fc074d2793955ef3410ee3f03b82b4cdc2370f71d865beb926ef6"
+        );
+        assert_eq!(
+            hex::encode(c3.binding.compress_vec()),
+            "02ad53031ddfbbacfc5fbda3d3b0c2445c8e3e99cbc4ca2db2aa283fa68525b135"
+        );
+
+        let package = SigningPackage::new(b"test".to_vec(), vec![c1, c3]).unwrap();
+        let z1 = sign_with::<Secp256k1Sha256, _>(&package, n1, &s1, &group_pubkey).unwrap();
+        let z3 = sign_with::<Secp256k1Sha256, _>(&package, n3, &s3, &group_pubkey).unwrap();
+        assert_eq!(
+            hex::encode(z1.response.to_bytes()),
+            "c4fce1775a1e141fb579944166eab0d65eefe7b98d480a569bbbfcb14f91c197"
+        );
+        assert_eq!(
+            hex::encode(z3.response.to_bytes()),
+            "0160fd0d388932f4826d2ebcd6b9eaba734f7c71cf25b4279a4ca2581e47b18d"
+        );
+
+        let signature =
+            aggregate_with::<Secp256k1Sha256, _>(&package, &[z1, z3], &group_pubkey, None).unwrap();
+        let encoded = signature.to_bytes_vec();
+        assert_eq!(
+            hex::encode(&encoded),
+            "0205b6d04d3774c8929413e3c76024d54149c372d57aae62574ed74319b5ea14d0\
+             c65dde8492a7471437e6c2fe3da49b90d23f642b5c6dbe7e36089f096dd97324"
+        );
+
+        let decoded = Signature::<ProjectivePoint>::from_bytes_slice(&encoded).unwrap();
+        assert!(verify_signature_with::<Secp256k1Sha256, _>(&group_pubkey, b"test", &decoded));
+        assert!(!verify_signature_with::<Secp256k1Sha256, _>(&group_pubkey, b"tesT", &decoded));
+    }
+
+    /// Adaptor signing over the RFC 9591 ciphersuite; the completed
+    /// signature must keep R's y parity, so it is sent with `to_bytes_vec`
+    #[test]
+    fn test_secp256k1_adaptor() {
+        use rand::rngs::OsRng;
+        let mut rng = OsRng;
+        let secret = <Scalar as OsstScalar>::random(&mut rng);
+        let group_pubkey = ProjectivePoint::GENERATOR.mul_scalar(&secret);
+        let s1 = SecretShare::new(1, secret.add(&Scalar::from_u32(7)));
+        let s2 = SecretShare::new(2, secret.add(&Scalar::from_u32(14)));
+        let message = b"atomic swap leg";
+
+        let t = <Scalar as OsstScalar>::random(&mut rng);
+        let adaptor = ProjectivePoint::GENERATOR.mul_scalar(&t);
+
+        let (n1, c1) = commit_with::<Secp256k1Sha256, ProjectivePoint, _>(&s1, &mut rng);
+        let (n2, c2) = commit_with::<Secp256k1Sha256, ProjectivePoint, _>(&s2, &mut rng);
+        let package = SigningPackage::with_adaptor(message.to_vec(), vec![c1, c2], adaptor).unwrap();
+        let z1 = sign_adaptor_with::<Secp256k1Sha256, _>(&package, n1, &s1, &group_pubkey).unwrap();
+        let z2 = sign_adaptor_with::<Secp256k1Sha256, _>(&package, n2, &s2, &group_pubkey).unwrap();
+        let presig =
+            aggregate_adaptor_with::<Secp256k1Sha256, _>(&package, &[z1, z2], &group_pubkey, None)
+                .unwrap();
+
+        assert!(verify_presignature_with::<Secp256k1Sha256, _>(&group_pubkey, message, &presig));
+        let signature = presig.complete(&t).unwrap();
+        let decoded = Signature::<ProjectivePoint>::from_bytes_slice(&signature.to_bytes_vec()).unwrap();
+        assert!(verify_signature_with::<Secp256k1Sha256, _>(&group_pubkey, message, &decoded));
+        assert_eq!(presig.extract(&decoded), Some(t));
+    }
 }
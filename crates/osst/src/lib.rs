@@ -42,7 +42,9 @@ use alloc::vec;
 use alloc::vec::Vec;
 use sha2::{Digest, Sha512};
 
+pub mod bip340;
 pub mod curve;
+pub mod decrypt;
 pub mod dkg;
 mod error;
 pub mod frost;
@@ -51,7 +53,9 @@ pub mod liveness;
 pub mod nested;
 pub mod redpallas;
 pub mod reshare;
+pub mod roast;
 mod types;
+pub mod weighted;
 
 pub use curve::{OsstCurve, OsstPoint, OsstScalar};
 pub use error::OsstError;
@@ -16,8 +16,32 @@
 //!
 //! # Integration with Ligerito
 //!
-//! Uses the existing `ligerito::verify_sha256()` or `verify_blake2b()`
-//! to verify that a custodian correctly processed a recent block.
+//! With the `ligerito` feature, [`LigeritoVerifier`] implements
+//! [`LivenessVerifier`] and [`LigeritoProver`] (feature `ligerito-prover`)
+//! produces the matching [`LivenessProof`]. The proven trace opens with the
+//! statement
+//!
+//! ```text
+//! "osst-liveness-v1" || anchor (48 bytes) || state_root (32 bytes)
+//! ```
+//!
+//! as 24 little-endian words, followed by the custodian's
+//! block-verification trace. The proof opens the first message row of the
+//! commitment (an evaluation claim whose column coordinates are all zero),
+//! and the verifier rebuilds that row's statement words itself, so a trace
+//! committed for any other anchor or root is rejected. The same statement
+//! seeds the Fiat-Shamir transcript, making every challenge and query
+//! depend on the checkpoint as well. The verifier also requires the anchor
+//! to be one it knows, so a custodian cannot attest to a block hash the
+//! chain never had.
+//!
+//! This binds a committed trace to the anchor and state root, and nothing
+//! more: no constraint covers the words after the statement, so the proof
+//! does not show that any block was verified. Anyone who knows a public
+//! checkpoint can produce an accepted proof with an all-zero trace. Until a
+//! block-verification circuit constrains the trace, treat a Ligerito
+//! liveness proof as a signed-off claim about (anchor, state_root), not as
+//! evidence that the custodian did the work.
 
 use alloc::vec::Vec;
 
@@ -72,7 +96,9 @@ impl CheckpointAnchor {
 
 /// Proof that custodian verified a checkpoint block
 ///
-/// Contains a Ligerito proof of correct block verification.
+/// Contains a Ligerito proof over the custodian's block-verification trace.
+/// What it establishes is up to the [`LivenessVerifier`];
+/// [`LigeritoVerifier`] binds only the checkpoint and state root.
 #[derive(Clone, Debug)]
 pub struct LivenessProof {
     /// The checkpoint being attested
@@ -338,6 +364,254 @@ pub trait LivenessVerifier {
     fn max_checkpoint_age(&self) -> u64;
 }
 
+// ============================================================================
+// Ligerito Backend
+// ============================================================================
+
+/// Domain tag opening the statement
+#[cfg(feature = "ligerito")]
+const LIVENESS_DOMAIN: &[u8; 16] = b"osst-liveness-v1";
+
+/// Trace words taken by the statement: domain tag, anchor and state root
+#[cfg(feature = "ligerito")]
+const STATEMENT_WORDS: usize = 24;
+
+/// Statement words the trace must open with
+#[cfg(feature = "ligerito")]
+fn liveness_statement(
+    anchor: &CheckpointAnchor,
+    state_root: &[u8; 32],
+) -> Vec<binary_fields::BinaryElem32> {
+    use binary_fields::BinaryElem32;
+
+    let mut bytes = [0u8; 4 * STATEMENT_WORDS];
+    bytes[0..16].copy_from_slice(LIVENESS_DOMAIN);
+    bytes[16..64].copy_from_slice(&anchor.to_bytes());
+    bytes[64..96].copy_from_slice(state_root);
+
+    bytes
+        .chunks_exact(4)
+        .map(|w| BinaryElem32::from(u32::from_le_bytes(w.try_into().unwrap())))
+        .collect()
+}
+
+/// Transcript seeded with the statement
+#[cfg(feature = "ligerito")]
+fn liveness_transcript(
+    anchor: &CheckpointAnchor,
+    state_root: &[u8; 32],
+) -> ligerito::FiatShamir {
+    use ligerito::transcript::Transcript;
+
+    let mut fs = ligerito::FiatShamir::new_merlin();
+    fs.absorb_elems(&liveness_statement(anchor, state_root));
+    fs
+}
+
+/// Evaluation point whose row combination is the first message row
+///
+/// With every column coordinate zero, yr is trace words 0..m, which the
+/// verifier checks against the commitment's opened rows.
+#[cfg(feature = "ligerito")]
+fn first_row_point(num_vars: usize) -> Vec<binary_fields::BinaryElem128> {
+    use binary_fields::{BinaryElem128, BinaryFieldElement};
+
+    alloc::vec![BinaryElem128::zero(); num_vars]
+}
+
+/// [`LivenessVerifier`] that checks Ligerito proofs against known checkpoints
+///
+/// Holds the checkpoints the chain has finalized within the last
+/// `max_age` blocks; the most recent one is the current anchor. A proof is
+/// accepted whatever trace follows the statement: it binds the anchor and
+/// state root but proves no block verification (see the module docs).
+#[cfg(feature = "ligerito")]
+#[derive(Clone, Debug)]
+pub struct LigeritoVerifier {
+    config: ligerito::VerifierConfig,
+    /// Known checkpoints, ascending height
+    anchors: Vec<CheckpointAnchor>,
+    max_age: u64,
+}
+
+#[cfg(feature = "ligerito")]
+impl LigeritoVerifier {
+    /// Verifier for 2^12-word traces (see [`LigeritoProver::new`])
+    pub fn new(anchor: CheckpointAnchor, max_age: u64) -> Self {
+        Self::with_config(ligerito::hardcoded_config_12_verifier(), anchor, max_age)
+    }
+
+    /// Verifier for traces proven under a matching prover config
+    pub fn with_config(
+        config: ligerito::VerifierConfig,
+        anchor: CheckpointAnchor,
+        max_age: u64,
+    ) -> Self {
+        Self {
+            config,
+            anchors: alloc::vec![anchor],
+            max_age,
+        }
+    }
+
+    /// Record a newly finalized checkpoint and forget those older than
+    /// `max_age` blocks. Anchors not above the current height are ignored.
+    pub fn push_anchor(&mut self, anchor: CheckpointAnchor) {
+        if anchor.height <= self.current().height {
+            return;
+        }
+        let (height, max_age) = (anchor.height, self.max_age);
+        self.anchors.push(anchor);
+        self.anchors.retain(|a| a.is_recent(height, max_age));
+    }
+
+    /// True if `anchor` is a known checkpoint
+    pub fn is_known(&self, anchor: &CheckpointAnchor) -> bool {
+        self.anchors.iter().any(|a| a == anchor)
+    }
+
+    fn current(&self) -> &CheckpointAnchor {
+        self.anchors.last().expect("at least one anchor")
+    }
+}
+
+#[cfg(feature = "ligerito")]
+impl LivenessVerifier for LigeritoVerifier {
+    fn verify_ligerito_proof(
+        &self,
+        anchor: &CheckpointAnchor,
+        proof: &[u8],
+        state_root: &[u8; 32],
+    ) -> bool {
+        use binary_fields::{BinaryElem128, BinaryElem32, BinaryFieldElement};
+
+        if !self.is_known(anchor) {
+            return false;
+        }
+
+        // [first row after the statement: (m - 24) words][ligerito proof]
+        let m = 1usize << self.config.initial_dim;
+        let row_len = match m.checked_sub(STATEMENT_WORDS) {
+            Some(words) if proof.len() >= 4 * words => 4 * words,
+            _ => return false,
+        };
+        let (row, proof) = proof.split_at(row_len);
+
+        let proof = match ligerito::FinalizedLigeritoProof::<BinaryElem32, BinaryElem128>::from_bytes_for_config(
+            proof,
+            &self.config,
+        ) {
+            Ok(proof) => proof,
+            Err(_) => return false,
+        };
+
+        let yr: Vec<BinaryElem128> = liveness_statement(anchor, state_root)
+            .into_iter()
+            .chain(row.chunks_exact(4).map(BinaryElem32::read_bytes))
+            .map(BinaryElem128::from)
+            .collect();
+        let value = yr[0];
+        let evaluation = ligerito::EvaluationProof { yr, proof };
+
+        let point = first_row_point(self.config.initial_dim + self.config.initial_k);
+        let fs = liveness_transcript(anchor, state_root);
+        matches!(
+            ligerito::verify_evaluation_with_transcript(&self.config, &point, value, &evaluation, fs),
+            Ok(true)
+        )
+    }
+
+    fn current_anchor(&self) -> CheckpointAnchor {
+        self.current().clone()
+    }
+
+    fn max_checkpoint_age(&self) -> u64 {
+        self.max_age
+    }
+}
+
+/// Custodian-side prover for [`LigeritoVerifier`]
+///
+/// The trace is committed but not checked, so any trace, including none,
+/// yields an accepted proof for a known anchor.
+#[cfg(feature = "ligerito-prover")]
+pub struct LigeritoProver {
+    config: ligerito::ProverConfig<binary_fields::BinaryElem32, binary_fields::BinaryElem128>,
+}
+
+#[cfg(feature = "ligerito-prover")]
+impl LigeritoProver {
+    /// Prover for traces of up to 2^12 words
+    pub fn new() -> Self {
+        Self::with_config(ligerito::hardcoded_config_12(
+            core::marker::PhantomData,
+            core::marker::PhantomData,
+        ))
+    }
+
+    /// Prover under a custom config; the verifier needs the matching one
+    pub fn with_config(
+        config: ligerito::ProverConfig<binary_fields::BinaryElem32, binary_fields::BinaryElem128>,
+    ) -> Self {
+        Self { config }
+    }
+
+    /// Number of trace words a proof covers, after the statement
+    pub fn trace_len(&self) -> usize {
+        self.config.initial_dims.0 * self.config.initial_dims.1 - STATEMENT_WORDS
+    }
+
+    /// Prove the block-verification `trace` for `anchor` and `state_root`
+    ///
+    /// The statement is prepended and the trace zero-padded to
+    /// [`trace_len`](Self::trace_len) words.
+    pub fn prove(
+        &self,
+        anchor: CheckpointAnchor,
+        state_root: [u8; 32],
+        trace: &[binary_fields::BinaryElem32],
+    ) -> ligerito::Result<LivenessProof> {
+        use binary_fields::{BinaryElem32, BinaryFieldElement};
+
+        let (m, cols) = self.config.initial_dims;
+        if m < STATEMENT_WORDS {
+            return Err(ligerito::LigeritoError::InvalidConfig(alloc::format!(
+                "first row of {} words cannot hold the statement",
+                m
+            )));
+        }
+        if trace.len() > self.trace_len() {
+            return Err(ligerito::LigeritoError::InvalidConfig(alloc::format!(
+                "trace of {} words exceeds {}",
+                trace.len(),
+                self.trace_len()
+            )));
+        }
+        let mut poly = liveness_statement(&anchor, &state_root);
+        poly.extend_from_slice(trace);
+        poly.resize(m * cols, BinaryElem32::zero());
+
+        let point = first_row_point((m * cols).trailing_zeros() as usize);
+        let fs = liveness_transcript(&anchor, &state_root);
+        let (_, evaluation) = ligerito::prove_evaluation_with_transcript(&self.config, &poly, &point, fs)?;
+
+        let mut bytes = alloc::vec![0u8; 4 * (m - STATEMENT_WORDS)];
+        for (word, out) in poly[STATEMENT_WORDS..m].iter().zip(bytes.chunks_exact_mut(4)) {
+            word.write_bytes(out);
+        }
+        bytes.extend(evaluation.proof.to_bytes().map_err(ligerito::LigeritoError::from)?);
+
+        Ok(LivenessProof::new(anchor, bytes, state_root))
+    }
+}
+
+#[cfg(feature = "ligerito-prover")]
+impl Default for LigeritoProver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Batch verifier for multiple contributions
 pub struct ContributionVerifier<'a, P: OsstPoint, V: LivenessVerifier> {
     verifier: &'a V,
@@ -547,3 +821,153 @@ mod tests {
         assert_eq!(recovered.state_root, [2u8; 32]);
     }
 }
+
+#[cfg(all(test, feature = "ligerito-prover", feature = "ristretto255"))]
+mod ligerito_tests {
+    use super::*;
+    use crate::reshare::Dealer;
+    use binary_fields::BinaryElem32;
+    use curve25519_dalek::{ristretto::RistrettoPoint, scalar::Scalar};
+    use rand::rngs::OsRng;
+
+    fn trace(seed: u32) -> Vec<BinaryElem32> {
+        (0..1000u32)
+            .map(|i| BinaryElem32::from(i.wrapping_mul(0x9e37_79b9) ^ seed))
+            .collect()
+    }
+
+    #[test]
+    fn test_ligerito_liveness_end_to_end() {
+        let mut rng = OsRng;
+        let anchor = CheckpointAnchor::new(100, [7u8; 32], 1_700_000_000);
+        let state_root = [3u8; 32];
+
+        let prover = LigeritoProver::new();
+        let verifier = LigeritoVerifier::new(anchor.clone(), 10);
+
+        let liveness = prover.prove(anchor.clone(), state_root, &trace(1)).unwrap();
+        assert!(verifier.verify_ligerito_proof(&anchor, &liveness.ligerito_proof, &state_root));
+
+        // survives the on-chain encoding
+        let decoded = LivenessProof::from_bytes(&liveness.to_bytes()).unwrap();
+        assert!(verifier.verify_ligerito_proof(
+            &decoded.anchor,
+            &decoded.ligerito_proof,
+            &decoded.state_root
+        ));
+
+        // full contribution path
+        let secret = Scalar::random(&mut rng);
+        let public: RistrettoPoint = RistrettoPoint::generator().mul_scalar(&secret);
+        let dealer: Dealer<RistrettoPoint> = Dealer::new(1, Scalar::random(&mut rng), 3, &mut rng);
+        let context = b"epoch-9";
+        let contribution =
+            DealerContribution::sign(dealer.commitment().clone(), liveness, &secret, context, &mut rng);
+
+        let cv = ContributionVerifier::<RistrettoPoint, _>::new(&verifier, context);
+        assert_eq!(cv.verify(&contribution, &public), Ok(()));
+    }
+
+    #[test]
+    fn test_ligerito_liveness_binds_anchor_and_root() {
+        let anchor = CheckpointAnchor::new(100, [7u8; 32], 0);
+        let state_root = [3u8; 32];
+
+        let prover = LigeritoProver::new();
+        let mut verifier = LigeritoVerifier::new(anchor.clone(), 10);
+        let liveness = prover.prove(anchor.clone(), state_root, &trace(2)).unwrap();
+        let proof = &liveness.ligerito_proof;
+
+        // another claimed state root
+        assert!(!verifier.verify_ligerito_proof(&anchor, proof, &[4u8; 32]));
+
+        // a later known checkpoint
+        let next = CheckpointAnchor::new(101, [8u8; 32], 0);
+        verifier.push_anchor(next.clone());
+        assert!(verifier.verify_ligerito_proof(&anchor, proof, &state_root));
+        assert!(!verifier.verify_ligerito_proof(&next, proof, &state_root));
+
+        // a forged block hash at a known height is not a known anchor
+        let forged = prover
+            .prove(CheckpointAnchor::new(101, [9u8; 32], 0), state_root, &trace(2))
+            .unwrap();
+        assert!(!verifier.verify_ligerito_proof(
+            &forged.anchor,
+            &forged.ligerito_proof,
+            &state_root
+        ));
+
+        // corrupted or truncated encodings
+        let mut corrupted = proof.clone();
+        let mid = corrupted.len() / 2;
+        corrupted[mid] ^= 1;
+        assert!(!verifier.verify_ligerito_proof(&anchor, &corrupted, &state_root));
+        assert!(!verifier.verify_ligerito_proof(&anchor, &proof[..proof.len() - 1], &state_root));
+        assert!(!verifier.verify_ligerito_proof(&anchor, &[], &state_root));
+
+        // oversize traces are refused by the prover
+        let too_long = vec![BinaryElem32::from(1u32); prover.trace_len() + 1];
+        assert!(prover.prove(anchor, state_root, &too_long).is_err());
+    }
+
+    #[test]
+    fn test_ligerito_liveness_binds_statement_only() {
+        use binary_fields::BinaryFieldElement;
+
+        let anchor = CheckpointAnchor::new(100, [7u8; 32], 0);
+        let state_root = [3u8; 32];
+        let prover = LigeritoProver::new();
+        let verifier = LigeritoVerifier::new(anchor.clone(), 10);
+        let (m, cols) = prover.config.initial_dims;
+
+        let encode = |poly: &[BinaryElem32], proof: &ligerito::FinalizedLigeritoProof<_, _>| {
+            let mut bytes = vec![0u8; 4 * (m - STATEMENT_WORDS)];
+            for (word, out) in poly[STATEMENT_WORDS..m].iter().zip(bytes.chunks_exact_mut(4)) {
+                word.write_bytes(out);
+            }
+            bytes.extend(proof.to_bytes().unwrap());
+            bytes
+        };
+
+        // a trace committed for another root fails even under this
+        // statement's transcript
+        let mut poly = liveness_statement(&anchor, &[4u8; 32]);
+        poly.extend(trace(3));
+        poly.resize(m * cols, BinaryElem32::zero());
+        let point = first_row_point((m * cols).trailing_zeros() as usize);
+        let fs = liveness_transcript(&anchor, &state_root);
+        let (_, evaluation) =
+            ligerito::prove_evaluation_with_transcript(&prover.config, &poly, &point, fs).unwrap();
+        let forged = encode(&poly, &evaluation.proof);
+        assert!(!verifier.verify_ligerito_proof(&anchor, &forged, &state_root));
+
+        // as does an honest proof whose opened row is edited
+        let liveness = prover.prove(anchor.clone(), state_root, &trace(3)).unwrap();
+        assert!(verifier.verify_ligerito_proof(&anchor, &liveness.ligerito_proof, &state_root));
+        let mut tampered = liveness.ligerito_proof.clone();
+        tampered[0] ^= 1;
+        assert!(!verifier.verify_ligerito_proof(&anchor, &tampered, &state_root));
+
+        // the trace itself is unconstrained: an empty one is accepted too
+        let empty = prover.prove(anchor.clone(), state_root, &[]).unwrap();
+        assert!(verifier.verify_ligerito_proof(&anchor, &empty.ligerito_proof, &state_root));
+    }
+
+    #[test]
+    fn test_ligerito_verifier_anchor_window() {
+        let mut verifier = LigeritoVerifier::new(CheckpointAnchor::new(100, [1u8; 32], 0), 10);
+
+        verifier.push_anchor(CheckpointAnchor::new(105, [2u8; 32], 0));
+        assert!(verifier.is_known(&CheckpointAnchor::new(100, [1u8; 32], 0)));
+        assert_eq!(verifier.current_anchor().height, 105);
+
+        // stale and out-of-order anchors are ignored
+        verifier.push_anchor(CheckpointAnchor::new(103, [3u8; 32], 0));
+        assert!(!verifier.is_known(&CheckpointAnchor::new(103, [3u8; 32], 0)));
+
+        // moving past max_age forgets the oldest checkpoint
+        verifier.push_anchor(CheckpointAnchor::new(111, [4u8; 32], 0));
+        assert!(!verifier.is_known(&CheckpointAnchor::new(100, [1u8; 32], 0)));
+        assert!(verifier.is_known(&CheckpointAnchor::new(105, [2u8; 32], 0)));
+    }
+}
@@ -35,12 +35,40 @@ pub mod zcash {
     use crate::curve::{OsstPoint, OsstScalar};
     use crate::error::OsstError;
     use crate::frost::{
-        self, Nonces, Signature, SignatureShare, SigningCommitments,
+        self, Nonces, PreSignature, Signature, SignatureShare, SigningCommitments,
     };
     use crate::lagrange::compute_lagrange_coefficients;
     use crate::SecretShare;
 
-    use pasta_curves::pallas::{Point, Scalar};
+    use pasta_curves::arithmetic::CurveAffine;
+    use pasta_curves::pallas::{Affine, Base, Point, Scalar};
+
+    // ========================================================================
+    // Spend authorization base point
+    // ========================================================================
+
+    /// G_SpendAuth = GroupHash("z.cash:Orchard", "G")
+    ///
+    /// The base point of Orchard spend authorization keys and signatures
+    /// (Zcash Protocol Spec §5.4.7.1), and what reddsa's `orchard::SpendAuth`
+    /// verifies against. It is not pasta's `Group::generator()`; the pallas
+    /// [`OsstPoint`] backend returns it as its generator so DKG, FROST and
+    /// this module all derive keys over the same base.
+    pub fn spend_auth_basepoint() -> Point {
+        const X: Base = Base::from_raw([
+            0x8d1a_7284_b875_c963,
+            0x0c7f_0ce3_7b70_a10c,
+            0x3b8d_187c_3e5f_445f,
+            0x3755_23b3_28f1_d606,
+        ]);
+        const Y: Base = Base::from_raw([
+            0x4ce3_3e81_7b0c_3bc9,
+            0xdfc9_14fe_c005_bdd8,
+            0x7b10_bcfc_fed6_24fb,
+            0x1ad0_357f_df1a_66db,
+        ]);
+        Affine::from_xy(X, Y).unwrap().into()
+    }
 
     // ========================================================================
     // RedPallas hash functions
@@ -72,11 +100,14 @@ pub mod zcash {
     /// Binding factor for RedPallas FROST
     ///
     /// Uses BLAKE2b-512 with "FROST_RedPallas_" personalization for
-    /// Zcash protocol compliance.
+    /// Zcash protocol compliance. `context` carries whatever else the
+    /// signature depends on (the adaptor point or the randomizer α), so the
+    /// nonces commit to it.
     fn redpallas_binding_factor(
         index: u32,
         message: &[u8],
         encoded_commitments: &[u8],
+        context: &[u8],
     ) -> Scalar {
         let h = blake2b_simd::Params::new()
             .hash_length(64)
@@ -86,6 +117,8 @@ pub mod zcash {
             .update(&(message.len() as u64).to_le_bytes())
             .update(message)
             .update(encoded_commitments)
+            .update(&(context.len() as u64).to_le_bytes())
+            .update(context)
             .finalize();
         let hash: [u8; 64] = *h.as_array();
         Scalar::from_bytes_wide(&hash)
@@ -100,6 +133,12 @@ pub mod zcash {
         message: Vec<u8>,
         commitments: BTreeMap<u32, SigningCommitments<Point>>,
         encoded_commitments: Vec<u8>,
+        /// adaptor point T for pre-signing
+        adaptor: Option<Point>,
+        /// spend authorization randomizer α for signing under rk
+        randomizer: Option<Scalar>,
+        /// binding factor context: 0x01 || T or 0x02 || α, empty otherwise
+        context: Vec<u8>,
     }
 
     impl RedPallasPackage {
@@ -127,9 +166,60 @@ pub mod zcash {
                 message,
                 commitments: map,
                 encoded_commitments: encoded,
+                adaptor: None,
+                randomizer: None,
+                context: Vec::new(),
             })
         }
 
+        /// Package for a spend authorization signature under rk = vk + [α]G_SpendAuth.
+        ///
+        /// α is hashed into every binding factor, so commitments collected
+        /// for one action cannot be reused for another action's rk.
+        pub fn randomized(
+            message: Vec<u8>,
+            commitments: Vec<SigningCommitments<Point>>,
+            randomizer: Scalar,
+        ) -> Result<Self, OsstError> {
+            let mut package = Self::new(message, commitments)?;
+            package.context = vec![0x02];
+            package.context.extend_from_slice(&randomizer.to_bytes());
+            package.randomizer = Some(randomizer);
+            Ok(package)
+        }
+
+        /// Package for a pre-signature under adaptor point T.
+        ///
+        /// T is hashed into every binding factor and the challenge is taken
+        /// over R + T, so the completed signature verifies as RedPallas.
+        pub fn with_adaptor(
+            message: Vec<u8>,
+            commitments: Vec<SigningCommitments<Point>>,
+            adaptor: Point,
+        ) -> Result<Self, OsstError> {
+            if adaptor == Point::identity() {
+                return Err(OsstError::InvalidCommitment);
+            }
+            let mut package = Self::new(message, commitments)?;
+            package.context = vec![0x01];
+            package.context.extend_from_slice(&adaptor.compress());
+            package.adaptor = Some(adaptor);
+            Ok(package)
+        }
+
+        pub fn adaptor(&self) -> Option<&Point> {
+            self.adaptor.as_ref()
+        }
+
+        pub fn randomizer(&self) -> Option<&Scalar> {
+            self.randomizer.as_ref()
+        }
+
+        /// Neither an adaptor nor a randomized package.
+        fn is_plain(&self) -> bool {
+            self.adaptor.is_none() && self.randomizer.is_none()
+        }
+
         pub fn signer_indices(&self) -> Vec<u32> {
             self.commitments.keys().copied().collect()
         }
@@ -139,7 +229,12 @@ pub mod zcash {
         }
 
         fn binding_factor(&self, index: u32) -> Scalar {
-            redpallas_binding_factor(index, &self.message, &self.encoded_commitments)
+            redpallas_binding_factor(
+                index,
+                &self.message,
+                &self.encoded_commitments,
+                &self.context,
+            )
         }
 
         fn group_commitment(&self) -> Point {
@@ -153,8 +248,14 @@ pub mod zcash {
             r
         }
 
+        /// c = H(R + T, vk, m), with T the identity outside adaptor signing
         fn challenge(&self, group_commitment: &Point, group_pubkey: &Point) -> Scalar {
-            redpallas_challenge(group_commitment, group_pubkey, &self.message)
+            match &self.adaptor {
+                Some(adaptor) => {
+                    redpallas_challenge(&group_commitment.add(adaptor), group_pubkey, &self.message)
+                }
+                None => redpallas_challenge(group_commitment, group_pubkey, &self.message),
+            }
         }
     }
 
@@ -186,6 +287,18 @@ pub mod zcash {
         nonces: Nonces<Scalar>,
         share: &SecretShare<Scalar>,
         group_pubkey: &Point,
+    ) -> Result<SignatureShare<Scalar>, OsstError> {
+        if !package.is_plain() {
+            return Err(OsstError::InvalidCommitment);
+        }
+        sign_share(package, nonces, share, group_pubkey)
+    }
+
+    fn sign_share(
+        package: &RedPallasPackage,
+        nonces: Nonces<Scalar>,
+        share: &SecretShare<Scalar>,
+        group_pubkey: &Point,
     ) -> Result<SignatureShare<Scalar>, OsstError> {
         if package.commitments.get(&share.index).is_none() {
             return Err(OsstError::InvalidIndex);
@@ -219,6 +332,19 @@ pub mod zcash {
         shares: &[SignatureShare<Scalar>],
         group_pubkey: &Point,
         verifier_shares: Option<&BTreeMap<u32, Point>>,
+    ) -> Result<Signature<Point>, OsstError> {
+        if !package.is_plain() {
+            return Err(OsstError::InvalidCommitment);
+        }
+        combine(package, shares, group_pubkey, verifier_shares)
+    }
+
+    /// (R, Σ z_i) after optional share verification
+    fn combine(
+        package: &RedPallasPackage,
+        shares: &[SignatureShare<Scalar>],
+        group_pubkey: &Point,
+        verifier_shares: Option<&BTreeMap<u32, Point>>,
     ) -> Result<Signature<Point>, OsstError> {
         if shares.len() < package.num_signers() {
             return Err(OsstError::InsufficientContributions {
@@ -251,7 +377,7 @@ pub mod zcash {
                     .get(&share.index)
                     .ok_or(OsstError::InvalidIndex)?;
 
-                let lhs = Point::generator().mul_scalar(&share.response);
+                let lhs = spend_auth_basepoint().mul_scalar(&share.response);
                 let rhs = comm
                     .hiding
                     .add(&comm.binding.mul_scalar(&rho))
@@ -282,11 +408,175 @@ pub mod zcash {
         signature: &Signature<Point>,
     ) -> bool {
         let challenge = redpallas_challenge(&signature.r, group_pubkey, message);
-        let lhs = Point::generator().mul_scalar(&signature.z);
+        let lhs = spend_auth_basepoint().mul_scalar(&signature.z);
         let rhs = signature.r.add(&group_pubkey.mul_scalar(&challenge));
         lhs == rhs
     }
 
+    // ========================================================================
+    // Adaptor signatures
+    // ========================================================================
+
+    /// Round 2 against the package's adaptor point T, with
+    /// c = H("Zcash_RedPallasH", R + T || vk || m).
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidCommitment` if the package has no adaptor point.
+    pub fn sign_adaptor(
+        package: &RedPallasPackage,
+        nonces: Nonces<Scalar>,
+        share: &SecretShare<Scalar>,
+        group_pubkey: &Point,
+    ) -> Result<SignatureShare<Scalar>, OsstError> {
+        if package.adaptor.is_none() {
+            return Err(OsstError::InvalidCommitment);
+        }
+        sign_share(package, nonces, share, group_pubkey)
+    }
+
+    /// Aggregate adaptor shares into a pre-signature (R, T, ẑ).
+    ///
+    /// [`PreSignature::complete`] turns it into (R + T, ẑ + t), which
+    /// [`verify_signature`] and any RedPallas verifier accept.
+    pub fn aggregate_adaptor(
+        package: &RedPallasPackage,
+        shares: &[SignatureShare<Scalar>],
+        group_pubkey: &Point,
+        verifier_shares: Option<&BTreeMap<u32, Point>>,
+    ) -> Result<PreSignature<Point>, OsstError> {
+        let adaptor = *package.adaptor.as_ref().ok_or(OsstError::InvalidCommitment)?;
+        let signature = combine(package, shares, group_pubkey, verifier_shares)?;
+        Ok(PreSignature {
+            r: signature.r,
+            adaptor,
+            z: signature.z,
+        })
+    }
+
+    /// Verify a pre-signature: [ẑ]G_SpendAuth == R + [H(R + T, vk, m)]·vk
+    pub fn verify_presignature(
+        group_pubkey: &Point,
+        message: &[u8],
+        presignature: &PreSignature<Point>,
+    ) -> bool {
+        let nonce = presignature.r.add(&presignature.adaptor);
+        let challenge = redpallas_challenge(&nonce, group_pubkey, message);
+        let lhs = spend_auth_basepoint().mul_scalar(&presignature.z);
+        let rhs = presignature.r.add(&group_pubkey.mul_scalar(&challenge));
+        lhs == rhs
+    }
+
+    // ========================================================================
+    // Randomized spend authorization (ZIP 312)
+    // ========================================================================
+
+    /// Randomized verification key rk = vk + [α]G_SpendAuth.
+    ///
+    /// Orchard actions are authorized under rk rather than vk. α is the
+    /// per-action spend authorization randomizer chosen by the tx builder,
+    /// which derives the action's rk the same way.
+    pub fn randomize_pubkey(group_pubkey: &Point, randomizer: &Scalar) -> Point {
+        group_pubkey.add(&spend_auth_basepoint().mul_scalar(randomizer))
+    }
+
+    /// Round 2 for a randomized signature: a share bound to rk.
+    ///
+    /// The package must come from [`RedPallasPackage::randomized`]. Signers
+    /// proceed exactly as in [`sign`] with the challenge computed over rk;
+    /// α is already in every binding factor and otherwise only enters at
+    /// aggregation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidCommitment` if the package carries no randomizer.
+    pub fn sign_randomized(
+        package: &RedPallasPackage,
+        nonces: Nonces<Scalar>,
+        share: &SecretShare<Scalar>,
+        group_pubkey: &Point,
+    ) -> Result<SignatureShare<Scalar>, OsstError> {
+        let randomizer = package.randomizer.as_ref().ok_or(OsstError::InvalidCommitment)?;
+        sign_share(package, nonces, share, &randomize_pubkey(group_pubkey, randomizer))
+    }
+
+    /// Aggregate shares into a signature valid under rk = vk + [α]G_SpendAuth.
+    ///
+    /// z = Σ z_i + c·α, so that [z]G_SpendAuth = R + [c]·rk.
+    pub fn aggregate_randomized(
+        package: &RedPallasPackage,
+        shares: &[SignatureShare<Scalar>],
+        group_pubkey: &Point,
+        verifier_shares: Option<&BTreeMap<u32, Point>>,
+    ) -> Result<Signature<Point>, OsstError> {
+        let randomizer = package.randomizer.as_ref().ok_or(OsstError::InvalidCommitment)?;
+        let rk = randomize_pubkey(group_pubkey, randomizer);
+        let mut signature = combine(package, shares, &rk, verifier_shares)?;
+        let challenge = package.challenge(&signature.r, &rk);
+        signature.z = signature.z.add(&challenge.mul(randomizer));
+        Ok(signature)
+    }
+
+    // ========================================================================
+    // Orchard spend validating key
+    // ========================================================================
+
+    /// 1 or -1, whichever makes [sign]·Y encode with ỹ = 0.
+    ///
+    /// Orchard only accepts a spend validating key ak whose encoding has the
+    /// sign bit ỹ clear (Zcash Protocol Spec §4.2.3), and a DKG group key has
+    /// it set half the time. As with BIP340's even-y keys, the group negates
+    /// its key and every share instead of re-running the DKG.
+    pub fn spend_auth_sign(group_pubkey: &Point) -> Scalar {
+        if group_pubkey.compress()[31] >> 7 == 0 {
+            Scalar::one()
+        } else {
+            Scalar::one().neg()
+        }
+    }
+
+    /// A pallas DKG result normalized for Orchard spend authorization.
+    pub struct SpendAuthShare {
+        /// ak = ±Y, encoded with ỹ = 0
+        pub ak: Point,
+        /// this participant's share of log(ak)
+        pub share: SecretShare<Scalar>,
+        /// ±Y_j for every participant, for share verification under ak
+        pub verifier_shares: BTreeMap<u32, Point>,
+    }
+
+    /// Finalize a pallas DKG into a share of an Orchard spend validating key.
+    ///
+    /// `aggregator` must hold sub-shares from exactly the qualified dealers
+    /// of the settled `state` (see [`dkg::Aggregator::retain_dealers`]). Every
+    /// participant applies the same sign, so the normalized shares still
+    /// interpolate to log(ak).
+    ///
+    /// [`dkg::Aggregator::retain_dealers`]: crate::dkg::Aggregator::retain_dealers
+    pub fn finalize_spend_auth(
+        state: &crate::dkg::DkgState<Point>,
+        aggregator: &crate::dkg::Aggregator<Point>,
+    ) -> Result<SpendAuthShare, OsstError> {
+        let group_pubkey = state.derive_group_key()?;
+        if aggregator.derive_group_key() != group_pubkey {
+            return Err(OsstError::InvalidCommitment);
+        }
+        let secret = aggregator.finalize(state.qualified_dealers().len() as u32)?;
+
+        let sign = spend_auth_sign(&group_pubkey);
+        let verifier_shares = state
+            .derive_all_verification_shares()?
+            .into_iter()
+            .map(|(j, y)| (j, y.mul_scalar(&sign)))
+            .collect();
+
+        Ok(SpendAuthShare {
+            ak: group_pubkey.mul_scalar(&sign),
+            share: SecretShare::new(aggregator.player_index(), secret.mul(&sign)),
+            verifier_shares,
+        })
+    }
+
     // ========================================================================
     // Nested FROST: jury network as one FROST participant
     // ========================================================================
@@ -553,7 +843,7 @@ pub mod zcash {
             let jury_secret = reconstruct_secret(
                 &jury.node_shares[..jury.threshold as usize],
             )?;
-            Point::generator().mul_scalar(&jury_secret)
+            spend_auth_basepoint().mul_scalar(&jury_secret)
         };
 
         let contributions: Vec<Contribution<Point>> = jury.node_shares
@@ -919,7 +1209,7 @@ pub mod zcash {
                 let jury_secret = reconstruct_secret(
                     &jury.node_shares[..jury.threshold as usize],
                 )?;
-                Point::generator().mul_scalar(&jury_secret)
+                spend_auth_basepoint().mul_scalar(&jury_secret)
             };
 
             let mut osst_rng = rand_core::OsRng;
@@ -1037,8 +1327,10 @@ pub mod zcash {
 #[cfg(all(test, feature = "pallas"))]
 mod tests {
     use super::zcash::*;
+    use alloc::collections::BTreeMap;
     use crate::frost::Signature;
     use crate::SecretShare;
+    use crate::OsstError;
     use pasta_curves::group::ff::Field;
     use pasta_curves::pallas::{Point, Scalar};
     use rand::rngs::OsRng;
@@ -1110,6 +1402,206 @@ mod tests {
         assert!(!verify_signature(&group_pubkey, b"wrong", &sig));
     }
 
+    #[test]
+    fn test_redpallas_randomized_signature() {
+        use reddsa::orchard::SpendAuth;
+
+        let mut rng = OsRng;
+        let secret = <Scalar as Field>::random(&mut rng);
+        let group_pubkey: Point = Point::generator().mul_scalar(&secret);
+        let alpha = <Scalar as Field>::random(&mut rng);
+        let rk = randomize_pubkey(&group_pubkey, &alpha);
+
+        let shares = test_shamir_split(&secret, 3, 2);
+        let vshares: BTreeMap<u32, Point> = shares
+            .iter()
+            .map(|s| (s.index, Point::generator().mul_scalar(s.scalar())))
+            .collect();
+        let sighash = [7u8; 32];
+
+        let mut nonces_vec = Vec::new();
+        let mut comms = Vec::new();
+        for s in &shares[1..3] {
+            let (n, c) = commit(s.index, &mut rng);
+            nonces_vec.push(n);
+            comms.push(c);
+        }
+
+        let pkg = RedPallasPackage::randomized(sighash.to_vec(), comms.clone(), alpha).unwrap();
+        assert_eq!(pkg.randomizer(), Some(&alpha));
+        let mut sig_shares = Vec::new();
+        for (s, n) in shares[1..3].iter().zip(nonces_vec) {
+            sig_shares.push(sign_randomized(&pkg, n, s, &group_pubkey).unwrap());
+        }
+        let sig = aggregate_randomized(&pkg, &sig_shares, &group_pubkey, Some(&vshares)).unwrap();
+
+        assert!(verify_signature(&rk, &sighash, &sig));
+        assert!(!verify_signature(&group_pubkey, &sighash, &sig));
+
+        // rk is the key reddsa randomizes to, and an Orchard verifier
+        // accepts the signature under it
+        let vk = reddsa::VerificationKey::<SpendAuth>::try_from(group_pubkey.compress()).unwrap();
+        let reddsa_rk = vk.randomize(&alpha);
+        assert_eq!(<[u8; 32]>::from(reddsa_rk), rk.compress());
+        let bytes: [u8; 64] = sig.to_bytes_vec().try_into().unwrap();
+        assert!(reddsa_rk.verify(&sighash, &reddsa::Signature::<SpendAuth>::from(bytes)).is_ok());
+
+        // the same commitments under another α give different binding
+        // factors, so the shares no longer check out
+        let other = RedPallasPackage::randomized(sighash.to_vec(), comms.clone(), alpha.double())
+            .unwrap();
+        assert!(matches!(
+            aggregate_randomized(&other, &sig_shares, &group_pubkey, Some(&vshares)),
+            Err(OsstError::InvalidResponse)
+        ));
+
+        // plain and randomized entry points refuse each other's packages
+        assert!(matches!(
+            aggregate(&pkg, &sig_shares, &group_pubkey, None),
+            Err(OsstError::InvalidCommitment)
+        ));
+        let plain = RedPallasPackage::new(sighash.to_vec(), comms).unwrap();
+        assert!(matches!(
+            aggregate_randomized(&plain, &sig_shares, &group_pubkey, None),
+            Err(OsstError::InvalidCommitment)
+        ));
+    }
+
+    /// DKG keys of either ỹ sign become valid Orchard ak, and the
+    /// normalized shares sign under it
+    #[test]
+    fn test_spend_auth_key_normalization() {
+        use crate::dkg::{Aggregator, Dealer, DkgState};
+
+        let mut rng = OsRng;
+        let y = Point::generator().mul_scalar(&<Scalar as Field>::random(&mut rng));
+        assert_eq!(spend_auth_sign(&y), -spend_auth_sign(&-y));
+        assert_eq!(y.mul_scalar(&spend_auth_sign(&y)).compress()[31] >> 7, 0);
+
+        let mut seen_negated = false;
+        let mut seen_kept = false;
+        while !(seen_negated && seen_kept) {
+            let dealers: Vec<Dealer<Point>> = (1..=3).map(|i| Dealer::new(i, 2, &mut rng)).collect();
+            let mut state: DkgState<Point> = DkgState::new(0, 2, 3);
+            for d in &dealers {
+                state.submit_commitment(d.commitment().clone()).unwrap();
+            }
+            state.close_complaints().unwrap();
+
+            let keys: Vec<SpendAuthShare> = (1..=3)
+                .map(|j| {
+                    let mut agg = Aggregator::new(j);
+                    for d in &dealers {
+                        agg.add_subshare(d.generate_subshare(j), d.commitment()).unwrap();
+                    }
+                    finalize_spend_auth(&state, &agg).unwrap()
+                })
+                .collect();
+
+            let ak = keys[0].ak;
+            assert_eq!(ak.compress()[31] >> 7, 0);
+            if ak == state.derive_group_key().unwrap() {
+                seen_kept = true;
+            } else {
+                assert_eq!(ak, -state.derive_group_key().unwrap());
+                seen_negated = true;
+            }
+
+            let sighash = [3u8; 32];
+            let mut nonces_vec = Vec::new();
+            let mut comms = Vec::new();
+            for k in &keys[1..] {
+                let (n, c) = commit(k.share.index, &mut rng);
+                nonces_vec.push(n);
+                comms.push(c);
+            }
+            let pkg = RedPallasPackage::new(sighash.to_vec(), comms).unwrap();
+            let sig_shares: Vec<_> = keys[1..]
+                .iter()
+                .zip(nonces_vec)
+                .map(|(k, n)| sign(&pkg, n, &k.share, &ak).unwrap())
+                .collect();
+            let sig = aggregate(&pkg, &sig_shares, &ak, Some(&keys[0].verifier_shares)).unwrap();
+            assert!(verify_signature(&ak, &sighash, &sig));
+        }
+    }
+
+    /// G_SpendAuth is GroupHash("z.cash:Orchard", "G"), the base reddsa
+    /// derives SpendAuth keys over, and the pallas backend's generator
+    #[test]
+    fn test_spend_auth_basepoint_matches_orchard() {
+        use pasta_curves::arithmetic::CurveExt;
+        use pasta_curves::group::ff::PrimeField;
+        use reddsa::orchard::SpendAuth;
+
+        let basepoint = spend_auth_basepoint();
+        assert_eq!(basepoint, Point::hash_to_curve("z.cash:Orchard")(b"G"));
+        assert_eq!(basepoint, <Point as OsstPoint>::generator());
+        assert_ne!(basepoint, <Point as pasta_curves::group::Group>::generator());
+
+        let mut rng = OsRng;
+        let sk = reddsa::SigningKey::<SpendAuth>::new(&mut rng);
+        let secret = Scalar::from_repr(<[u8; 32]>::from(sk)).unwrap();
+        let vk = reddsa::VerificationKey::from(&sk);
+        assert_eq!(<[u8; 32]>::from(vk), basepoint.mul_scalar(&secret).compress());
+
+        // and reddsa signatures verify here
+        let sig: [u8; 64] = sk.sign(&mut rng, b"sighash").into();
+        let signature = Signature::<Point>::from_bytes(&sig).unwrap();
+        let ak = basepoint.mul_scalar(&secret);
+        assert!(verify_signature(&ak, b"sighash", &signature));
+    }
+
+    /// The completed pre-signature is an ordinary spend authorization
+    /// signature, accepted by the reddsa verifier Orchard nodes run
+    #[test]
+    fn test_redpallas_adaptor_completes_to_spend_auth() {
+        use reddsa::orchard::SpendAuth;
+
+        let mut rng = OsRng;
+        let secret = <Scalar as Field>::random(&mut rng);
+        let group_pubkey: Point = Point::generator().mul_scalar(&secret);
+        let shares = test_shamir_split(&secret, 3, 2);
+        let vshares: BTreeMap<u32, Point> = shares
+            .iter()
+            .map(|s| (s.index, Point::generator().mul_scalar(s.scalar())))
+            .collect();
+        let sighash = [9u8; 32];
+
+        let t = <Scalar as Field>::random(&mut rng);
+        let adaptor = Point::generator().mul_scalar(&t);
+
+        let mut nonces_vec = Vec::new();
+        let mut comms = Vec::new();
+        for s in &shares[..2] {
+            let (n, c) = commit(s.index, &mut rng);
+            nonces_vec.push(n);
+            comms.push(c);
+        }
+        let pkg = RedPallasPackage::with_adaptor(sighash.to_vec(), comms, adaptor).unwrap();
+        let sig_shares: Vec<_> = shares[..2]
+            .iter()
+            .zip(nonces_vec)
+            .map(|(s, n)| sign_adaptor(&pkg, n, s, &group_pubkey).unwrap())
+            .collect();
+        let presig = aggregate_adaptor(&pkg, &sig_shares, &group_pubkey, Some(&vshares)).unwrap();
+        assert!(verify_presignature(&group_pubkey, &sighash, &presig));
+        assert!(matches!(
+            aggregate(&pkg, &sig_shares, &group_pubkey, None),
+            Err(OsstError::InvalidCommitment)
+        ));
+
+        let signature = presig.complete(&t).unwrap();
+        assert!(verify_signature(&group_pubkey, &sighash, &signature));
+        assert_eq!(presig.extract(&signature), Some(t));
+
+        let vk = reddsa::VerificationKey::<SpendAuth>::try_from(group_pubkey.compress()).unwrap();
+        let bytes: [u8; 64] = signature.to_bytes_vec().try_into().unwrap();
+        let sig = reddsa::Signature::<SpendAuth>::from(bytes);
+        assert!(vk.verify(&sighash, &sig).is_ok());
+        assert!(vk.verify(b"other sighash", &sig).is_err());
+    }
+
     #[test]
     fn test_escrow_setup_and_sign() {
         let mut rng = OsRng;
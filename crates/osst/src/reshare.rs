@@ -6,7 +6,8 @@
 //! # Security Model
 //!
 //! - Assumes honest majority among dealers (t_old honest of n_old)
-//! - Sub-shares must be encrypted in transit (not handled here)
+//! - Sub-shares travel as [`EncryptedSubShare`], which anyone can check
+//!   against the dealer's commitment
 //! - Commitments provide public verifiability
 //! - Group public key Y = g^s is an invariant across reshares
 //!
@@ -14,7 +15,7 @@
 //!
 //! Designed for O(1000) participants:
 //! - Commitments: O(t) points per dealer, posted to chain
-//! - Sub-shares: Encrypted, can be batched or posted to chain
+//! - Sub-shares: ~49 KiB encrypted with range proofs, can be posted to chain
 //! - Verification: Batched for efficiency
 //! - Aggregation: O(t) operations per player, parallelizable
 //!
@@ -24,11 +25,31 @@
 //! 2. **Distribution**: Sub-shares sent (encrypted) to players
 //! 3. **Verification**: Players verify against commitments
 //! 4. **Aggregation**: Players combine t_old sub-shares into new share
-
+//!
+//! # Refresh
+//!
+//! When the custodian set does not change, [`Dealer::new_refresh`] deals a
+//! polynomial δ_i with δ_i(0) = 0 and each custodian adds the agreed
+//! dealers' δ_i(j) to its existing share with a [`Refresher`]. Shares are
+//! re-randomized each epoch without any Lagrange interpolation, and old
+//! shares leaked before the refresh cannot be combined with new ones.
+//!
+//! # Rotation Transcripts
+//!
+//! A [`RotationTranscript`] condenses one refresh or reshare into the dealer
+//! set, each dealer's constant-term commitment and the combined polynomial
+//! commitment. [`RotationTranscript::verify`] checks against the previous
+//! epoch's verification shares that Y is unchanged in O(t) group
+//! operations, and the combined commitment yields every custodian's next
+//! verification share.
+
+use alloc::collections::BTreeMap;
 use alloc::vec;
 use alloc::vec::Vec;
 use core::marker::PhantomData;
 
+use sha2::{Digest, Sha512};
+
 use crate::curve::{OsstPoint, OsstScalar};
 use crate::error::OsstError;
 use crate::lagrange::compute_lagrange_coefficients;
@@ -291,6 +312,18 @@ impl<P: OsstPoint> Dealer<P> {
         }
     }
 
+    /// Create a refresh dealer
+    ///
+    /// Deals a random polynomial with zero constant term, so the sub-shares
+    /// re-randomize the current sharing without moving the secret.
+    pub fn new_refresh<R: rand_core::RngCore + rand_core::CryptoRng>(
+        index: u32,
+        threshold: u32,
+        rng: &mut R,
+    ) -> Self {
+        Self::new(index, P::Scalar::zero(), threshold, rng)
+    }
+
     #[inline]
     pub fn index(&self) -> u32 {
         self.index
@@ -528,6 +561,122 @@ impl<P: OsstPoint> core::fmt::Debug for Aggregator<P> {
     }
 }
 
+// ============================================================================
+// Refresher (Same Custodian Set)
+// ============================================================================
+
+/// Refresher collects zero-constant sub-shares for an existing custodian
+///
+/// The refreshed share is s'_j = s_j + Σ δ_i(j) over the dealers fixed by
+/// the epoch's [`RotationTranscript`]. Every custodian must sum the same
+/// dealer set, or the new shares no longer lie on one polynomial.
+pub struct Refresher<P: OsstPoint> {
+    player_index: u32,
+    /// Verified δ_i(j) from refresh dealers
+    deltas: Vec<VerifiedSubShare<P::Scalar>>,
+    _marker: PhantomData<P>,
+}
+
+impl<P: OsstPoint> Refresher<P> {
+    pub fn new(player_index: u32) -> Self {
+        assert!(player_index > 0, "player index must be 1-indexed");
+        Self {
+            player_index,
+            deltas: Vec::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn player_index(&self) -> u32 {
+        self.player_index
+    }
+
+    /// Number of verified sub-shares collected
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.deltas.len()
+    }
+
+    /// Add a refresh sub-share with verification
+    ///
+    /// Returns Ok(true) if added, Ok(false) if duplicate, Err if invalid or
+    /// if the commitment's constant term is not the identity.
+    pub fn add_subshare(
+        &mut self,
+        subshare: SubShare<P::Scalar>,
+        commitment: &DealerCommitment<P>,
+    ) -> Result<bool, OsstError> {
+        if subshare.player_index != self.player_index {
+            return Err(OsstError::InvalidIndex);
+        }
+        if subshare.dealer_index != commitment.dealer_index || subshare.dealer_index == 0 {
+            return Err(OsstError::InvalidIndex);
+        }
+        if commitment.share_commitment() != &P::identity() {
+            return Err(OsstError::InvalidCommitment);
+        }
+
+        if self
+            .deltas
+            .iter()
+            .any(|d| d.dealer_index == subshare.dealer_index)
+        {
+            return Ok(false);
+        }
+
+        if !commitment.verify_subshare(self.player_index, subshare.value()) {
+            return Err(OsstError::InvalidResponse);
+        }
+
+        self.deltas.push(VerifiedSubShare {
+            dealer_index: subshare.dealer_index,
+            value: subshare.value().clone(),
+        });
+        Ok(true)
+    }
+
+    /// Apply the refresh: s'_j = s_j + Σ_{i ∈ dealers} δ_i(j)
+    ///
+    /// `transcript` fixes the dealer set. Fails if it is not a refresh or
+    /// if a sub-share from one of its dealers is missing.
+    pub fn finalize(
+        &self,
+        share: &P::Scalar,
+        transcript: &RotationTranscript<P>,
+    ) -> Result<P::Scalar, OsstError> {
+        if transcript.kind != RotationKind::Refresh {
+            return Err(OsstError::WrongPhase);
+        }
+
+        let mut refreshed = share.clone();
+        let mut got = 0;
+        for dealer in &transcript.dealers {
+            if let Some(d) = self.deltas.iter().find(|d| d.dealer_index == *dealer) {
+                refreshed = refreshed.add(&d.value);
+                got += 1;
+            }
+        }
+        if got < transcript.dealers.len() {
+            return Err(OsstError::InsufficientContributions {
+                got,
+                need: transcript.dealers.len(),
+            });
+        }
+
+        Ok(refreshed)
+    }
+}
+
+impl<P: OsstPoint> core::fmt::Debug for Refresher<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Refresher")
+            .field("player_index", &self.player_index)
+            .field("count", &self.deltas.len())
+            .finish()
+    }
+}
+
 // ============================================================================
 // On-Chain Coordination Types
 // ============================================================================
@@ -634,6 +783,842 @@ impl<P: OsstPoint> ReshareState<P> {
 
         Ok(derived_key == self.group_key)
     }
+
+    /// Condense the submitted commitments into a [`RotationTranscript`]
+    pub fn transcript(&self) -> Result<RotationTranscript<P>, OsstError> {
+        if !self.has_quorum() {
+            return Err(OsstError::InsufficientContributions {
+                got: self.commitment_count(),
+                need: self.old_threshold as usize,
+            });
+        }
+        RotationTranscript::reshare(self.target_epoch, &self.get_commitments())
+    }
+}
+
+// ============================================================================
+// Rotation Transcript
+// ============================================================================
+
+/// Whether a rotation keeps the custodian set
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RotationKind {
+    /// Same custodians, shares re-randomized by zero-constant polynomials
+    Refresh,
+    /// Shares moved to a new custodian set
+    Reshare,
+}
+
+/// Compact public record of one refresh or reshare epoch
+///
+/// For a reshare, `combined` commits to F = Σ λ_i f_i, the new sharing
+/// polynomial; for a refresh, to Δ = Σ δ_i, which is added to the old one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RotationTranscript<P: OsstPoint> {
+    pub kind: RotationKind,
+    /// Epoch being rotated into
+    pub epoch: u64,
+    /// Dealers whose polynomials were combined, ascending
+    pub dealers: Vec<u32>,
+    /// C_{i,0} of each dealer in `dealers` order (empty for a refresh)
+    pub constant_terms: Vec<P>,
+    /// Commitment to the combined polynomial, `threshold()` points
+    pub combined: Vec<P>,
+}
+
+impl<P: OsstPoint> RotationTranscript<P> {
+    /// Combine refresh commitments: Δ_k = Σ_i C_{i,k}
+    ///
+    /// Every commitment must have an identity constant term.
+    pub fn refresh(epoch: u64, commitments: &[&DealerCommitment<P>]) -> Result<Self, OsstError> {
+        let sorted = sorted_commitments(commitments)?;
+        let mut combined = vec![P::identity(); sorted[0].coefficients.len()];
+        for c in &sorted {
+            if c.share_commitment() != &P::identity() {
+                return Err(OsstError::InvalidCommitment);
+            }
+            for (acc, coeff) in combined.iter_mut().zip(&c.coefficients) {
+                *acc = acc.add(coeff);
+            }
+        }
+
+        Ok(Self {
+            kind: RotationKind::Refresh,
+            epoch,
+            dealers: sorted.iter().map(|c| c.dealer_index).collect(),
+            constant_terms: Vec::new(),
+            combined,
+        })
+    }
+
+    /// Combine reshare commitments: F_k = Σ_i λ_i · C_{i,k}
+    pub fn reshare(epoch: u64, commitments: &[&DealerCommitment<P>]) -> Result<Self, OsstError> {
+        let sorted = sorted_commitments(commitments)?;
+        let dealers: Vec<u32> = sorted.iter().map(|c| c.dealer_index).collect();
+        let lagrange = compute_lagrange_coefficients::<P::Scalar>(&dealers)?;
+
+        let mut combined = vec![P::identity(); sorted[0].coefficients.len()];
+        for (c, lambda) in sorted.iter().zip(&lagrange) {
+            for (acc, coeff) in combined.iter_mut().zip(&c.coefficients) {
+                *acc = acc.add(&coeff.mul_scalar(lambda));
+            }
+        }
+
+        Ok(Self {
+            kind: RotationKind::Reshare,
+            epoch,
+            constant_terms: sorted.iter().map(|c| c.share_commitment().clone()).collect(),
+            dealers,
+            combined,
+        })
+    }
+
+    /// Threshold of the sharing after this rotation
+    #[inline]
+    pub fn threshold(&self) -> u32 {
+        self.combined.len() as u32
+    }
+
+    /// Check that the rotation preserves the group key
+    ///
+    /// `previous_shares` maps each previous custodian to its verification
+    /// share g^{s_i}. Checks that at least `previous_threshold` of them
+    /// dealt, then:
+    ///
+    /// - refresh: Δ_0 is the identity and the threshold is unchanged
+    /// - reshare: each C_{i,0} equals the dealer's verification share, and
+    ///   F_0 = Σ λ_i · C_{i,0} = Y
+    ///
+    /// O(t) group operations. Does not check that `combined` is the sum of
+    /// the dealers' full commitments; see [`verify_against`](Self::verify_against).
+    pub fn verify(
+        &self,
+        group_key: &P,
+        previous_shares: &BTreeMap<u32, P>,
+        previous_threshold: u32,
+    ) -> Result<bool, OsstError> {
+        if self.dealers.len() < previous_threshold as usize {
+            return Err(OsstError::InsufficientContributions {
+                got: self.dealers.len(),
+                need: previous_threshold as usize,
+            });
+        }
+        if self.combined.is_empty() || self.dealers.windows(2).any(|w| w[0] >= w[1]) {
+            return Ok(false);
+        }
+        for dealer in &self.dealers {
+            if !previous_shares.contains_key(dealer) {
+                return Err(OsstError::InvalidIndex);
+            }
+        }
+
+        match self.kind {
+            RotationKind::Refresh => Ok(self.threshold() == previous_threshold
+                && self.constant_terms.is_empty()
+                && self.combined[0] == P::identity()),
+            RotationKind::Reshare => {
+                if self.constant_terms.len() != self.dealers.len() {
+                    return Ok(false);
+                }
+                let lagrange = compute_lagrange_coefficients::<P::Scalar>(&self.dealers)?;
+                let mut derived = P::identity();
+                for ((dealer, term), lambda) in
+                    self.dealers.iter().zip(&self.constant_terms).zip(&lagrange)
+                {
+                    if &previous_shares[dealer] != term {
+                        return Ok(false);
+                    }
+                    derived = derived.add(&term.mul_scalar(lambda));
+                }
+                Ok(&derived == group_key && self.combined[0] == derived)
+            }
+        }
+    }
+
+    /// Check that this transcript is exactly the combination of `commitments`
+    ///
+    /// O(t²): for auditors holding every dealer commitment.
+    pub fn verify_against(&self, commitments: &[&DealerCommitment<P>]) -> bool {
+        let rebuilt = match self.kind {
+            RotationKind::Refresh => Self::refresh(self.epoch, commitments),
+            RotationKind::Reshare => Self::reshare(self.epoch, commitments),
+        };
+        matches!(rebuilt, Ok(r) if &r == self)
+    }
+
+    /// Verification share of custodian j after this rotation
+    ///
+    /// A refresh adds Δ(j) to the custodian's `previous` verification share;
+    /// a reshare evaluates F(j) and ignores `previous`.
+    pub fn next_verification_share(&self, player_index: u32, previous: Option<&P>) -> Option<P> {
+        if player_index == 0 {
+            return None;
+        }
+        let j = P::Scalar::from_u32(player_index);
+        let mut evaluated = P::identity();
+        for coeff in self.combined.iter().rev() {
+            evaluated = evaluated.mul_scalar(&j);
+            evaluated = evaluated.add(coeff);
+        }
+
+        match self.kind {
+            RotationKind::Refresh => previous.map(|p| p.add(&evaluated)),
+            RotationKind::Reshare => Some(evaluated),
+        }
+    }
+
+    /// Serialize: [kind:1][epoch:8][dealers:4][threshold:4][indices][C_0s][combined]
+    ///
+    /// Points use the backend's full `P::COMPRESSED_SIZE` encoding, so the
+    /// secp256k1 y-parity is kept and bound by [`digest`](Self::digest).
+    /// A refresh omits the constant terms and Δ_0, which are the identity.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        buf.push(match self.kind {
+            RotationKind::Refresh => 0,
+            RotationKind::Reshare => 1,
+        });
+        buf.extend_from_slice(&self.epoch.to_le_bytes());
+        buf.extend_from_slice(&(self.dealers.len() as u32).to_le_bytes());
+        buf.extend_from_slice(&self.threshold().to_le_bytes());
+        for dealer in &self.dealers {
+            buf.extend_from_slice(&dealer.to_le_bytes());
+        }
+        for term in &self.constant_terms {
+            buf.extend_from_slice(&term.compress_vec());
+        }
+        let skip = match self.kind {
+            RotationKind::Refresh => 1,
+            RotationKind::Reshare => 0,
+        };
+        for coeff in self.combined.iter().skip(skip) {
+            buf.extend_from_slice(&coeff.compress_vec());
+        }
+        buf
+    }
+
+    /// Deserialize from [`to_bytes`](Self::to_bytes) output
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, OsstError> {
+        if bytes.len() < 17 {
+            return Err(OsstError::InvalidCommitment);
+        }
+        let kind = match bytes[0] {
+            0 => RotationKind::Refresh,
+            1 => RotationKind::Reshare,
+            _ => return Err(OsstError::InvalidCommitment),
+        };
+        let epoch = u64::from_le_bytes(bytes[1..9].try_into().unwrap());
+        let num_dealers = u32::from_le_bytes(bytes[9..13].try_into().unwrap()) as usize;
+        let threshold = u32::from_le_bytes(bytes[13..17].try_into().unwrap()) as usize;
+        if num_dealers == 0 || threshold == 0 {
+            return Err(OsstError::InvalidCommitment);
+        }
+        // each dealer index and all but one coefficient take input bytes, so
+        // neither count can exceed the input; this keeps the size arithmetic
+        // below in range on 32-bit targets and caps the allocations
+        if num_dealers > bytes.len() / 4 || threshold > bytes.len() / P::COMPRESSED_SIZE + 1 {
+            return Err(OsstError::InvalidCommitment);
+        }
+
+        let (num_terms, num_encoded) = match kind {
+            RotationKind::Refresh => (0, threshold - 1),
+            RotationKind::Reshare => (num_dealers, threshold),
+        };
+        let expected = (num_terms + num_encoded)
+            .checked_mul(P::COMPRESSED_SIZE)
+            .and_then(|points| points.checked_add(4 * num_dealers))
+            .and_then(|body| body.checked_add(17));
+        if expected != Some(bytes.len()) {
+            return Err(OsstError::InvalidCommitment);
+        }
+
+        let mut offset = 17;
+        let mut dealers = Vec::with_capacity(num_dealers);
+        for _ in 0..num_dealers {
+            dealers.push(u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap()));
+            offset += 4;
+        }
+        let mut points = Vec::with_capacity(num_terms + num_encoded);
+        for _ in 0..num_terms + num_encoded {
+            let point_bytes = &bytes[offset..offset + P::COMPRESSED_SIZE];
+            points.push(P::decompress_slice(point_bytes).ok_or(OsstError::InvalidCommitment)?);
+            offset += P::COMPRESSED_SIZE;
+        }
+        let mut combined = points.split_off(num_terms);
+        if kind == RotationKind::Refresh {
+            combined.insert(0, P::identity());
+        }
+
+        Ok(Self {
+            kind,
+            epoch,
+            dealers,
+            constant_terms: points,
+            combined,
+        })
+    }
+
+    /// Binding digest for storing the transcript on chain
+    ///
+    /// H("osst-rotation-v1" || to_bytes())
+    pub fn digest(&self) -> [u8; 64] {
+        let mut h = Sha512::new();
+        h.update(b"osst-rotation-v1");
+        h.update(self.to_bytes());
+        h.finalize().into()
+    }
+}
+
+/// Sort by dealer index, rejecting empty input, duplicates and mixed thresholds
+fn sorted_commitments<'a, P: OsstPoint>(
+    commitments: &[&'a DealerCommitment<P>],
+) -> Result<Vec<&'a DealerCommitment<P>>, OsstError> {
+    let mut sorted = commitments.to_vec();
+    sorted.sort_by_key(|c| c.dealer_index);
+
+    let first = sorted.first().ok_or(OsstError::EmptyContributions)?;
+    let threshold = first.coefficients.len();
+    for w in sorted.windows(2) {
+        if w[0].dealer_index == w[1].dealer_index {
+            return Err(OsstError::DuplicateIndex(w[1].dealer_index));
+        }
+    }
+    if sorted.iter().any(|c| c.coefficients.len() != threshold) {
+        return Err(OsstError::InvalidCommitment);
+    }
+    Ok(sorted)
+}
+
+// ============================================================================
+// Encrypted Transport
+// ============================================================================
+
+/// Bits per encrypted chunk of a sub-share
+const CHUNK_BITS: u32 = 16;
+
+/// Chunks per sub-share, covering any 256-bit scalar
+const NUM_CHUNKS: usize = 256 / CHUNK_BITS as usize;
+
+/// Sub-share encrypted to the player's public key, verifiable by anyone
+///
+/// The value is split into 16-bit chunks m_k, each ElGamal-encrypted in the
+/// exponent: (R_k, C_k) = (g^{r_k}, g^{m_k} · PK^{r_k}). Recombining the
+/// chunks with weights 2^{16k} gives an encryption (R, C) of g^{f(j)}, and a
+/// Chaum-Pedersen proof shows log_g R = log_PK (C / g^{f(j)}), where g^{f(j)}
+/// comes from the dealer's commitment. A [`ChunkRangeProof`] per chunk shows
+/// m_k < 2^16, so every ciphertext that verifies decrypts to f(j). The player
+/// decrypts each chunk by a 2^8 x 2^8 baby-step giant-step search.
+///
+/// All proofs are Fiat-Shamir transformed over one statement digest: the
+/// indices, the dealer's full commitment, g^{f(j)}, PK, and every chunk and
+/// bit ciphertext.
+///
+/// Size: 8 + 546 * point size + 1025 * 32 bytes (50280 for 32-byte points)
+#[derive(Clone, Debug, PartialEq)]
+pub struct EncryptedSubShare<P: OsstPoint> {
+    pub dealer_index: u32,
+    pub player_index: u32,
+    /// Per-chunk ElGamal ciphertexts (R_k, C_k), least significant first
+    pub chunks: Vec<(P, P)>,
+    /// Range proof of each chunk, in `chunks` order
+    pub range_proofs: Vec<ChunkRangeProof<P>>,
+    /// Proof commitments (g^w, PK^w)
+    pub proof_commitments: (P, P),
+    /// Proof response z = w + e * Σ 2^{16k} r_k
+    pub proof_response: P::Scalar,
+}
+
+/// Proof that a chunk ciphertext encrypts a value below 2^16
+///
+/// The chunk's bits b_i are encrypted as (R_{k,i}, C_{k,i}) =
+/// (g^{r_{k,i}}, g^{b_i} · PK^{r_{k,i}}) with r_k = Σ 2^i r_{k,i}, so the
+/// bit ciphertexts recombine to (R_k, C_k). A Cramer-Damgård-Schoenmakers
+/// OR proof per bit shows it encrypts 0 or 1.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChunkRangeProof<P: OsstPoint> {
+    /// Bit ciphertexts (R_{k,i}, C_{k,i}), least significant first
+    pub bits: Vec<(P, P)>,
+    /// OR proof (e_0, e_1, z_0, z_1) of each bit ciphertext
+    pub bit_proofs: Vec<[P::Scalar; 4]>,
+}
+
+impl<P: OsstPoint> EncryptedSubShare<P> {
+    /// Encrypt `subshare` to the player's public key `recipient_key`
+    ///
+    /// `commitment` is the dealer's commitment the sub-share was dealt under;
+    /// it is bound into the proofs.
+    pub fn encrypt<R: rand_core::RngCore + rand_core::CryptoRng>(
+        subshare: &SubShare<P::Scalar>,
+        recipient_key: &P,
+        commitment: &DealerCommitment<P>,
+        rng: &mut R,
+    ) -> Self {
+        let g = P::generator();
+        let mut chunk_values = scalar_chunks(subshare.value());
+
+        let mut bit_randomness = Vec::with_capacity(NUM_CHUNKS);
+        let mut randomness = Vec::with_capacity(NUM_CHUNKS);
+        let mut chunks = Vec::with_capacity(NUM_CHUNKS);
+        let mut range_proofs = Vec::with_capacity(NUM_CHUNKS);
+        for m in &chunk_values {
+            let rs: Vec<P::Scalar> = (0..CHUNK_BITS).map(|_| P::Scalar::random(rng)).collect();
+            let bits = rs
+                .iter()
+                .enumerate()
+                .map(|(i, r)| {
+                    let c = recipient_key.mul_scalar(r);
+                    let c = if (m >> i) & 1 == 1 { c.add(&g) } else { c };
+                    (g.mul_scalar(r), c)
+                })
+                .collect();
+
+            let r = recombine_bits(&rs);
+            let c = g
+                .mul_scalar(&P::Scalar::from_u32(*m))
+                .add(&recipient_key.mul_scalar(&r));
+            chunks.push((g.mul_scalar(&r), c));
+            range_proofs.push(ChunkRangeProof { bits, bit_proofs: Vec::new() });
+            randomness.push(r);
+            bit_randomness.push(rs);
+        }
+
+        let statement = statement_digest(
+            subshare.dealer_index,
+            subshare.player_index,
+            commitment,
+            recipient_key,
+            &chunks,
+            &range_proofs,
+        );
+        for (k, (proof, rs)) in range_proofs.iter_mut().zip(&mut bit_randomness).enumerate() {
+            proof.bit_proofs = proof
+                .bits
+                .iter()
+                .zip(rs.iter())
+                .enumerate()
+                .map(|(i, (bit, r))| {
+                    let b = (chunk_values[k] >> i) & 1 == 1;
+                    prove_bit(&statement, k, i, recipient_key, bit, b, r, rng)
+                })
+                .collect();
+            rs.iter_mut().for_each(|r| r.zeroize());
+        }
+        chunk_values.iter_mut().for_each(|m| *m = 0);
+
+        let mut r_total = recombine(&randomness);
+        let w = P::Scalar::random(rng);
+        let proof_commitments = (g.mul_scalar(&w), recipient_key.mul_scalar(&w));
+
+        let e = encryption_challenge(&statement, &proof_commitments);
+        let proof_response = w.add(&e.mul(&r_total));
+
+        r_total.zeroize();
+        for r in &mut randomness {
+            r.zeroize();
+        }
+
+        Self {
+            dealer_index: subshare.dealer_index,
+            player_index: subshare.player_index,
+            chunks,
+            range_proofs,
+            proof_commitments,
+            proof_response,
+        }
+    }
+
+    /// Check that this encrypts the sub-share committed to by `commitment`
+    ///
+    /// O(NUM_CHUNKS · CHUNK_BITS) group operations for the range proofs.
+    pub fn verify(&self, recipient_key: &P, commitment: &DealerCommitment<P>) -> bool {
+        if self.dealer_index != commitment.dealer_index
+            || self.player_index == 0
+            || self.chunks.len() != NUM_CHUNKS
+            || self.range_proofs.len() != NUM_CHUNKS
+            || self.range_proofs.iter().any(|p| {
+                p.bits.len() != CHUNK_BITS as usize || p.bit_proofs.len() != CHUNK_BITS as usize
+            })
+        {
+            return false;
+        }
+
+        let statement = statement_digest(
+            self.dealer_index,
+            self.player_index,
+            commitment,
+            recipient_key,
+            &self.chunks,
+            &self.range_proofs,
+        );
+
+        let bit_weights: Vec<P::Scalar> =
+            (0..CHUNK_BITS).map(|i| P::Scalar::from_u32(1 << i)).collect();
+        for (k, (chunk, proof)) in self.chunks.iter().zip(&self.range_proofs).enumerate() {
+            let (rs, cs): (Vec<P>, Vec<P>) = proof.bits.iter().cloned().unzip();
+            if P::multiscalar_mul(&bit_weights, &rs) != chunk.0
+                || P::multiscalar_mul(&bit_weights, &cs) != chunk.1
+            {
+                return false;
+            }
+            for (i, (bit, bit_proof)) in proof.bits.iter().zip(&proof.bit_proofs).enumerate() {
+                if !verify_bit(&statement, k, i, recipient_key, bit, bit_proof) {
+                    return false;
+                }
+            }
+        }
+
+        let (r_total, c_total) = self.recombined();
+        let expected = commitment.evaluate_at(self.player_index);
+        let masked = c_total.add(&expected.mul_scalar(&P::Scalar::one().neg()));
+
+        let e = encryption_challenge(&statement, &self.proof_commitments);
+        let z = &self.proof_response;
+        let (a_g, a_pk) = &self.proof_commitments;
+
+        P::generator().mul_scalar(z) == a_g.add(&r_total.mul_scalar(&e))
+            && recipient_key.mul_scalar(z) == a_pk.add(&masked.mul_scalar(&e))
+    }
+
+    /// Decrypt with the player's secret key
+    ///
+    /// Fails with `InvalidResponse` if a chunk is not a 16-bit value, which
+    /// only the wrong key produces once [`verify`](Self::verify) passed.
+    pub fn decrypt(&self, secret_key: &P::Scalar) -> Result<SubShare<P::Scalar>, OsstError> {
+        if self.dealer_index == 0 || self.player_index == 0 {
+            return Err(OsstError::InvalidIndex);
+        }
+        if self.chunks.len() != NUM_CHUNKS {
+            return Err(OsstError::InvalidCommitment);
+        }
+
+        let table = BabySteps::<P>::new();
+        let neg_key = secret_key.neg();
+        let mut values = Vec::with_capacity(NUM_CHUNKS);
+        for (r, c) in &self.chunks {
+            let m_point = c.add(&r.mul_scalar(&neg_key));
+            let m = table.solve(&m_point).ok_or(OsstError::InvalidResponse)?;
+            values.push(P::Scalar::from_u32(m));
+        }
+
+        let value = recombine(&values);
+        for v in &mut values {
+            v.zeroize();
+        }
+
+        Ok(SubShare::new(self.dealer_index, self.player_index, value))
+    }
+
+    /// Serialized size in bytes
+    #[inline]
+    pub fn byte_size() -> usize {
+        let bits = NUM_CHUNKS * CHUNK_BITS as usize;
+        8 + (2 * NUM_CHUNKS + 2 + 2 * bits) * P::COMPRESSED_SIZE + (1 + 4 * bits) * 32
+    }
+
+    /// Serialize to bytes (for on-chain storage)
+    ///
+    /// [i:4][j:4][chunks][A][bit ciphertexts][z][bit proofs]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(Self::byte_size());
+        buf.extend_from_slice(&self.dealer_index.to_le_bytes());
+        buf.extend_from_slice(&self.player_index.to_le_bytes());
+        for (r, c) in &self.chunks {
+            buf.extend_from_slice(&r.compress_vec());
+            buf.extend_from_slice(&c.compress_vec());
+        }
+        buf.extend_from_slice(&self.proof_commitments.0.compress_vec());
+        buf.extend_from_slice(&self.proof_commitments.1.compress_vec());
+        for (r, c) in self.range_proofs.iter().flat_map(|p| &p.bits) {
+            buf.extend_from_slice(&r.compress_vec());
+            buf.extend_from_slice(&c.compress_vec());
+        }
+        buf.extend_from_slice(&self.proof_response.to_bytes());
+        for scalar in self.range_proofs.iter().flat_map(|p| &p.bit_proofs).flatten() {
+            buf.extend_from_slice(&scalar.to_bytes());
+        }
+        buf
+    }
+
+    /// Deserialize from bytes
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, OsstError> {
+        if bytes.len() != Self::byte_size() {
+            return Err(OsstError::InvalidCommitment);
+        }
+
+        let dealer_index = u32::from_le_bytes(bytes[0..4].try_into().unwrap());
+        let player_index = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+        if dealer_index == 0 || player_index == 0 {
+            return Err(OsstError::InvalidIndex);
+        }
+
+        let bits = NUM_CHUNKS * CHUNK_BITS as usize;
+        let scalars_start = bytes.len() - (1 + 4 * bits) * 32;
+        let mut points = bytes[8..scalars_start]
+            .chunks_exact(P::COMPRESSED_SIZE)
+            .map(|b| P::decompress_slice(b).ok_or(OsstError::InvalidCommitment));
+        let mut scalars = bytes[scalars_start..].chunks_exact(32).map(|b| {
+            let b: [u8; 32] = b.try_into().unwrap();
+            P::Scalar::from_canonical_bytes(&b).ok_or(OsstError::InvalidResponse)
+        });
+
+        let mut chunks = Vec::with_capacity(NUM_CHUNKS);
+        for _ in 0..NUM_CHUNKS {
+            let r = points.next().unwrap()?;
+            let c = points.next().unwrap()?;
+            chunks.push((r, c));
+        }
+        let proof_commitments = (points.next().unwrap()?, points.next().unwrap()?);
+        let proof_response = scalars.next().unwrap()?;
+
+        let mut range_proofs = Vec::with_capacity(NUM_CHUNKS);
+        for _ in 0..NUM_CHUNKS {
+            let mut proof = ChunkRangeProof {
+                bits: Vec::with_capacity(CHUNK_BITS as usize),
+                bit_proofs: Vec::with_capacity(CHUNK_BITS as usize),
+            };
+            for _ in 0..CHUNK_BITS {
+                proof.bits.push((points.next().unwrap()?, points.next().unwrap()?));
+            }
+            range_proofs.push(proof);
+        }
+        for proof in &mut range_proofs {
+            for _ in 0..CHUNK_BITS {
+                proof.bit_proofs.push([
+                    scalars.next().unwrap()?,
+                    scalars.next().unwrap()?,
+                    scalars.next().unwrap()?,
+                    scalars.next().unwrap()?,
+                ]);
+            }
+        }
+
+        Ok(Self {
+            dealer_index,
+            player_index,
+            chunks,
+            range_proofs,
+            proof_commitments,
+            proof_response,
+        })
+    }
+
+    /// (Σ 2^{16k} R_k, Σ 2^{16k} C_k)
+    fn recombined(&self) -> (P, P) {
+        let weights = chunk_weights::<P::Scalar>();
+        let (rs, cs): (Vec<P>, Vec<P>) = self.chunks.iter().cloned().unzip();
+        (
+            P::multiscalar_mul(&weights, &rs),
+            P::multiscalar_mul(&weights, &cs),
+        )
+    }
+}
+
+/// Statement digest:
+/// H("osst-subshare-enc-v2" || i || j || C_0..C_{t-1} || g^{f(j)} || PK || chunks || bits)
+fn statement_digest<P: OsstPoint>(
+    dealer_index: u32,
+    player_index: u32,
+    commitment: &DealerCommitment<P>,
+    recipient_key: &P,
+    chunks: &[(P, P)],
+    range_proofs: &[ChunkRangeProof<P>],
+) -> [u8; 64] {
+    let mut h = Sha512::new();
+    h.update(b"osst-subshare-enc-v2");
+    h.update(dealer_index.to_le_bytes());
+    h.update(player_index.to_le_bytes());
+    h.update((commitment.coefficients.len() as u32).to_le_bytes());
+    for coeff in &commitment.coefficients {
+        h.update(coeff.compress_vec());
+    }
+    h.update(commitment.evaluate_at(player_index).compress_vec());
+    h.update(recipient_key.compress_vec());
+    for (r, c) in chunks.iter().chain(range_proofs.iter().flat_map(|p| &p.bits)) {
+        h.update(r.compress_vec());
+        h.update(c.compress_vec());
+    }
+    h.finalize().into()
+}
+
+/// Challenge: e = H("osst-subshare-enc-v2" || statement || A)
+fn encryption_challenge<P: OsstPoint>(statement: &[u8; 64], proof_commitments: &(P, P)) -> P::Scalar {
+    let mut h = Sha512::new();
+    h.update(b"osst-subshare-enc-v2");
+    h.update(statement);
+    h.update(proof_commitments.0.compress_vec());
+    h.update(proof_commitments.1.compress_vec());
+    let hash: [u8; 64] = h.finalize().into();
+    P::Scalar::from_bytes_wide(&hash)
+}
+
+/// Challenge: e = H("osst-subshare-bit-v1" || statement || k || i || A_0 || B_0 || A_1 || B_1)
+fn bit_challenge<P: OsstPoint>(
+    statement: &[u8; 64],
+    chunk: usize,
+    bit: usize,
+    commitments: &[P; 4],
+) -> P::Scalar {
+    let mut h = Sha512::new();
+    h.update(b"osst-subshare-bit-v1");
+    h.update(statement);
+    h.update((chunk as u32).to_le_bytes());
+    h.update((bit as u32).to_le_bytes());
+    for point in commitments {
+        h.update(point.compress_vec());
+    }
+    let hash: [u8; 64] = h.finalize().into();
+    P::Scalar::from_bytes_wide(&hash)
+}
+
+/// (g^z · R^{-e}, PK^z · (C / g^b)^{-e}), the OR-proof commitments of branch b
+fn bit_commitments<P: OsstPoint>(
+    recipient_key: &P,
+    ciphertext: &(P, P),
+    b: u32,
+    e: &P::Scalar,
+    z: &P::Scalar,
+) -> (P, P) {
+    let g = P::generator();
+    let neg_e = e.neg();
+    let c = ciphertext.1.add(&g.mul_scalar(&P::Scalar::from_u32(b).neg()));
+    (
+        P::multiscalar_mul(&[z.clone(), neg_e.clone()], &[g, ciphertext.0.clone()]),
+        P::multiscalar_mul(&[z.clone(), neg_e], &[recipient_key.clone(), c]),
+    )
+}
+
+/// OR proof that `ciphertext` = (g^r, g^b · PK^r) with b ∈ {0, 1}
+///
+/// The branch of the other bit value is simulated.
+#[allow(clippy::too_many_arguments)]
+fn prove_bit<P: OsstPoint, R: rand_core::RngCore + rand_core::CryptoRng>(
+    statement: &[u8; 64],
+    chunk: usize,
+    bit: usize,
+    recipient_key: &P,
+    ciphertext: &(P, P),
+    b: bool,
+    r: &P::Scalar,
+    rng: &mut R,
+) -> [P::Scalar; 4] {
+    let (real, fake) = if b { (1, 0) } else { (0, 1) };
+    let e_fake = P::Scalar::random(rng);
+    let z_fake = P::Scalar::random(rng);
+    let simulated = bit_commitments(recipient_key, ciphertext, fake, &e_fake, &z_fake);
+
+    let mut w = P::Scalar::random(rng);
+    let honest = (P::generator().mul_scalar(&w), recipient_key.mul_scalar(&w));
+    let (first, second) = if b { (simulated, honest) } else { (honest, simulated) };
+
+    let e = bit_challenge(statement, chunk, bit, &[first.0, first.1, second.0, second.1]);
+    let e_real = e.sub(&e_fake);
+    let z_real = w.add(&e_real.mul(r));
+    w.zeroize();
+
+    let mut proof = [P::Scalar::zero(), P::Scalar::zero(), P::Scalar::zero(), P::Scalar::zero()];
+    proof[real as usize] = e_real;
+    proof[fake as usize] = e_fake;
+    proof[2 + real as usize] = z_real;
+    proof[2 + fake as usize] = z_fake;
+    proof
+}
+
+/// Check an OR proof from [`prove_bit`]: e_0 + e_1 = H(.., A_0, B_0, A_1, B_1)
+fn verify_bit<P: OsstPoint>(
+    statement: &[u8; 64],
+    chunk: usize,
+    bit: usize,
+    recipient_key: &P,
+    ciphertext: &(P, P),
+    proof: &[P::Scalar; 4],
+) -> bool {
+    let [e0, e1, z0, z1] = proof;
+    let (a0, b0) = bit_commitments(recipient_key, ciphertext, 0, e0, z0);
+    let (a1, b1) = bit_commitments(recipient_key, ciphertext, 1, e1, z1);
+    bit_challenge(statement, chunk, bit, &[a0, b0, a1, b1]) == e0.add(e1)
+}
+
+/// Σ 2^i r_i over the bits of one chunk
+fn recombine_bits<S: OsstScalar>(values: &[S]) -> S {
+    values
+        .iter()
+        .enumerate()
+        .fold(S::zero(), |acc, (i, r)| acc.add(&r.mul(&S::from_u32(1 << i))))
+}
+
+/// 2^{16k} for k in 0..NUM_CHUNKS
+fn chunk_weights<S: OsstScalar>() -> Vec<S> {
+    let base = S::from_u32(1 << CHUNK_BITS);
+    let mut weights = Vec::with_capacity(NUM_CHUNKS);
+    let mut w = S::one();
+    for _ in 0..NUM_CHUNKS {
+        weights.push(w.clone());
+        w = w.mul(&base);
+    }
+    weights
+}
+
+/// Σ 2^{16k} x_k
+fn recombine<S: OsstScalar>(values: &[S]) -> S {
+    values
+        .iter()
+        .zip(chunk_weights::<S>())
+        .fold(S::zero(), |acc, (x, w)| acc.add(&x.mul(&w)))
+}
+
+/// 16-bit chunks of a scalar, least significant first
+///
+/// Backends disagree on byte order, so it is read off the encoding of one.
+fn scalar_chunks<S: OsstScalar>(value: &S) -> [u32; NUM_CHUNKS] {
+    let mut bytes = value.to_bytes();
+    if S::one().to_bytes()[0] != 1 {
+        bytes.reverse();
+    }
+
+    let mut chunks = [0u32; NUM_CHUNKS];
+    for (chunk, pair) in chunks.iter_mut().zip(bytes.chunks_exact(2)) {
+        *chunk = u16::from_le_bytes([pair[0], pair[1]]) as u32;
+    }
+    bytes.iter_mut().for_each(|b| *b = 0);
+    chunks
+}
+
+/// Lookup table of b·g for b < 2^8, sorted by encoding
+struct BabySteps<P: OsstPoint> {
+    table: Vec<(Vec<u8>, u32)>,
+    giant: P,
+}
+
+impl<P: OsstPoint> BabySteps<P> {
+    const STEPS: u32 = 1 << (CHUNK_BITS / 2);
+
+    fn new() -> Self {
+        let g = P::generator();
+        let mut table = Vec::with_capacity(Self::STEPS as usize);
+        let mut point = P::identity();
+        for b in 0..Self::STEPS {
+            table.push((point.compress_vec(), b));
+            point = point.add(&g);
+        }
+        table.sort_unstable();
+
+        // point is now STEPS·g; giant steps subtract it
+        let giant = point.mul_scalar(&P::Scalar::one().neg());
+        Self { table, giant }
+    }
+
+    /// m < 2^16 with m·g = `target`
+    fn solve(&self, target: &P) -> Option<u32> {
+        let mut point = target.clone();
+        for a in 0..Self::STEPS {
+            let key = point.compress_vec();
+            if let Ok(pos) = self.table.binary_search_by(|(k, _)| k.as_slice().cmp(&key)) {
+                return Some(a * Self::STEPS + self.table[pos].1);
+            }
+            point = point.add(&self.giant);
+        }
+        None
+    }
 }
 
 // ============================================================================
@@ -902,4 +1887,378 @@ mod tests {
             assert_eq!(eval, expected);
         }
     }
+
+    #[test]
+    fn test_encrypted_subshare_roundtrip() {
+        let mut rng = OsRng;
+        let share = Scalar::random(&mut rng);
+        let dealer: Dealer<RistrettoPoint> = Dealer::new(2, share, 3, &mut rng);
+
+        let sk = Scalar::random(&mut rng);
+        let pk = RistrettoPoint::generator().mul_scalar(&sk);
+
+        let subshare = dealer.generate_subshare(5);
+        let encrypted = EncryptedSubShare::encrypt(&subshare, &pk, dealer.commitment(), &mut rng);
+        assert!(encrypted.verify(&pk, dealer.commitment()));
+
+        let bytes = encrypted.to_bytes();
+        assert_eq!(bytes.len(), EncryptedSubShare::<RistrettoPoint>::byte_size());
+        assert_eq!(bytes.len(), 50280);
+        let decoded = EncryptedSubShare::<RistrettoPoint>::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, encrypted);
+
+        let decrypted = decoded.decrypt(&sk).unwrap();
+        assert_eq!(decrypted.dealer_index, 2);
+        assert_eq!(decrypted.player_index, 5);
+        assert_eq!(decrypted.value(), subshare.value());
+    }
+
+    #[test]
+    fn test_encrypted_subshare_rejects_tampering() {
+        let mut rng = OsRng;
+        let dealer: Dealer<RistrettoPoint> = Dealer::new(1, Scalar::random(&mut rng), 2, &mut rng);
+        let other: Dealer<RistrettoPoint> = Dealer::new(1, Scalar::random(&mut rng), 2, &mut rng);
+
+        let sk = Scalar::random(&mut rng);
+        let pk = RistrettoPoint::generator().mul_scalar(&sk);
+        let other_pk = RistrettoPoint::random(&mut rng);
+
+        let encrypted =
+            EncryptedSubShare::encrypt(&dealer.generate_subshare(3), &pk, dealer.commitment(), &mut rng);
+        assert!(!encrypted.verify(&pk, other.commitment()));
+        assert!(!encrypted.verify(&other_pk, dealer.commitment()));
+
+        // sub-share of another dealer's polynomial under the right index
+        let forged = SubShare::new(1, 3, *other.generate_subshare(3).value());
+        let forged = EncryptedSubShare::encrypt(&forged, &pk, dealer.commitment(), &mut rng);
+        assert!(!forged.verify(&pk, dealer.commitment()));
+
+        let mut swapped = encrypted.clone();
+        swapped.chunks.swap(0, 1);
+        swapped.range_proofs.swap(0, 1);
+        assert!(!swapped.verify(&pk, dealer.commitment()));
+
+        // bit proofs are bound to their position
+        let mut moved = encrypted.clone();
+        moved.range_proofs[0].bit_proofs.swap(0, 1);
+        assert!(!moved.verify(&pk, dealer.commitment()));
+
+        let mut reindexed = encrypted.clone();
+        reindexed.player_index = 4;
+        assert!(!reindexed.verify(&pk, dealer.commitment()));
+
+        // the wrong key does not yield 16-bit chunks
+        assert!(matches!(
+            encrypted.decrypt(&Scalar::random(&mut rng)),
+            Err(OsstError::InvalidResponse)
+        ));
+    }
+
+    #[test]
+    fn test_oversized_chunk_rejected() {
+        let mut rng = OsRng;
+        let dealer: Dealer<RistrettoPoint> = Dealer::new(1, Scalar::random(&mut rng), 2, &mut rng);
+        let subshare = dealer.generate_subshare(2);
+
+        let sk = Scalar::random(&mut rng);
+        let pk = RistrettoPoint::generator().mul_scalar(&sk);
+        let g = RistrettoPoint::generator();
+
+        // whole value in the lowest bit of the lowest chunk: recombines
+        // correctly and the Chaum-Pedersen proof holds, but no bit proof does
+        let mut randomness = Vec::new();
+        let mut chunks = Vec::new();
+        let mut range_proofs = Vec::new();
+        for k in 0..NUM_CHUNKS {
+            let m = if k == 0 { *subshare.value() } else { Scalar::ZERO };
+            let r = Scalar::random(&mut rng);
+            let mut bits = vec![(g * r, g * m + pk * r)];
+            let zero = <RistrettoPoint as OsstPoint>::identity();
+            bits.resize(CHUNK_BITS as usize, (zero, zero));
+            chunks.push(bits[0]);
+            range_proofs.push(ChunkRangeProof { bits, bit_proofs: Vec::new() });
+            randomness.push(r);
+        }
+
+        let statement = statement_digest(1, 2, dealer.commitment(), &pk, &chunks, &range_proofs);
+        for (k, proof) in range_proofs.iter_mut().enumerate() {
+            proof.bit_proofs = (0..CHUNK_BITS as usize)
+                .map(|i| {
+                    let r = if i == 0 { randomness[k] } else { Scalar::ZERO };
+                    prove_bit(&statement, k, i, &pk, &proof.bits[i], i == 0 && k == 0, &r, &mut rng)
+                })
+                .collect();
+        }
+        let w = Scalar::random(&mut rng);
+        let proof_commitments = (g * w, pk * w);
+        let e: Scalar = encryption_challenge(&statement, &proof_commitments);
+        let encrypted = EncryptedSubShare {
+            dealer_index: 1,
+            player_index: 2,
+            chunks,
+            range_proofs,
+            proof_commitments,
+            proof_response: w + e * recombine(&randomness),
+        };
+
+        assert!(!encrypted.verify(&pk, dealer.commitment()));
+        assert!(matches!(encrypted.decrypt(&sk), Err(OsstError::InvalidResponse)));
+
+        // only the oversized bit is at fault: every other bit proof holds
+        let proof = &encrypted.range_proofs[0];
+        assert!(!verify_bit(&statement, 0, 0, &pk, &proof.bits[0], &proof.bit_proofs[0]));
+        assert!(verify_bit(&statement, 0, 1, &pk, &proof.bits[1], &proof.bit_proofs[1]));
+    }
+
+    #[test]
+    fn test_scalar_chunks_recombine() {
+        let mut rng = OsRng;
+        for value in [Scalar::ZERO, Scalar::ONE, -Scalar::ONE, Scalar::random(&mut rng)] {
+            let chunks = scalar_chunks(&value);
+            assert!(chunks.iter().all(|&c| c < 1 << CHUNK_BITS));
+            let scalars: Vec<Scalar> = chunks.iter().map(|&c| Scalar::from(c)).collect();
+            assert_eq!(recombine(&scalars), value);
+        }
+    }
+
+    fn verification_shares(shares: &[SecretShare<Scalar>]) -> BTreeMap<u32, RistrettoPoint> {
+        shares
+            .iter()
+            .map(|s| (s.index, RistrettoPoint::generator().mul_scalar(s.scalar())))
+            .collect()
+    }
+
+    #[test]
+    fn test_refresh_preserves_group_key() {
+        let mut rng = OsRng;
+        let secret = Scalar::random(&mut rng);
+        let group_key = RistrettoPoint::generator().mul_scalar(&secret);
+        let (n, t) = (5u32, 3u32);
+        let old_shares = shamir_split(&secret, n, t);
+        let old_vshares = verification_shares(&old_shares);
+
+        // custodians 1, 2 and 4 deal refresh polynomials
+        let dealers: Vec<Dealer<RistrettoPoint>> = [1u32, 2, 4]
+            .iter()
+            .map(|&i| Dealer::new_refresh(i, t, &mut rng))
+            .collect();
+        let commitments: Vec<&DealerCommitment<RistrettoPoint>> =
+            dealers.iter().map(|d| d.commitment()).collect();
+        let transcript = RotationTranscript::refresh(7, &commitments).unwrap();
+        assert!(transcript.verify(&group_key, &old_vshares, t).unwrap());
+        assert!(transcript.verify_against(&commitments));
+
+        let mut new_shares = Vec::new();
+        for old in &old_shares {
+            let mut refresher = Refresher::<RistrettoPoint>::new(old.index);
+            for dealer in &dealers {
+                let sub = dealer.generate_subshare(old.index);
+                assert!(refresher.add_subshare(sub, dealer.commitment()).unwrap());
+            }
+            let refreshed = refresher.finalize(old.scalar(), &transcript).unwrap();
+            assert_ne!(&refreshed, old.scalar());
+
+            let expected = transcript
+                .next_verification_share(old.index, old_vshares.get(&old.index))
+                .unwrap();
+            assert_eq!(RistrettoPoint::generator().mul_scalar(&refreshed), expected);
+            new_shares.push(SecretShare::new(old.index, refreshed));
+        }
+
+        // any t refreshed shares still reconstruct the secret
+        let subset = [&new_shares[0], &new_shares[3], &new_shares[4]];
+        let indices: Vec<u32> = subset.iter().map(|s| s.index).collect();
+        let lagrange = compute_lagrange_coefficients::<Scalar>(&indices).unwrap();
+        let reconstructed: Scalar = subset
+            .iter()
+            .zip(&lagrange)
+            .map(|(s, l)| s.scalar() * l)
+            .sum();
+        assert_eq!(reconstructed, secret);
+    }
+
+    #[test]
+    fn test_refresh_rejects_nonzero_constant() {
+        let mut rng = OsRng;
+        let cheat: Dealer<RistrettoPoint> = Dealer::new(2, Scalar::random(&mut rng), 3, &mut rng);
+
+        let mut refresher = Refresher::<RistrettoPoint>::new(1);
+        assert!(matches!(
+            refresher.add_subshare(cheat.generate_subshare(1), cheat.commitment()),
+            Err(OsstError::InvalidCommitment)
+        ));
+        assert!(matches!(
+            RotationTranscript::refresh(1, &[cheat.commitment()]),
+            Err(OsstError::InvalidCommitment)
+        ));
+
+        // a refresher missing a transcript dealer's sub-share cannot finalize
+        let honest: Dealer<RistrettoPoint> = Dealer::new_refresh(3, 3, &mut rng);
+        let other: Dealer<RistrettoPoint> = Dealer::new_refresh(4, 3, &mut rng);
+        let transcript =
+            RotationTranscript::refresh(1, &[honest.commitment(), other.commitment()]).unwrap();
+        refresher
+            .add_subshare(honest.generate_subshare(1), honest.commitment())
+            .unwrap();
+        assert!(matches!(
+            refresher.finalize(&Scalar::ONE, &transcript),
+            Err(OsstError::InsufficientContributions { got: 1, need: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_reshare_transcript() {
+        let mut rng = OsRng;
+        let secret = Scalar::random(&mut rng);
+        let group_key = RistrettoPoint::generator().mul_scalar(&secret);
+        let old_shares = shamir_split(&secret, 5, 3);
+        let old_vshares = verification_shares(&old_shares);
+
+        let mut state = ReshareState::new(2, 5, 3, 4, 6, group_key);
+        let dealers: Vec<Dealer<RistrettoPoint>> = old_shares[1..4]
+            .iter()
+            .map(|s| Dealer::new(s.index, *s.scalar(), 4, &mut rng))
+            .collect();
+        for d in &dealers {
+            state.submit_commitment(d.commitment().clone()).unwrap();
+        }
+
+        let transcript = state.transcript().unwrap();
+        assert_eq!(transcript.dealers, vec![2, 3, 4]);
+        assert_eq!(transcript.threshold(), 4);
+        assert!(transcript.verify(&group_key, &old_vshares, 3).unwrap());
+        assert!(transcript.verify_against(&state.get_commitments()));
+
+        // new verification shares match aggregated shares
+        for j in 1..=6u32 {
+            let mut agg = Aggregator::<RistrettoPoint>::new(j);
+            for d in &dealers {
+                agg.add_subshare(d.generate_subshare(j), d.commitment().clone())
+                    .unwrap();
+            }
+            let share = agg.finalize(3, &group_key).unwrap();
+            assert_eq!(
+                transcript.next_verification_share(j, None).unwrap(),
+                RistrettoPoint::generator().mul_scalar(&share)
+            );
+        }
+
+        let decoded = RotationTranscript::<RistrettoPoint>::from_bytes(&transcript.to_bytes()).unwrap();
+        assert_eq!(decoded, transcript);
+        assert_eq!(decoded.digest(), transcript.digest());
+    }
+
+    #[test]
+    fn test_reshare_transcript_detects_wrong_constant() {
+        let mut rng = OsRng;
+        let secret = Scalar::random(&mut rng);
+        let group_key = RistrettoPoint::generator().mul_scalar(&secret);
+        let old_shares = shamir_split(&secret, 3, 2);
+        let old_vshares = verification_shares(&old_shares);
+
+        // dealer 2 reshares a value other than its share
+        let d1: Dealer<RistrettoPoint> = Dealer::new(1, *old_shares[0].scalar(), 2, &mut rng);
+        let d2: Dealer<RistrettoPoint> = Dealer::new(2, Scalar::random(&mut rng), 2, &mut rng);
+        let transcript = RotationTranscript::reshare(1, &[d1.commitment(), d2.commitment()]).unwrap();
+        assert!(!transcript.verify(&group_key, &old_vshares, 2).unwrap());
+
+        // too few dealers
+        let short = RotationTranscript::reshare(1, &[d1.commitment()]).unwrap();
+        assert!(matches!(
+            short.verify(&group_key, &old_vshares, 2),
+            Err(OsstError::InsufficientContributions { got: 1, need: 2 })
+        ));
+
+        // refresh transcripts round-trip without their identity terms
+        let r: Dealer<RistrettoPoint> = Dealer::new_refresh(1, 2, &mut rng);
+        let refresh = RotationTranscript::refresh(1, &[r.commitment()]).unwrap();
+        let decoded = RotationTranscript::<RistrettoPoint>::from_bytes(&refresh.to_bytes()).unwrap();
+        assert_eq!(decoded, refresh);
+
+        // counts beyond the input are refused before any size is computed,
+        // including ones whose byte lengths wrap a 32-bit usize
+        for (dealers, threshold) in [(u32::MAX, 2), (1, u32::MAX), (0x4000_0000, 2), (1, 0x0800_0001)] {
+            let mut crafted = refresh.to_bytes();
+            crafted[9..13].copy_from_slice(&dealers.to_le_bytes());
+            crafted[13..17].copy_from_slice(&threshold.to_le_bytes());
+            assert!(RotationTranscript::<RistrettoPoint>::from_bytes(&crafted).is_err());
+        }
+
+        // a refresh cannot change the threshold
+        let same: Vec<Dealer<RistrettoPoint>> =
+            (1..=2).map(|i| Dealer::new_refresh(i, 2, &mut rng)).collect();
+        let same = RotationTranscript::refresh(1, &[same[0].commitment(), same[1].commitment()]).unwrap();
+        assert!(same.verify(&group_key, &old_vshares, 2).unwrap());
+        let wider: Vec<Dealer<RistrettoPoint>> =
+            (1..=2).map(|i| Dealer::new_refresh(i, 3, &mut rng)).collect();
+        let wider = RotationTranscript::refresh(1, &[wider[0].commitment(), wider[1].commitment()]).unwrap();
+        assert!(!wider.verify(&group_key, &old_vshares, 2).unwrap());
+    }
+}
+
+#[cfg(all(test, feature = "pallas"))]
+mod pallas_tests {
+    use super::*;
+    use pasta_curves::{group::ff::Field, pallas::{Point, Scalar}};
+    use rand::rngs::OsRng;
+
+    #[test]
+    fn test_pallas_encrypted_subshare() {
+        let mut rng = OsRng;
+        let dealer: Dealer<Point> = Dealer::new(1, <Scalar as Field>::random(&mut rng), 2, &mut rng);
+        let sk = <Scalar as Field>::random(&mut rng);
+        let pk = Point::generator().mul_scalar(&sk);
+
+        let subshare = dealer.generate_subshare(7);
+        let encrypted = EncryptedSubShare::encrypt(&subshare, &pk, dealer.commitment(), &mut rng);
+        let decoded = EncryptedSubShare::<Point>::from_bytes(&encrypted.to_bytes()).unwrap();
+        assert!(decoded.verify(&pk, dealer.commitment()));
+        assert_eq!(decoded.decrypt(&sk).unwrap().value(), subshare.value());
+    }
+}
+
+#[cfg(all(test, feature = "secp256k1"))]
+mod secp256k1_tests {
+    use super::*;
+    use k256::{ProjectivePoint, Scalar};
+    use rand::rngs::OsRng;
+
+    #[test]
+    fn test_secp256k1_encrypted_subshare() {
+        let mut rng = OsRng;
+        let dealer: Dealer<ProjectivePoint> =
+            Dealer::new(1, <Scalar as OsstScalar>::random(&mut rng), 2, &mut rng);
+        let sk = <Scalar as OsstScalar>::random(&mut rng);
+        let pk = ProjectivePoint::GENERATOR * sk;
+
+        let subshare = dealer.generate_subshare(7);
+        let encrypted = EncryptedSubShare::encrypt(&subshare, &pk, dealer.commitment(), &mut rng);
+        let bytes = encrypted.to_bytes();
+        assert_eq!(bytes.len(), 8 + 546 * 33 + 1025 * 32);
+
+        let decoded = EncryptedSubShare::<ProjectivePoint>::from_bytes(&bytes).unwrap();
+        assert!(decoded.verify(&pk, dealer.commitment()));
+        assert_eq!(decoded.decrypt(&sk).unwrap().value(), subshare.value());
+    }
+
+    #[test]
+    fn test_secp256k1_transcript_keeps_y_parity() {
+        let mut rng = OsRng;
+        let dealers: Vec<Dealer<ProjectivePoint>> = (1..=3)
+            .map(|i| Dealer::new(i, <Scalar as OsstScalar>::random(&mut rng), 3, &mut rng))
+            .collect();
+        let commitments: Vec<_> = dealers.iter().map(|d| d.commitment()).collect();
+        let transcript = RotationTranscript::reshare(1, &commitments).unwrap();
+
+        let bytes = transcript.to_bytes();
+        assert_eq!(bytes.len(), 17 + 4 * 3 + 33 * (3 + 3));
+        let decoded = RotationTranscript::<ProjectivePoint>::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, transcript);
+
+        // negating a point flips only its parity byte, which the digest sees
+        let mut flipped = transcript.clone();
+        flipped.combined[1] = -flipped.combined[1];
+        assert_ne!(flipped.digest(), transcript.digest());
+        assert_eq!(RotationTranscript::<ProjectivePoint>::from_bytes(&flipped.to_bytes()).unwrap(), flipped);
+    }
 }
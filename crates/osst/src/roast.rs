@@ -0,0 +1,517 @@
+//! ROAST: robust asynchronous FROST signing
+//!
+//! [`frost::sign`] and [`frost::aggregate`] need every signer in a
+//! [`SigningPackage`] to answer. ROAST wraps them in a coordinator that keeps
+//! several sessions open at once, so a signer that stops responding after
+//! Round 1 only stalls its own session, and a bad [`SignatureShare`] gets
+//! its sender excluded from all later sessions.
+//!
+//! Reference: Ruffing, Ronge, Jin, Schneider-Bensch, Schröder, "ROAST:
+//! Robust Asynchronous Schnorr Threshold Signatures" (CCS 2022).
+//!
+//! # Protocol
+//!
+//! ```text
+//! Signer i sends an initial commitment; i is now ready.
+//!
+//! Whenever t signers are ready:
+//!     open a session with their latest commitments; they are no longer ready.
+//!
+//! When signer i answers its session with (z_i, next commitment):
+//!     if z_i is invalid, i is malicious and ignored from then on.
+//!     otherwise store z_i; i is ready again with the next commitment.
+//!     once the session has all t shares, aggregate and stop.
+//! ```
+//!
+//! Each signer is in at most one open session and every session start uses
+//! t signers that have just answered, so with t honest signers online some
+//! session completes after at most n - t + 1 sessions.
+//!
+//! [`frost::sign`]: crate::frost::sign
+//! [`frost::aggregate`]: crate::frost::aggregate
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec::Vec;
+
+use crate::curve::OsstPoint;
+use crate::error::OsstError;
+use crate::frost::{
+    self, Nonces, Signature, SignatureShare, SigningCommitments, SigningPackage,
+};
+use crate::SecretShare;
+
+/// Session identifier, assigned in order from 0
+pub type SessionId = u64;
+
+/// Outcome of feeding a message to the [`Coordinator`]
+#[derive(Clone, Debug)]
+pub enum Progress<P: OsstPoint> {
+    /// Nothing new to send
+    Waiting,
+    /// A session started; send its package to its signers
+    Started(SessionId),
+    /// The signer sent an invalid share and is now excluded
+    Misbehaved(u32),
+    /// A session completed with this signature
+    Signed(Signature<P>),
+}
+
+struct Session<P: OsstPoint> {
+    package: SigningPackage<P>,
+    shares: BTreeMap<u32, SignatureShare<P::Scalar>>,
+}
+
+/// ROAST coordinator for one message
+///
+/// Untrusted: it sees only public commitments and shares, and every share
+/// is checked against the signer's verification share before it counts.
+pub struct Coordinator<P: OsstPoint> {
+    group_pubkey: P,
+    threshold: u32,
+    message: Vec<u8>,
+    verification_shares: BTreeMap<u32, P>,
+    /// Latest unused commitment of each ready signer
+    ready: BTreeMap<u32, SigningCommitments<P>>,
+    malicious: BTreeSet<u32>,
+    /// Open session of each signer that has not answered yet
+    awaiting: BTreeMap<u32, SessionId>,
+    sessions: Vec<Session<P>>,
+    signature: Option<Signature<P>>,
+}
+
+impl<P: OsstPoint> Coordinator<P> {
+    /// Coordinate signing of `message` by any `threshold` of the signers in
+    /// `verification_shares` (index → Y_i = g^{s_i}).
+    pub fn new(
+        group_pubkey: P,
+        threshold: u32,
+        message: Vec<u8>,
+        verification_shares: BTreeMap<u32, P>,
+    ) -> Result<Self, OsstError> {
+        if threshold == 0 || verification_shares.contains_key(&0) {
+            return Err(OsstError::InvalidIndex);
+        }
+        if verification_shares.len() < threshold as usize {
+            return Err(OsstError::InsufficientContributions {
+                got: verification_shares.len(),
+                need: threshold as usize,
+            });
+        }
+
+        Ok(Self {
+            group_pubkey,
+            threshold,
+            message,
+            verification_shares,
+            ready: BTreeMap::new(),
+            malicious: BTreeSet::new(),
+            awaiting: BTreeMap::new(),
+            sessions: Vec::new(),
+            signature: None,
+        })
+    }
+
+    /// Accept a signer's initial commitment.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidIndex` if the signer is unknown or malicious
+    /// - `DuplicateIndex` if the signer is already ready or in a session
+    /// - `WrongPhase` once a signature has been produced
+    pub fn add_commitment(
+        &mut self,
+        commitments: SigningCommitments<P>,
+    ) -> Result<Progress<P>, OsstError> {
+        if self.signature.is_some() {
+            return Err(OsstError::WrongPhase);
+        }
+
+        let index = commitments.index;
+        if !self.verification_shares.contains_key(&index) || self.malicious.contains(&index) {
+            return Err(OsstError::InvalidIndex);
+        }
+        if self.ready.contains_key(&index) || self.awaiting.contains_key(&index) {
+            return Err(OsstError::DuplicateIndex(index));
+        }
+
+        self.ready.insert(index, commitments);
+        self.maybe_start()
+    }
+
+    /// Accept a signer's answer to `session`: its share and its commitment
+    /// for the next session.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidIndex` if the signer was not awaited in `session`, or the
+    ///   next commitment is for another index
+    /// - `WrongPhase` once a signature has been produced
+    pub fn add_share(
+        &mut self,
+        session: SessionId,
+        share: SignatureShare<P::Scalar>,
+        next: SigningCommitments<P>,
+    ) -> Result<Progress<P>, OsstError> {
+        if self.signature.is_some() {
+            return Err(OsstError::WrongPhase);
+        }
+
+        let index = share.index;
+        if self.awaiting.get(&index) != Some(&session) || next.index != index {
+            return Err(OsstError::InvalidIndex);
+        }
+        self.awaiting.remove(&index);
+
+        let yi = &self.verification_shares[&index];
+        let open = &mut self.sessions[session as usize];
+        if !frost::verify_share(&open.package, &share, &self.group_pubkey, yi)? {
+            self.malicious.insert(index);
+            return Ok(Progress::Misbehaved(index));
+        }
+
+        open.shares.insert(index, share);
+        if open.shares.len() == open.package.num_signers() {
+            let shares: Vec<SignatureShare<P::Scalar>> =
+                core::mem::take(&mut open.shares).into_values().collect();
+            let signature = frost::aggregate(&open.package, &shares, &self.group_pubkey, None)?;
+            self.signature = Some(signature.clone());
+            return Ok(Progress::Signed(signature));
+        }
+
+        self.ready.insert(index, next);
+        self.maybe_start()
+    }
+
+    /// Package of `session` to send to its signers
+    pub fn package(&self, session: SessionId) -> Option<&SigningPackage<P>> {
+        self.sessions.get(session as usize).map(|s| &s.package)
+    }
+
+    /// Signers that received a package and have not answered, ascending
+    pub fn unresponsive(&self) -> Vec<u32> {
+        self.awaiting.keys().copied().collect()
+    }
+
+    /// Signers excluded for invalid shares, ascending
+    pub fn malicious(&self) -> Vec<u32> {
+        self.malicious.iter().copied().collect()
+    }
+
+    /// Number of sessions started so far
+    pub fn num_sessions(&self) -> usize {
+        self.sessions.len()
+    }
+
+    /// True while enough non-malicious signers remain to finish
+    pub fn can_finish(&self) -> bool {
+        self.verification_shares.len() - self.malicious.len() >= self.threshold as usize
+    }
+
+    /// The signature, once a session has completed
+    pub fn signature(&self) -> Option<&Signature<P>> {
+        self.signature.as_ref()
+    }
+
+    fn maybe_start(&mut self) -> Result<Progress<P>, OsstError> {
+        if self.ready.len() < self.threshold as usize {
+            return Ok(Progress::Waiting);
+        }
+
+        let commitments: Vec<SigningCommitments<P>> =
+            core::mem::take(&mut self.ready).into_values().collect();
+        let package = SigningPackage::new(self.message.clone(), commitments)?;
+
+        let id = self.sessions.len() as SessionId;
+        for index in package.signer_indices() {
+            self.awaiting.insert(index, id);
+        }
+        self.sessions.push(Session {
+            package,
+            shares: BTreeMap::new(),
+        });
+
+        Ok(Progress::Started(id))
+    }
+}
+
+impl<P: OsstPoint> core::fmt::Debug for Coordinator<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("roast::Coordinator")
+            .field("threshold", &self.threshold)
+            .field("sessions", &self.sessions.len())
+            .field("ready", &self.ready.keys().collect::<Vec<_>>())
+            .field("awaiting", &self.awaiting)
+            .field("malicious", &self.malicious)
+            .field("signed", &self.signature.is_some())
+            .finish()
+    }
+}
+
+/// Signer side of ROAST: keeps exactly one outstanding nonce pair
+///
+/// The signer fixes the message it is willing to sign up front, so a
+/// coordinator cannot swap in a different one between sessions.
+pub struct Signer<P: OsstPoint> {
+    share: SecretShare<P::Scalar>,
+    group_pubkey: P,
+    message: Vec<u8>,
+    pending: Option<(Nonces<P::Scalar>, SigningCommitments<P>)>,
+}
+
+impl<P: OsstPoint> Signer<P> {
+    pub fn new(share: SecretShare<P::Scalar>, group_pubkey: P, message: Vec<u8>) -> Self {
+        Self {
+            share,
+            group_pubkey,
+            message,
+            pending: None,
+        }
+    }
+
+    #[inline]
+    pub fn index(&self) -> u32 {
+        self.share.index
+    }
+
+    /// Fresh commitment for [`Coordinator::add_commitment`], discarding any
+    /// outstanding nonces
+    pub fn commit<R: rand_core::RngCore + rand_core::CryptoRng>(
+        &mut self,
+        rng: &mut R,
+    ) -> SigningCommitments<P> {
+        let (nonces, commitments) = frost::commit::<P, R>(self.share.index, rng);
+        self.pending = Some((nonces, commitments.clone()));
+        commitments
+    }
+
+    /// Sign `package` and commit for the next session.
+    ///
+    /// The package must carry this signer's outstanding commitment, so a
+    /// nonce pair is never used twice.
+    ///
+    /// # Errors
+    ///
+    /// - `MessageMismatch` if the package carries a different message than
+    ///   the one given to [`Signer::new`]; the outstanding nonces are kept
+    /// - `InvalidCommitment` if there is no outstanding commitment or the
+    ///   package carries a different one
+    pub fn sign<R: rand_core::RngCore + rand_core::CryptoRng>(
+        &mut self,
+        package: &SigningPackage<P>,
+        rng: &mut R,
+    ) -> Result<(SignatureShare<P::Scalar>, SigningCommitments<P>), OsstError> {
+        if package.message() != self.message.as_slice() {
+            return Err(OsstError::MessageMismatch);
+        }
+
+        let expected = match (&self.pending, package.get_commitments(self.share.index)) {
+            (Some((_, ours)), Some(theirs)) => {
+                ours.hiding == theirs.hiding && ours.binding == theirs.binding
+            }
+            _ => false,
+        };
+        if !expected {
+            return Err(OsstError::InvalidCommitment);
+        }
+
+        let (nonces, _) = self.pending.take().expect("checked above");
+        let share = frost::sign(package, nonces, &self.share, &self.group_pubkey)?;
+        Ok((share, self.commit(rng)))
+    }
+}
+
+impl<P: OsstPoint> core::fmt::Debug for Signer<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("roast::Signer")
+            .field("index", &self.share.index)
+            .field("pending", &self.pending.is_some())
+            .finish()
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(all(test, feature = "ristretto255"))]
+mod tests {
+    use super::*;
+    use crate::frost::verify_signature;
+    use curve25519_dalek::{ristretto::RistrettoPoint, scalar::Scalar};
+    use rand::rngs::OsRng;
+
+    const MESSAGE: &[u8] = b"escrow payout";
+
+    type Setup = (RistrettoPoint, Coordinator<RistrettoPoint>, Vec<Signer<RistrettoPoint>>);
+
+    fn setup(n: u32, t: u32) -> Setup {
+        let mut rng = OsRng;
+        let coeffs: Vec<Scalar> = (0..t).map(|_| Scalar::random(&mut rng)).collect();
+        let group_pubkey = RistrettoPoint::generator().mul_scalar(&coeffs[0]);
+
+        let mut vshares = BTreeMap::new();
+        let mut signers = Vec::new();
+        for i in 1..=n {
+            let x = Scalar::from(i);
+            let s = coeffs.iter().rev().fold(Scalar::ZERO, |acc, c| acc * x + c);
+            vshares.insert(i, RistrettoPoint::generator().mul_scalar(&s));
+            signers.push(Signer::new(SecretShare::new(i, s), group_pubkey, MESSAGE.to_vec()));
+        }
+
+        let coordinator = Coordinator::new(group_pubkey, t, MESSAGE.to_vec(), vshares).unwrap();
+        (group_pubkey, coordinator, signers)
+    }
+
+    fn answer(
+        coordinator: &mut Coordinator<RistrettoPoint>,
+        signer: &mut Signer<RistrettoPoint>,
+        session: SessionId,
+    ) -> Progress<RistrettoPoint> {
+        let package = coordinator.package(session).unwrap();
+        let (share, next) = signer.sign(package, &mut OsRng).unwrap();
+        coordinator.add_share(session, share, next).unwrap()
+    }
+
+    #[test]
+    fn test_roast_all_honest() {
+        let (group_pubkey, mut coordinator, mut signers) = setup(5, 3);
+
+        let mut started = None;
+        for signer in &mut signers[..3] {
+            if let Progress::Started(id) = coordinator.add_commitment(signer.commit(&mut OsRng)).unwrap() {
+                started = Some(id);
+            }
+        }
+        assert_eq!(started, Some(0));
+        assert_eq!(coordinator.unresponsive(), vec![1, 2, 3]);
+
+        assert!(matches!(answer(&mut coordinator, &mut signers[0], 0), Progress::Waiting));
+        assert!(matches!(answer(&mut coordinator, &mut signers[1], 0), Progress::Waiting));
+        let signature = match answer(&mut coordinator, &mut signers[2], 0) {
+            Progress::Signed(signature) => signature,
+            other => panic!("expected signature, got {:?}", other),
+        };
+
+        assert!(verify_signature(&group_pubkey, MESSAGE, &signature));
+        assert_eq!(coordinator.num_sessions(), 1);
+        assert!(matches!(
+            coordinator.add_commitment(signers[3].commit(&mut OsRng)),
+            Err(OsstError::WrongPhase)
+        ));
+    }
+
+    #[test]
+    fn test_roast_survives_unresponsive_signer() {
+        let (group_pubkey, mut coordinator, mut signers) = setup(5, 3);
+
+        // session 0 = {1, 2, 3}; signer 2 goes offline after Round 1
+        for signer in &mut signers[..3] {
+            coordinator.add_commitment(signer.commit(&mut OsRng)).unwrap();
+        }
+        for signer in &mut signers[3..] {
+            let progress = coordinator.add_commitment(signer.commit(&mut OsRng)).unwrap();
+            assert!(matches!(progress, Progress::Waiting));
+        }
+
+        // signer 1 answers and is ready again with 4 and 5: session 1
+        assert!(matches!(answer(&mut coordinator, &mut signers[0], 0), Progress::Started(1)));
+        assert!(matches!(answer(&mut coordinator, &mut signers[2], 0), Progress::Waiting));
+        assert_eq!(coordinator.unresponsive(), vec![1, 2, 4, 5]);
+
+        // 3, 4 and 5 are ready: a third session opens alongside
+        assert!(matches!(answer(&mut coordinator, &mut signers[3], 1), Progress::Waiting));
+        assert!(matches!(answer(&mut coordinator, &mut signers[4], 1), Progress::Started(2)));
+        let signature = match answer(&mut coordinator, &mut signers[0], 1) {
+            Progress::Signed(signature) => signature,
+            other => panic!("expected signature, got {:?}", other),
+        };
+
+        assert!(verify_signature(&group_pubkey, MESSAGE, &signature));
+        assert_eq!(coordinator.num_sessions(), 3);
+        assert_eq!(coordinator.unresponsive(), vec![2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_roast_excludes_bad_share() {
+        let (group_pubkey, mut coordinator, mut signers) = setup(4, 3);
+
+        for signer in &mut signers[..3] {
+            coordinator.add_commitment(signer.commit(&mut OsRng)).unwrap();
+        }
+
+        // signer 3 answers with garbage
+        let package = coordinator.package(0).unwrap();
+        let (mut bad, next) = signers[2].sign(package, &mut OsRng).unwrap();
+        bad.response += Scalar::ONE;
+        assert!(matches!(
+            coordinator.add_share(0, bad, next.clone()),
+            Ok(Progress::Misbehaved(3))
+        ));
+        assert_eq!(coordinator.malicious(), vec![3]);
+        assert!(coordinator.can_finish());
+
+        // it cannot come back, neither in its session nor as a fresh signer
+        let retry = SignatureShare { index: 3, response: Scalar::ONE };
+        assert!(coordinator.add_share(0, retry, next).is_err());
+        assert!(matches!(
+            coordinator.add_commitment(signers[2].commit(&mut OsRng)),
+            Err(OsstError::InvalidIndex)
+        ));
+
+        // session 0 stalls; 1, 2 and the late signer 4 finish in session 1
+        assert!(matches!(answer(&mut coordinator, &mut signers[0], 0), Progress::Waiting));
+        assert!(matches!(answer(&mut coordinator, &mut signers[1], 0), Progress::Waiting));
+        assert!(matches!(
+            coordinator.add_commitment(signers[3].commit(&mut OsRng)).unwrap(),
+            Progress::Started(1)
+        ));
+        assert_eq!(coordinator.package(1).unwrap().signer_indices(), vec![1, 2, 4]);
+
+        answer(&mut coordinator, &mut signers[0], 1);
+        answer(&mut coordinator, &mut signers[1], 1);
+        let signature = match answer(&mut coordinator, &mut signers[3], 1) {
+            Progress::Signed(signature) => signature,
+            other => panic!("expected signature, got {:?}", other),
+        };
+        assert!(verify_signature(&group_pubkey, MESSAGE, &signature));
+    }
+
+    #[test]
+    fn test_signer_refuses_stale_commitment() {
+        let (_, mut coordinator, mut signers) = setup(3, 2);
+
+        coordinator.add_commitment(signers[0].commit(&mut OsRng)).unwrap();
+        coordinator.add_commitment(signers[1].commit(&mut OsRng)).unwrap();
+
+        // signer 1 re-commits, so the package no longer matches its nonces
+        signers[0].commit(&mut OsRng);
+        let package = coordinator.package(0).unwrap();
+        assert!(matches!(
+            signers[0].sign(package, &mut OsRng),
+            Err(OsstError::InvalidCommitment)
+        ));
+
+        // and signing twice with the same package is refused
+        signers[1].sign(package, &mut OsRng).unwrap();
+        assert!(matches!(
+            signers[1].sign(package, &mut OsRng),
+            Err(OsstError::InvalidCommitment)
+        ));
+    }
+
+    #[test]
+    fn test_signer_refuses_other_message() {
+        let (_, _, mut signers) = setup(3, 2);
+
+        let commitments = vec![signers[0].commit(&mut OsRng), signers[1].commit(&mut OsRng)];
+        let forged = SigningPackage::new(b"pay the coordinator".to_vec(), commitments.clone()).unwrap();
+        assert!(matches!(
+            signers[0].sign(&forged, &mut OsRng),
+            Err(OsstError::MessageMismatch)
+        ));
+
+        // the nonces survive, so the honest package still signs
+        let honest = SigningPackage::new(MESSAGE.to_vec(), commitments).unwrap();
+        signers[0].sign(&honest, &mut OsRng).unwrap();
+    }
+}
@@ -0,0 +1,749 @@
+//! Weighted parties
+//!
+//! A party with weight w owns w consecutive Shamir indices of one sharing
+//! and counts for w towards the threshold. The [`WeightTable`] fixes the
+//! assignment: with weights [3, 1, 2], party 1 holds indices 1..=3, party 2
+//! index 4 and party 3 indices 5..=6, and a threshold of 4 is met by parties
+//! {1, 2} or {1, 3} but not {2, 3}.
+//!
+//! # Contributions
+//!
+//! A [`WeightedContribution`] is one message per party, carrying one
+//! Schnorr commitment and response per owned index under a single challenge
+//!
+//! ```text
+//! c_p = H(u_{p,1} || ... || u_{p,w} || payload)
+//! s_{p,k} = r_{p,k} + c_p · x_k
+//! ```
+//!
+//! For w = 1 this is exactly an OSST [`Contribution`](crate::Contribution).
+//! [`verify`] checks equation (3.3) of the paper over the union of the
+//! parties' indices, with one challenge per party rather than per index:
+//!
+//! ```text
+//! g^{Σ μ_k·s_k} = Y^{c̄} · Π u_k^{μ_k},   c̄ = Π_p c_p,   μ_k = λ_k · Π_{q≠p(k)} c_q
+//! ```
+//!
+//! A weighted contribution is therefore one message counting for w, but not
+//! one commitment and response: the per-index pairs cannot be folded into
+//! one. A non-interactive prover does not know which other parties will be
+//! counted, and the Lagrange coefficients of its indices depend on them, so
+//! the verifier needs all w of them. FROST signers learn the signing set
+//! before they respond, so there a weighted party sends a single
+//! [`SignatureShare`](crate::frost::SignatureShare), see
+//! [`frost::sign_weighted`](crate::frost::sign_weighted).
+//!
+//! [`WeightedVerifier`] accumulates contributions as they arrive and checks
+//! the proof without redoing the work for the parties already added.
+//!
+//! # Key generation
+//!
+//! In a weighted DKG every party deals once, with a polynomial of degree
+//! T − 1 for weight threshold T, and sends each party the sub-shares for all
+//! of its indices; see [`dkg::WeightedAggregator`](crate::dkg::WeightedAggregator).
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+use sha2::{Digest, Sha512};
+
+use crate::curve::{OsstPoint, OsstScalar};
+use crate::error::OsstError;
+use crate::lagrange::compute_lagrange_coefficients;
+use crate::SecretShare;
+
+// ============================================================================
+// Weight table
+// ============================================================================
+
+/// Assignment of consecutive share indices to weighted parties
+///
+/// Parties are 1-indexed; party p owns indices
+/// `w_1 + ... + w_{p-1} + 1 ..= w_1 + ... + w_p`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WeightTable {
+    /// offsets[p] = w_1 + ... + w_p, offsets[0] = 0
+    offsets: Vec<u32>,
+}
+
+impl WeightTable {
+    /// Create a table from the weights of parties 1..=n, in order.
+    pub fn new(weights: &[u32]) -> Self {
+        assert!(weights.iter().all(|&w| w > 0), "weights must be positive");
+
+        let mut offsets = Vec::with_capacity(weights.len() + 1);
+        offsets.push(0u32);
+        for w in weights {
+            let last = *offsets.last().unwrap();
+            offsets.push(last.checked_add(*w).expect("total weight overflows u32"));
+        }
+
+        Self { offsets }
+    }
+
+    /// Number of parties
+    #[inline]
+    pub fn num_parties(&self) -> u32 {
+        (self.offsets.len() - 1) as u32
+    }
+
+    /// Sum of all weights, i.e. the number of share indices
+    #[inline]
+    pub fn total_weight(&self) -> u32 {
+        *self.offsets.last().unwrap()
+    }
+
+    /// Weight of `party`, if it is in the table
+    pub fn weight(&self, party: u32) -> Option<u32> {
+        self.indices(party).map(|r| r.end - r.start)
+    }
+
+    /// Share indices owned by `party`, if it is in the table
+    pub fn indices(&self, party: u32) -> Option<Range<u32>> {
+        if party == 0 || party > self.num_parties() {
+            return None;
+        }
+        let p = party as usize;
+        Some(self.offsets[p - 1] + 1..self.offsets[p] + 1)
+    }
+
+    /// Party owning share index `index`
+    pub fn party_of(&self, index: u32) -> Option<u32> {
+        if index == 0 || index > self.total_weight() {
+            return None;
+        }
+        // first party whose last index is >= index
+        Some(self.offsets[1..].partition_point(|&end| end < index) as u32 + 1)
+    }
+}
+
+// ============================================================================
+// Shares and contributions
+// ============================================================================
+
+/// A weighted party's shares, one per owned index
+#[derive(Clone, Debug)]
+pub struct WeightedShare<S: OsstScalar> {
+    /// Party index in the weight table (1-indexed)
+    pub party: u32,
+    /// Shares in ascending index order
+    shares: Vec<SecretShare<S>>,
+}
+
+impl<S: OsstScalar> WeightedShare<S> {
+    /// Bundle the shares of `party`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidIndex` unless `shares` holds exactly the indices the
+    /// table assigns to `party`, in ascending order.
+    pub fn new(
+        party: u32,
+        table: &WeightTable,
+        shares: Vec<SecretShare<S>>,
+    ) -> Result<Self, OsstError> {
+        let range = table.indices(party).ok_or(OsstError::InvalidIndex)?;
+        if !shares.iter().map(|s| s.index).eq(range) {
+            return Err(OsstError::InvalidIndex);
+        }
+        Ok(Self { party, shares })
+    }
+
+    /// Number of indices this party counts for
+    #[inline]
+    pub fn weight(&self) -> u32 {
+        self.shares.len() as u32
+    }
+
+    /// Access the underlying shares (use sparingly)
+    #[inline]
+    pub fn shares(&self) -> &[SecretShare<S>] {
+        &self.shares
+    }
+
+    /// Derive public verification shares y_k = g^{x_k}, keyed by index
+    pub fn public_shares<P: OsstPoint<Scalar = S>>(&self) -> Vec<(u32, P)> {
+        self.shares
+            .iter()
+            .map(|s| (s.index, s.public_share::<P>()))
+            .collect()
+    }
+
+    /// Generate this party's contribution
+    ///
+    /// - u_k = g^{r_k} for each owned index k
+    /// - c_p = H(u_1 || ... || u_w || payload)
+    /// - s_k = r_k + c_p · x_k
+    pub fn contribute<P: OsstPoint<Scalar = S>, R: rand_core::RngCore + rand_core::CryptoRng>(
+        &self,
+        rng: &mut R,
+        payload: &[u8],
+    ) -> WeightedContribution<P> {
+        let mut nonces: Vec<S> = self.shares.iter().map(|_| S::random(rng)).collect();
+
+        let commitments: Vec<P> = nonces
+            .iter()
+            .map(|r| P::generator().mul_scalar(r))
+            .collect();
+
+        let challenge: S = weighted_challenge(&commitments, payload);
+
+        let responses = nonces
+            .iter()
+            .zip(&self.shares)
+            .map(|(r, share)| r.add(&challenge.mul(share.scalar())))
+            .collect();
+
+        for r in &mut nonces {
+            r.zeroize();
+        }
+
+        WeightedContribution {
+            party: self.party,
+            commitments,
+            responses,
+        }
+    }
+}
+
+/// A weighted party's contribution to the threshold proof
+#[derive(Clone, Debug)]
+pub struct WeightedContribution<P: OsstPoint> {
+    /// Party index in the weight table (1-indexed)
+    pub party: u32,
+    /// Schnorr commitments u_k = g^{r_k}, in index order
+    pub commitments: Vec<P>,
+    /// Schnorr responses s_k = r_k + c_p · x_k, in index order
+    pub responses: Vec<P::Scalar>,
+}
+
+impl<P: OsstPoint> WeightedContribution<P> {
+    /// Number of indices this contribution counts for
+    #[inline]
+    pub fn weight(&self) -> u32 {
+        self.commitments.len() as u32
+    }
+
+    /// Serialize to variable-length bytes
+    ///
+    /// Format: [party: 4][weight: 4][commitment: COMPRESSED_SIZE]*w[response: 32]*w
+    pub fn to_bytes_vec(&self) -> Vec<u8> {
+        let mut buf =
+            Vec::with_capacity(8 + self.commitments.len() * (P::COMPRESSED_SIZE + 32));
+        buf.extend_from_slice(&self.party.to_le_bytes());
+        buf.extend_from_slice(&self.weight().to_le_bytes());
+        for u in &self.commitments {
+            buf.extend_from_slice(&u.compress_vec());
+        }
+        for s in &self.responses {
+            buf.extend_from_slice(&s.to_bytes());
+        }
+        buf
+    }
+
+    /// Deserialize from variable-length bytes
+    pub fn from_bytes_vec(bytes: &[u8]) -> Result<Self, OsstError> {
+        if bytes.len() < 8 {
+            return Err(OsstError::InvalidCommitment);
+        }
+
+        let party = u32::from_le_bytes(bytes[0..4].try_into().unwrap());
+        let weight = u32::from_le_bytes(bytes[4..8].try_into().unwrap()) as usize;
+
+        let expected_len = weight
+            .checked_mul(P::COMPRESSED_SIZE + 32)
+            .and_then(|body| body.checked_add(8));
+        if weight == 0 || expected_len != Some(bytes.len()) {
+            return Err(OsstError::InvalidCommitment);
+        }
+
+        let (points, scalars) = bytes[8..].split_at(weight * P::COMPRESSED_SIZE);
+
+        let commitments = points
+            .chunks_exact(P::COMPRESSED_SIZE)
+            .map(|chunk| P::decompress_slice(chunk).ok_or(OsstError::InvalidCommitment))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let responses = scalars
+            .chunks_exact(32)
+            .map(|chunk| {
+                P::Scalar::from_canonical_bytes(chunk.try_into().unwrap())
+                    .ok_or(OsstError::InvalidResponse)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            party,
+            commitments,
+            responses,
+        })
+    }
+}
+
+/// c_p = H(u_1 || ... || u_w || payload)
+///
+/// Equal to [`hash_to_challenge`](crate::hash_to_challenge) for w = 1.
+fn weighted_challenge<P: OsstPoint>(commitments: &[P], payload: &[u8]) -> P::Scalar {
+    let mut hasher = Sha512::new();
+    for u in commitments {
+        hasher.update(u.compress());
+    }
+    hasher.update(payload);
+    let hash: [u8; 64] = hasher.finalize().into();
+    P::Scalar::from_bytes_wide(&hash)
+}
+
+// ============================================================================
+// Verification
+// ============================================================================
+
+/// Verify a weighted OSST proof
+///
+/// `threshold` is counted in weight: the contributing parties' weights must
+/// sum to at least `threshold`.
+///
+/// # Errors
+///
+/// - `EmptyContributions` if `contributions` is empty
+/// - `DuplicateIndex` if a party contributes twice
+/// - `InvalidIndex` if a party is not in `table`
+/// - `InvalidResponse` if a contribution's length differs from the party's weight
+/// - `InsufficientContributions` if the total weight is below `threshold`
+pub fn verify<P: OsstPoint>(
+    group_pubkey: &P,
+    table: &WeightTable,
+    contributions: &[WeightedContribution<P>],
+    threshold: u32,
+    payload: &[u8],
+) -> Result<bool, OsstError> {
+    if contributions.is_empty() {
+        return Err(OsstError::EmptyContributions);
+    }
+
+    // Check for duplicate parties
+    let mut parties: Vec<u32> = contributions.iter().map(|c| c.party).collect();
+    parties.sort();
+    for i in 1..parties.len() {
+        if parties[i] == parties[i - 1] {
+            return Err(OsstError::DuplicateIndex(parties[i]));
+        }
+    }
+
+    // Collect share indices and check each contribution matches its weight
+    let mut indices: Vec<u32> = Vec::new();
+    for c in contributions {
+        let range = table.indices(c.party).ok_or(OsstError::InvalidIndex)?;
+        if c.commitments.len() != range.len() || c.responses.len() != range.len() {
+            return Err(OsstError::InvalidResponse);
+        }
+        indices.extend(range);
+    }
+
+    // Check threshold
+    if indices.len() < threshold as usize {
+        return Err(OsstError::InsufficientContributions {
+            got: indices.len(),
+            need: threshold as usize,
+        });
+    }
+
+    // Challenges c_p, one per party
+    let challenges: Vec<P::Scalar> = contributions
+        .iter()
+        .map(|c| weighted_challenge(&c.commitments, payload))
+        .collect();
+
+    for c in &challenges {
+        if c == &P::Scalar::zero() {
+            return Err(OsstError::ZeroChallenge);
+        }
+    }
+
+    // Normalizer c̄ = Π c_p
+    let normalizer: P::Scalar = challenges
+        .iter()
+        .fold(P::Scalar::one(), |acc, c| acc.mul(c));
+
+    let lagrange = compute_lagrange_coefficients::<P::Scalar>(&indices)?;
+
+    // μ_k = λ_k · Π_{q≠p} c_q, accumulating both sides as we go
+    let mut lhs_exponent = P::Scalar::zero();
+    let mut scalars = vec![normalizer];
+    let mut points = vec![group_pubkey.clone()];
+
+    let mut lambdas = lagrange.iter();
+    for (p, c) in contributions.iter().enumerate() {
+        let mut cofactor = P::Scalar::one();
+        for (q, c_q) in challenges.iter().enumerate() {
+            if p != q {
+                cofactor = cofactor.mul(c_q);
+            }
+        }
+
+        for (u, s) in c.commitments.iter().zip(&c.responses) {
+            let μ = lambdas.next().unwrap().mul(&cofactor);
+            lhs_exponent = lhs_exponent.add(&μ.mul(s));
+            scalars.push(μ);
+            points.push(u.clone());
+        }
+    }
+
+    let lhs = P::generator().mul_scalar(&lhs_exponent);
+    let rhs = P::multiscalar_mul(&scalars, &points);
+
+    Ok(lhs == rhs)
+}
+
+/// Weighted proof accumulated one party at a time
+///
+/// Checks the same equation as [`verify`], rearranged per index: dividing
+/// (3.3) by c̄ gives Σ λ_k · y_k = Y with y_k = (g^{s_k} · u_k^{-1})^{1/c_p}.
+/// Each contribution's y_k is derived once when it is added, and the
+/// Lagrange coefficients over the accumulated indices are rescaled rather
+/// than recomputed, so adding a party of weight w costs O(|Q| · w) field
+/// operations and O(w) group operations, and [`check`](Self::check) is one
+/// multiscalar multiplication over the accumulated indices.
+#[derive(Clone, Debug)]
+pub struct WeightedVerifier<P: OsstPoint> {
+    group_pubkey: P,
+    table: WeightTable,
+    threshold: u32,
+    payload: Vec<u8>,
+    parties: Vec<u32>,
+    /// Accumulated share indices, with their y_k and λ_k over all of them
+    indices: Vec<u32>,
+    shares: Vec<P>,
+    lagrange: Vec<P::Scalar>,
+}
+
+impl<P: OsstPoint> WeightedVerifier<P> {
+    pub fn new(group_pubkey: P, table: WeightTable, threshold: u32, payload: &[u8]) -> Self {
+        Self {
+            group_pubkey,
+            table,
+            threshold,
+            payload: payload.to_vec(),
+            parties: Vec::new(),
+            indices: Vec::new(),
+            shares: Vec::new(),
+            lagrange: Vec::new(),
+        }
+    }
+
+    /// Total weight of the parties added so far
+    #[inline]
+    pub fn weight(&self) -> u32 {
+        self.indices.len() as u32
+    }
+
+    /// Parties added so far, in the order they were added
+    #[inline]
+    pub fn parties(&self) -> &[u32] {
+        &self.parties
+    }
+
+    /// Add one party's contribution
+    ///
+    /// Leaves the verifier unchanged on error.
+    ///
+    /// # Errors
+    ///
+    /// As [`verify`], except that the threshold is only checked by
+    /// [`check`](Self::check).
+    pub fn add(&mut self, contribution: &WeightedContribution<P>) -> Result<(), OsstError> {
+        if self.parties.contains(&contribution.party) {
+            return Err(OsstError::DuplicateIndex(contribution.party));
+        }
+        let range = self.table.indices(contribution.party).ok_or(OsstError::InvalidIndex)?;
+        if contribution.commitments.len() != range.len()
+            || contribution.responses.len() != range.len()
+        {
+            return Err(OsstError::InvalidResponse);
+        }
+
+        let challenge: P::Scalar = weighted_challenge(&contribution.commitments, &self.payload);
+        if challenge == P::Scalar::zero() {
+            return Err(OsstError::ZeroChallenge);
+        }
+
+        // y_k = g^{s_k / c_p} · u_k^{-1 / c_p}
+        let inverse = challenge.invert();
+        let neg_inverse = inverse.neg();
+        let new_shares: Vec<P> = contribution
+            .commitments
+            .iter()
+            .zip(&contribution.responses)
+            .map(|(u, s)| {
+                P::multiscalar_mul(
+                    &[s.mul(&inverse), neg_inverse.clone()],
+                    &[P::generator(), u.clone()],
+                )
+            })
+            .collect();
+
+        // λ_k over Q ∪ N is λ_k over Q times Π_{j ∈ N} j / (j − k)
+        let new_indices: Vec<u32> = range.collect();
+        for (k, lambda) in self.indices.iter().zip(self.lagrange.iter_mut()) {
+            *lambda = lambda.mul(&lagrange_factor::<P::Scalar>(*k, &new_indices));
+        }
+        for &k in &new_indices {
+            let others: Vec<u32> = self
+                .indices
+                .iter()
+                .chain(&new_indices)
+                .copied()
+                .filter(|&j| j != k)
+                .collect();
+            self.lagrange.push(lagrange_factor(k, &others));
+        }
+
+        self.parties.push(contribution.party);
+        self.indices.extend(new_indices);
+        self.shares.extend(new_shares);
+        Ok(())
+    }
+
+    /// Check the accumulated proof: Σ λ_k · y_k = Y
+    ///
+    /// # Errors
+    ///
+    /// - `EmptyContributions` if no party was added
+    /// - `InsufficientContributions` if the total weight is below the threshold
+    pub fn check(&self) -> Result<bool, OsstError> {
+        if self.parties.is_empty() {
+            return Err(OsstError::EmptyContributions);
+        }
+        if self.indices.len() < self.threshold as usize {
+            return Err(OsstError::InsufficientContributions {
+                got: self.indices.len(),
+                need: self.threshold as usize,
+            });
+        }
+        Ok(P::multiscalar_mul(&self.lagrange, &self.shares) == self.group_pubkey)
+    }
+}
+
+/// Π_{j ∈ others} j / (j − k)
+fn lagrange_factor<S: OsstScalar>(k: u32, others: &[u32]) -> S {
+    let x_k = S::from_u32(k);
+    let (num, den) = others.iter().fold((S::one(), S::one()), |(num, den), &j| {
+        let x_j = S::from_u32(j);
+        (num.mul(&x_j), den.mul(&x_j.sub(&x_k)))
+    });
+    num.mul(&den.invert())
+}
+
+/// Incremental verification: add one more party and check the proof
+///
+/// Work already done for the parties in `verifier` is reused; see
+/// [`WeightedVerifier`].
+///
+/// # Errors
+///
+/// As [`WeightedVerifier::add`] and [`WeightedVerifier::check`]. The
+/// contribution stays added when only the threshold is not yet met.
+pub fn verify_incremental<P: OsstPoint>(
+    verifier: &mut WeightedVerifier<P>,
+    new_contribution: &WeightedContribution<P>,
+) -> Result<bool, OsstError> {
+    verifier.add(new_contribution)?;
+    verifier.check()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(all(test, feature = "ristretto255"))]
+mod tests {
+    use super::*;
+    use curve25519_dalek::{ristretto::RistrettoPoint, scalar::Scalar};
+    use rand::rngs::OsRng;
+
+    /// Shamir-split `secret` with threshold `t` over the table's indices
+    fn weighted_split(
+        secret: &Scalar,
+        table: &WeightTable,
+        t: u32,
+    ) -> Vec<WeightedShare<Scalar>> {
+        let mut coeffs = vec![*secret];
+        for _ in 1..t {
+            coeffs.push(Scalar::random(&mut OsRng));
+        }
+        (1..=table.num_parties())
+            .map(|p| {
+                let shares = table
+                    .indices(p)
+                    .unwrap()
+                    .map(|i| {
+                        let x = Scalar::from(i);
+                        let y = coeffs.iter().rev().fold(Scalar::ZERO, |acc, a| acc * x + a);
+                        SecretShare::new(i, y)
+                    })
+                    .collect();
+                WeightedShare::new(p, table, shares).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_weight_table() {
+        let table = WeightTable::new(&[3, 1, 2]);
+        assert_eq!(table.num_parties(), 3);
+        assert_eq!(table.total_weight(), 6);
+        assert_eq!(table.indices(1), Some(1..4));
+        assert_eq!(table.indices(2), Some(4..5));
+        assert_eq!(table.indices(3), Some(5..7));
+        assert_eq!(table.indices(4), None);
+        assert_eq!(table.weight(3), Some(2));
+
+        let owners: Vec<_> = (0..=7).map(|i| table.party_of(i)).collect();
+        assert_eq!(
+            owners,
+            [None, Some(1), Some(1), Some(1), Some(2), Some(3), Some(3), None]
+        );
+    }
+
+    #[test]
+    fn test_weighted_verify() {
+        let mut rng = OsRng;
+        let table = WeightTable::new(&[3, 1, 2, 1]);
+        let t = 4;
+
+        let secret = Scalar::random(&mut rng);
+        let group_pubkey = RistrettoPoint::generator().mul_scalar(&secret);
+        let parties = weighted_split(&secret, &table, t);
+        let payload = b"weighted liveness epoch 7";
+
+        let contributions: Vec<WeightedContribution<RistrettoPoint>> = parties
+            .iter()
+            .map(|p| p.contribute(&mut rng, payload))
+            .collect();
+
+        // party 1 (weight 3) + party 2 (weight 1) meets the threshold
+        let heavy = [contributions[0].clone(), contributions[1].clone()];
+        assert!(verify(&group_pubkey, &table, &heavy, t, payload).unwrap());
+
+        // everyone
+        assert!(verify(&group_pubkey, &table, &contributions, t, payload).unwrap());
+
+        // so do the three light parties, 1 + 2 + 1
+        let light = &contributions[1..];
+        assert!(verify(&group_pubkey, &table, light, t, payload).unwrap());
+
+        // parties 2 and 4 carry weight 2
+        let short = [contributions[1].clone(), contributions[3].clone()];
+        assert_eq!(
+            verify(&group_pubkey, &table, &short, t, payload),
+            Err(OsstError::InsufficientContributions { got: 2, need: 4 })
+        );
+
+        // wrong payload
+        assert!(!verify(&group_pubkey, &table, &heavy, t, b"other").unwrap());
+
+        // a contribution claiming another party's slot fails
+        let mut stolen = heavy.clone();
+        stolen[1].party = 4;
+        assert!(!verify(&group_pubkey, &table, &stolen, t, payload).unwrap());
+
+        // truncated contribution
+        let mut truncated = heavy.clone();
+        truncated[0].responses.pop();
+        assert_eq!(
+            verify(&group_pubkey, &table, &truncated, t, payload),
+            Err(OsstError::InvalidResponse)
+        );
+    }
+
+    #[test]
+    fn test_weighted_verify_incremental() {
+        let mut rng = OsRng;
+        let table = WeightTable::new(&[2, 2, 3, 1]);
+        let t = 4;
+
+        let secret = Scalar::random(&mut rng);
+        let group_pubkey = RistrettoPoint::generator().mul_scalar(&secret);
+        let parties = weighted_split(&secret, &table, t);
+        let payload = b"incremental";
+
+        let contributions: Vec<WeightedContribution<RistrettoPoint>> = parties
+            .iter()
+            .map(|p| p.contribute(&mut rng, payload))
+            .collect();
+
+        let mut verifier = WeightedVerifier::new(group_pubkey, table.clone(), t, payload);
+        assert_eq!(verifier.check(), Err(OsstError::EmptyContributions));
+        assert_eq!(
+            verify_incremental(&mut verifier, &contributions[0]),
+            Err(OsstError::InsufficientContributions { got: 2, need: 4 })
+        );
+        assert_eq!(verifier.weight(), 2);
+
+        // every later party keeps the proof valid, as the batch check agrees
+        for n in [2, 1, 3] {
+            assert!(verify_incremental(&mut verifier, &contributions[n]).unwrap());
+        }
+        assert_eq!(verifier.parties(), &[1, 3, 2, 4]);
+        assert!(verify(&group_pubkey, &table, &contributions, t, payload).unwrap());
+
+        assert_eq!(
+            verify_incremental(&mut verifier, &contributions[0]),
+            Err(OsstError::DuplicateIndex(1))
+        );
+        assert_eq!(verifier.weight(), 8);
+
+        // a bad party spoils the proof; a rejected one leaves it untouched
+        let mut verifier = WeightedVerifier::new(group_pubkey, table.clone(), t, payload);
+        verifier.add(&contributions[0]).unwrap();
+        let mut truncated = contributions[2].clone();
+        truncated.responses.pop();
+        assert_eq!(verifier.add(&truncated), Err(OsstError::InvalidResponse));
+        assert_eq!(verifier.weight(), 2);
+
+        let mut forged = contributions[1].clone();
+        forged.responses[0] += Scalar::ONE;
+        assert!(!verify_incremental(&mut verifier, &forged).unwrap());
+    }
+
+    #[test]
+    fn test_unit_weight_matches_osst() {
+        let mut rng = OsRng;
+        let table = WeightTable::new(&[1, 1, 1]);
+        let t = 2;
+
+        let secret = Scalar::random(&mut rng);
+        let group_pubkey = RistrettoPoint::generator().mul_scalar(&secret);
+        let parties = weighted_split(&secret, &table, t);
+        let payload = b"unit weights";
+
+        // a weight-1 contribution verifies as a plain OSST contribution
+        let plain: Vec<crate::Contribution<RistrettoPoint>> = parties[..2]
+            .iter()
+            .map(|p| {
+                let c: WeightedContribution<RistrettoPoint> = p.contribute(&mut rng, payload);
+                crate::Contribution::new(p.shares()[0].index, c.commitments[0], c.responses[0])
+            })
+            .collect();
+
+        assert!(crate::verify(&group_pubkey, &plain, t, payload).unwrap());
+    }
+
+    #[test]
+    fn test_weighted_contribution_serialization() {
+        let mut rng = OsRng;
+        let table = WeightTable::new(&[3]);
+        let parties = weighted_split(&Scalar::random(&mut rng), &table, 2);
+
+        let c: WeightedContribution<RistrettoPoint> = parties[0].contribute(&mut rng, b"bytes");
+        let bytes = c.to_bytes_vec();
+        assert_eq!(bytes.len(), 8 + 3 * 64);
+
+        let decoded = WeightedContribution::<RistrettoPoint>::from_bytes_vec(&bytes).unwrap();
+        assert_eq!(decoded.party, 1);
+        assert_eq!(decoded.commitments, c.commitments);
+        assert_eq!(decoded.responses, c.responses);
+
+        assert!(WeightedContribution::<RistrettoPoint>::from_bytes_vec(&bytes[..bytes.len() - 1])
+            .is_err());
+    }
+}
@@ -3,7 +3,7 @@
 use std::marker::PhantomData;
 
 use binary_fields::{BinaryElem32, BinaryElem128};
-use ligerito::{prover, verifier, ProverConfig, VerifierConfig};
+use ligerito::{prover, verifier, MerkleHasher, ProverConfig, VerifierConfig};
 use reed_solomon::reed_solomon;
 use rand::Rng;
 use std::time::Instant;
@@ -33,6 +33,8 @@ fn create_small_config() -> ProverConfig<BinaryElem32, BinaryElem128> {
         ks,
         initial_reed_solomon,
         reed_solomon_codes,
+        num_queries: ligerito::configs::DEFAULT_NUM_QUERIES,
+        hasher: MerkleHasher::Sha256,
     }
 }
 
@@ -43,6 +45,9 @@ fn create_small_verifier_config() -> VerifierConfig {
         log_dims: vec![6],
         initial_k: 4,
         ks: vec![2],
+        log_inv_rate: ligerito::configs::DEFAULT_LOG_INV_RATE,
+        num_queries: ligerito::configs::DEFAULT_NUM_QUERIES,
+        hasher: MerkleHasher::Sha256,
     }
 }
 
@@ -77,9 +82,8 @@ fn main() {
     // Time the verification
     println!("\nVerifying proof...");
     let start = Instant::now();
-    // let verification_result = verifier(&verifier_config, &proof)
-    let verification_result = ligerito::verifier::verify_debug(&verifier_config, &proof)
-    .expect("Verification failed");
+    let verification_result = verifier(&verifier_config, &proof)
+        .expect("Verification failed");
     let verify_time = start.elapsed();
 
     println!("✓ Verification completed in: {:?}", verify_time);
@@ -0,0 +1,497 @@
+//! Canonical binary encoding for `FinalizedLigeritoProof`
+//!
+//! Every component that stores or ships proofs should use this format
+//! instead of picking its own serde backend. Only `core` and `alloc` are
+//! used, so the decoder works in `no_std` runtimes.
+//!
+//! # Layout (version 2)
+//!
+//! All integers are little-endian. Field elements use their canonical
+//! `BinaryFieldElement::write_bytes` encoding.
+//!
+//! ```text
+//! header      magic "LGRT" | version u8 | T bytes u8 | U bytes u8 | reserved u8 (0)
+//! root        present u8 (0/1) | [32 bytes if present]
+//! rows<F>     count u32 | width u32 (> 0) | count * width elements of F
+//! merkle      count u32 | count * 32 bytes
+//!
+//! proof       header
+//!             root                                 initial_ligero_cm
+//!             rows<T> merkle                       initial_ligero_proof
+//!             count u32 | count * root             recursive_commitments
+//!             count u32 | count * (rows<U> merkle) recursive_proofs
+//!             len u32 | len * U | rows<U> merkle   final_ligero_proof
+//!             count u32 | count * 3 * U            sumcheck_transcript
+//! ```
+//!
+//! Version 1 carried proofs of the earlier sumcheck, whose challenges were
+//! drawn from {0, 1} and whose columns were encoded systematically. Those
+//! proofs do not verify under the current protocol and are refused as
+//! `UnsupportedVersion(1)`.
+//!
+//! Decoding checks every length against the remaining input before
+//! allocating and rejects trailing bytes, so a malformed or oversize proof
+//! is refused before any field arithmetic runs.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use binary_fields::BinaryFieldElement;
+use merkle_tree::{BatchedMerkleProof, Hash, MerkleRoot};
+
+use crate::data_structures::{
+    FinalLigeroProof, FinalizedLigeritoProof, RecursiveLigeroCommitment, RecursiveLigeroProof,
+    SumcheckTranscript, VerifierConfig,
+};
+
+/// Leading bytes of every encoded proof
+pub const PROOF_MAGIC: [u8; 4] = *b"LGRT";
+
+/// Current encoding version
+pub const PROOF_VERSION: u8 = 2;
+
+/// Proofs larger than this are rejected without being parsed
+///
+/// Proofs for every built-in config up to 2^30 are well under 2 MiB; the
+/// limit leaves room for larger derived configs without letting a caller
+/// hand the decoder tens of megabytes.
+pub const MAX_PROOF_BYTES: usize = 1 << 22;
+
+const HEADER_LEN: usize = 8;
+
+/// Errors from encoding or decoding a proof
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CodecError {
+    /// Input exceeds `MAX_PROOF_BYTES`
+    TooLarge(usize),
+
+    /// Input does not start with `PROOF_MAGIC`
+    BadMagic,
+
+    /// Encoding version not supported by this decoder
+    UnsupportedVersion(u8),
+
+    /// Field element sizes do not match the requested proof type
+    FieldMismatch,
+
+    /// Input ended before the proof was complete
+    Truncated,
+
+    /// Bytes left over after the proof
+    TrailingBytes,
+
+    /// A flag or reserved byte has an invalid value
+    InvalidTag,
+
+    /// Proof shape does not match the verifier config
+    ShapeMismatch(&'static str),
+}
+
+impl fmt::Display for CodecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooLarge(len) => write!(f, "proof of {} bytes exceeds limit", len),
+            Self::BadMagic => write!(f, "bad magic bytes"),
+            Self::UnsupportedVersion(v) => write!(f, "unsupported encoding version {}", v),
+            Self::FieldMismatch => write!(f, "field element size mismatch"),
+            Self::Truncated => write!(f, "proof truncated"),
+            Self::TrailingBytes => write!(f, "trailing bytes after proof"),
+            Self::InvalidTag => write!(f, "invalid tag byte"),
+            Self::ShapeMismatch(what) => write!(f, "proof shape mismatch: {}", what),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CodecError {}
+
+impl<T: BinaryFieldElement, U: BinaryFieldElement> FinalizedLigeritoProof<T, U> {
+    /// Encode the proof in the canonical wire format
+    ///
+    /// Fails only if a set of opened rows is empty or ragged, which the
+    /// prover never produces.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, CodecError> {
+        let mut out = Vec::with_capacity(HEADER_LEN + self.size_of() + 64);
+        out.extend_from_slice(&PROOF_MAGIC);
+        out.push(PROOF_VERSION);
+        out.push(T::BYTES as u8);
+        out.push(U::BYTES as u8);
+        out.push(0);
+
+        write_root(&mut out, &self.initial_ligero_cm.root);
+        write_rows(&mut out, &self.initial_ligero_proof.opened_rows)?;
+        write_merkle(&mut out, &self.initial_ligero_proof.merkle_proof);
+
+        write_u32(&mut out, self.recursive_commitments.len());
+        for cm in &self.recursive_commitments {
+            write_root(&mut out, &cm.root);
+        }
+
+        write_u32(&mut out, self.recursive_proofs.len());
+        for p in &self.recursive_proofs {
+            write_rows(&mut out, &p.opened_rows)?;
+            write_merkle(&mut out, &p.merkle_proof);
+        }
+
+        write_u32(&mut out, self.final_ligero_proof.yr.len());
+        write_elems(&mut out, &self.final_ligero_proof.yr);
+        write_rows(&mut out, &self.final_ligero_proof.opened_rows)?;
+        write_merkle(&mut out, &self.final_ligero_proof.merkle_proof);
+
+        write_u32(&mut out, self.sumcheck_transcript.transcript.len());
+        for (a, b, c) in &self.sumcheck_transcript.transcript {
+            write_elems(&mut out, &[*a, *b, *c]);
+        }
+
+        Ok(out)
+    }
+
+    /// Decode a proof, checking only structural limits
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, CodecError> {
+        let mut r = Reader::new(bytes)?;
+        r.header::<T, U>()?;
+
+        let initial_ligero_cm = RecursiveLigeroCommitment { root: r.root()? };
+        let initial_ligero_proof = RecursiveLigeroProof {
+            opened_rows: r.rows::<T>()?,
+            merkle_proof: r.merkle()?,
+        };
+
+        let n = r.count(33)?;
+        let mut recursive_commitments = Vec::with_capacity(n);
+        for _ in 0..n {
+            recursive_commitments.push(RecursiveLigeroCommitment { root: r.root()? });
+        }
+
+        let n = r.count(12)?;
+        let mut recursive_proofs = Vec::with_capacity(n);
+        for _ in 0..n {
+            recursive_proofs.push(RecursiveLigeroProof {
+                opened_rows: r.rows::<U>()?,
+                merkle_proof: r.merkle()?,
+            });
+        }
+
+        let yr = r.elems::<U>()?;
+        let final_ligero_proof = FinalLigeroProof {
+            yr,
+            opened_rows: r.rows::<U>()?,
+            merkle_proof: r.merkle()?,
+        };
+
+        let n = r.count(3 * U::BYTES)?;
+        let mut transcript = Vec::with_capacity(n);
+        for _ in 0..n {
+            transcript.push((r.elem::<U>()?, r.elem::<U>()?, r.elem::<U>()?));
+        }
+
+        r.finish()?;
+
+        Ok(Self {
+            initial_ligero_cm,
+            initial_ligero_proof,
+            recursive_commitments,
+            recursive_proofs,
+            final_ligero_proof,
+            sumcheck_transcript: SumcheckTranscript { transcript },
+        })
+    }
+
+    /// Decode a proof and check its shape against a verifier config
+    pub fn from_bytes_for_config(
+        bytes: &[u8],
+        config: &VerifierConfig,
+    ) -> Result<Self, CodecError> {
+        let proof = Self::from_bytes(bytes)?;
+        proof.check_shape(config)?;
+        Ok(proof)
+    }
+
+    /// Check every dimension of the proof against a verifier config
+    pub fn check_shape(&self, config: &VerifierConfig) -> Result<(), CodecError> {
+        use CodecError::ShapeMismatch;
+
+        let steps = config.recursive_steps;
+        if steps == 0 || config.log_dims.len() != steps || config.ks.len() != steps {
+            return Err(ShapeMismatch("config"));
+        }
+
+        fn rows_ok<F>(rows: &[Vec<F>], count: usize, width: usize) -> bool {
+            rows.len() == count && rows.iter().all(|r| r.len() == width)
+        }
+        let q = config.num_queries;
+
+        if !rows_ok(&self.initial_ligero_proof.opened_rows, q, 1 << config.initial_k) {
+            return Err(ShapeMismatch("initial opened rows"));
+        }
+        if self.recursive_commitments.len() != steps {
+            return Err(ShapeMismatch("recursive commitments"));
+        }
+        if self.recursive_proofs.len() != steps - 1 {
+            return Err(ShapeMismatch("recursive proofs"));
+        }
+        for (i, p) in self.recursive_proofs.iter().enumerate() {
+            if !rows_ok(&p.opened_rows, q, 1 << config.ks[i]) {
+                return Err(ShapeMismatch("recursive opened rows"));
+            }
+        }
+        if self.final_ligero_proof.yr.len() != 1 << config.log_dims[steps - 1] {
+            return Err(ShapeMismatch("final yr"));
+        }
+        if !rows_ok(&self.final_ligero_proof.opened_rows, q, 1 << config.ks[steps - 1]) {
+            return Err(ShapeMismatch("final opened rows"));
+        }
+        if self.sumcheck_transcript.transcript.len() != config.ks.iter().sum::<usize>() {
+            return Err(ShapeMismatch("sumcheck transcript"));
+        }
+
+        Ok(())
+    }
+}
+
+fn write_u32(out: &mut Vec<u8>, n: usize) {
+    out.extend_from_slice(&(n as u32).to_le_bytes());
+}
+
+fn write_elems<F: BinaryFieldElement>(out: &mut Vec<u8>, elems: &[F]) {
+    let start = out.len();
+    out.resize(start + elems.len() * F::BYTES, 0);
+    for (e, chunk) in elems.iter().zip(out[start..].chunks_exact_mut(F::BYTES)) {
+        e.write_bytes(chunk);
+    }
+}
+
+fn write_root(out: &mut Vec<u8>, root: &MerkleRoot) {
+    match &root.root {
+        Some(hash) => {
+            out.push(1);
+            out.extend_from_slice(hash);
+        }
+        None => out.push(0),
+    }
+}
+
+fn write_rows<F: BinaryFieldElement>(out: &mut Vec<u8>, rows: &[Vec<F>]) -> Result<(), CodecError> {
+    let width = rows.first().map_or(0, |r| r.len());
+    if width == 0 {
+        return Err(CodecError::ShapeMismatch("empty rows"));
+    }
+    if rows.iter().any(|r| r.len() != width) {
+        return Err(CodecError::ShapeMismatch("ragged rows"));
+    }
+    write_u32(out, rows.len());
+    write_u32(out, width);
+    for row in rows {
+        write_elems(out, row);
+    }
+    Ok(())
+}
+
+fn write_merkle(out: &mut Vec<u8>, proof: &BatchedMerkleProof) {
+    write_u32(out, proof.siblings.len());
+    for s in &proof.siblings {
+        out.extend_from_slice(s);
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Result<Self, CodecError> {
+        if bytes.len() > MAX_PROOF_BYTES {
+            return Err(CodecError::TooLarge(bytes.len()));
+        }
+        Ok(Self { bytes })
+    }
+
+    fn take(&mut self, n: usize) -> Result<&'a [u8], CodecError> {
+        if n > self.bytes.len() {
+            return Err(CodecError::Truncated);
+        }
+        let (head, rest) = self.bytes.split_at(n);
+        self.bytes = rest;
+        Ok(head)
+    }
+
+    fn u8(&mut self) -> Result<u8, CodecError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<usize, CodecError> {
+        let b = self.take(4)?;
+        Ok(u32::from_le_bytes([b[0], b[1], b[2], b[3]]) as usize)
+    }
+
+    /// Read a count of items that each occupy at least `min_item_len` bytes
+    fn count(&mut self, min_item_len: usize) -> Result<usize, CodecError> {
+        let n = self.u32()?;
+        if n.saturating_mul(min_item_len) > self.bytes.len() {
+            return Err(CodecError::Truncated);
+        }
+        Ok(n)
+    }
+
+    fn header<T: BinaryFieldElement, U: BinaryFieldElement>(&mut self) -> Result<(), CodecError> {
+        let h = self.take(HEADER_LEN).map_err(|_| CodecError::BadMagic)?;
+        if h[..4] != PROOF_MAGIC {
+            return Err(CodecError::BadMagic);
+        }
+        if h[4] != PROOF_VERSION {
+            return Err(CodecError::UnsupportedVersion(h[4]));
+        }
+        if h[5] as usize != T::BYTES || h[6] as usize != U::BYTES {
+            return Err(CodecError::FieldMismatch);
+        }
+        if h[7] != 0 {
+            return Err(CodecError::InvalidTag);
+        }
+        Ok(())
+    }
+
+    fn root(&mut self) -> Result<MerkleRoot, CodecError> {
+        match self.u8()? {
+            0 => Ok(MerkleRoot { root: None }),
+            1 => {
+                let mut hash: Hash = [0u8; 32];
+                hash.copy_from_slice(self.take(32)?);
+                Ok(MerkleRoot { root: Some(hash) })
+            }
+            _ => Err(CodecError::InvalidTag),
+        }
+    }
+
+    fn elem<F: BinaryFieldElement>(&mut self) -> Result<F, CodecError> {
+        Ok(F::read_bytes(self.take(F::BYTES)?))
+    }
+
+    fn elems<F: BinaryFieldElement>(&mut self) -> Result<Vec<F>, CodecError> {
+        let n = self.count(F::BYTES)?;
+        let bytes = self.take(n * F::BYTES)?;
+        Ok(bytes.chunks_exact(F::BYTES).map(F::read_bytes).collect())
+    }
+
+    fn rows<F: BinaryFieldElement>(&mut self) -> Result<Vec<Vec<F>>, CodecError> {
+        let count = self.u32()?;
+        let width = self.u32()?;
+        if width == 0 {
+            return Err(CodecError::ShapeMismatch("empty rows"));
+        }
+        // every row takes at least one byte, so count is bounded by the input
+        let row_len = width.checked_mul(F::BYTES).ok_or(CodecError::Truncated)?;
+        if count.saturating_mul(row_len) > self.bytes.len() {
+            return Err(CodecError::Truncated);
+        }
+
+        let mut rows = Vec::with_capacity(count);
+        for _ in 0..count {
+            let bytes = self.take(row_len)?;
+            rows.push(bytes.chunks_exact(F::BYTES).map(F::read_bytes).collect());
+        }
+        Ok(rows)
+    }
+
+    fn merkle(&mut self) -> Result<BatchedMerkleProof, CodecError> {
+        let n = self.count(32)?;
+        let siblings = (0..n)
+            .map(|_| {
+                let mut hash: Hash = [0u8; 32];
+                hash.copy_from_slice(self.take(32)?);
+                Ok(hash)
+            })
+            .collect::<Result<Vec<_>, CodecError>>()?;
+        Ok(BatchedMerkleProof { siblings })
+    }
+
+    fn finish(self) -> Result<(), CodecError> {
+        if self.bytes.is_empty() {
+            Ok(())
+        } else {
+            Err(CodecError::TrailingBytes)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{hardcoded_config_12, hardcoded_config_12_verifier, prove};
+    use binary_fields::{BinaryElem32, BinaryElem128};
+    use std::marker::PhantomData;
+
+    type Proof = FinalizedLigeritoProof<BinaryElem32, BinaryElem128>;
+
+    fn sample_proof() -> Proof {
+        let config = hardcoded_config_12(PhantomData::<BinaryElem32>, PhantomData::<BinaryElem128>);
+        let poly: Vec<BinaryElem32> = (0..1u32 << 12).map(BinaryElem32::from).collect();
+        prove(&config, &poly).unwrap()
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let proof = sample_proof();
+        let bytes = proof.to_bytes().unwrap();
+        assert_eq!(&bytes[..4], &PROOF_MAGIC);
+
+        let decoded = Proof::from_bytes_for_config(&bytes, &hardcoded_config_12_verifier()).unwrap();
+        assert_eq!(decoded.to_bytes().unwrap(), bytes);
+        assert_eq!(
+            decoded.final_ligero_proof.yr,
+            proof.final_ligero_proof.yr,
+        );
+    }
+
+    #[test]
+    fn test_rejects_malformed() {
+        let bytes = sample_proof().to_bytes().unwrap();
+
+        // every strict prefix is truncated
+        for cut in [0, 3, HEADER_LEN, HEADER_LEN + 1, bytes.len() / 2, bytes.len() - 1] {
+            assert!(Proof::from_bytes(&bytes[..cut]).is_err());
+        }
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert_eq!(Proof::from_bytes(&trailing).unwrap_err(), CodecError::TrailingBytes);
+
+        let mut bad_version = bytes.clone();
+        bad_version[4] = 99;
+        assert_eq!(Proof::from_bytes(&bad_version).unwrap_err(), CodecError::UnsupportedVersion(99));
+        bad_version[4] = 1;
+        assert_eq!(Proof::from_bytes(&bad_version).unwrap_err(), CodecError::UnsupportedVersion(1));
+
+        // wrong field types
+        assert_eq!(
+            FinalizedLigeritoProof::<BinaryElem128, BinaryElem128>::from_bytes(&bytes).unwrap_err(),
+            CodecError::FieldMismatch,
+        );
+
+        // huge row count is refused before allocating
+        let mut huge = bytes.clone();
+        let rows_at = HEADER_LEN + 33;
+        huge[rows_at..rows_at + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert_eq!(Proof::from_bytes(&huge).unwrap_err(), CodecError::Truncated);
+
+        // zero-width rows would let a count run past the input
+        let mut empty = bytes.clone();
+        empty[rows_at + 4..rows_at + 8].copy_from_slice(&0u32.to_le_bytes());
+        assert_eq!(Proof::from_bytes(&empty).unwrap_err(), CodecError::ShapeMismatch("empty rows"));
+
+        assert!(bytes.len() < MAX_PROOF_BYTES / 16);
+        assert!(matches!(
+            Proof::from_bytes(&vec![0u8; MAX_PROOF_BYTES + 1]),
+            Err(CodecError::TooLarge(_))
+        ));
+    }
+
+    #[test]
+    fn test_shape_checked_against_config() {
+        let bytes = sample_proof().to_bytes().unwrap();
+        let other = crate::hardcoded_config_16_verifier();
+        assert!(matches!(
+            Proof::from_bytes_for_config(&bytes, &other),
+            Err(CodecError::ShapeMismatch(_))
+        ));
+    }
+}
@@ -1,9 +1,32 @@
-//! Hardcoded configurations for different proof sizes
+//! Hardcoded configurations for different proof sizes, plus a builder that
+//! derives matching prover/verifier configurations for any log-size
 
 use crate::data_structures::{ProverConfig, VerifierConfig};
+#[cfg(feature = "std")]
+use crate::LigeritoError;
 use binary_fields::BinaryFieldElement;
 use reed_solomon::reed_solomon;
-use std::marker::PhantomData;
+use merkle_tree::MerkleHasher;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+/// Reed-Solomon rate used by the hardcoded configs (rate 1/4)
+pub const DEFAULT_LOG_INV_RATE: usize = 2;
+
+/// Target security level of the hardcoded configs, in bits
+pub const DEFAULT_SECURITY_BITS: usize = 100;
+
+/// Query count of the hardcoded configs (100 bits at rate 1/4)
+pub const DEFAULT_NUM_QUERIES: usize = 148;
+
+/// Matrices are not folded below this many columns in a recursive step
+#[cfg(feature = "std")]
+const DEFAULT_FOLD_K: usize = 4;
+
+/// Recursion stops once the committed matrix has at most 2^11 rows
+#[cfg(feature = "std")]
+const DEFAULT_FINAL_LOG_DIM: usize = 11;
 
 /// Create minimal configuration for 2^12 polynomial (for testing/demos)
 pub fn hardcoded_config_12<T, U>(
@@ -36,6 +59,8 @@ where
         ks,
         initial_reed_solomon,
         reed_solomon_codes,
+        num_queries: DEFAULT_NUM_QUERIES,
+        hasher: MerkleHasher::Sha256,
     }
 }
 
@@ -46,6 +71,9 @@ pub fn hardcoded_config_12_verifier() -> VerifierConfig {
         log_dims: vec![6],
         initial_k: 4,
         ks: vec![2],
+        log_inv_rate: DEFAULT_LOG_INV_RATE,
+        num_queries: DEFAULT_NUM_QUERIES,
+        hasher: MerkleHasher::Sha256,
     }
 }
 
@@ -80,6 +108,8 @@ where
         ks,
         initial_reed_solomon,
         reed_solomon_codes,
+        num_queries: DEFAULT_NUM_QUERIES,
+        hasher: MerkleHasher::Sha256,
     }
 }
 
@@ -90,6 +120,9 @@ pub fn hardcoded_config_16_verifier() -> VerifierConfig {
         log_dims: vec![8],
         initial_k: 4,
         ks: vec![4],
+        log_inv_rate: DEFAULT_LOG_INV_RATE,
+        num_queries: DEFAULT_NUM_QUERIES,
+        hasher: MerkleHasher::Sha256,
     }
 }
 
@@ -126,6 +159,8 @@ where
         ks,
         initial_reed_solomon,
         reed_solomon_codes,
+        num_queries: DEFAULT_NUM_QUERIES,
+        hasher: MerkleHasher::Sha256,
     }
 }
 
@@ -136,6 +171,9 @@ pub fn hardcoded_config_20_verifier() -> VerifierConfig {
         log_dims: vec![10],
         initial_k: 6,
         ks: vec![4],
+        log_inv_rate: DEFAULT_LOG_INV_RATE,
+        num_queries: DEFAULT_NUM_QUERIES,
+        hasher: MerkleHasher::Sha256,
     }
 }
 
@@ -173,6 +211,8 @@ where
         ks,
         initial_reed_solomon,
         reed_solomon_codes,
+        num_queries: DEFAULT_NUM_QUERIES,
+        hasher: MerkleHasher::Sha256,
     }
 }
 
@@ -183,6 +223,9 @@ pub fn hardcoded_config_24_verifier() -> VerifierConfig {
         log_dims: vec![14, 10],
         initial_k: 6,
         ks: vec![4, 4],
+        log_inv_rate: DEFAULT_LOG_INV_RATE,
+        num_queries: DEFAULT_NUM_QUERIES,
+        hasher: MerkleHasher::Sha256,
     }
 }
 
@@ -222,6 +265,8 @@ where
         ks,
         initial_reed_solomon,
         reed_solomon_codes,
+        num_queries: DEFAULT_NUM_QUERIES,
+        hasher: MerkleHasher::Sha256,
     }
 }
 
@@ -232,6 +277,9 @@ pub fn hardcoded_config_28_verifier() -> VerifierConfig {
         log_dims: vec![19, 16, 13, 10],
         initial_k: 6,
         ks: vec![3, 3, 3, 3],
+        log_inv_rate: DEFAULT_LOG_INV_RATE,
+        num_queries: DEFAULT_NUM_QUERIES,
+        hasher: MerkleHasher::Sha256,
     }
 }
 
@@ -270,6 +318,8 @@ where
         ks,
         initial_reed_solomon,
         reed_solomon_codes,
+        num_queries: DEFAULT_NUM_QUERIES,
+        hasher: MerkleHasher::Sha256,
     }
 }
 
@@ -280,5 +330,314 @@ pub fn hardcoded_config_30_verifier() -> VerifierConfig {
         log_dims: vec![19, 15, 11],
         initial_k: 7,
         ks: vec![4, 4, 4],
+        log_inv_rate: DEFAULT_LOG_INV_RATE,
+        num_queries: DEFAULT_NUM_QUERIES,
+        hasher: MerkleHasher::Sha256,
+    }
+}
+
+/// Number of column queries needed for `security_bits` of soundness at
+/// rate 2^-log_inv_rate, assuming proximity within the unique decoding
+/// radius (1 - rho) / 2.
+#[cfg(feature = "std")]
+pub fn num_queries_for_security(security_bits: usize, log_inv_rate: usize) -> usize {
+    let rho = 1.0 / (1u64 << log_inv_rate) as f64;
+    let bits_per_query = -(1.0 - (1.0 - rho) / 2.0).log2();
+    (security_bits as f64 / bits_per_query).ceil() as usize
+}
+
+/// Shape of a Ligerito proof, from which both the prover and the verifier
+/// config are derived so the two cannot drift apart
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConfigParams {
+    pub log_size: usize,
+    pub recursive_steps: usize,
+    pub initial_dim: usize,
+    pub initial_k: usize,
+    pub log_dims: Vec<usize>,
+    pub ks: Vec<usize>,
+    pub log_inv_rate: usize,
+    pub num_queries: usize,
+    pub hasher: MerkleHasher,
+}
+
+impl ConfigParams {
+    /// Derive parameters for a 2^log_size polynomial at the default security level
+    #[cfg(feature = "std")]
+    pub fn new(log_size: usize) -> crate::Result<Self> {
+        ConfigBuilder::new(log_size).build()
+    }
+
+    /// Build the prover config
+    pub fn prover_config<T, U>(&self) -> ProverConfig<T, U>
+    where
+        T: BinaryFieldElement,
+        U: BinaryFieldElement,
+    {
+        let inv_rate = 1 << self.log_inv_rate;
+        let initial_dims = (1 << self.initial_dim, 1 << self.initial_k);
+        let dims: Vec<(usize, usize)> = self.log_dims.iter()
+            .zip(self.ks.iter())
+            .map(|(&d, &k)| (1 << d, 1 << k))
+            .collect();
+
+        let initial_reed_solomon = reed_solomon::<T>(initial_dims.0, initial_dims.0 * inv_rate);
+        let reed_solomon_codes = dims.iter()
+            .map(|&(m, _)| reed_solomon::<U>(m, m * inv_rate))
+            .collect();
+
+        ProverConfig {
+            recursive_steps: self.recursive_steps,
+            initial_dims,
+            dims,
+            initial_k: self.initial_k,
+            ks: self.ks.clone(),
+            initial_reed_solomon,
+            reed_solomon_codes,
+            num_queries: self.num_queries,
+            hasher: self.hasher,
+        }
+    }
+
+    /// Build the verifier config
+    pub fn verifier_config(&self) -> VerifierConfig {
+        VerifierConfig {
+            recursive_steps: self.recursive_steps,
+            initial_dim: self.initial_dim,
+            log_dims: self.log_dims.clone(),
+            initial_k: self.initial_k,
+            ks: self.ks.clone(),
+            log_inv_rate: self.log_inv_rate,
+            num_queries: self.num_queries,
+            hasher: self.hasher,
+        }
+    }
+}
+
+/// Builder for [`ConfigParams`]
+///
+/// The defaults reproduce the shape of the hardcoded configs: fold 4
+/// variables per recursive step until the committed matrix has at most 2^11
+/// rows, at rate 1/4 and 100 bits of security. Needs `std` for the
+/// floating point query count; `no_std` verifiers use a fixed config.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug)]
+pub struct ConfigBuilder {
+    log_size: usize,
+    security_bits: usize,
+    log_inv_rate: usize,
+    initial_k: Option<usize>,
+    fold_k: usize,
+    final_log_dim: usize,
+    hasher: MerkleHasher,
+}
+
+#[cfg(feature = "std")]
+impl ConfigBuilder {
+    pub fn new(log_size: usize) -> Self {
+        Self {
+            log_size,
+            security_bits: DEFAULT_SECURITY_BITS,
+            log_inv_rate: DEFAULT_LOG_INV_RATE,
+            initial_k: None,
+            fold_k: DEFAULT_FOLD_K,
+            final_log_dim: DEFAULT_FINAL_LOG_DIM,
+            hasher: MerkleHasher::Sha256,
+        }
+    }
+
+    /// Target soundness in bits (determines the number of queries)
+    pub fn security_bits(mut self, bits: usize) -> Self {
+        self.security_bits = bits;
+        self
+    }
+
+    /// Reed-Solomon inverse rate as a power of two
+    pub fn log_inv_rate(mut self, log_inv_rate: usize) -> Self {
+        self.log_inv_rate = log_inv_rate;
+        self
+    }
+
+    /// Number of variables folded by the initial (base field) step
+    pub fn initial_k(mut self, k: usize) -> Self {
+        self.initial_k = Some(k);
+        self
+    }
+
+    /// Number of variables folded per recursive step
+    pub fn fold_k(mut self, k: usize) -> Self {
+        self.fold_k = k;
+        self
+    }
+
+    /// Stop recursing once the matrix has at most 2^final_log_dim rows
+    pub fn final_log_dim(mut self, d: usize) -> Self {
+        self.final_log_dim = d;
+        self
+    }
+
+    /// Merkle tree hash for all commitments (SHA-256 by default)
+    pub fn hasher(mut self, hasher: MerkleHasher) -> Self {
+        self.hasher = hasher;
+        self
+    }
+
+    pub fn build(self) -> crate::Result<ConfigParams> {
+        if self.log_inv_rate == 0 {
+            return Err(LigeritoError::InvalidConfig("log_inv_rate must be at least 1".into()));
+        }
+        if self.fold_k == 0 {
+            return Err(LigeritoError::InvalidConfig("fold_k must be at least 1".into()));
+        }
+        if self.security_bits == 0 {
+            return Err(LigeritoError::InvalidConfig("security_bits must be at least 1".into()));
+        }
+
+        let num_queries = num_queries_for_security(self.security_bits, self.log_inv_rate);
+
+        // every committed matrix needs at least num_queries distinct rows
+        let mut min_log_dim = 0;
+        while (1usize << (min_log_dim + self.log_inv_rate)) < num_queries {
+            min_log_dim += 1;
+        }
+
+        // initial step needs room for at least one recursive fold below it
+        if self.log_size < min_log_dim + 2 {
+            return Err(LigeritoError::InvalidConfig(format!(
+                "log_size {} too small: need at least {} for {} queries",
+                self.log_size, min_log_dim + 2, num_queries,
+            )));
+        }
+
+        let initial_k = match self.initial_k {
+            Some(k) => k,
+            None => default_initial_k(self.log_size).min(self.log_size - min_log_dim - 1),
+        };
+        if initial_k == 0 || initial_k + min_log_dim + 1 > self.log_size {
+            return Err(LigeritoError::InvalidConfig(format!(
+                "initial_k {} invalid for log_size {}", initial_k, self.log_size,
+            )));
+        }
+
+        let initial_dim = self.log_size - initial_k;
+        let final_log_dim = self.final_log_dim.max(min_log_dim);
+
+        let mut log_dims = Vec::new();
+        let mut ks = Vec::new();
+        let mut dim = initial_dim;
+        loop {
+            let k = self.fold_k.min(dim - min_log_dim);
+            dim -= k;
+            log_dims.push(dim);
+            ks.push(k);
+            if dim <= final_log_dim || dim == min_log_dim {
+                break;
+            }
+        }
+
+        Ok(ConfigParams {
+            log_size: self.log_size,
+            recursive_steps: log_dims.len(),
+            initial_dim,
+            initial_k,
+            log_dims,
+            ks,
+            log_inv_rate: self.log_inv_rate,
+            num_queries,
+            hasher: self.hasher,
+        })
+    }
+}
+
+/// Base field fold used by the hardcoded ladder: wider for larger polynomials
+#[cfg(feature = "std")]
+fn default_initial_k(log_size: usize) -> usize {
+    match log_size {
+        0..=16 => 4,
+        17..=28 => 6,
+        _ => 7,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use binary_fields::{BinaryElem32, BinaryElem128};
+
+    #[test]
+    fn test_default_query_count() {
+        assert_eq!(
+            num_queries_for_security(DEFAULT_SECURITY_BITS, DEFAULT_LOG_INV_RATE),
+            DEFAULT_NUM_QUERIES,
+        );
+    }
+
+    #[test]
+    fn test_builder_matches_hardcoded() {
+        assert_eq!(ConfigParams::new(12).unwrap().verifier_config(), hardcoded_config_12_verifier());
+        assert_eq!(ConfigParams::new(16).unwrap().verifier_config(), hardcoded_config_16_verifier());
+        assert_eq!(ConfigParams::new(20).unwrap().verifier_config(), hardcoded_config_20_verifier());
+        assert_eq!(ConfigParams::new(24).unwrap().verifier_config(), hardcoded_config_24_verifier());
+        assert_eq!(ConfigParams::new(30).unwrap().verifier_config(), hardcoded_config_30_verifier());
+    }
+
+    #[test]
+    fn test_params_consistent_for_all_sizes() {
+        for log_size in 8..=40 {
+            for bits in [80, 100, 128] {
+                let params = ConfigBuilder::new(log_size).security_bits(bits).build().unwrap();
+                assert_eq!(params.initial_dim + params.initial_k, log_size);
+                assert_eq!(params.recursive_steps, params.log_dims.len());
+                assert_eq!(params.recursive_steps, params.ks.len());
+
+                let mut prev = params.initial_dim;
+                for (&d, &k) in params.log_dims.iter().zip(params.ks.iter()) {
+                    assert!(k > 0);
+                    assert_eq!(d + k, prev);
+                    assert!(1 << (d + params.log_inv_rate) >= params.num_queries);
+                    prev = d;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_prover_and_verifier_agree() {
+        for log_size in 8..=14 {
+            let params = ConfigParams::new(log_size).unwrap();
+            let p = params.prover_config::<BinaryElem32, BinaryElem128>();
+            let v = params.verifier_config();
+
+            assert_eq!(p.initial_dims, (1 << v.initial_dim, 1 << v.initial_k));
+            assert_eq!(p.initial_dims.0 * p.initial_dims.1, 1 << log_size);
+            assert_eq!(p.initial_reed_solomon.log_block_length, v.initial_dim + v.log_inv_rate);
+            for (i, &(m, n)) in p.dims.iter().enumerate() {
+                assert_eq!((m, n), (1 << v.log_dims[i], 1 << v.ks[i]));
+                assert_eq!(p.reed_solomon_codes[i].log_block_length, v.log_dims[i] + v.log_inv_rate);
+            }
+            assert_eq!(p.num_queries, v.num_queries);
+        }
+    }
+
+    #[test]
+    fn test_derived_configs_prove_and_verify() {
+        use rand::Rng;
+
+        let mut rng = rand::thread_rng();
+        for log_size in [13, 17] {
+            let params = ConfigParams::new(log_size).unwrap();
+            let config = params.prover_config::<BinaryElem32, BinaryElem128>();
+            let poly: Vec<BinaryElem32> = (0..1 << log_size).map(|_| BinaryElem32::from(rng.gen::<u32>())).collect();
+
+            let proof = crate::prove(&config, &poly).unwrap();
+            assert!(crate::verify(&params.verifier_config(), &proof).unwrap(), "log_size {}", log_size);
+        }
+    }
+
+    #[test]
+    fn test_rejects_too_small() {
+        assert!(ConfigParams::new(7).is_err());
+        assert!(ConfigBuilder::new(20).log_inv_rate(0).build().is_err());
+        assert!(ConfigBuilder::new(20).initial_k(14).build().is_err());
     }
 }
@@ -0,0 +1,255 @@
+//! Deterministic cost model for [`verify`](crate::verify)
+//!
+//! Counts the field multiplications, hash compressions and transcript work
+//! the verifier performs for a given [`VerifierConfig`]. The counts depend
+//! only on the config and the field types, never on the proof, so a runtime
+//! can charge weight up front and refuse a proof before running the verifier.
+//!
+//! Hash counts are upper bounds: SHA-256 row hashing assumes the longest
+//! `Debug` encoding of every element and Merkle paths assume no shared
+//! siblings.
+//! Query sampling is counted as one squeeze per query; redrawing duplicates
+//! adds a small transcript-dependent number of squeezes on top.
+
+use alloc::format;
+use binary_fields::BinaryFieldElement;
+
+use merkle_tree::MerkleHasher;
+
+use crate::VerifierConfig;
+
+/// Operation counts of one verification, or per-operation prices when passed
+/// to [`VerifierCost::weight`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct VerifierCost {
+    /// Multiplications in the base field
+    pub base_muls: u64,
+    /// Multiplications in the extension field
+    pub ext_muls: u64,
+    /// Compression function calls of the config's hasher, or permutations
+    /// for Rescue
+    pub hash_blocks: u64,
+    /// Bytes absorbed into the Fiat-Shamir transcript
+    pub transcript_bytes: u64,
+    /// Challenges and query indices drawn from the transcript
+    pub transcript_squeezes: u64,
+}
+
+impl VerifierCost {
+    /// Total weight given a price for each operation, saturating on overflow
+    pub fn weight(&self, prices: &VerifierCost) -> u64 {
+        [
+            (self.base_muls, prices.base_muls),
+            (self.ext_muls, prices.ext_muls),
+            (self.hash_blocks, prices.hash_blocks),
+            (self.transcript_bytes, prices.transcript_bytes),
+            (self.transcript_squeezes, prices.transcript_squeezes),
+        ]
+        .iter()
+        .fold(0u64, |acc, &(n, p)| acc.saturating_add(n.saturating_mul(p)))
+    }
+}
+
+/// Cost of [`verify`](crate::verify) for proofs over base field `T` and
+/// extension field `U` under `config`
+pub fn verifier_cost<T, U>(config: &VerifierConfig) -> VerifierCost
+where
+    T: BinaryFieldElement,
+    U: BinaryFieldElement,
+{
+    let s = config.num_queries as u64;
+    let r = config.log_inv_rate;
+    let mut cost = VerifierCost::default();
+
+    // initial commitment: k base field challenges, S openings of width 2^k
+    cost.transcript_bytes += 2 * 32;
+    cost.transcript_squeezes += config.initial_k as u64 + s + 1;
+    cost.hash_blocks += opening_hash_blocks::<T>(config.hasher, config.initial_dim + r, 1 << config.initial_k, s);
+    cost.base_muls += sk_vks_muls(config.initial_dim) + s * sks_muls(config.initial_dim);
+    cost.ext_muls += induce_muls(config.initial_k, s);
+    cost.transcript_bytes += U::BYTES as u64;
+
+    for i in 0..config.recursive_steps {
+        let k = config.ks[i];
+        let dim = config.log_dims[i];
+        // the folded basis keeps S terms per level glued so far
+        let terms = s * (i as u64 + 1);
+
+        // three quadratic evaluations and a fold of every basis term per round
+        cost.ext_muls += k as u64 * (9 + 2 * terms);
+        cost.transcript_squeezes += k as u64;
+        cost.transcript_bytes += (3 * k * U::BYTES) as u64;
+
+        cost.hash_blocks += opening_hash_blocks::<U>(config.hasher, dim + r, 1 << k, s);
+
+        if i == config.recursive_steps - 1 {
+            // yr is absorbed, checked against every opened row and paired
+            // with the expanded basis
+            cost.transcript_bytes += (1u64 << dim) * U::BYTES as u64;
+            cost.transcript_squeezes += s;
+            cost.ext_muls += sk_vks_muls(dim)
+                + lagrange_muls(k)
+                + s * ((1 << k) + sks_muls(dim) + scaled_basis_muls(dim) + (1 << dim))
+                + terms * scaled_basis_muls(dim)
+                + (1 << dim);
+        } else {
+            cost.transcript_bytes += 32 + U::BYTES as u64;
+            cost.transcript_squeezes += s + 2;
+            cost.ext_muls += sk_vks_muls(dim) + s * sks_muls(dim) + induce_muls(k, s) + s + 1;
+        }
+    }
+
+    cost
+}
+
+/// Alpha powers for the basis terms and the enforced sum of S rows of width 2^k
+fn induce_muls(k: usize, s: u64) -> u64 {
+    2 * s.saturating_sub(1) + lagrange_muls(k) + s * ((1 << k) + 1)
+}
+
+fn lagrange_muls(k: usize) -> u64 {
+    if k == 0 {
+        0
+    } else {
+        (1u64 << (k + 1)) - 4
+    }
+}
+
+/// Expanding s_k(q) into the 2^n products of a generator row
+fn scaled_basis_muls(n: usize) -> u64 {
+    (1u64 << n) - 1
+}
+
+/// s_0(q), ..., s_{n-1}(q) for one query
+fn sks_muls(n: usize) -> u64 {
+    2 * n as u64
+}
+
+fn sk_vks_muls(n: usize) -> u64 {
+    (n * (n + 1)) as u64
+}
+
+/// Hashing S opened rows of `width` elements and checking their Merkle paths
+fn opening_hash_blocks<F: BinaryFieldElement>(
+    hasher: MerkleHasher,
+    depth: usize,
+    width: usize,
+    s: u64,
+) -> u64 {
+    let row_bytes = match hasher {
+        MerkleHasher::Sha256 => width as u64 * max_row_elem_bytes::<F>(),
+        _ => (width * F::BYTES) as u64,
+    };
+    let rows = s * hash_blocks(hasher, row_bytes);
+
+    // leaves are row digests, inner nodes two digests; at most S per layer
+    let inner: u64 = (0..depth)
+        .map(|layer| s.min(1u64 << layer))
+        .sum();
+
+    rows + s * hash_blocks(hasher, 32) + inner * node_blocks(hasher)
+}
+
+/// Longest per-element input to `hash_row`: a u32 position and the `Debug`
+/// encoding, which prints the element value in decimal
+fn max_row_elem_bytes<F: BinaryFieldElement>() -> u64 {
+    let zero_len = format!("{:?}", F::zero()).len() as u64;
+    let max_digits = (F::BYTES as u64 * 8 * 30103) / 100000 + 1;
+    4 + zero_len - 1 + max_digits
+}
+
+/// Compressions (or permutations) to hash `len` bytes as a leaf
+///
+/// The byte hashers prefix a one-byte domain tag.
+fn hash_blocks(hasher: MerkleHasher, len: u64) -> u64 {
+    let tagged = len + 1;
+    match hasher {
+        MerkleHasher::Sha256 => (tagged + 9).div_ceil(64),
+        // 1024-byte chunks of 64-byte blocks, merged by parent compressions
+        MerkleHasher::Blake3 => tagged.div_ceil(64) + tagged.div_ceil(1024) - 1,
+        MerkleHasher::Keccak256 => (tagged + 1).div_ceil(136),
+        // domain tag and length, 16-byte elements at rate 2, one padding call
+        MerkleHasher::Rescue => (2 + len.div_ceil(16)).div_ceil(2) + 1,
+    }
+}
+
+/// Compressions (or permutations) to hash two 32-byte children after the tag
+fn node_blocks(hasher: MerkleHasher) -> u64 {
+    match hasher {
+        MerkleHasher::Sha256 | MerkleHasher::Blake3 => 2,
+        MerkleHasher::Keccak256 => 1,
+        MerkleHasher::Rescue => 4,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{hardcoded_config_12_verifier, hardcoded_config_24_verifier};
+    use binary_fields::{BinaryElem128, BinaryElem32};
+
+    #[test]
+    fn test_row_bound_covers_largest_elements() {
+        let max32 = BinaryElem32::from_bits(u32::MAX as u64);
+        let row32 = format!("{:?}", max32).len() as u64 + 4;
+        assert!(row32 <= max_row_elem_bytes::<BinaryElem32>());
+
+        let max128 = BinaryElem128::read_bytes(&[0xff; 16]);
+        let row128 = format!("{:?}", max128).len() as u64 + 4;
+        assert!(row128 <= max_row_elem_bytes::<BinaryElem128>());
+    }
+
+    #[test]
+    fn test_cost_grows_with_size() {
+        let small = verifier_cost::<BinaryElem32, BinaryElem128>(&hardcoded_config_12_verifier());
+        let large = verifier_cost::<BinaryElem32, BinaryElem128>(&hardcoded_config_24_verifier());
+
+        assert!(small.ext_muls > 0 && small.hash_blocks > 0);
+        assert!(large.ext_muls > small.ext_muls);
+        assert!(large.hash_blocks > small.hash_blocks);
+        assert_eq!(small, verifier_cost::<BinaryElem32, BinaryElem128>(&hardcoded_config_12_verifier()));
+    }
+
+    #[test]
+    fn test_hasher_only_changes_hash_blocks() {
+        let sha = hardcoded_config_12_verifier();
+        let sha_cost = verifier_cost::<BinaryElem32, BinaryElem128>(&sha);
+
+        for hasher in [MerkleHasher::Blake3, MerkleHasher::Keccak256, MerkleHasher::Rescue] {
+            let config = crate::VerifierConfig { hasher, ..sha.clone() };
+            let cost = verifier_cost::<BinaryElem32, BinaryElem128>(&config);
+            assert!(cost.hash_blocks > 0);
+            assert_eq!(cost, VerifierCost { hash_blocks: cost.hash_blocks, ..sha_cost });
+            if hasher != MerkleHasher::Rescue {
+                assert!(cost.hash_blocks < sha_cost.hash_blocks);
+            }
+        }
+
+        // two tagged 32-byte children are one tagged 64-byte input
+        for hasher in [MerkleHasher::Sha256, MerkleHasher::Blake3, MerkleHasher::Keccak256] {
+            assert_eq!(node_blocks(hasher), hash_blocks(hasher, 64));
+        }
+    }
+
+    #[test]
+    fn test_weight_is_linear_and_saturates() {
+        let cost = VerifierCost {
+            base_muls: 1,
+            ext_muls: 2,
+            hash_blocks: 3,
+            transcript_bytes: 4,
+            transcript_squeezes: 5,
+        };
+        let prices = VerifierCost {
+            base_muls: 10,
+            ext_muls: 100,
+            hash_blocks: 1000,
+            transcript_bytes: 1,
+            transcript_squeezes: 0,
+        };
+        assert_eq!(cost.weight(&prices), 10 + 200 + 3000 + 4);
+
+        let huge = VerifierCost { ext_muls: u64::MAX, ..VerifierCost::default() };
+        assert_eq!(huge.weight(&prices), u64::MAX);
+    }
+}
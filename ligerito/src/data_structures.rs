@@ -1,6 +1,7 @@
+use alloc::vec::Vec;
 use binary_fields::BinaryFieldElement;
 use reed_solomon::ReedSolomon;
-use merkle_tree::{CompleteMerkleTree, MerkleRoot, BatchedMerkleProof};
+use merkle_tree::{CompleteMerkleTree, MerkleRoot, BatchedMerkleProof, MerkleHasher};
 use serde::{Serialize, Deserialize};
 
 /// Prover configuration
@@ -12,22 +13,37 @@ pub struct ProverConfig<T: BinaryFieldElement, U: BinaryFieldElement> {
     pub ks: Vec<usize>,
     pub initial_reed_solomon: ReedSolomon<T>,
     pub reed_solomon_codes: Vec<ReedSolomon<U>>,
+    pub num_queries: usize,
+    /// Hash of every Merkle tree and opened row
+    pub hasher: MerkleHasher,
 }
 
 /// Verifier configuration
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct VerifierConfig {
     pub recursive_steps: usize,
     pub initial_dim: usize,
     pub log_dims: Vec<usize>,
     pub initial_k: usize,
     pub ks: Vec<usize>,
+    pub log_inv_rate: usize,
+    pub num_queries: usize,
+    /// Must match the prover's hasher
+    pub hasher: MerkleHasher,
 }
 
 /// Recursive Ligero witness (prover side)
+#[derive(Clone, Serialize, Deserialize)]
 pub struct RecursiveLigeroWitness<T: BinaryFieldElement> {
     pub mat: Vec<Vec<T>>,  // Row-major matrix
-    pub tree: CompleteMerkleTree,
+    pub tree: CompleteMerkleTree<MerkleHasher>,
+}
+
+/// Prover state between `commit` and `open`
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ProverState<T: BinaryFieldElement> {
+    pub poly: Vec<T>,
+    pub witness: RecursiveLigeroWitness<T>,
 }
 
 /// Recursive Ligero commitment
@@ -52,7 +68,7 @@ pub struct RecursiveLigeroProof<T: BinaryFieldElement> {
 impl<T: BinaryFieldElement> RecursiveLigeroProof<T> {
     pub fn size_of(&self) -> usize {
         self.opened_rows.iter()
-            .map(|row| row.len() * std::mem::size_of::<T>())
+            .map(|row| row.len() * core::mem::size_of::<T>())
             .sum::<usize>()
             + self.merkle_proof.size_of()
     }
@@ -68,9 +84,9 @@ pub struct FinalLigeroProof<T: BinaryFieldElement> {
 
 impl<T: BinaryFieldElement> FinalLigeroProof<T> {
     pub fn size_of(&self) -> usize {
-        self.yr.len() * std::mem::size_of::<T>()
+        self.yr.len() * core::mem::size_of::<T>()
             + self.opened_rows.iter()
-                .map(|row| row.len() * std::mem::size_of::<T>())
+                .map(|row| row.len() * core::mem::size_of::<T>())
                 .sum::<usize>()
             + self.merkle_proof.size_of()
     }
@@ -84,7 +100,7 @@ pub struct SumcheckTranscript<T: BinaryFieldElement> {
 
 impl<T: BinaryFieldElement> SumcheckTranscript<T> {
     pub fn size_of(&self) -> usize {
-        self.transcript.len() * 3 * std::mem::size_of::<T>()
+        self.transcript.len() * 3 * core::mem::size_of::<T>()
     }
 }
 
@@ -137,6 +153,38 @@ impl<T: BinaryFieldElement, U: BinaryFieldElement> FinalizedLigeritoProof<T, U>
     }
 }
 
+/// Several polynomials opened under one Ligerito proof
+///
+/// `log_sizes[i]` is the log-length of the i-th polynomial; the initial
+/// opened rows are the concatenation of every polynomial's encoded row.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BatchedLigeritoProof<T: BinaryFieldElement, U: BinaryFieldElement> {
+    pub log_sizes: Vec<usize>,
+    pub proof: FinalizedLigeritoProof<T, U>,
+}
+
+impl<T: BinaryFieldElement, U: BinaryFieldElement> BatchedLigeritoProof<T, U> {
+    pub fn size_of(&self) -> usize {
+        self.log_sizes.len() * core::mem::size_of::<u32>() + self.proof.size_of()
+    }
+}
+
+/// Proof that a committed polynomial evaluates to a claimed value
+///
+/// `yr` is the combination of the message rows with the column part of the
+/// evaluation point; it is checked against the initial opened rows.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EvaluationProof<T: BinaryFieldElement, U: BinaryFieldElement> {
+    pub yr: Vec<U>,
+    pub proof: FinalizedLigeritoProof<T, U>,
+}
+
+impl<T: BinaryFieldElement, U: BinaryFieldElement> EvaluationProof<T, U> {
+    pub fn size_of(&self) -> usize {
+        self.yr.len() * core::mem::size_of::<U>() + self.proof.size_of()
+    }
+}
+
 /// Finalize a proof builder into a complete proof
 pub fn finalize<T: BinaryFieldElement, U: BinaryFieldElement>(
     proof: LigeritoProof<T, U>,
@@ -1,14 +1,44 @@
 //! Ligerito polynomial commitment scheme implementation
 //! Based on the paper by Andrija Novakovic and Guillermo Angeris
 //! https://angeris.github.io/papers/ligerito.pdf
-
+//!
+//! # Features
+//!
+//! - `std` (default): standard library support
+//! - `parallel`: multithreaded verification helpers (rayon)
+//! - `merlin`: the Merlin transcript behind `prove` / `verify` and friends
+//! - `prover` (default): proving, requires `std`, `parallel` and `merlin`
+//! - `verifier-only`: verifier, codec and [`cost`] model only
+//!
+//! With `default-features = false` the crate is `no_std` + `alloc` and the
+//! verifier runs single-threaded, for use in runtimes and PolkaVM guests.
+//! `verifier-only` guarantees no prover, rayon or merlin code is linked:
+//! such builds verify SHA-256 transcript proofs (`prove_sha256` /
+//! [`verify_sha256`]).
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(all(feature = "verifier-only", any(feature = "prover", feature = "parallel", feature = "merlin")))]
+compile_error!("`verifier-only` excludes the `prover`, `parallel` and `merlin` features; \
+    build with default-features = false");
+
+extern crate alloc;
+
+use alloc::string::String;
+use core::fmt;
+
+pub mod codec;
 pub mod configs;
+pub mod cost;
 pub mod data_structures;
 pub mod transcript;
 pub mod utils;
 pub mod sumcheck_polys;
 pub mod ligero;
+#[cfg(feature = "prover")]
 pub mod prover;
+#[cfg(feature = "prover")]
+pub mod streaming;
 pub mod verifier;
 
 pub use configs::{
@@ -18,35 +48,96 @@ pub use configs::{
     hardcoded_config_24, hardcoded_config_24_verifier,
     hardcoded_config_28, hardcoded_config_28_verifier,
     hardcoded_config_30, hardcoded_config_30_verifier,
+    ConfigParams,
 };
+#[cfg(feature = "std")]
+pub use configs::ConfigBuilder;
+pub use codec::CodecError;
+pub use cost::{verifier_cost, VerifierCost};
 pub use data_structures::*;
-pub use prover::{prove, prove_sha256, prove_with_transcript};
-pub use verifier::{verify, verify_sha256, verify_with_transcript};
+#[cfg(feature = "prover")]
+pub use prover::{
+    prove, prove_sha256, prove_with_transcript, commit, open,
+    prove_batch, prove_batch_with_transcript,
+    prove_evaluation, prove_evaluation_with_transcript,
+};
+#[cfg(feature = "prover")]
+pub use streaming::{
+    prove_streaming, prove_streaming_with_transcript,
+    PolySource, ReaderSource, RowSource,
+};
+pub use verifier::{
+    verify_sha256, verify_with_transcript,
+    verify_batch_with_transcript, verify_evaluation_with_transcript,
+};
+#[cfg(feature = "merlin")]
+pub use verifier::{verify, verify_batch, verify_evaluation};
 pub use transcript::{FiatShamir, TranscriptType};
+pub use merkle_tree::MerkleHasher;
 use utils::evaluate_lagrange_basis;
 use sumcheck_polys::precompute_alpha_powers;
 
 use binary_fields::BinaryFieldElement;
 
 /// Error types for Ligerito
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug)]
 pub enum LigeritoError {
-    #[error("Invalid configuration: {0}")]
     InvalidConfig(String),
 
-    #[error("Proof verification failed")]
     VerificationFailed,
 
-    #[error("Invalid proof structure")]
     InvalidProof,
 
-    #[error("Merkle tree error: {0}")]
     MerkleError(String),
+
+    Decode(CodecError),
+
+    #[cfg(feature = "std")]
+    Io(std::io::Error),
 }
 
-pub type Result<T> = std::result::Result<T, LigeritoError>;
+impl fmt::Display for LigeritoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidConfig(msg) => write!(f, "Invalid configuration: {}", msg),
+            Self::VerificationFailed => write!(f, "Proof verification failed"),
+            Self::InvalidProof => write!(f, "Invalid proof structure"),
+            Self::MerkleError(msg) => write!(f, "Merkle tree error: {}", msg),
+            Self::Decode(e) => write!(f, "Invalid proof encoding: {}", e),
+            #[cfg(feature = "std")]
+            Self::Io(e) => write!(f, "I/O error: {}", e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LigeritoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Decode(e) => Some(e),
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<CodecError> for LigeritoError {
+    fn from(e: CodecError) -> Self {
+        Self::Decode(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for LigeritoError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+pub type Result<T> = core::result::Result<T, LigeritoError>;
 
 /// Main prover function (uses Merlin transcript by default)
+#[cfg(feature = "prover")]
 pub fn prover<T, U>(
     config: &ProverConfig<T, U>,
     poly: &[T],
@@ -59,7 +150,7 @@ where
 }
 
 /// Main verifier function (uses Merlin transcript by default)
-
+#[cfg(feature = "merlin")]
 pub fn verifier<T, U>(
     config: &VerifierConfig,
     proof: &FinalizedLigeritoProof<T, U>,
@@ -300,50 +391,296 @@ mod tests {
         assert!(non_zero > 0, "Lagrange basis should have non-zero entries!");
     }
 
-#[test] 
-    fn test_basis_polynomial_sum() {
+#[test]
+    fn test_induced_basis_matches_opened_rows() {
         use binary_fields::{BinaryElem32, BinaryElem128, BinaryFieldElement};
-        use crate::sumcheck_polys::induce_sumcheck_poly_debug;
-        use crate::utils::eval_sk_at_vks;
-
-        // Create a simple test case
-        let n = 4; // 2^4 = 16 elements
+        use crate::ligero::ligero_commit;
+        use crate::sumcheck_polys::{induce_sumcheck_poly, FoldedBasis};
+        use crate::utils::{eval_sk_at_vks, partial_eval_multilinear_high};
+
+        // 16 rows, 4 columns, rate 1/4
+        let n = 4;
+        let poly: Vec<BinaryElem32> = (0..64u32).map(|i| BinaryElem32::from(i.wrapping_mul(0x9e3779b9))).collect();
+        let rs = reed_solomon::reed_solomon::<BinaryElem32>(16, 64);
+        let wtns = ligero_commit(&poly, 16, 4, &rs, crate::MerkleHasher::Sha256);
+
+        let v_challenges = vec![BinaryElem128::from(0x1234), BinaryElem128::from(0x5678)];
+        let mut folded: Vec<BinaryElem128> = poly.iter().map(|&x| BinaryElem128::from(x)).collect();
+        partial_eval_multilinear_high(&mut folded, &v_challenges);
+
+        let queries = vec![3, 17, 40, 63];
+        let opened_rows: Vec<Vec<BinaryElem32>> = queries.iter().map(|&q| wtns.mat[q].clone()).collect();
+        let alpha = BinaryElem128::from(0x1111);
         let sks_vks: Vec<BinaryElem32> = eval_sk_at_vks(1 << n);
 
-        // Use challenges that give non-zero Lagrange basis
-        let v_challenges = vec![
-            BinaryElem128::from(0x1234),
-            BinaryElem128::from(0x5678),
-            BinaryElem128::from(0x9ABC),
-            BinaryElem128::from(0xDEF0),
-        ];
+        let (mut basis_poly, enforced) = induce_sumcheck_poly(
+            n,
+            &sks_vks,
+            &opened_rows,
+            &v_challenges,
+            &queries,
+            alpha,
+        );
 
-        // Simple queries and rows
-        let queries = vec![0, 1, 2, 3];
-        let opened_rows = vec![
-            vec![BinaryElem32::one(); 16],
-            vec![BinaryElem32::from(2); 16],
-            vec![BinaryElem32::from(3); 16],
-            vec![BinaryElem32::from(4); 16],
-        ];
+        let dot = |a: &[BinaryElem128], b: &[BinaryElem128]| {
+            a.iter().zip(b).fold(BinaryElem128::zero(), |acc, (x, y)| acc.add(&x.mul(y)))
+        };
+        assert_ne!(enforced, BinaryElem128::zero());
+        assert_eq!(dot(&folded, &basis_poly), enforced);
+
+        // the verifier's factored basis folds like the prover's vector
+        let mut factored = FoldedBasis::new(n, &sks_vks, &queries, alpha);
+        assert_eq!(factored.evaluate(&folded), Some(enforced));
+
+        let r = BinaryElem128::from(0xabcdef);
+        partial_eval_multilinear_high(&mut basis_poly, &[r]);
+        factored.fold(r);
+        let probe: Vec<BinaryElem128> = (0..8u128).map(|i| BinaryElem128::from(i * 31 + 7)).collect();
+        assert_eq!(factored.evaluate(&probe), Some(dot(&probe, &basis_poly)));
+    }
+
+    #[test]
+    fn test_committed_columns_are_codewords() {
+        use crate::ligero::ligero_commit;
+
+        let config = hardcoded_config_12(
+            std::marker::PhantomData::<BinaryElem32>,
+            std::marker::PhantomData::<BinaryElem128>,
+        );
+        let (m, n) = config.initial_dims;
+        let rs = &config.initial_reed_solomon;
+
+        let mut rng = rand::thread_rng();
+        let poly: Vec<BinaryElem32> = (0..m * n).map(|_| BinaryElem32::from(rng.gen::<u32>())).collect();
+        let wtns = ligero_commit(&poly, m, n, rs, config.hasher);
+        assert_eq!(wtns.mat.len(), rs.block_length());
+
+        // column j encodes poly[j * m..(j + 1) * m] and nothing else: the
+        // rows past m must be zero before encoding
+        for j in 0..n {
+            let mut expected = vec![BinaryElem32::zero(); rs.block_length()];
+            expected[..m].copy_from_slice(&poly[j * m..(j + 1) * m]);
+            reed_solomon::encode_non_systematic(rs, &mut expected);
+
+            let column: Vec<BinaryElem32> = wtns.mat.iter().map(|row| row[j]).collect();
+            assert_eq!(column, expected, "column {}", j);
+        }
+    }
+
+    #[test]
+    fn test_batch_commitment_is_linear() {
+        use crate::ligero::{ligero_commit, ligero_commit_batch, combine_rows};
+
+        let config = hardcoded_config_12(
+            std::marker::PhantomData::<BinaryElem32>,
+            std::marker::PhantomData::<BinaryElem128>,
+        );
+        let (m, n) = config.initial_dims;
+
+        let mut rng = rand::thread_rng();
+        let a: Vec<BinaryElem32> = (0..1 << 12).map(|_| BinaryElem32::from(rng.gen::<u32>())).collect();
+        let b: Vec<BinaryElem32> = (0..1 << 10).map(|_| BinaryElem32::from(rng.gen::<u32>())).collect();
+        let gammas = vec![BinaryElem32::from(0x1234), BinaryElem32::from(0x5678)];
+
+        let (batch, widths) = ligero_commit_batch(&[&a, &b], m, &config.initial_reed_solomon, config.hasher);
+        assert_eq!(widths, vec![16, 4]);
+
+        // rows of the batch fold into the commitment of the zero-extended combination
+        let mut combined = vec![BinaryElem32::zero(); m * n];
+        for (poly, gamma) in [&a, &b].iter().zip(gammas.iter()) {
+            for (c, x) in combined.iter_mut().zip(poly.iter()) {
+                *c = c.add(&gamma.mul(x));
+            }
+        }
+        let single = ligero_commit(&combined, m, n, &config.initial_reed_solomon, config.hasher);
+        let folded = combine_rows(&batch.mat, &widths, &gammas, n).unwrap();
+        assert_eq!(folded, single.mat);
 
-            let alpha = BinaryElem128::from(0x1111);
+        assert!(combine_rows(&batch.mat, &widths[..1], &gammas[..1], n).is_none());
+    }
 
-            let (basis_poly, _) = induce_sumcheck_poly_debug(
-                n,
-                &sks_vks,
-                &opened_rows,
-                &v_challenges,
-                &queries,
-                alpha,
-            );
+    #[test]
+    fn test_commitment_uses_config_hasher() {
+        use crate::ligero::{hash_row_with, ligero_commit};
+        use merkle_tree::MerkleHasher;
 
-            // Check the sum
-            let sum = basis_poly.iter().fold(BinaryElem128::zero(), |acc, &x| acc.add(&x));
-            println!("Basis polynomial sum: {:?}", sum);
+        let config = hardcoded_config_12(
+            std::marker::PhantomData::<BinaryElem32>,
+            std::marker::PhantomData::<BinaryElem128>,
+        );
+        let (m, n) = config.initial_dims;
+        let poly: Vec<BinaryElem32> = (0..1u32 << 12).map(BinaryElem32::from).collect();
+
+        let sha = ligero_commit(&poly, m, n, &config.initial_reed_solomon, config.hasher);
+        let queries = vec![1, 5, 9];
+
+        for hasher in [MerkleHasher::Blake3, MerkleHasher::Keccak256, MerkleHasher::Rescue] {
+            let wtns = ligero_commit(&poly, m, n, &config.initial_reed_solomon, hasher);
+            assert_eq!(wtns.mat, sha.mat);
+            assert_ne!(wtns.tree.get_root().root, sha.tree.get_root().root);
+
+            let root = wtns.tree.get_root();
+            let proof = wtns.tree.prove(&queries);
+            let leaves: Vec<_> = queries.iter().map(|&q| hash_row_with(hasher, &wtns.mat[q])).collect();
+            let depth = wtns.tree.get_depth();
+            assert!(merkle_tree::verify_with(&hasher, &root, &proof, depth, &leaves, &queries));
+            assert!(!merkle_tree::verify_with(&MerkleHasher::Sha256, &root, &proof, depth, &leaves, &queries));
+        }
 
-            // The sum should NOT be zero for a valid polynomial
-            assert_ne!(sum, BinaryElem128::zero(), "Basis polynomial sum should not be zero!");
+        let params = ConfigBuilder::new(12).hasher(MerkleHasher::Blake3).build().unwrap();
+        assert_eq!(params.prover_config::<BinaryElem32, BinaryElem128>().hasher, MerkleHasher::Blake3);
+        assert_eq!(params.verifier_config().hasher, MerkleHasher::Blake3);
     }
 
+    #[test]
+    fn test_prove_batch_shape() {
+        let config = hardcoded_config_12(
+            std::marker::PhantomData::<BinaryElem32>,
+            std::marker::PhantomData::<BinaryElem128>,
+        );
+
+        let a = vec![BinaryElem32::one(); 1 << 12];
+        let b = vec![BinaryElem32::from(7); 1 << 9];
+        let c = vec![BinaryElem32::from(3); 1 << 8];
+
+        let proof = prove_batch(&config, &[&a, &b, &c]).unwrap();
+        assert_eq!(proof.log_sizes, vec![12, 9, 8]);
+        for row in &proof.proof.initial_ligero_proof.opened_rows {
+            assert_eq!(row.len(), 16 + 2 + 1);
+        }
+
+        // one proof for all three is far smaller than three proofs
+        let single = prover(&config, &a).unwrap();
+        assert!(proof.size_of() < 2 * single.size_of());
+
+        // polynomials must fit the config
+        let too_small = vec![BinaryElem32::one(); 1 << 4];
+        assert!(prove_batch(&config, &[&a, &too_small]).is_err());
+        assert!(prove_batch::<BinaryElem32, BinaryElem128>(&config, &[]).is_err());
+    }
+
+    #[test]
+    fn test_verify_batch() {
+        let config = hardcoded_config_12(
+            std::marker::PhantomData::<BinaryElem32>,
+            std::marker::PhantomData::<BinaryElem128>,
+        );
+        let verifier_config = hardcoded_config_12_verifier();
+
+        let mut rng = rand::thread_rng();
+        let a: Vec<BinaryElem32> = (0..1 << 12).map(|_| BinaryElem32::from(rng.gen::<u32>())).collect();
+        let b: Vec<BinaryElem32> = (0..1 << 9).map(|_| BinaryElem32::from(rng.gen::<u32>())).collect();
+        let c: Vec<BinaryElem32> = (0..1 << 8).map(|_| BinaryElem32::from(rng.gen::<u32>())).collect();
+
+        let proof = prove_batch(&config, &[&a, &b, &c]).unwrap();
+        assert!(verify_batch(&verifier_config, &proof).unwrap());
+
+        // the batch shape is bound by the transcript
+        let mut reshaped = proof.clone();
+        reshaped.log_sizes = vec![12, 8, 9];
+        assert!(!verify_batch(&verifier_config, &reshaped).unwrap());
+
+        // an opened row of the second polynomial
+        let mut tampered = proof.clone();
+        let row = &mut tampered.proof.initial_ligero_proof.opened_rows[0];
+        row[16] = row[16].add(&BinaryElem32::one());
+        assert!(!verify_batch(&verifier_config, &tampered).unwrap());
+
+        // a sumcheck round that still sums to the previous claim
+        let mut tampered = proof.clone();
+        let (a0, a1, a2) = tampered.proof.sumcheck_transcript.transcript[0];
+        let delta = BinaryElem128::one();
+        tampered.proof.sumcheck_transcript.transcript[0] = (a0.add(&delta), a1.add(&delta), a2);
+        assert!(!verify_batch(&verifier_config, &tampered).unwrap());
+
+        // the final polynomial
+        let mut tampered = proof;
+        tampered.proof.final_ligero_proof.yr[0] = tampered.proof.final_ligero_proof.yr[0].add(&BinaryElem128::one());
+        assert!(!verify_batch(&verifier_config, &tampered).unwrap());
+    }
+
+    #[test]
+    fn test_evaluation_claim() {
+        use crate::ligero::{ligero_commit, verify_row_combination};
+        use crate::utils::partial_eval_multilinear;
+
+        let config = hardcoded_config_12(
+            std::marker::PhantomData::<BinaryElem32>,
+            std::marker::PhantomData::<BinaryElem128>,
+        );
+        let verifier_config = hardcoded_config_12_verifier();
+
+        let mut rng = rand::thread_rng();
+        let poly: Vec<BinaryElem32> = (0..1 << 12).map(|_| BinaryElem32::from(rng.gen::<u32>())).collect();
+        let point: Vec<BinaryElem128> = (0..12).map(|_| BinaryElem128::from(rng.gen::<u128>())).collect();
+
+        let (value, proof) = prove_evaluation(&config, &poly, &point).unwrap();
+        assert!(verify_evaluation(&verifier_config, &point, value, &proof).unwrap());
+
+        let mut expected: Vec<BinaryElem128> = poly.iter().map(|&x| BinaryElem128::from(x)).collect();
+        partial_eval_multilinear(&mut expected, &point);
+        assert_eq!(value, expected[0]);
+
+        // every encoded row is consistent with the row combination
+        let (m, n) = config.initial_dims;
+        let wtns = ligero_commit(&poly, m, n, &config.initial_reed_solomon, config.hasher);
+        let all: Vec<usize> = (0..wtns.mat.len()).collect();
+        let z_col = &point[verifier_config.initial_dim..];
+        let rs = config.initial_reed_solomon.embed::<BinaryElem128>();
+        assert!(verify_row_combination(&rs, &all, &wtns.mat, &proof.yr, z_col));
+
+        let mut bad_yr = proof.yr.clone();
+        bad_yr[3] = bad_yr[3].add(&BinaryElem128::one());
+        assert!(!verify_row_combination(&rs, &all, &wtns.mat, &bad_yr, z_col));
+
+        // a row combination that still matches the value is caught by the rows
+        let mut shifted = proof.clone();
+        let eq_row = crate::utils::evaluate_eq_basis(&point[..verifier_config.initial_dim]);
+        let delta = BinaryElem128::from(5);
+        shifted.yr[0] = shifted.yr[0].add(&delta.mul(&eq_row[1]));
+        shifted.yr[1] = shifted.yr[1].add(&delta.mul(&eq_row[0]));
+        assert!(!verify_evaluation(&verifier_config, &point, value, &shifted).unwrap());
+
+        // wrong value, point or proof shape is rejected
+        let wrong = value.add(&BinaryElem128::one());
+        assert!(!verify_evaluation(&verifier_config, &point, wrong, &proof).unwrap());
+        let mut moved = point.clone();
+        moved[0] = moved[0].add(&BinaryElem128::one());
+        assert!(!verify_evaluation(&verifier_config, &moved, value, &proof).unwrap());
+        assert!(!verify_evaluation(&verifier_config, &point[1..], value, &proof).unwrap());
+        assert!(prove_evaluation(&config, &poly, &point[1..]).is_err());
+    }
+
+    #[test]
+    fn test_commit_then_open_matches_prove() {
+        use crate::transcript::Transcript;
+
+        let config = hardcoded_config_12(
+            std::marker::PhantomData::<BinaryElem32>,
+            std::marker::PhantomData::<BinaryElem128>,
+        );
+
+        let mut rng = rand::thread_rng();
+        let poly: Vec<BinaryElem32> = (0..1 << 12).map(|_| BinaryElem32::from(rng.gen::<u32>())).collect();
+
+        let (commitment, state) = commit(&config, &poly).unwrap();
+
+        // state survives a trip through another process
+        let bytes = bincode::serialize(&state).unwrap();
+        let state: ProverState<BinaryElem32> = bincode::deserialize(&bytes).unwrap();
+
+        let opened = open(&config, &state, FiatShamir::new_merlin()).unwrap();
+        let proved = prove(&config, &poly).unwrap();
+
+        assert_eq!(opened.initial_ligero_cm.root.root, commitment.root.root);
+        assert_eq!(bincode::serialize(&opened).unwrap(), bincode::serialize(&proved).unwrap());
+
+        // external challenges absorbed before opening change the proof
+        let mut fs = FiatShamir::new_merlin();
+        fs.absorb_elem(BinaryElem32::from(42));
+        let bound = open(&config, &state, fs).unwrap();
+        assert_ne!(bincode::serialize(&bound).unwrap(), bincode::serialize(&proved).unwrap());
+
+        assert!(commit(&config, &poly[..1 << 11]).is_err());
+    }
 }
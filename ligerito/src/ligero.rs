@@ -1,11 +1,19 @@
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
 use binary_fields::BinaryFieldElement;
 use reed_solomon::ReedSolomon;
-use merkle_tree::{build_merkle_tree, Hash};
+use merkle_tree::{Hash, Hasher, MerkleHasher};
+#[cfg(feature = "prover")]
+use merkle_tree::build_merkle_tree_with;
+#[cfg(feature = "prover")]
 use crate::data_structures::{RecursiveLigeroWitness};
-use crate::utils::{evaluate_lagrange_basis, eval_sk_at_vks, evaluate_scaled_basis_inplace};
+use crate::utils::{evaluate_lagrange_basis, evaluate_eq_basis, eval_sk_at_vks, evaluate_scaled_basis_inplace};
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 use sha2::{Sha256, Digest};
 
+#[cfg(feature = "prover")]
 pub fn poly2mat<F: BinaryFieldElement>(
     poly: &[F],
     m: usize,
@@ -18,6 +26,10 @@ pub fn poly2mat<F: BinaryFieldElement>(
     mat.par_iter_mut()
         .enumerate()
         .for_each(|(i, row)| {
+            // rows past the message length are the zero padding to be encoded
+            if i >= m {
+                return;
+            }
             for j in 0..n {
                 let idx = j * m + i;
                 if idx < poly.len() {
@@ -29,6 +41,7 @@ pub fn poly2mat<F: BinaryFieldElement>(
     mat
 }
 
+#[cfg(feature = "prover")]
 pub fn encode_cols<F: BinaryFieldElement + Send + Sync>(
     poly_mat: &mut Vec<Vec<F>>,
     rs: &ReedSolomon<F>,
@@ -48,7 +61,7 @@ pub fn encode_cols<F: BinaryFieldElement + Send + Sync>(
 
         cols.par_iter_mut()
             .for_each(|col| {
-                reed_solomon::encode_in_place(rs, col);
+                reed_solomon::encode_non_systematic(rs, col);
             });
 
         for (i, row) in poly_mat.iter_mut().enumerate() {
@@ -59,7 +72,7 @@ pub fn encode_cols<F: BinaryFieldElement + Send + Sync>(
     } else {
         for j in 0..n {
             let mut col: Vec<F> = poly_mat.iter().map(|row| row[j]).collect();
-            reed_solomon::encode_in_place(rs, &mut col);
+            reed_solomon::encode_non_systematic(rs, &mut col);
             for (i, val) in col.iter().enumerate() {
                 poly_mat[i][j] = *val;
             }
@@ -84,62 +97,230 @@ pub fn hash_row<F: BinaryFieldElement>(row: &[F]) -> Hash {
     hasher.finalize().into()
 }
 
+/// Hash a row for the Merkle tree of a commitment under `hasher`
+///
+/// SHA-256 keeps the original [`hash_row`] encoding so existing proofs stay
+/// valid; the other hashers take the canonical fixed-width encoding, which
+/// is injective without position prefixes.
+pub fn hash_row_with<F: BinaryFieldElement>(hasher: MerkleHasher, row: &[F]) -> Hash {
+    if hasher == MerkleHasher::Sha256 {
+        return hash_row(row);
+    }
+
+    let mut bytes = vec![0u8; row.len() * F::BYTES];
+    for (elem, out) in row.iter().zip(bytes.chunks_exact_mut(F::BYTES)) {
+        elem.write_bytes(out);
+    }
+    hasher.hash_leaf(&bytes)
+}
+
+#[cfg(feature = "prover")]
 pub fn ligero_commit<F: BinaryFieldElement + Send + Sync>(
     poly: &[F],
     m: usize,
     n: usize,
     rs: &ReedSolomon<F>,
+    hasher: MerkleHasher,
 ) -> RecursiveLigeroWitness<F> {
-    let mut poly_mat = poly2mat(poly, m, n, 4);
+    let inv_rate = rs.block_length() / m;
+    let mut poly_mat = poly2mat(poly, m, n, inv_rate);
     encode_cols(&mut poly_mat, rs, true);
 
     let hashed_rows: Vec<Hash> = poly_mat.iter()
-        .map(|row| hash_row(row))
+        .map(|row| hash_row_with(hasher, row))
         .collect();
     
-    let tree = build_merkle_tree(&hashed_rows);
+    let tree = build_merkle_tree_with(hasher, &hashed_rows);
 
     RecursiveLigeroWitness { mat: poly_mat, tree }
 }
 
+#[cfg(feature = "prover")]
+/// Commit to several polynomials in one Merkle tree
+///
+/// Every polynomial is laid out with `m` rows and encoded column-wise with
+/// `rs`; leaves are the concatenation of the encoded rows of all polynomials.
+/// Returns the witness and the column count of each polynomial.
+pub fn ligero_commit_batch<F: BinaryFieldElement + Send + Sync>(
+    polys: &[&[F]],
+    m: usize,
+    rs: &ReedSolomon<F>,
+    hasher: MerkleHasher,
+) -> (RecursiveLigeroWitness<F>, Vec<usize>) {
+    let inv_rate = rs.block_length() / m;
+    let widths: Vec<usize> = polys.iter().map(|p| p.len() / m).collect();
+
+    let mut mat = vec![Vec::with_capacity(widths.iter().sum()); m * inv_rate];
+    for (poly, &width) in polys.iter().zip(widths.iter()) {
+        let mut poly_mat = poly2mat(poly, m, width, inv_rate);
+        encode_cols(&mut poly_mat, rs, true);
+        for (row, part) in mat.iter_mut().zip(poly_mat) {
+            row.extend(part);
+        }
+    }
+
+    let hashed_rows: Vec<Hash> = mat.par_iter()
+        .map(|row| hash_row_with(hasher, row))
+        .collect();
+
+    let tree = build_merkle_tree_with(hasher, &hashed_rows);
+
+    (RecursiveLigeroWitness { mat, tree }, widths)
+}
+
+/// Fold concatenated batch rows into rows of sum_i gamma_i * f_i
+///
+/// Each polynomial occupies the first `widths[i]` of `n` columns of the
+/// combined matrix (zero-extension). Returns `None` if a row does not have
+/// the expected length.
+pub fn combine_rows<F: BinaryFieldElement>(
+    rows: &[Vec<F>],
+    widths: &[usize],
+    gammas: &[F],
+    n: usize,
+) -> Option<Vec<Vec<F>>> {
+    let total: usize = widths.iter().sum();
+    if widths.len() != gammas.len() || widths.iter().any(|&w| w > n) {
+        return None;
+    }
+
+    rows.iter()
+        .map(|row| {
+            if row.len() != total {
+                return None;
+            }
+            let mut combined = vec![F::zero(); n];
+            let mut offset = 0;
+            for (&width, &gamma) in widths.iter().zip(gammas.iter()) {
+                for (c, x) in combined.iter_mut().zip(&row[offset..offset + width]) {
+                    *c = c.add(&gamma.mul(x));
+                }
+                offset += width;
+            }
+            Some(combined)
+        })
+        .collect()
+}
+
+/// Encode batch polynomial sizes for absorption into the transcript
+pub fn batch_size_elems<F: BinaryFieldElement>(log_sizes: &[usize]) -> Vec<F> {
+    log_sizes.iter().map(|&l| F::from_bits(l as u64)).collect()
+}
+
+#[cfg(feature = "prover")]
+/// Combine the message rows of a committed polynomial with eq(z_col, .)
+///
+/// Returns yr with yr[i] = sum_j M[i][j] * eq(z_col, j), where M is the
+/// `m`-row message matrix laid out as in `poly2mat`. Then
+/// f(z_row, z_col) = <eq(z_row, .), yr>.
+pub fn row_combination<T, U>(
+    poly: &[T],
+    m: usize,
+    z_col: &[U],
+) -> Vec<U>
+where
+    T: BinaryFieldElement + Send + Sync,
+    U: BinaryFieldElement + Send + Sync + From<T>,
+{
+    let eq_col = evaluate_eq_basis(z_col);
+    (0..m).into_par_iter()
+        .map(|i| {
+            eq_col.iter()
+                .enumerate()
+                .fold(U::zero(), |acc, (j, e)| acc.add(&U::from(poly[j * m + i]).mul(e)))
+        })
+        .collect()
+}
+
+/// Check opened encoded rows against a claimed row combination
+///
+/// Encoding is linear column-wise, so for every opened position q the
+/// combination of the row with eq(z_col, .) must equal Enc(yr)[q]. `rs` is
+/// the code of the rows embedded into the field of `yr`.
+pub fn verify_row_combination<T, U>(
+    rs: &ReedSolomon<U>,
+    queries: &[usize],
+    opened_rows: &[Vec<T>],
+    yr: &[U],
+    z_col: &[U],
+) -> bool
+where
+    T: BinaryFieldElement,
+    U: BinaryFieldElement + From<T>,
+{
+    if yr.len() != rs.message_length() || queries.len() != opened_rows.len() {
+        return false;
+    }
+
+    let eq_col = evaluate_eq_basis(z_col);
+    let mut encoded = vec![U::zero(); rs.block_length()];
+    encoded[..yr.len()].copy_from_slice(yr);
+    reed_solomon::encode_non_systematic(rs, &mut encoded);
+
+    queries.iter()
+        .zip(opened_rows.iter())
+        .all(|(&q, row)| {
+            q < encoded.len()
+                && row.len() == eq_col.len()
+                && row.iter()
+                    .zip(eq_col.iter())
+                    .fold(U::zero(), |acc, (&r, e)| acc.add(&U::from(r).mul(e))) == encoded[q]
+        })
+}
+
+/// Check the final opened rows against the final polynomial
+///
+/// Every opened row combined with the Lagrange basis of `challenges` must
+/// equal the non-systematic encoding of `yr` at the queried position.
 pub fn verify_ligero<T, U>(
     queries: &[usize],
     opened_rows: &[Vec<T>],
     yr: &[T],
     challenges: &[U],
-) where
+) -> bool
+where
     T: BinaryFieldElement + Send + Sync,
     U: BinaryFieldElement + Send + Sync + From<T>,
 {
     let gr = evaluate_lagrange_basis(challenges);
+    if !yr.len().is_power_of_two()
+        || queries.len() != opened_rows.len()
+        || opened_rows.iter().any(|row| row.len() != gr.len())
+    {
+        return false;
+    }
+
     let n = yr.len().trailing_zeros() as usize;
     let sks_vks: Vec<T> = eval_sk_at_vks(1 << n);
 
-    queries.par_iter()
-        .zip(opened_rows.par_iter())
-        .for_each(|(&query, row)| {
-            let dot = row.iter()
-                .zip(gr.iter())
-                .fold(U::zero(), |acc, (&r, &g)| {
-                    let r_u = U::from(r);
-                    acc.add(&r_u.mul(&g))
-                });
+    let check = |(&query, row): (&usize, &Vec<T>)| {
+        let dot = row.iter()
+            .zip(gr.iter())
+            .fold(U::zero(), |acc, (&r, &g)| {
+                let r_u = U::from(r);
+                acc.add(&r_u.mul(&g))
+            });
 
-            let qf = T::from_bits(query as u64);
+        let qf = T::from_bits(query as u64);
 
-            let mut local_sks_x = vec![T::zero(); sks_vks.len()];
-            let mut local_basis = vec![U::zero(); 1 << n];
+        let mut local_sks_x = vec![T::zero(); sks_vks.len()];
+        let mut local_basis = vec![U::zero(); 1 << n];
 
-            let scale = U::one();
-            evaluate_scaled_basis_inplace(&mut local_sks_x, &mut local_basis, &sks_vks, qf, scale);
+        let scale = U::one();
+        evaluate_scaled_basis_inplace(&mut local_sks_x, &mut local_basis, &sks_vks, qf, scale);
 
-            let e = yr.iter()
-                .zip(local_basis.iter())
-                .fold(U::zero(), |acc, (&y, &b)| {
-                    let y_u = U::from(y);
-                    acc.add(&y_u.mul(&b))
-                });
+        let e = yr.iter()
+            .zip(local_basis.iter())
+            .fold(U::zero(), |acc, (&y, &b)| {
+                let y_u = U::from(y);
+                acc.add(&y_u.mul(&b))
+            });
 
-            assert_eq!(e, dot, "Verification failed at query {}", query);
-        });
+        e == dot
+    };
+
+    #[cfg(feature = "parallel")]
+    return queries.par_iter().zip(opened_rows.par_iter()).all(check);
+    #[cfg(not(feature = "parallel"))]
+    return queries.iter().zip(opened_rows.iter()).all(check);
 }
@@ -1,21 +1,292 @@
 use binary_fields::BinaryFieldElement;
+use merkle_tree::{BatchedMerkleProof, MerkleRoot};
+use rayon::prelude::*;
 use crate::{
     ProverConfig, LigeritoProof, FinalizedLigeritoProof, RecursiveLigeroCommitment,
-    RecursiveLigeroProof, FinalLigeroProof, SumcheckTranscript,
+    RecursiveLigeroProof, FinalLigeroProof, SumcheckTranscript, BatchedLigeritoProof,
+    EvaluationProof, LigeritoError, ProverState, RecursiveLigeroWitness,
     transcript::{FiatShamir, Transcript},
-    ligero::ligero_commit,
-    sumcheck_polys::induce_sumcheck_poly_debug,
-    utils::{eval_sk_at_vks, partial_eval_multilinear},
+    ligero::{ligero_commit, ligero_commit_batch, combine_rows, batch_size_elems, row_combination},
+    sumcheck_polys::induce_sumcheck_poly,
+    utils::{eval_sk_at_vks, partial_eval_multilinear_high, evaluate_eq_basis},
     data_structures::finalize,
 };
 
-// Hardcoded for now
-const S: usize = 148;
-
 /// Main prover function with configurable transcript
 pub fn prove_with_transcript<T, U>(
     config: &ProverConfig<T, U>,
     poly: &[T],
+    fs: impl Transcript,
+) -> crate::Result<FinalizedLigeritoProof<T, U>>
+where
+    T: BinaryFieldElement + Send + Sync,
+    U: BinaryFieldElement + Send + Sync + From<T>,
+{
+    prove_core(config, &[poly], None, fs)
+}
+
+/// Prove several polynomials under a single Ligerito proof
+///
+/// Polynomials may have different (power of two) sizes, each at least
+/// `config.initial_dims.0` and at most the size `config` was built for.
+/// All of them are committed in one Merkle tree whose leaves are the
+/// concatenated encoded rows, and the rest of the protocol runs on a random
+/// linear combination of the (zero-extended) polynomials.
+pub fn prove_batch_with_transcript<T, U>(
+    config: &ProverConfig<T, U>,
+    polys: &[&[T]],
+    fs: impl Transcript,
+) -> crate::Result<BatchedLigeritoProof<T, U>>
+where
+    T: BinaryFieldElement + Send + Sync,
+    U: BinaryFieldElement + Send + Sync + From<T>,
+{
+    let log_sizes = batch_log_sizes(config, polys)?;
+    let proof = prove_core(config, polys, Some(&log_sizes), fs)?;
+    Ok(BatchedLigeritoProof { log_sizes, proof })
+}
+
+/// Batch prover using default Merlin transcript
+pub fn prove_batch<T, U>(
+    config: &ProverConfig<T, U>,
+    polys: &[&[T]],
+) -> crate::Result<BatchedLigeritoProof<T, U>>
+where
+    T: BinaryFieldElement + Send + Sync,
+    U: BinaryFieldElement + Send + Sync + From<T>,
+{
+    let fs = FiatShamir::new_merlin();
+    prove_batch_with_transcript(config, polys, fs)
+}
+
+/// Prove that `poly` evaluates to the returned value at `point`
+///
+/// The point is multilinear and little-endian (`point[0]` binds the lowest
+/// index bit) and lives in the extension field, like the challenges of a
+/// protocol the claim is part of. Point, value and the row combination are
+/// absorbed into the transcript before the commitment, so the Ligerito
+/// queries bind them.
+pub fn prove_evaluation_with_transcript<T, U>(
+    config: &ProverConfig<T, U>,
+    poly: &[T],
+    point: &[U],
+    mut fs: impl Transcript,
+) -> crate::Result<(U, EvaluationProof<T, U>)>
+where
+    T: BinaryFieldElement + Send + Sync,
+    U: BinaryFieldElement + Send + Sync + From<T>,
+{
+    let (m, n_cols) = config.initial_dims;
+    if poly.len() != m * n_cols || 1 << point.len() != poly.len() {
+        return Err(LigeritoError::InvalidConfig(format!(
+            "evaluation point of {} variables does not match polynomial of length {}",
+            point.len(), poly.len(),
+        )));
+    }
+
+    let log_m = m.trailing_zeros() as usize;
+    let (z_row, z_col) = point.split_at(log_m);
+
+    let yr = row_combination(poly, m, z_col);
+    let value = evaluate_eq_basis(z_row).iter()
+        .zip(yr.iter())
+        .fold(U::zero(), |acc, (e, y)| acc.add(&e.mul(y)));
+
+    fs.absorb_elems(point);
+    fs.absorb_elem(value);
+    fs.absorb_elems(&yr);
+
+    let proof = prove_core(config, &[poly], None, fs)?;
+    Ok((value, EvaluationProof { yr, proof }))
+}
+
+/// Evaluation prover using default Merlin transcript
+pub fn prove_evaluation<T, U>(
+    config: &ProverConfig<T, U>,
+    poly: &[T],
+    point: &[U],
+) -> crate::Result<(U, EvaluationProof<T, U>)>
+where
+    T: BinaryFieldElement + Send + Sync,
+    U: BinaryFieldElement + Send + Sync + From<T>,
+{
+    let fs = FiatShamir::new_merlin();
+    prove_evaluation_with_transcript(config, poly, point, fs)
+}
+
+fn batch_log_sizes<T, U>(
+    config: &ProverConfig<T, U>,
+    polys: &[&[T]],
+) -> crate::Result<Vec<usize>>
+where
+    T: BinaryFieldElement,
+    U: BinaryFieldElement,
+{
+    if polys.is_empty() {
+        return Err(LigeritoError::InvalidConfig("empty batch".into()));
+    }
+
+    let max_len = config.initial_dims.0 * config.initial_dims.1;
+    polys.iter()
+        .map(|p| {
+            if !p.len().is_power_of_two() || p.len() < config.initial_dims.0 || p.len() > max_len {
+                return Err(LigeritoError::InvalidConfig(format!(
+                    "batched polynomial of length {} does not fit config ({}..={})",
+                    p.len(), config.initial_dims.0, max_len,
+                )));
+            }
+            Ok(p.len().trailing_zeros() as usize)
+        })
+        .collect()
+}
+
+/// Commit to a polynomial without opening it
+///
+/// Returns the commitment to publish and the state needed to open it later
+/// with [`open`]. The state is serializable, so the opening may run in a
+/// different process.
+pub fn commit<T, U>(
+    config: &ProverConfig<T, U>,
+    poly: &[T],
+) -> crate::Result<(RecursiveLigeroCommitment, ProverState<T>)>
+where
+    T: BinaryFieldElement + Send + Sync,
+    U: BinaryFieldElement + Send + Sync + From<T>,
+{
+    let (m, n_cols) = config.initial_dims;
+    if poly.len() != m * n_cols {
+        return Err(LigeritoError::InvalidConfig(format!(
+            "polynomial of length {} does not match config of size {}",
+            poly.len(), m * n_cols,
+        )));
+    }
+
+    let witness = commit_core(config, &[poly], false)?;
+    let commitment = RecursiveLigeroCommitment {
+        root: witness.tree.get_root(),
+    };
+    Ok((commitment, ProverState { poly: poly.to_vec(), witness }))
+}
+
+/// Open a commitment produced by [`commit`]
+///
+/// [`commit`] touches no transcript. `open` absorbs the initial root into
+/// `fs` after whatever `fs` already holds (e.g. external challenges), then
+/// draws the folding challenges and continues as [`prove_with_transcript`]:
+/// each recursive root, sumcheck round, glue sum and the final polynomial
+/// is absorbed before the challenges that depend on it. Opening with a
+/// fresh transcript therefore yields the same proof as
+/// `prove_with_transcript`.
+pub fn open<T, U>(
+    config: &ProverConfig<T, U>,
+    state: &ProverState<T>,
+    fs: impl Transcript,
+) -> crate::Result<FinalizedLigeritoProof<T, U>>
+where
+    T: BinaryFieldElement + Send + Sync,
+    U: BinaryFieldElement + Send + Sync + From<T>,
+{
+    let mut initial = InMemoryMatrix {
+        polys: &[&state.poly],
+        witness: &state.witness,
+        len: state.poly.len(),
+    };
+    open_core(config, None, &mut initial, fs)
+}
+
+/// The committed initial matrix, as seen by the opening phase
+pub(crate) trait InitialMatrix<T: BinaryFieldElement> {
+    fn root(&self) -> MerkleRoot;
+
+    /// Number of rows of the encoded matrix
+    fn num_rows(&self) -> usize;
+
+    /// Opened rows and their Merkle proof for sorted 0-based queries
+    fn open_rows(&mut self, queries: &[usize]) -> crate::Result<(Vec<Vec<T>>, BatchedMerkleProof)>;
+
+    /// Combine the committed polynomials with `gammas` (if batched) and
+    /// fold their column variables with `challenges`, most significant first
+    fn fold(&mut self, gammas: &[T], challenges: &[T]) -> crate::Result<Vec<T>>;
+}
+
+struct InMemoryMatrix<'a, T: BinaryFieldElement> {
+    polys: &'a [&'a [T]],
+    witness: &'a RecursiveLigeroWitness<T>,
+    // size of the (combined) polynomial
+    len: usize,
+}
+
+impl<T: BinaryFieldElement> InitialMatrix<T> for InMemoryMatrix<'_, T> {
+    fn root(&self) -> MerkleRoot {
+        self.witness.tree.get_root()
+    }
+
+    fn num_rows(&self) -> usize {
+        self.witness.mat.len()
+    }
+
+    fn open_rows(&mut self, queries: &[usize]) -> crate::Result<(Vec<Vec<T>>, BatchedMerkleProof)> {
+        let rows = queries.iter()
+            .map(|&q| self.witness.mat[q].clone())
+            .collect();
+        Ok((rows, self.witness.tree.prove(queries)))
+    }
+
+    fn fold(&mut self, gammas: &[T], challenges: &[T]) -> crate::Result<Vec<T>> {
+        let mut f_evals = if gammas.is_empty() {
+            self.polys[0].to_vec()
+        } else {
+            let mut combined = vec![T::zero(); self.len];
+            for (p, &gamma) in self.polys.iter().zip(gammas.iter()) {
+                for (c, &x) in combined.iter_mut().zip(p.iter()) {
+                    *c = c.add(&gamma.mul(&x));
+                }
+            }
+            combined
+        };
+        partial_eval_multilinear_high(&mut f_evals, challenges);
+        Ok(f_evals)
+    }
+}
+
+fn prove_core<T, U>(
+    config: &ProverConfig<T, U>,
+    polys: &[&[T]],
+    log_sizes: Option<&[usize]>,
+    fs: impl Transcript,
+) -> crate::Result<FinalizedLigeritoProof<T, U>>
+where
+    T: BinaryFieldElement + Send + Sync,
+    U: BinaryFieldElement + Send + Sync + From<T>,
+{
+    let witness = commit_core(config, polys, log_sizes.is_some())?;
+    let (m, n_cols) = config.initial_dims;
+    let mut initial = InMemoryMatrix { polys, witness: &witness, len: m * n_cols };
+    open_core(config, log_sizes, &mut initial, fs)
+}
+
+fn commit_core<T, U>(
+    config: &ProverConfig<T, U>,
+    polys: &[&[T]],
+    batched: bool,
+) -> crate::Result<RecursiveLigeroWitness<T>>
+where
+    T: BinaryFieldElement + Send + Sync,
+    U: BinaryFieldElement + Send + Sync + From<T>,
+{
+    let (m, n_cols) = config.initial_dims;
+    if batched {
+        batch_log_sizes(config, polys)?;
+        Ok(ligero_commit_batch(polys, m, &config.initial_reed_solomon, config.hasher).0)
+    } else {
+        Ok(ligero_commit(polys[0], m, n_cols, &config.initial_reed_solomon, config.hasher))
+    }
+}
+
+pub(crate) fn open_core<T, U>(
+    config: &ProverConfig<T, U>,
+    log_sizes: Option<&[usize]>,
+    initial: &mut impl InitialMatrix<T>,
     mut fs: impl Transcript,
 ) -> crate::Result<FinalizedLigeritoProof<T, U>>
 where
@@ -23,30 +294,39 @@ where
     U: BinaryFieldElement + Send + Sync + From<T>,
 {
     let mut proof = LigeritoProof::<T, U>::new();
+    let (m, n_cols) = config.initial_dims;
 
-    // Initial commitment
-    let wtns_0 = ligero_commit(poly, config.initial_dims.0, config.initial_dims.1, &config.initial_reed_solomon);
+    // Initial commitment, preceded by the batch shape
+    let widths: Vec<usize> = match log_sizes {
+        Some(log_sizes) => {
+            fs.absorb_elems(&batch_size_elems::<T>(log_sizes));
+            log_sizes.iter().map(|&l| (1 << l) / m).collect()
+        }
+        None => Vec::new(),
+    };
     let cm_0 = RecursiveLigeroCommitment {
-        root: wtns_0.tree.get_root(),
+        root: initial.root(),
     };
     proof.initial_ligero_cm = Some(cm_0.clone());
     fs.absorb_root(&cm_0.root);
 
+    // Batched polynomials are folded into one by a random linear combination
+    let gammas: Vec<T> = widths.iter().map(|_| fs.get_challenge()).collect();
+
     // Get initial challenges - get them as T type (base field)
     let partial_evals_0: Vec<T> = (0..config.initial_k)
         .map(|_| fs.get_challenge())
         .collect();
 
     // Partial evaluation of multilinear polynomial
-    let mut f_evals = poly.to_vec();
-    partial_eval_multilinear(&mut f_evals, &partial_evals_0);
+    let f_evals = initial.fold(&gammas, &partial_evals_0)?;
 
     // Convert to U type for extension field operations
     let partial_evals_0_u: Vec<U> = partial_evals_0.iter().map(|&x| U::from(x)).collect();
 
     // First recursive step - convert to U type
     let f_evals_u: Vec<U> = f_evals.iter().map(|&x| U::from(x)).collect();
-    let wtns_1 = ligero_commit(&f_evals_u, config.dims[0].0, config.dims[0].1, &config.reed_solomon_codes[0]);
+    let wtns_1 = ligero_commit(&f_evals_u, config.dims[0].0, config.dims[0].1, &config.reed_solomon_codes[0], config.hasher);
     let cm_1 = RecursiveLigeroCommitment {
         root: wtns_1.tree.get_root(),
     };
@@ -54,27 +334,28 @@ where
     fs.absorb_root(&cm_1.root);
 
     // Query selection
-    let rows = wtns_0.mat.len();
-    let queries = fs.get_distinct_queries(rows, S);  // Returns 0-based indices
+    let rows = initial.num_rows();
+    let queries = fs.get_distinct_queries(rows, config.num_queries);  // Returns 0-based indices
     let alpha = fs.get_challenge::<U>();
 
-    // Prepare for sumcheck
-    let n = f_evals.len().trailing_zeros() as usize;
-    let sks_vks: Vec<T> = eval_sk_at_vks(1 << n);
-
-    // Use 0-based queries directly for array access
-    let opened_rows: Vec<Vec<T>> = queries.iter()
-        .map(|&q| wtns_0.mat[q].clone())
-        .collect();
-
-    let mtree_proof = wtns_0.tree.prove(&queries);  // prove() expects 0-based
+    let (opened_rows, mtree_proof) = initial.open_rows(&queries)?;
     proof.initial_ligero_proof = Some(RecursiveLigeroProof {
         opened_rows: opened_rows.clone(),
         merkle_proof: mtree_proof,
     });
 
-    // Induce sumcheck polynomial
-    let (basis_poly, _) = induce_sumcheck_poly_debug(
+    // The sumcheck runs on rows of the combined matrix
+    let opened_rows = if log_sizes.is_some() {
+        combine_rows(&opened_rows, &widths, &gammas, n_cols)
+            .ok_or(LigeritoError::InvalidProof)?
+    } else {
+        opened_rows
+    };
+
+    // Every opened row must agree with the folded polynomial
+    let n = f_evals.len().trailing_zeros() as usize;
+    let sks_vks: Vec<T> = eval_sk_at_vks(1 << n);
+    let (mut basis_poly, mut current_sum) = induce_sumcheck_poly(
         n,
         &sks_vks,
         &opened_rows,
@@ -83,35 +364,31 @@ where
         alpha,
     );
 
-    // Initialize sumcheck with the sum of basis polynomial evaluations
-    let basis_sum = basis_poly.iter().fold(U::zero(), |acc, &x| acc.add(&x));
-    let mut sumcheck_transcript = vec![];
-    let mut current_poly = basis_poly;
-    let mut current_sum = basis_sum; // Use basis sum
-
     // First sumcheck round absorb
     fs.absorb_elem(current_sum);
 
+    let mut sumcheck_transcript = vec![];
+    let mut current_poly = f_evals_u;
+
     // Recursive rounds
     let mut wtns_prev = wtns_1;
 
     for i in 0..config.recursive_steps {
         let mut rs = Vec::new();
 
-        // Sumcheck rounds
+        // Sumcheck rounds on <current_poly, basis_poly>
         for _ in 0..config.ks[i] {
-            let ri = fs.get_challenge::<U>();
-            
-            // Fold polynomial
-            let (new_poly, coeffs) = fold_polynomial(&current_poly, ri);
+            let coeffs = sumcheck_round(&current_poly, &basis_poly);
             sumcheck_transcript.push(coeffs);
-            
+            fs.absorb_elems(&[coeffs.0, coeffs.1, coeffs.2]);
+
+            let ri = fs.get_challenge::<U>();
+            partial_eval_multilinear_high(&mut current_poly, &[ri]);
+            partial_eval_multilinear_high(&mut basis_poly, &[ri]);
             rs.push(ri);
-            current_poly = new_poly;
 
             // Update sum
             current_sum = evaluate_quadratic(coeffs, ri);
-            fs.absorb_elem(current_sum);
         }
 
         // Final round
@@ -119,7 +396,7 @@ where
             fs.absorb_elems(&current_poly);
 
             let rows = wtns_prev.mat.len();
-            let queries = fs.get_distinct_queries(rows, S);  // 0-based
+            let queries = fs.get_distinct_queries(rows, config.num_queries);  // 0-based
 
             // Use 0-based queries directly for array access
             let opened_rows: Vec<Vec<U>> = queries.iter()
@@ -129,7 +406,7 @@ where
             let mtree_proof = wtns_prev.tree.prove(&queries);  // 0-based
 
             proof.final_ligero_proof = Some(FinalLigeroProof {
-                yr: current_poly.clone(),
+                yr: current_poly,
                 opened_rows,
                 merkle_proof: mtree_proof,
             });
@@ -145,6 +422,7 @@ where
             config.dims[i + 1].0,
             config.dims[i + 1].1,
             &config.reed_solomon_codes[i + 1],
+            config.hasher,
         );
 
         let cm_next = RecursiveLigeroCommitment {
@@ -154,7 +432,7 @@ where
         fs.absorb_root(&cm_next.root);
 
         let rows = wtns_prev.mat.len();
-        let queries = fs.get_distinct_queries(rows, S);  // 0-based
+        let queries = fs.get_distinct_queries(rows, config.num_queries);  // 0-based
         let alpha = fs.get_challenge::<U>();
 
         // Use 0-based queries directly for array access
@@ -163,16 +441,12 @@ where
             .collect();
 
         let mtree_proof = wtns_prev.tree.prove(&queries);  // 0-based
-        proof.recursive_proofs.push(RecursiveLigeroProof {
-            opened_rows: opened_rows.clone(),
-            merkle_proof: mtree_proof,
-        });
 
         // Update for next round
         let n = current_poly.len().trailing_zeros() as usize;
         let sks_vks: Vec<U> = eval_sk_at_vks(1 << n);
 
-        let (basis_poly, _) = induce_sumcheck_poly_debug(
+        let (basis_poly_next, enforced_sum) = induce_sumcheck_poly(
             n,
             &sks_vks,
             &opened_rows,
@@ -180,23 +454,25 @@ where
             &queries,
             alpha,
         );
-        
-        // Use the sum of basis polynomial for sumcheck
-        let basis_sum = basis_poly.iter().fold(U::zero(), |acc, &x| acc.add(&x));
+
+        proof.recursive_proofs.push(RecursiveLigeroProof {
+            opened_rows,
+            merkle_proof: mtree_proof,
+        });
 
         // Glue sumcheck absorb
-        let glue_sum = current_sum.add(&basis_sum);
+        let glue_sum = current_sum.add(&enforced_sum);
         fs.absorb_elem(glue_sum);
 
         // Glue polynomials
         let beta = fs.get_challenge::<U>();
-        current_poly = glue_polynomials(&current_poly, &basis_poly, beta);
-        current_sum = glue_sums(current_sum, basis_sum, beta);
+        basis_poly = glue_polynomials(&basis_poly, &basis_poly_next, beta);
+        current_sum = glue_sums(current_sum, enforced_sum, beta);
 
         wtns_prev = wtns_next;
     }
 
-    unreachable!("Should have returned in final round");
+    Err(LigeritoError::InvalidConfig("config has no recursive steps".into()))
 }
 
 /// Main prover function using default Merlin transcript
@@ -229,30 +505,31 @@ where
 
 // Helper functions
 
-fn fold_polynomial<F: BinaryFieldElement>(poly: &[F], r: F) -> (Vec<F>, (F, F, F)) {
-    let n = poly.len() / 2;
-    let mut new_poly = vec![F::zero(); n];
-
-    let mut s0 = F::zero();
-    let mut s1 = F::zero();
-    let mut s2 = F::zero();
-
-    for i in 0..n {
-        let p0 = poly[2 * i];
-        let p1 = poly[2 * i + 1];
-
-        s0 = s0.add(&p0);
-        s1 = s1.add(&p0.add(&p1));
-        s2 = s2.add(&p1);
-
-        new_poly[i] = p0.add(&r.mul(&p1.add(&p0)));
-    }
+/// Round polynomial of the sumcheck for `sum_x f(x) g(x)` over the top variable
+///
+/// Returns (q(0), q(1), leading coefficient) of the quadratic q, where the
+/// top variable is set to the argument and the rest are summed over.
+fn sumcheck_round<F: BinaryFieldElement + Send + Sync>(f: &[F], g: &[F]) -> (F, F, F) {
+    let half = f.len() / 2;
+    let (f0, f1) = f.split_at(half);
+    let (g0, g1) = g.split_at(half);
+
+    let add3 = |a: (F, F, F), b: (F, F, F)| (a.0.add(&b.0), a.1.add(&b.1), a.2.add(&b.2));
+    let term = |i: usize| (
+        f0[i].mul(&g0[i]),
+        f1[i].mul(&g1[i]),
+        f0[i].add(&f1[i]).mul(&g0[i].add(&g1[i])),
+    );
+    let zero = (F::zero(), F::zero(), F::zero());
 
-    (new_poly, (s0, s1, s2))
+    (0..half).into_par_iter()
+        .map(term)
+        .reduce(|| zero, add3)
 }
 
 fn evaluate_quadratic<F: BinaryFieldElement>(coeffs: (F, F, F), x: F) -> F {
     let (a0, a1, a2) = coeffs;
+    // q(0) = a0, q(1) = a1, leading coefficient a2:
     // a0 + (a1 - a0 - a2) * x + a2 * x^2
     let linear = a1.add(&a0).add(&a2);
     a0.add(&linear.mul(&x)).add(&a2.mul(&x).mul(&x))
@@ -0,0 +1,407 @@
+//! Out-of-core prover for polynomials that do not fit in memory
+//!
+//! The encoded initial matrix is `inv_rate` times larger than the polynomial
+//! and dominates the prover's memory. [`prove_streaming`] reads the
+//! polynomial from a [`PolySource`] a chunk of columns at a time, encodes
+//! each chunk into a scratch file, hashes the rows back from disk and later
+//! reads only the queried rows. After the first fold everything is
+//! 2^initial_k times smaller and runs in memory as in [`prove`](crate::prove).
+//!
+//! Peak memory is about two chunks of `chunk_cols` encoded columns plus the
+//! Merkle tree over the rows. Proofs are byte-identical to `prove`.
+//!
+//! A memory-mapped polynomial can be passed directly as a `&[T]` source;
+//! files holding the canonical encoding are read through [`ReaderSource`].
+
+use std::io::{self, Read, Seek, SeekFrom, Write};
+
+use binary_fields::BinaryFieldElement;
+use merkle_tree::{build_merkle_tree_with, BatchedMerkleProof, CompleteMerkleTree, Hash, MerkleHasher, MerkleRoot};
+use rayon::prelude::*;
+
+use crate::{
+    ProverConfig, FinalizedLigeritoProof, LigeritoError,
+    ligero::hash_row_with,
+    prover::{open_core, InitialMatrix},
+    transcript::{FiatShamir, Transcript},
+    utils::{evaluate_lagrange_basis, partial_eval_multilinear_high},
+};
+
+/// Flush encoded rows to the scratch file in pieces of this many bytes
+const WRITE_BUFFER: usize = 1 << 20;
+
+/// Random access to the coefficients of the polynomial being proven
+pub trait PolySource<T> {
+    /// Number of coefficients in the polynomial
+    fn num_coeffs(&self) -> usize;
+
+    /// Fill `out` with the coefficients starting at `offset`
+    ///
+    /// The streaming prover reads whole columns (`m` coefficients each) in
+    /// increasing order, once to commit and once to fold.
+    fn read_coeffs(&mut self, offset: usize, out: &mut [T]) -> io::Result<()>;
+}
+
+impl<T: Copy> PolySource<T> for &[T] {
+    fn num_coeffs(&self) -> usize {
+        self.len()
+    }
+
+    fn read_coeffs(&mut self, offset: usize, out: &mut [T]) -> io::Result<()> {
+        let src = self.get(offset..offset + out.len())
+            .ok_or_else(|| io::Error::from(io::ErrorKind::UnexpectedEof))?;
+        out.copy_from_slice(src);
+        Ok(())
+    }
+}
+
+/// Coefficients stored back to back in their canonical byte encoding
+/// ([`BinaryFieldElement::write_bytes`]), e.g. in a file
+pub struct ReaderSource<R> {
+    reader: R,
+    len: usize,
+}
+
+impl<R> ReaderSource<R> {
+    /// `len` is the number of coefficients, not bytes
+    pub fn new(reader: R, len: usize) -> Self {
+        Self { reader, len }
+    }
+}
+
+impl<T: BinaryFieldElement, R: Read + Seek> PolySource<T> for ReaderSource<R> {
+    fn num_coeffs(&self) -> usize {
+        self.len
+    }
+
+    fn read_coeffs(&mut self, offset: usize, out: &mut [T]) -> io::Result<()> {
+        self.reader.seek(SeekFrom::Start((offset * T::BYTES) as u64))?;
+        let mut buf = vec![0u8; out.len() * T::BYTES];
+        self.reader.read_exact(&mut buf)?;
+        for (x, bytes) in out.iter_mut().zip(buf.chunks_exact(T::BYTES)) {
+            *x = T::read_bytes(bytes);
+        }
+        Ok(())
+    }
+}
+
+/// Rows of the `m x n` message matrix, produced by a caller closure
+///
+/// Row `i` holds coefficients `i, m + i, 2m + i, ...`, the layout of
+/// [`poly2mat`](crate::ligero::poly2mat). The closure is called once per
+/// chunk of columns and must yield the same `m` rows every time.
+pub struct RowSource<F> {
+    rows: F,
+    m: usize,
+    n: usize,
+}
+
+impl<F> RowSource<F> {
+    pub fn new(m: usize, n: usize, rows: F) -> Self {
+        Self { rows, m, n }
+    }
+}
+
+impl<T, F, I> PolySource<T> for RowSource<F>
+where
+    T: Copy,
+    F: FnMut() -> I,
+    I: IntoIterator<Item = Vec<T>>,
+{
+    fn num_coeffs(&self) -> usize {
+        self.m * self.n
+    }
+
+    fn read_coeffs(&mut self, offset: usize, out: &mut [T]) -> io::Result<()> {
+        let m = self.m;
+        if !offset.is_multiple_of(m) || !out.len().is_multiple_of(m) || offset + out.len() > m * self.n {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "read is not column aligned"));
+        }
+
+        let first = offset / m;
+        let cols = out.len() / m;
+        let mut count = 0;
+        for (i, row) in (self.rows)().into_iter().take(m).enumerate() {
+            if row.len() != self.n {
+                return Err(io::Error::new(io::ErrorKind::InvalidData, "row has wrong length"));
+            }
+            for c in 0..cols {
+                out[c * m + i] = row[first + c];
+            }
+            count += 1;
+        }
+
+        if count != m {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+        }
+        Ok(())
+    }
+}
+
+/// Initial matrix encoded into a scratch file
+///
+/// Columns are grouped into chunks of `chunk_cols`; each chunk is stored
+/// row-major, so a chunk is written sequentially and a row is read back as
+/// one contiguous run per chunk.
+struct StreamedMatrix<P, S> {
+    source: P,
+    scratch: S,
+    tree: CompleteMerkleTree<MerkleHasher>,
+    m: usize,
+    n: usize,
+    rows: usize,
+    chunk_cols: usize,
+}
+
+impl<P, S> StreamedMatrix<P, S>
+where
+    S: Read + Write + Seek,
+{
+    fn commit<T, U>(
+        config: &ProverConfig<T, U>,
+        mut source: P,
+        mut scratch: S,
+        chunk_cols: usize,
+    ) -> crate::Result<Self>
+    where
+        T: BinaryFieldElement + Send + Sync,
+        U: BinaryFieldElement,
+        P: PolySource<T>,
+    {
+        let (m, n) = config.initial_dims;
+        if source.num_coeffs() != m * n {
+            return Err(LigeritoError::InvalidConfig(format!(
+                "polynomial of length {} does not match config of size {}",
+                source.num_coeffs(), m * n,
+            )));
+        }
+
+        let rs = &config.initial_reed_solomon;
+        let rows = rs.block_length();
+        let c = 1 << chunk_cols.clamp(1, n).ilog2();
+
+        // encode one chunk of columns at a time, written out row-major
+        let mut msg = vec![T::zero(); c * m];
+        let mut buf = Vec::with_capacity(WRITE_BUFFER + c * T::BYTES);
+        scratch.seek(SeekFrom::Start(0))?;
+        for chunk in 0..n / c {
+            source.read_coeffs(chunk * c * m, &mut msg)?;
+
+            let cols: Vec<Vec<T>> = msg.par_chunks(m)
+                .map(|message| {
+                    let mut col = vec![T::zero(); rows];
+                    col[..m].copy_from_slice(message);
+                    reed_solomon::encode_non_systematic(rs, &mut col);
+                    col
+                })
+                .collect();
+
+            for r in 0..rows {
+                for col in &cols {
+                    let start = buf.len();
+                    buf.resize(start + T::BYTES, 0);
+                    col[r].write_bytes(&mut buf[start..]);
+                }
+                if buf.len() >= WRITE_BUFFER {
+                    scratch.write_all(&buf)?;
+                    buf.clear();
+                }
+            }
+        }
+        scratch.write_all(&buf)?;
+        scratch.flush()?;
+
+        let mut matrix = Self {
+            source,
+            scratch,
+            tree: CompleteMerkleTree { layers: Vec::new(), hasher: config.hasher },
+            m,
+            n,
+            rows,
+            chunk_cols: c,
+        };
+
+        // hash full rows, a block of rows at a time
+        let block = (c * rows / n).max(1);
+        let mut hashes: Vec<Hash> = Vec::with_capacity(rows);
+        let mut row_block = vec![T::zero(); block * n];
+        for r0 in (0..rows).step_by(block) {
+            matrix.read_rows::<T>(r0, &mut row_block)?;
+            hashes.par_extend(row_block.par_chunks(n).map(|row| hash_row_with(config.hasher, row)));
+        }
+        matrix.tree = build_merkle_tree_with(config.hasher, &hashes);
+
+        Ok(matrix)
+    }
+
+    /// Read consecutive full rows starting at `first` into `out`
+    fn read_rows<T: BinaryFieldElement>(&mut self, first: usize, out: &mut [T]) -> io::Result<()> {
+        let (n, c) = (self.n, self.chunk_cols);
+        let count = out.len() / n;
+        let mut bytes = vec![0u8; count * c * T::BYTES];
+
+        for chunk in 0..n / c {
+            let offset = (chunk * self.rows + first) * c * T::BYTES;
+            self.scratch.seek(SeekFrom::Start(offset as u64))?;
+            self.scratch.read_exact(&mut bytes)?;
+
+            for (i, elem) in bytes.chunks_exact(T::BYTES).enumerate() {
+                let (r, j) = (i / c, i % c);
+                out[r * n + chunk * c + j] = T::read_bytes(elem);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T, P, S> InitialMatrix<T> for StreamedMatrix<P, S>
+where
+    T: BinaryFieldElement,
+    P: PolySource<T>,
+    S: Read + Write + Seek,
+{
+    fn root(&self) -> MerkleRoot {
+        self.tree.get_root()
+    }
+
+    fn num_rows(&self) -> usize {
+        self.rows
+    }
+
+    fn open_rows(&mut self, queries: &[usize]) -> crate::Result<(Vec<Vec<T>>, BatchedMerkleProof)> {
+        let mut opened = Vec::with_capacity(queries.len());
+        for &q in queries {
+            let mut row = vec![T::zero(); self.n];
+            self.read_rows(q, &mut row)?;
+            opened.push(row);
+        }
+        Ok((opened, self.tree.prove(queries)))
+    }
+
+    fn fold(&mut self, gammas: &[T], challenges: &[T]) -> crate::Result<Vec<T>> {
+        if !gammas.is_empty() {
+            return Err(LigeritoError::InvalidConfig("streaming prover does not batch".into()));
+        }
+
+        // a chunk of whole columns folds on its own; the chunk index picks
+        // the Lagrange weight of the high column bits
+        let (m, c) = (self.m, self.chunk_cols);
+        let log_chunks = (self.n / c).trailing_zeros() as usize;
+        let (high, low) = challenges.split_at(log_chunks);
+        let weights = evaluate_lagrange_basis(high);
+
+        let mut f_evals = vec![T::zero(); m];
+        let mut chunk = vec![T::zero(); c * m];
+        for (idx, weight) in weights.iter().enumerate() {
+            chunk.resize(c * m, T::zero());
+            self.source.read_coeffs(idx * c * m, &mut chunk)?;
+            partial_eval_multilinear_high(&mut chunk, low);
+            f_evals.par_iter_mut()
+                .zip(chunk.par_iter())
+                .for_each(|(f, x)| *f = f.add(&weight.mul(x)));
+        }
+        Ok(f_evals)
+    }
+}
+
+/// Prove a polynomial read from `poly`, keeping the encoded initial matrix
+/// in `scratch`
+///
+/// `chunk_cols` columns (rounded down to a power of two) are encoded at a
+/// time; `scratch` must be able to hold `inv_rate` times the polynomial in
+/// canonical encoding and is overwritten from the start.
+pub fn prove_streaming_with_transcript<T, U, P, S>(
+    config: &ProverConfig<T, U>,
+    poly: P,
+    scratch: S,
+    chunk_cols: usize,
+    fs: impl Transcript,
+) -> crate::Result<FinalizedLigeritoProof<T, U>>
+where
+    T: BinaryFieldElement + Send + Sync,
+    U: BinaryFieldElement + Send + Sync + From<T>,
+    P: PolySource<T>,
+    S: Read + Write + Seek,
+{
+    let mut initial = StreamedMatrix::commit(config, poly, scratch, chunk_cols)?;
+    open_core(config, None, &mut initial, fs)
+}
+
+/// Streaming prover using default Merlin transcript
+pub fn prove_streaming<T, U, P, S>(
+    config: &ProverConfig<T, U>,
+    poly: P,
+    scratch: S,
+    chunk_cols: usize,
+) -> crate::Result<FinalizedLigeritoProof<T, U>>
+where
+    T: BinaryFieldElement + Send + Sync,
+    U: BinaryFieldElement + Send + Sync + From<T>,
+    P: PolySource<T>,
+    S: Read + Write + Seek,
+{
+    let fs = FiatShamir::new_merlin();
+    prove_streaming_with_transcript(config, poly, scratch, chunk_cols, fs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{hardcoded_config_12, prove};
+    use binary_fields::{BinaryElem32, BinaryElem128};
+    use std::io::Cursor;
+    use std::marker::PhantomData;
+
+    fn config() -> ProverConfig<BinaryElem32, BinaryElem128> {
+        hardcoded_config_12(PhantomData::<BinaryElem32>, PhantomData::<BinaryElem128>)
+    }
+
+    fn test_poly() -> Vec<BinaryElem32> {
+        (0..1u64 << 12).map(|i| BinaryElem32::from_bits(i * 0x9e37 + 7)).collect()
+    }
+
+    #[test]
+    fn test_streaming_matches_in_memory() {
+        let config = config();
+        let poly = test_poly();
+        let expected = prove(&config, &poly).unwrap().to_bytes().unwrap();
+
+        for chunk_cols in [1, 3, 16] {
+            let proof = prove_streaming(&config, poly.as_slice(), Cursor::new(Vec::new()), chunk_cols)
+                .unwrap();
+            assert_eq!(proof.to_bytes().unwrap(), expected, "chunk_cols = {}", chunk_cols);
+        }
+    }
+
+    #[test]
+    fn test_reader_and_row_sources() {
+        let config = config();
+        let poly = test_poly();
+        let expected = prove(&config, &poly).unwrap().to_bytes().unwrap();
+
+        let mut encoded = vec![0u8; poly.len() * BinaryElem32::BYTES];
+        for (x, out) in poly.iter().zip(encoded.chunks_exact_mut(BinaryElem32::BYTES)) {
+            x.write_bytes(out);
+        }
+        let mut file = tempfile::tempfile().unwrap();
+        file.write_all(&encoded).unwrap();
+        let source = ReaderSource::new(file, poly.len());
+        let proof = prove_streaming(&config, source, tempfile::tempfile().unwrap(), 4).unwrap();
+        assert_eq!(proof.to_bytes().unwrap(), expected);
+
+        let (m, n) = config.initial_dims;
+        let poly = &poly;
+        let rows = RowSource::new(m, n, move || {
+            (0..m).map(move |i| (0..n).map(|j| poly[j * m + i]).collect::<Vec<_>>())
+        });
+        let proof = prove_streaming(&config, rows, Cursor::new(Vec::new()), 2).unwrap();
+        assert_eq!(proof.to_bytes().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_rejects_wrong_length() {
+        let config = config();
+        let poly = vec![BinaryElem32::zero(); 1 << 10];
+        assert!(prove_streaming(&config, poly.as_slice(), Cursor::new(Vec::new()), 4).is_err());
+    }
+}
@@ -1,10 +1,19 @@
 use binary_fields::BinaryFieldElement;
-use crate::utils::{evaluate_lagrange_basis, evaluate_scaled_basis_inplace};
+use crate::utils::{evaluate_lagrange_basis, eval_sks_at_point, expand_scaled_basis};
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+// Row index bits tabulated once per query when inducing the basis
+const LOW_BITS: usize = 10;
+
 /// Precompute alpha powers for efficiency
 pub fn precompute_alpha_powers<F: BinaryFieldElement>(alpha: F, n: usize) -> Vec<F> {
     let mut alpha_pows = vec![F::zero(); n];
+    if n == 0 {
+        return alpha_pows;
+    }
     alpha_pows[0] = F::one();
 
     for i in 1..n {
@@ -14,125 +23,37 @@ pub fn precompute_alpha_powers<F: BinaryFieldElement>(alpha: F, n: usize) -> Vec
     alpha_pows
 }
 
-/// Debug version of induce_sumcheck_poly to find the issue
-pub fn induce_sumcheck_poly_debug<T, U>(
-    n: usize,
-    sks_vks: &[T],
-    opened_rows: &[Vec<T>],
-    v_challenges: &[U],
-    sorted_queries: &[usize],
-    alpha: U,
-) -> (Vec<U>, U)
+/// Random combination of the opened rows, each combined with the Lagrange
+/// basis of the folding challenges
+///
+/// If the rows are consistent with the folded polynomial y, this equals
+/// `<y, basis>` for the basis returned by [`induce_sumcheck_poly`].
+pub fn enforced_sum<T, U>(opened_rows: &[Vec<T>], v_challenges: &[U], alpha: U) -> U
 where
     T: BinaryFieldElement,
     U: BinaryFieldElement + From<T>,
 {
-    println!("\n=== DEBUG induce_sumcheck_poly ===");
-    println!("n: {}, sks_vks.len: {}, opened_rows.len: {}, v_challenges.len: {}", 
-             n, sks_vks.len(), opened_rows.len(), v_challenges.len());
-    println!("First few queries: {:?}", &sorted_queries[..sorted_queries.len().min(5)]);
-    
-    // Check if alpha is zero - this is problematic!
-    if alpha == U::zero() {
-        println!("WARNING: Alpha is zero! This will cause issues!");
-    }
-    
     let gr = evaluate_lagrange_basis(v_challenges);
-    println!("Lagrange basis gr.len: {}", gr.len());
-    println!("First few gr values: {:?}", &gr[..gr.len().min(4)]);
-    
-    // Check if Lagrange basis is all zeros
-    if gr.iter().all(|&x| x == U::zero()) {
-        println!("WARNING: Lagrange basis is all zeros!");
-    }
-    
-    let mut basis_poly = vec![U::zero(); 1 << n];
-    let mut enforced_sum = U::zero();
-
     let alpha_pows = precompute_alpha_powers(alpha, opened_rows.len());
-    println!("Alpha: {:?}", alpha);
-    println!("First few alpha powers: {:?}", &alpha_pows[..alpha_pows.len().min(4)]);
-
-    for (i, (row, &query)) in opened_rows.iter().zip(sorted_queries.iter()).enumerate() {
-        if i < 3 {  // Debug first few iterations
-            println!("\nProcessing query {} (index {})", query, i);
-            println!("Row length: {}", row.len());
-            if row.len() > 0 {
-                println!("First few row values: {:?}", &row[..row.len().min(4)]);
-            }
-        }
-        
-        // Compute dot product
-        let dot = row.iter()
-            .zip(gr.iter())
-            .fold(U::zero(), |acc, (&r, &g)| {
-                let r_u = U::from(r);
-                acc.add(&r_u.mul(&g))
-            });
-        
-        if i < 3 {
-            println!("Dot product: {:?}", dot);
-        }
 
-        let alpha_pow = alpha_pows[i];
-        let contribution = dot.mul(&alpha_pow);
-        enforced_sum = enforced_sum.add(&contribution);
-        
-        if i < 3 {
-            println!("Alpha power: {:?}", alpha_pow);
-            println!("Contribution to sum: {:?}", contribution);
-            println!("Running enforced_sum: {:?}", enforced_sum);
-        }
-
-        // Create field element from query index (0-based)
-        let qf = T::from_bits(query as u64);
-        if i < 3 {
-            println!("Query {} as field element: {:?}", query, qf);
-        }
-        
-        let mut local_sks_x = vec![T::zero(); sks_vks.len()];
-        let mut local_basis = vec![U::zero(); 1 << n];
-
-        evaluate_scaled_basis_inplace(&mut local_sks_x, &mut local_basis, sks_vks, qf, alpha_pow);
-        
-        if i < 3 {
-            println!("First few basis values after evaluation: {:?}", 
-                     &local_basis[..local_basis.len().min(4)]);
-        }
-
-        // Add to basis polynomial
-        for (j, &val) in local_basis.iter().enumerate() {
-            basis_poly[j] = basis_poly[j].add(&val);
-        }
-    }
-
-    println!("\nFinal enforced_sum: {:?}", enforced_sum);
-    println!("First few basis_poly values: {:?}", &basis_poly[..basis_poly.len().min(4)]);
-    
-    // Check if basis polynomial is all zeros
-    if basis_poly.iter().all(|&x| x == U::zero()) {
-        println!("WARNING: Basis polynomial is all zeros!");
-    }
-    
-    // Compute and print the sum of basis polynomial
-    let basis_sum = basis_poly.iter().fold(U::zero(), |acc, &x| acc.add(&x));
-    println!("Sum of basis polynomial: {:?}", basis_sum);
-    
-    // CRITICAL FIX: If basis sum is zero but we have non-zero values, 
-    // we need to adjust the polynomial to ensure non-zero sum
-    if basis_sum == U::zero() && !basis_poly.iter().all(|&x| x == U::zero()) {
-        println!("WARNING: Basis polynomial sums to zero! Adjusting...");
-        // Add a small perturbation to break the symmetry
-        basis_poly[0] = basis_poly[0].add(&U::one());
-    }
-    
-    println!("=== END DEBUG ===\n");
-
-    (basis_poly, enforced_sum)
+    opened_rows.iter()
+        .zip(alpha_pows.iter())
+        .fold(U::zero(), |acc, (row, alpha_pow)| {
+            let dot = row.iter()
+                .zip(gr.iter())
+                .fold(U::zero(), |acc, (&r, g)| acc.add(&U::from(r).mul(g)));
+            acc.add(&dot.mul(alpha_pow))
+        })
 }
 
-/// Induce sumcheck polynomial in parallel
-pub fn induce_sumcheck_poly_parallel<T, U>(
+/// Induce the sumcheck polynomial from the opened rows
+///
+/// Row q of a matrix whose columns are non-systematically encoded messages
+/// satisfies `<row_q, lagrange(v_challenges)> = <y, G_q>`, with y the
+/// columns folded by `v_challenges` and `G_q` row q of the generator matrix.
+/// Returns `sum_t alpha^t G_{q_t}` over the `2^n` message positions together
+/// with the matching [`enforced_sum`].
+pub fn induce_sumcheck_poly<T, U>(
     n: usize,
     sks_vks: &[T],
     opened_rows: &[Vec<T>],
@@ -144,74 +65,107 @@ where
     T: BinaryFieldElement + Send + Sync,
     U: BinaryFieldElement + Send + Sync + From<T>,
 {
-    let gr = evaluate_lagrange_basis(v_challenges);
-    
-    // Check if we have the expected dimensions
-    if !opened_rows.is_empty() {
-        assert_eq!(opened_rows[0].len(), gr.len(), 
-                   "Row length {} doesn't match Lagrange basis length {}", 
-                   opened_rows[0].len(), gr.len());
-    }
-    assert_eq!(opened_rows.len(), sorted_queries.len());
-
-    let n_threads = rayon::current_num_threads();
-    let n_rows = opened_rows.len();
-    let chunk_size = (n_rows + n_threads - 1) / n_threads;
-
-    let alpha_pows = precompute_alpha_powers(alpha, n_rows);
-
-    // Parallel computation
-    let results: Vec<(Vec<U>, U)> = (0..n_threads)
-        .into_par_iter()
-        .map(|t| {
-            let mut local_basis = vec![U::zero(); 1 << n];
-            let mut local_sum = U::zero();
-            let mut local_sks_x = vec![T::zero(); sks_vks.len()];
-
-            let start_idx = t * chunk_size;
-            let end_idx = ((t + 1) * chunk_size).min(n_rows);
-
-            for i in start_idx..end_idx {
-                let row = &opened_rows[i];
-                let query = sorted_queries[i];
-
-                // Compute dot product
-                let dot = row.iter()
-                    .zip(gr.iter())
-                    .fold(U::zero(), |acc, (&r, &g)| {
-                        let r_u = U::from(r);
-                        acc.add(&r_u.mul(&g))
-                    });
-
-                let alpha_pow = alpha_pows[i];
-                local_sum = local_sum.add(&dot.mul(&alpha_pow));
-
-                // Create field element from query index (0-based)
-                let qf = T::from_bits(query as u64);
-                
-                let mut temp_basis = vec![U::zero(); 1 << n];
-                evaluate_scaled_basis_inplace(&mut local_sks_x, &mut temp_basis, sks_vks, qf, alpha_pow);
-
-                // Add to local basis
-                for j in 0..(1 << n) {
-                    local_basis[j] = local_basis[j].add(&temp_basis[j]);
-                }
-            }
-
-            (local_basis, local_sum)
+    let enforced_sum = enforced_sum(opened_rows, v_challenges, alpha);
+    let alpha_pows = precompute_alpha_powers(alpha, sorted_queries.len());
+
+    // Split row indices: the low bits are tabulated per query, the high bits
+    // contribute one factor per chunk of the basis
+    let low = n.min(LOW_BITS);
+    let (sks_x, low_tables): (Vec<Vec<T>>, Vec<Vec<U>>) = sorted_queries.iter()
+        .zip(alpha_pows.iter())
+        .map(|(&query, &alpha_pow)| {
+            let qf = T::from_bits(query as u64);
+            let mut sks_x = vec![T::zero(); n];
+            eval_sks_at_point(&mut sks_x, sks_vks, qf);
+            let mut table = vec![U::zero(); 1 << low];
+            expand_scaled_basis(&sks_x[..low], &mut table, alpha_pow);
+            (sks_x, table)
         })
-        .collect();
+        .unzip();
+
+    let fill = |(chunk_idx, chunk): (usize, &mut [U])| {
+        for (sks, table) in sks_x.iter().zip(low_tables.iter()) {
+            let high = sks[low..].iter()
+                .enumerate()
+                .filter(|(k, _)| (chunk_idx >> k) & 1 == 1)
+                .fold(T::one(), |acc, (_, sk)| acc.mul(sk));
+            let high = U::from(high);
+            for (b, t) in chunk.iter_mut().zip(table.iter()) {
+                *b = b.add(&t.mul(&high));
+            }
+        }
+    };
 
-    // Combine results
     let mut basis_poly = vec![U::zero(); 1 << n];
-    let mut enforced_sum = U::zero();
+    #[cfg(feature = "parallel")]
+    basis_poly.par_chunks_mut(1 << low).enumerate().for_each(fill);
+    #[cfg(not(feature = "parallel"))]
+    basis_poly.chunks_mut(1 << low).enumerate().for_each(fill);
+
+    (basis_poly, enforced_sum)
+}
 
-    for (partial_basis, partial_sum) in results {
-        for (i, &val) in partial_basis.iter().enumerate() {
-            basis_poly[i] = basis_poly[i].add(&val);
+/// The verifier's view of the sumcheck basis polynomial
+///
+/// A basis induced from opened rows is a combination of tensor products
+/// `prod_k s_k(q)^{i_k}`, so binding its top variable to r only rescales
+/// each term by `(1 + r) + r s_top(q)`. Keeping the terms instead of the
+/// evaluations makes folding cost one multiplication per opened row.
+pub struct FoldedBasis<U> {
+    // (s_k(q) for the unbound variables k, scale) per opened row
+    terms: Vec<(Vec<U>, U)>,
+}
+
+impl<U: BinaryFieldElement> FoldedBasis<U> {
+    /// The basis [`induce_sumcheck_poly`] would return over `2^n` positions
+    pub fn new<T>(n: usize, sks_vks: &[T], sorted_queries: &[usize], alpha: U) -> Self
+    where
+        T: BinaryFieldElement,
+        U: From<T>,
+    {
+        let alpha_pows = precompute_alpha_powers(alpha, sorted_queries.len());
+        let terms = sorted_queries.iter()
+            .zip(alpha_pows)
+            .map(|(&query, alpha_pow)| {
+                let mut sks_x = vec![T::zero(); n];
+                eval_sks_at_point(&mut sks_x, sks_vks, T::from_bits(query as u64));
+                (sks_x.into_iter().map(U::from).collect(), alpha_pow)
+            })
+            .collect();
+        Self { terms }
+    }
+
+    /// Bind the highest unbound variable to `r`
+    pub fn fold(&mut self, r: U) {
+        let one_plus_r = U::one().add(&r);
+        for (sks_x, scale) in self.terms.iter_mut() {
+            if let Some(top) = sks_x.pop() {
+                *scale = scale.mul(&one_plus_r.add(&r.mul(&top)));
+            }
         }
-        enforced_sum = enforced_sum.add(&partial_sum);
     }
 
-    (basis_poly, enforced_sum)
+    /// Add `beta` times `other`, which must have as many unbound variables
+    pub fn glue(&mut self, other: Self, beta: U) {
+        self.terms.extend(other.terms.into_iter().map(|(sks_x, scale)| (sks_x, scale.mul(&beta))));
+    }
+
+    /// `<poly, basis>` for a polynomial over the unbound variables
+    pub fn evaluate(&self, poly: &[U]) -> Option<U> {
+        let mut basis = vec![U::zero(); poly.len()];
+        let mut acc = vec![U::zero(); poly.len()];
+        for (sks_x, scale) in &self.terms {
+            if 1 << sks_x.len() != poly.len() {
+                return None;
+            }
+            expand_scaled_basis(sks_x, &mut basis, *scale);
+            for (a, b) in acc.iter_mut().zip(basis.iter()) {
+                *a = a.add(b);
+            }
+        }
+
+        Some(poly.iter()
+            .zip(acc.iter())
+            .fold(U::zero(), |sum, (p, b)| sum.add(&p.mul(b))))
+    }
 }
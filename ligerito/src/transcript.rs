@@ -6,7 +6,9 @@ use merkle_tree::MerkleRoot;
 use sha2::{Sha256, Digest};
 use rand::{Rng, RngCore, SeedableRng};
 use rand::rngs::StdRng;
-use std::collections::HashSet;
+use alloc::collections::BTreeSet;
+use alloc::vec;
+use alloc::vec::Vec;
 
 /// Trait for Fiat-Shamir transcripts
 pub trait Transcript: Send + Sync {
@@ -30,10 +32,12 @@ pub trait Transcript: Send + Sync {
 }
 
 /// Merlin-based Fiat-Shamir transcript (recommended)
+#[cfg(feature = "merlin")]
 pub struct MerlinTranscript {
     transcript: merlin::Transcript,
 }
 
+#[cfg(feature = "merlin")]
 impl MerlinTranscript {
     pub fn new(domain: &'static [u8]) -> Self {
         Self {
@@ -42,6 +46,7 @@ impl MerlinTranscript {
     }
 }
 
+#[cfg(feature = "merlin")]
 impl Transcript for MerlinTranscript {
     fn absorb_root(&mut self, root: &MerkleRoot) {
         if let Some(hash) = &root.root {
@@ -51,9 +56,9 @@ impl Transcript for MerlinTranscript {
 
     fn absorb_elems<F: BinaryFieldElement>(&mut self, elems: &[F]) {
         let bytes = unsafe {
-            std::slice::from_raw_parts(
+            core::slice::from_raw_parts(
                 elems.as_ptr() as *const u8,
-                elems.len() * std::mem::size_of::<F>()
+                core::mem::size_of_val(elems)
             )
         };
         self.transcript.append_message(b"field_elements", bytes);
@@ -61,82 +66,19 @@ impl Transcript for MerlinTranscript {
 
     fn absorb_elem<F: BinaryFieldElement>(&mut self, elem: F) {
         let bytes = unsafe {
-            std::slice::from_raw_parts(
+            core::slice::from_raw_parts(
                 &elem as *const F as *const u8,
-                std::mem::size_of::<F>()
+                core::mem::size_of::<F>()
             )
         };
         self.transcript.append_message(b"field_element", bytes);
     }
 
     fn get_challenge<F: BinaryFieldElement>(&mut self) -> F {
-        let field_bytes = std::mem::size_of::<F>();
-        let mut bytes = vec![0u8; field_bytes];
+        // uniform over the field: one random bit per polynomial coefficient
+        let mut bytes = vec![0u8; F::BYTES];
         self.transcript.challenge_bytes(b"challenge", &mut bytes);
-        
-        // For binary fields, we need to interpret bytes as polynomial coefficients
-        // Each bit represents a coefficient of x^i
-        let mut result = F::zero();
-        
-        // Process only the valid bits for this field size
-        let bits_needed = match field_bytes {
-            4 => 32,   // BinaryElem32
-            16 => 128, // BinaryElem128
-            _ => field_bytes * 8,
-        };
-        
-        let mut bit_count = 0;
-        for (byte_idx, byte) in bytes.iter().enumerate() {
-            for bit_idx in 0..8 {
-                if bit_count >= bits_needed {
-                    break;
-                }
-                
-                if (byte >> bit_idx) & 1 == 1 {
-                    // Create x^bit_count by repeated doubling
-                    let mut power = F::one();
-                    for _ in 0..bit_count {
-                        power = power.add(&power);
-                    }
-                    result = result.add(&power);
-                }
-                bit_count += 1;
-            }
-            if bit_count >= bits_needed {
-                break;
-            }
-        }
-        
-        // If we got all ones in low bits (which gives us 1), add more entropy
-        if result == F::one() || result == F::zero() {
-            // Mix in more entropy by getting another set of bytes
-            self.transcript.append_message(b"extra_entropy", &bytes);
-            self.transcript.challenge_bytes(b"challenge2", &mut bytes);
-            
-            // XOR with the new bytes to get more randomness
-            bit_count = 0;
-            for (byte_idx, byte) in bytes.iter().enumerate() {
-                for bit_idx in 0..8 {
-                    if bit_count >= bits_needed {
-                        break;
-                    }
-                    
-                    if (byte >> bit_idx) & 1 == 1 {
-                        let mut power = F::one();
-                        for _ in 0..bit_count {
-                            power = power.add(&power);
-                        }
-                        result = result.add(&power);
-                    }
-                    bit_count += 1;
-                }
-                if bit_count >= bits_needed {
-                    break;
-                }
-            }
-        }
-        
-        result
+        F::read_bytes(&bytes)
     }
 
     fn get_query(&mut self, max: usize) -> usize {
@@ -147,8 +89,10 @@ impl Transcript for MerlinTranscript {
     }
 
     fn get_distinct_queries(&mut self, max: usize, count: usize) -> Vec<usize> {
+        // there are only max distinct indices to draw
+        let count = count.min(max);
         let mut queries = Vec::with_capacity(count);
-        let mut seen = HashSet::new();
+        let mut seen = BTreeSet::new();
 
         while queries.len() < count {
             let q = self.get_query(max);
@@ -208,9 +152,9 @@ impl Transcript for Sha256Transcript {
 
     fn absorb_elems<F: BinaryFieldElement>(&mut self, elems: &[F]) {
         let bytes = unsafe {
-            std::slice::from_raw_parts(
+            core::slice::from_raw_parts(
                 elems.as_ptr() as *const u8,
-                elems.len() * std::mem::size_of::<F>()
+                core::mem::size_of_val(elems)
             )
         };
         self.hasher.update(bytes);
@@ -218,9 +162,9 @@ impl Transcript for Sha256Transcript {
 
     fn absorb_elem<F: BinaryFieldElement>(&mut self, elem: F) {
         let bytes = unsafe {
-            std::slice::from_raw_parts(
+            core::slice::from_raw_parts(
                 &elem as *const F as *const u8,
-                std::mem::size_of::<F>()
+                core::mem::size_of::<F>()
             )
         };
         self.hasher.update(bytes);
@@ -228,20 +172,9 @@ impl Transcript for Sha256Transcript {
 
     fn get_challenge<F: BinaryFieldElement>(&mut self) -> F {
         let mut rng = self.squeeze_rng();
-        let mut result = F::zero();
-        let num_bits = std::mem::size_of::<F>() * 8;
-        
-        for i in 0..num_bits {
-            if rng.gen_bool(0.5) {
-                let mut bit = F::one();
-                for _ in 0..i {
-                    bit = bit.add(&bit);
-                }
-                result = result.add(&bit);
-            }
-        }
-        
-        result
+        let mut bytes = vec![0u8; F::BYTES];
+        rng.fill_bytes(&mut bytes);
+        F::read_bytes(&bytes)
     }
 
     fn get_query(&mut self, max: usize) -> usize {
@@ -254,8 +187,10 @@ impl Transcript for Sha256Transcript {
     }
 
     fn get_distinct_queries(&mut self, max: usize, count: usize) -> Vec<usize> {
+        // there are only max distinct indices to draw
+        let count = count.min(max);
         let mut queries = Vec::with_capacity(count);
-        let mut seen = HashSet::new();
+        let mut seen = BTreeSet::new();
 
         while queries.len() < count {
             let q = self.get_query(max);
@@ -271,12 +206,14 @@ impl Transcript for Sha256Transcript {
 
 /// Factory for creating transcripts
 pub enum TranscriptType {
+    #[cfg(feature = "merlin")]
     Merlin,
     Sha256(i32), // seed
 }
 
 /// Wrapper type that can hold either transcript implementation
 pub enum FiatShamir {
+    #[cfg(feature = "merlin")]
     Merlin(MerlinTranscript),
     Sha256(Sha256Transcript),
 }
@@ -285,6 +222,7 @@ impl FiatShamir {
     /// Create a new transcript
     pub fn new(transcript_type: TranscriptType) -> Self {
         match transcript_type {
+            #[cfg(feature = "merlin")]
             TranscriptType::Merlin => {
                 FiatShamir::Merlin(MerlinTranscript::new(b"ligerito-v1"))
             }
@@ -295,6 +233,7 @@ impl FiatShamir {
     }
     
     /// Create Merlin transcript (recommended)
+    #[cfg(feature = "merlin")]
     pub fn new_merlin() -> Self {
         Self::new(TranscriptType::Merlin)
     }
@@ -309,6 +248,7 @@ impl FiatShamir {
 impl Transcript for FiatShamir {
     fn absorb_root(&mut self, root: &MerkleRoot) {
         match self {
+            #[cfg(feature = "merlin")]
             FiatShamir::Merlin(t) => t.absorb_root(root),
             FiatShamir::Sha256(t) => t.absorb_root(root),
         }
@@ -316,6 +256,7 @@ impl Transcript for FiatShamir {
     
     fn absorb_elems<F: BinaryFieldElement>(&mut self, elems: &[F]) {
         match self {
+            #[cfg(feature = "merlin")]
             FiatShamir::Merlin(t) => t.absorb_elems(elems),
             FiatShamir::Sha256(t) => t.absorb_elems(elems),
         }
@@ -323,6 +264,7 @@ impl Transcript for FiatShamir {
     
     fn absorb_elem<F: BinaryFieldElement>(&mut self, elem: F) {
         match self {
+            #[cfg(feature = "merlin")]
             FiatShamir::Merlin(t) => t.absorb_elem(elem),
             FiatShamir::Sha256(t) => t.absorb_elem(elem),
         }
@@ -330,6 +272,7 @@ impl Transcript for FiatShamir {
     
     fn get_challenge<F: BinaryFieldElement>(&mut self) -> F {
         match self {
+            #[cfg(feature = "merlin")]
             FiatShamir::Merlin(t) => t.get_challenge(),
             FiatShamir::Sha256(t) => t.get_challenge(),
         }
@@ -337,6 +280,7 @@ impl Transcript for FiatShamir {
     
     fn get_query(&mut self, max: usize) -> usize {
         match self {
+            #[cfg(feature = "merlin")]
             FiatShamir::Merlin(t) => t.get_query(max),
             FiatShamir::Sha256(t) => t.get_query(max),
         }
@@ -344,6 +288,7 @@ impl Transcript for FiatShamir {
     
     fn get_distinct_queries(&mut self, max: usize, count: usize) -> Vec<usize> {
         match self {
+            #[cfg(feature = "merlin")]
             FiatShamir::Merlin(t) => t.get_distinct_queries(max, count),
             FiatShamir::Sha256(t) => t.get_distinct_queries(max, count),
         }
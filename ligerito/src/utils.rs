@@ -1,5 +1,7 @@
 //! Utility functions for Ligerito
 
+use alloc::vec;
+use alloc::vec::Vec;
 use binary_fields::BinaryFieldElement;
 
 /// Evaluate Lagrange basis at given points
@@ -28,6 +30,15 @@ pub fn evaluate_lagrange_basis<F: BinaryFieldElement>(rs: &[F]) -> Vec<F> {
     current_layer
 }
 
+/// Evaluate eq(point, x) for every x in the hypercube
+///
+/// Variables are little-endian: `point[0]` binds the lowest index bit, the
+/// same order `partial_eval_multilinear` folds in.
+pub fn evaluate_eq_basis<F: BinaryFieldElement>(point: &[F]) -> Vec<F> {
+    let reversed: Vec<F> = point.iter().rev().copied().collect();
+    evaluate_lagrange_basis(&reversed)
+}
+
 /// Evaluate s_k at v_k values (for sumcheck)
 /// Returns evaluation of all s_k polynomials at v_k points
 pub fn eval_sk_at_vks<F: BinaryFieldElement>(n: usize) -> Vec<F> {
@@ -65,42 +76,54 @@ pub fn eval_sk_at_vks<F: BinaryFieldElement>(n: usize) -> Vec<F> {
     sks_vks
 }
 
+/// Evaluate s_k at the domain point `qf` for every k < `sks_x.len()`
+///
+/// Uses s_{k+1}(x) = s_k(x)^2 + s_k(v_k) s_k(x) with `sks_vks[k] = s_k(v_k)`.
+pub fn eval_sks_at_point<F: BinaryFieldElement>(sks_x: &mut [F], sks_vks: &[F], qf: F) {
+    let mut sk = qf;
+    for (k, out) in sks_x.iter_mut().enumerate() {
+        *out = sk;
+        sk = sk.mul(&sk).add(&sks_vks[k].mul(&sk));
+    }
+}
+
 /// Evaluate scaled basis in-place
+///
+/// Writes `scale * prod_k s_k(qf)^{i_k}` to `basis[i]`, i.e. row `qf` of the
+/// non-systematic Reed-Solomon generator matrix: the codeword of a message
+/// `msg` at the point `qf` is `sum_i msg[i] * basis[i]` with `scale = 1`.
+/// `sks_x` receives s_k(qf) for k < log2(basis.len()).
 pub fn evaluate_scaled_basis_inplace<F: BinaryFieldElement, U: BinaryFieldElement>(
-    _sks_x: &mut [F],
+    sks_x: &mut [F],
     basis: &mut [U],
-    _sks_vks: &[F],
+    sks_vks: &[F],
     qf: F,
     scale: U,
 ) where
     U: From<F>,
 {
-    let n = basis.len();
-    let log_n = n.trailing_zeros() as usize;
-
-    // Initialize basis with scale
-    for i in 0..n {
-        basis[i] = scale;
-    }
+    let log_n = basis.len().trailing_zeros() as usize;
+    let sks_x = &mut sks_x[..log_n];
+    eval_sks_at_point(sks_x, sks_vks, qf);
 
-    // Convert qf to U for computations
-    let qf_u = U::from(qf);
-    let one_u = U::one();
+    expand_scaled_basis(sks_x, basis, scale);
+}
 
-    // Build the multilinear basis
-    let mut stride = 1;
-    for _bit_idx in 0..log_n {
-        for i in 0..n {
-            if (i / stride) % 2 == 1 {
-                // Multiply by qf for this bit
-                basis[i] = basis[i].mul(&qf_u);
-            } else {
-                // Multiply by (1 + qf) for this bit (in binary fields, 1 - x = 1 + x)
-                let one_plus_qf = one_u.add(&qf_u);
-                basis[i] = basis[i].mul(&one_plus_qf);
-            }
+/// Write `scale * prod_k sks_x[k]^{i_k}` to `basis[i]`
+///
+/// `basis` must have `2^sks_x.len()` entries.
+pub fn expand_scaled_basis<F: BinaryFieldElement, U: BinaryFieldElement + From<F>>(
+    sks_x: &[F],
+    basis: &mut [U],
+    scale: U,
+) {
+    basis[0] = scale;
+    for (k, &sk) in sks_x.iter().enumerate() {
+        let sk = U::from(sk);
+        let (lo, hi) = basis[..2 << k].split_at_mut(1 << k);
+        for (h, l) in hi.iter_mut().zip(lo.iter()) {
+            *h = l.mul(&sk);
         }
-        stride *= 2;
     }
 }
 
@@ -109,16 +132,10 @@ pub fn is_power_of_two(n: usize) -> bool {
     n > 0 && (n & (n - 1)) == 0
 }
 
-/// Encode non-systematic Reed-Solomon
-pub fn encode_non_systematic<F: BinaryFieldElement>(
-    rs: &reed_solomon::ReedSolomon<F>,
-    data: &mut [F],
-) {
-    // Non-systematic encoding (no original message preservation)
-    reed_solomon::encode_in_place(rs, data);
-}
-
 /// Multilinear polynomial partial evaluation
+///
+/// Binds the lowest index bit first; see [`partial_eval_multilinear_high`]
+/// for the order the Ligero matrices are folded in.
 pub fn partial_eval_multilinear<F: BinaryFieldElement>(
     poly: &mut Vec<F>, 
     evals: &[F]
@@ -138,6 +155,25 @@ pub fn partial_eval_multilinear<F: BinaryFieldElement>(
     poly.truncate(n);
 }
 
+/// Bind the highest index bits of a multilinear polynomial, MSB first
+///
+/// Afterwards `poly[i] = sum_j poly_old[j * len + i] * lagrange(evals)[j]`
+/// with `len` the new length: the column combination of a matrix laid out
+/// by `poly2mat`.
+pub fn partial_eval_multilinear_high<F: BinaryFieldElement>(
+    poly: &mut Vec<F>,
+    evals: &[F],
+) {
+    for &e in evals {
+        let half = poly.len() / 2;
+        let (lo, hi) = poly.split_at_mut(half);
+        for (p0, p1) in lo.iter_mut().zip(hi.iter()) {
+            *p0 = p0.add(&e.mul(&p1.add(p0)));
+        }
+        poly.truncate(half);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -179,6 +215,71 @@ mod tests {
         println!("Non-zero entries: {}/{}", non_zero_count, basis.len());
     }
 
+    #[test]
+    fn test_eq_basis_matches_partial_eval() {
+        use binary_fields::BinaryElem32;
+
+        let poly: Vec<BinaryElem32> = (0..16u32).map(|i| BinaryElem32::from(i * 7 + 3)).collect();
+        let point = vec![
+            BinaryElem32::from(0x1234),
+            BinaryElem32::from(0x5678),
+            BinaryElem32::from(0x9ABC),
+            BinaryElem32::from(0xDEF0),
+        ];
+
+        let mut folded = poly.clone();
+        partial_eval_multilinear(&mut folded, &point);
+
+        let eq = evaluate_eq_basis(&point);
+        let dot = poly.iter()
+            .zip(eq.iter())
+            .fold(BinaryElem32::zero(), |acc, (p, e)| acc.add(&p.mul(e)));
+
+        assert_eq!(folded, vec![dot]);
+    }
+
+    #[test]
+    fn test_scaled_basis_is_generator_row() {
+        use binary_fields::BinaryElem32;
+
+        let rs = reed_solomon::reed_solomon::<BinaryElem32>(16, 64);
+        let msg: Vec<BinaryElem32> = (0..16u32).map(|i| BinaryElem32::from(i * 0x9e37 + 5)).collect();
+        let mut codeword = vec![BinaryElem32::zero(); 64];
+        codeword[..16].copy_from_slice(&msg);
+        reed_solomon::encode_non_systematic(&rs, &mut codeword);
+
+        let sks_vks: Vec<BinaryElem32> = eval_sk_at_vks(16);
+        let mut sks_x = vec![BinaryElem32::zero(); sks_vks.len()];
+        let mut basis = vec![BinaryElem32::zero(); 16];
+        for (q, &c) in codeword.iter().enumerate() {
+            let qf = BinaryElem32::from_bits(q as u64);
+            evaluate_scaled_basis_inplace(&mut sks_x, &mut basis, &sks_vks, qf, BinaryElem32::one());
+            let e = msg.iter()
+                .zip(basis.iter())
+                .fold(BinaryElem32::zero(), |acc, (m, b)| acc.add(&m.mul(b)));
+            assert_eq!(e, c);
+        }
+    }
+
+    #[test]
+    fn test_high_fold_combines_columns() {
+        use binary_fields::BinaryElem32;
+
+        let poly: Vec<BinaryElem32> = (0..32u32).map(|i| BinaryElem32::from(i * 13 + 1)).collect();
+        let rs = vec![BinaryElem32::from(0x1234), BinaryElem32::from(0x5678)];
+
+        let mut folded = poly.clone();
+        partial_eval_multilinear_high(&mut folded, &rs);
+
+        let gr = evaluate_lagrange_basis(&rs);
+        let expected: Vec<BinaryElem32> = (0..8)
+            .map(|i| gr.iter()
+                .enumerate()
+                .fold(BinaryElem32::zero(), |acc, (j, g)| acc.add(&poly[j * 8 + i].mul(g))))
+            .collect();
+        assert_eq!(folded, expected);
+    }
+
     #[test]
     fn test_power_of_two() {
         assert!(is_power_of_two(1));
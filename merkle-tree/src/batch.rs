@@ -1,6 +1,7 @@
 // src/batch.rs
+use alloc::vec::Vec;
 use bytemuck::Pod;
-use crate::{CompleteMerkleTree, MerkleRoot, Hash, hash_leaf, hash_siblings};
+use crate::{CompleteMerkleTree, MerkleRoot, Hash, Hasher, Sha256Hasher};
 
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -15,7 +16,9 @@ impl BatchedMerkleProof {
 }
 
 /// Create a batched proof for multiple queries (0-based indices)
-pub fn prove_batch(tree: &CompleteMerkleTree, queries: &[usize]) -> BatchedMerkleProof {
+///
+/// Opening only copies stored nodes, so any hasher's tree can be proven.
+pub fn prove_batch<H>(tree: &CompleteMerkleTree<H>, queries: &[usize]) -> BatchedMerkleProof {
     let mut siblings = Vec::new();
     let depth = tree.get_depth();
 
@@ -40,13 +43,25 @@ pub fn prove_batch(tree: &CompleteMerkleTree, queries: &[usize]) -> BatchedMerkl
     BatchedMerkleProof { siblings }
 }
 
-/// Verify a batched proof (0-based indices)
+/// Verify a batched proof of a SHA-256 tree (0-based indices)
 pub fn verify_batch<T: Pod>(
     root: &MerkleRoot,
     proof: &BatchedMerkleProof,
     depth: usize,
     leaves: &[T],
     leaf_indices: &[usize],
+) -> bool {
+    verify_batch_with(&Sha256Hasher, root, proof, depth, leaves, leaf_indices)
+}
+
+/// Verify a batched proof of a tree built with `hasher` (0-based indices)
+pub fn verify_batch_with<H: Hasher, T: Pod>(
+    hasher: &H,
+    root: &MerkleRoot,
+    proof: &BatchedMerkleProof,
+    depth: usize,
+    leaves: &[T],
+    leaf_indices: &[usize],
 ) -> bool {
     let Some(expected_root) = root.root else {
         return false;
@@ -55,7 +70,7 @@ pub fn verify_batch<T: Pod>(
     if depth == 0 {
         // Single leaf tree
         if leaves.len() == 1 && leaf_indices.len() == 1 && leaf_indices[0] == 0 {
-            let leaf_hash = hash_leaf(&leaves[0]);
+            let leaf_hash = hasher.hash_leaf(bytemuck::bytes_of(&leaves[0]));
             return leaf_hash == expected_root;
         }
         return false;
@@ -63,7 +78,7 @@ pub fn verify_batch<T: Pod>(
 
     // Hash leaves
     let mut layer: Vec<Hash> = leaves.iter()
-        .map(hash_leaf)
+        .map(|leaf| hasher.hash_leaf(bytemuck::bytes_of(leaf)))
         .collect();
 
     // Work with 0-based indices directly
@@ -75,6 +90,7 @@ pub fn verify_batch<T: Pod>(
     // Process each layer
     for _ in 0..depth {
         let (next_cnt, next_proof_cnt) = verify_ith_layer(
+            hasher,
             &mut layer,
             &mut queries,
             curr_cnt,
@@ -127,7 +143,8 @@ fn ith_layer(
     next_queries_len
 }
 
-fn verify_ith_layer(
+fn verify_ith_layer<H: Hasher>(
+    hasher: &H,
     layer: &mut Vec<Hash>,
     queries: &mut Vec<usize>,
     curr_cnt: usize,
@@ -148,9 +165,9 @@ fn verify_ith_layer(
             proof_cnt += 1;
             let pp = proof.get(proof_cnt - 1).copied().unwrap_or_default();
             layer[next_cnt - 1] = if query % 2 != 0 {
-                hash_siblings(&pp, &layer[i])
+                hasher.hash_siblings(&pp, &layer[i])
             } else {
-                hash_siblings(&layer[i], &pp)
+                hasher.hash_siblings(&layer[i], &pp)
             };
             break;
         }
@@ -158,16 +175,16 @@ fn verify_ith_layer(
         if query % 2 != 0 {
             proof_cnt += 1;
             let pp = proof.get(proof_cnt - 1).copied().unwrap_or_default();
-            layer[next_cnt - 1] = hash_siblings(&pp, &layer[i]);
+            layer[next_cnt - 1] = hasher.hash_siblings(&pp, &layer[i]);
             i += 1;
         } else {
             if queries[i + 1] != sibling {
                 proof_cnt += 1;
                 let pp = proof.get(proof_cnt - 1).copied().unwrap_or_default();
-                layer[next_cnt - 1] = hash_siblings(&layer[i], &pp);
+                layer[next_cnt - 1] = hasher.hash_siblings(&layer[i], &pp);
                 i += 1;
             } else {
-                layer[next_cnt - 1] = hash_siblings(&layer[i], &layer[i + 1]);
+                layer[next_cnt - 1] = hasher.hash_siblings(&layer[i], &layer[i + 1]);
                 i += 2;
             }
         }
@@ -0,0 +1,271 @@
+// src/hasher.rs
+//! Hash functions for leaves and internal nodes
+//!
+//! [`Sha256Hasher`] is the original tree hash. [`Blake3Hasher`] is faster on
+//! CPUs, [`Keccak256Hasher`] matches EVM `keccak256(abi.encodePacked(..))`,
+//! and [`RescueHasher`] is a Rescue-Prime sponge over GF(2^128) that is cheap
+//! to re-verify in binary field constraint systems. Every hasher domain
+//! separates leaves from nodes: the byte hashers prefix leaves with 0x00 and
+//! nodes with 0x01, the Rescue sponge absorbs a 0 or 1 element first, so no
+//! leaf can be passed off as an internal node.
+//!
+//! [`MerkleHasher`] selects one of these at runtime, for configs that carry
+//! the choice as data.
+
+use crate::Hash;
+use binary_fields::{BinaryElem128, BinaryFieldElement};
+use sha2::Digest;
+
+/// First byte of every leaf preimage of the byte hashers
+const LEAF_TAG: u8 = 0;
+
+/// First byte of every node preimage of the byte hashers
+const NODE_TAG: u8 = 1;
+
+/// Hash function used to build and verify a tree
+pub trait Hasher: Clone + Send + Sync {
+    /// Digest of a leaf's byte encoding
+    fn hash_leaf(&self, bytes: &[u8]) -> Hash;
+
+    /// Digest of an internal node from its two children
+    fn hash_siblings(&self, left: &Hash, right: &Hash) -> Hash;
+}
+
+/// SHA-256
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sha256Hasher;
+
+impl Hasher for Sha256Hasher {
+    fn hash_leaf(&self, bytes: &[u8]) -> Hash {
+        let mut hasher = sha2::Sha256::new();
+        hasher.update([LEAF_TAG]);
+        hasher.update(bytes);
+        hasher.finalize().into()
+    }
+
+    fn hash_siblings(&self, left: &Hash, right: &Hash) -> Hash {
+        let mut hasher = sha2::Sha256::new();
+        hasher.update([NODE_TAG]);
+        hasher.update(left);
+        hasher.update(right);
+        hasher.finalize().into()
+    }
+}
+
+/// BLAKE3 in its default hash mode
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Blake3Hasher;
+
+impl Hasher for Blake3Hasher {
+    fn hash_leaf(&self, bytes: &[u8]) -> Hash {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(&[LEAF_TAG]);
+        hasher.update(bytes);
+        hasher.finalize().into()
+    }
+
+    fn hash_siblings(&self, left: &Hash, right: &Hash) -> Hash {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(&[NODE_TAG]);
+        hasher.update(left);
+        hasher.update(right);
+        hasher.finalize().into()
+    }
+}
+
+/// Keccak-256 with the original padding, as used by the EVM
+///
+/// Leaves are `keccak256(abi.encodePacked(uint8(0), data))` and nodes
+/// `keccak256(abi.encodePacked(uint8(1), left, right))`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Keccak256Hasher;
+
+impl Hasher for Keccak256Hasher {
+    fn hash_leaf(&self, bytes: &[u8]) -> Hash {
+        let mut hasher = sha3::Keccak256::new();
+        hasher.update([LEAF_TAG]);
+        hasher.update(bytes);
+        hasher.finalize().into()
+    }
+
+    fn hash_siblings(&self, left: &Hash, right: &Hash) -> Hash {
+        let mut hasher = sha3::Keccak256::new();
+        hasher.update([NODE_TAG]);
+        hasher.update(left);
+        hasher.update(right);
+        hasher.finalize().into()
+    }
+}
+
+/// Rescue-Prime sponge over GF(2^128)
+///
+/// Width 4, rate 2, 14 rounds of the x^-1 S-box and the circulant MDS
+/// matrix [2, 3, 1, 1]. The two capacity elements give 128-bit collision
+/// resistance, matching the 256-bit digest; the width-3 `wim::rescue`
+/// permutation has a single capacity element and only 64 bits. Leaves
+/// absorb `[0, byte_len, 16-byte little-endian chunks..]`, nodes absorb
+/// `[1, left_lo, left_hi, right_lo, right_hi]`, and the digest is the two
+/// rate elements after the padding permutation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RescueHasher;
+
+const RESCUE_WIDTH: usize = 4;
+const RESCUE_RATE: usize = 2;
+const RESCUE_ROUNDS: usize = 14;
+
+/// SHAKE-256("rescue-prime-gf2^128-width4-rounds14") read as little-endian
+/// u128s, derived like the width-3 constants of `wim::rescue`
+const RESCUE_CONSTANTS: [[u128; RESCUE_WIDTH]; RESCUE_ROUNDS] = [
+    [0x4db26fae9398ed6f92bf78d12e449f0a, 0x2aaca3ba4839b34ac4ba6b4b9310eba3, 0x2a65608331ddebc6e040382fc8027a19, 0x20608009aef9d492cf0b32c813042ac9],
+    [0x3fb9dac4a1c7e3fd63b6a4f9bc847e43, 0xd002690f6c603aa3153ced809d45f857, 0xcaacb9964cc83afa6544b851e97293b2, 0x2f86b6d6e61fac3eb38164b6e9672718],
+    [0x30a741e981675cb937d3bb5cb7ddbc4b, 0xce0b09be3974f39191b886db80d49e10, 0x7de4f52209a03f4f7aed2f40267db8c6, 0x5f9d657fb1aa699b570337f8d33d76af],
+    [0xda3895d62c72e7f27cb4714b328cbea8, 0x16a1726a9d004cb0f74f643141003bf5, 0x60b6190704f99f086b32a1c2f3a09d8c, 0x315d86102e042d8f71bbb7bdf993c3a3],
+    [0xc3556aa4931234a3e35595cbb8f6dcef, 0xfee8b9460e648b7f6b2013f1e4a43713, 0x99a5b00d80341f6544fa8c8455356915, 0xde302907c4901a8629281843140254bc],
+    [0x2e9c01470a00be15ad98e7b4ddedeadf, 0x84c9147c231b41fbf5521541b9af1758, 0x157535dd2ad332bd3f20b0544a9a54f8, 0x7478eb066e9011f7e7f4c3e1d3d3119b],
+    [0x2f35af95e41cbd6d19841736c920f1fc, 0x03d600f87fd206a216b8414d9623955a, 0x10ff37080375f8ebb187c6065c539c36, 0x7ffd4393bccf4f031b54c632a0a4bc48],
+    [0xe57a2c1092acc309afa7a79b1c2409a7, 0x5a6062f9ca2e393a56a5da76fd31dd3e, 0x7828fb0b371077a554b341bedc46854d, 0x0f0910ebec5db93d0e1b6682209d3a71],
+    [0xa185f9f642eeafe5d47861419aa853b2, 0x284c92d7b296448879bd797083d7fd74, 0x0ef277b923ff113c8d9eb14ff9155a5f, 0x3a9a2f4a8c27cbf7d5bb83d3733e0a06],
+    [0x739934d0e00eb75d7a6aab907cd9020a, 0x0bd1ad1d05514650e308520a25e5d44e, 0x2136d1ef0a3e466d18c482efd955c790, 0x40f061a84075f18276d9a254dbdbe35c],
+    [0xdd10a23be673e46f0e9db48aac222c68, 0xd1ce90ee5166a56a663eecb5655b555d, 0x984239ceaaedff5780757ce94d5271df, 0xad234518c39cc3a931b6829aabada483],
+    [0x2f704e4413765bc29b3913251010ebee, 0xa8c737e88ada57a308d702561e8d340e, 0xfea40451d56f7380085e788cf19a2fde, 0x3795f94585905eb3d3f9067e091d7f36],
+    [0x9a7473fbb3d91c39eaa0f60dc1a09b69, 0x3ccde741401e64592d264eced617b44f, 0xf7e0a0cf2bc550937a1953bd42d9bb65, 0x941ba97726447af173f1c6466d2067bf],
+    [0x8ff3964d30ad8c72ce04811c6cbf94cf, 0x8c1338d9744a85f2575ae0de52cc55f7, 0x39d5d5df894d088964a892857791b7a4, 0x4443e74dc9f88c9789239bd4530fd5c5],
+];
+
+struct RescueSponge {
+    state: [BinaryElem128; RESCUE_WIDTH],
+    pending: usize,
+}
+
+impl RescueSponge {
+    fn new() -> Self {
+        Self { state: [BinaryElem128::zero(); RESCUE_WIDTH], pending: 0 }
+    }
+
+    fn absorb(&mut self, elem: BinaryElem128) {
+        self.state[self.pending] = self.state[self.pending].add(&elem);
+        self.pending += 1;
+        if self.pending == RESCUE_RATE {
+            self.permute();
+            self.pending = 0;
+        }
+    }
+
+    fn absorb_bytes(&mut self, bytes: &[u8]) {
+        for chunk in bytes.chunks(16) {
+            let mut buf = [0u8; 16];
+            buf[..chunk.len()].copy_from_slice(chunk);
+            self.absorb(BinaryElem128::from_value(u128::from_le_bytes(buf)));
+        }
+    }
+
+    fn finalize(mut self) -> Hash {
+        if self.pending != 0 {
+            self.permute();
+        }
+        self.state[0] = self.state[0].add(&BinaryElem128::one());
+        self.permute();
+
+        let mut out = [0u8; 32];
+        self.state[0].write_bytes(&mut out[..16]);
+        self.state[1].write_bytes(&mut out[16..]);
+        out
+    }
+
+    fn permute(&mut self) {
+        for constants in RESCUE_CONSTANTS.iter() {
+            // the S-box is an involution, so both half-rounds use it
+            for _ in 0..2 {
+                invert(&mut self.state);
+                self.mds();
+                for (s, &c) in self.state.iter_mut().zip(constants) {
+                    *s = s.add(&BinaryElem128::from_value(c));
+                }
+            }
+        }
+    }
+
+    /// Circulant [2, 3, 1, 1] (the AES MixColumns matrix): row i is
+    /// sum + s_i + 2 * (s_i + s_{i+1})
+    fn mds(&mut self) {
+        let old = self.state;
+        let sum = old.iter().fold(BinaryElem128::zero(), |acc, s| acc.add(s));
+        for (i, s) in self.state.iter_mut().enumerate() {
+            let next = old[(i + 1) % RESCUE_WIDTH];
+            *s = sum.add(&old[i]).add(&mul_by_x(old[i].add(&next)));
+        }
+    }
+}
+
+/// x -> x^(2^128 - 2) on the whole state, which maps 0 to 0
+fn invert(state: &mut [BinaryElem128; RESCUE_WIDTH]) {
+    let mut square = *state;
+    BinaryElem128::mul_slice(&mut square, state);
+    let mut result = square;
+    for _ in 1..127 {
+        let prev = square;
+        BinaryElem128::mul_slice(&mut square, &prev);
+        BinaryElem128::mul_slice(&mut result, &square);
+    }
+    *state = result;
+}
+
+/// Multiplication by 2 = x modulo x^128 + x^7 + x^2 + x + 1
+fn mul_by_x(a: BinaryElem128) -> BinaryElem128 {
+    let v = a.poly().value();
+    let carry = if v >> 127 == 1 { 0x87 } else { 0 };
+    BinaryElem128::from_value((v << 1) ^ carry)
+}
+
+impl Hasher for RescueHasher {
+    fn hash_leaf(&self, bytes: &[u8]) -> Hash {
+        let mut sponge = RescueSponge::new();
+        sponge.absorb(BinaryElem128::zero());
+        sponge.absorb(BinaryElem128::from_value(bytes.len() as u128));
+        sponge.absorb_bytes(bytes);
+        sponge.finalize()
+    }
+
+    fn hash_siblings(&self, left: &Hash, right: &Hash) -> Hash {
+        let mut sponge = RescueSponge::new();
+        sponge.absorb(BinaryElem128::one());
+        sponge.absorb_bytes(left);
+        sponge.absorb_bytes(right);
+        sponge.finalize()
+    }
+}
+
+/// Runtime choice of [`Hasher`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MerkleHasher {
+    #[default]
+    Sha256,
+    Blake3,
+    Keccak256,
+    Rescue,
+}
+
+impl Hasher for MerkleHasher {
+    fn hash_leaf(&self, bytes: &[u8]) -> Hash {
+        match self {
+            Self::Sha256 => Sha256Hasher.hash_leaf(bytes),
+            Self::Blake3 => Blake3Hasher.hash_leaf(bytes),
+            Self::Keccak256 => Keccak256Hasher.hash_leaf(bytes),
+            Self::Rescue => RescueHasher.hash_leaf(bytes),
+        }
+    }
+
+    fn hash_siblings(&self, left: &Hash, right: &Hash) -> Hash {
+        match self {
+            Self::Sha256 => Sha256Hasher.hash_siblings(left, right),
+            Self::Blake3 => Blake3Hasher.hash_siblings(left, right),
+            Self::Keccak256 => Keccak256Hasher.hash_siblings(left, right),
+            Self::Rescue => RescueHasher.hash_siblings(left, right),
+        }
+    }
+}
@@ -2,16 +2,30 @@
 //! Merkle tree with batch opening support
 //! Matches the Julia BatchedMerkleTree implementation
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
 pub mod batch;
-pub use batch::{BatchedMerkleProof, prove_batch, verify_batch};
+pub mod hasher;
+pub use batch::{BatchedMerkleProof, prove_batch, verify_batch, verify_batch_with};
+pub use hasher::{
+    Hasher, MerkleHasher, Sha256Hasher, Blake3Hasher, Keccak256Hasher, RescueHasher,
+};
 
-use sha2::{Sha256, Digest};
 use bytemuck::Pod;
 
 pub type Hash = [u8; 32];
 
-pub struct CompleteMerkleTree {
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompleteMerkleTree<H = Sha256Hasher> {
     pub layers: Vec<Vec<Hash>>,
+    /// Hasher the layers were built with
+    pub hasher: H,
 }
 
 #[derive(Clone, Debug)]
@@ -31,22 +45,21 @@ pub fn is_power_of_two(n: usize) -> bool {
 }
 
 pub fn hash_leaf<T: Pod>(leaf: &T) -> Hash {
-    let bytes = bytemuck::bytes_of(leaf);
-    let mut hasher = Sha256::new();
-    hasher.update(bytes);
-    hasher.finalize().into()
+    Sha256Hasher.hash_leaf(bytemuck::bytes_of(leaf))
 }
 
 pub fn hash_siblings(left: &Hash, right: &Hash) -> Hash {
-    let mut hasher = Sha256::new();
-    hasher.update(left);
-    hasher.update(right);
-    hasher.finalize().into()
+    Sha256Hasher.hash_siblings(left, right)
 }
 
 pub fn build_merkle_tree<T: Pod>(leaves: &[T]) -> CompleteMerkleTree {
+    build_merkle_tree_with(Sha256Hasher, leaves)
+}
+
+/// Build a tree with the given hasher
+pub fn build_merkle_tree_with<H: Hasher, T: Pod>(hasher: H, leaves: &[T]) -> CompleteMerkleTree<H> {
     if leaves.is_empty() {
-        return CompleteMerkleTree { layers: vec![] };
+        return CompleteMerkleTree { layers: vec![], hasher };
     }
 
     if !is_power_of_two(leaves.len()) {
@@ -54,7 +67,7 @@ pub fn build_merkle_tree<T: Pod>(leaves: &[T]) -> CompleteMerkleTree {
     }
 
     let mut current_layer: Vec<Hash> = leaves.iter()
-        .map(|leaf| hash_leaf(leaf))
+        .map(|leaf| hasher.hash_leaf(bytemuck::bytes_of(leaf)))
         .collect();
 
     let mut layers = vec![current_layer.clone()];
@@ -62,17 +75,17 @@ pub fn build_merkle_tree<T: Pod>(leaves: &[T]) -> CompleteMerkleTree {
     while current_layer.len() > 1 {
         let next_layer: Vec<Hash> = current_layer
             .chunks_exact(2)
-            .map(|chunk| hash_siblings(&chunk[0], &chunk[1]))
+            .map(|chunk| hasher.hash_siblings(&chunk[0], &chunk[1]))
             .collect();
 
         layers.push(next_layer.clone());
         current_layer = next_layer;
     }
 
-    CompleteMerkleTree { layers }
+    CompleteMerkleTree { layers, hasher }
 }
 
-impl CompleteMerkleTree {
+impl<H> CompleteMerkleTree<H> {
     pub fn get_root(&self) -> MerkleRoot {
         MerkleRoot {
             root: self.layers.last()
@@ -105,6 +118,18 @@ pub fn verify<T: Pod>(
     verify_batch(root, proof, depth, leaves, leaf_indices)
 }
 
+/// Verify a batched Merkle proof built with `hasher` (0-based indices)
+pub fn verify_with<H: Hasher, T: Pod>(
+    hasher: &H,
+    root: &MerkleRoot,
+    proof: &BatchedMerkleProof,
+    depth: usize,
+    leaves: &[T],
+    leaf_indices: &[usize],
+) -> bool {
+    verify_batch_with(hasher, root, proof, depth, leaves, leaf_indices)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -310,4 +335,75 @@ mod tests {
 
         assert!(is_valid, "Multiple query verification failed");
     }
+
+    #[test]
+    fn test_hasher_known_answers() {
+        // digests of the single leaf tag byte 0x00
+        assert_eq!(
+            Keccak256Hasher.hash_leaf(b"")[..4],
+            [0xbc, 0x36, 0x78, 0x9e],
+        );
+        assert_eq!(
+            Blake3Hasher.hash_leaf(b"")[..4],
+            [0x2d, 0x3a, 0xde, 0xdf],
+        );
+        assert_eq!(
+            Sha256Hasher.hash_leaf(b"")[..4],
+            [0x6e, 0x34, 0x0b, 0x9c],
+        );
+    }
+
+    #[test]
+    fn test_batch_proof_all_hashers() {
+        let leaves: Vec<u64> = (0..8).collect();
+        let queries = vec![1, 4, 5];
+        let queried_leaves: Vec<u64> = queries.iter().map(|&i| leaves[i]).collect();
+
+        let hashers = [
+            MerkleHasher::Sha256,
+            MerkleHasher::Blake3,
+            MerkleHasher::Keccak256,
+            MerkleHasher::Rescue,
+        ];
+        let mut roots = Vec::new();
+
+        for hasher in hashers {
+            let tree = build_merkle_tree_with(hasher, &leaves);
+            let root = tree.get_root();
+            let proof = prove_batch(&tree, &queries);
+
+            assert!(verify_with(&hasher, &root, &proof, tree.get_depth(), &queried_leaves, &queries));
+            assert!(!verify_with(&hasher, &root, &proof, tree.get_depth(), &[9u64, 4, 5], &queries));
+
+            // a proof only verifies under the hasher that built it
+            for other in hashers.iter().filter(|&&h| h != hasher) {
+                assert!(!verify_with(other, &root, &proof, tree.get_depth(), &queried_leaves, &queries));
+            }
+            roots.push(root.root.unwrap());
+        }
+
+        // the default tree is the SHA-256 one
+        assert_eq!(build_merkle_tree(&leaves).get_root().root.unwrap(), roots[0]);
+        assert_eq!(
+            build_merkle_tree_with(RescueHasher, &leaves).get_root().root.unwrap(),
+            roots[3],
+        );
+    }
+
+    #[test]
+    fn test_leaf_node_domain_separation() {
+        let left = [1u8; 32];
+        let right = [2u8; 32];
+        let mut concat = [0u8; 64];
+        concat[..32].copy_from_slice(&left);
+        concat[32..].copy_from_slice(&right);
+
+        // a 64-byte leaf never hashes like the node over its two halves
+        assert_ne!(Sha256Hasher.hash_leaf(&concat), Sha256Hasher.hash_siblings(&left, &right));
+        assert_ne!(Blake3Hasher.hash_leaf(&concat), Blake3Hasher.hash_siblings(&left, &right));
+        assert_ne!(Keccak256Hasher.hash_leaf(&concat), Keccak256Hasher.hash_siblings(&left, &right));
+        assert_ne!(RescueHasher.hash_leaf(&concat), RescueHasher.hash_siblings(&left, &right));
+        // the length is absorbed, so zero padding does not collide
+        assert_ne!(RescueHasher.hash_leaf(&[7]), RescueHasher.hash_leaf(&[7, 0]));
+    }
 }
@@ -0,0 +1,266 @@
+// reed-solomon/src/decode.rs
+//! Erasure and error decoding
+//!
+//! Symbol `i` of a codeword is the evaluation, at the field element with bit
+//! pattern `i`, of a polynomial of degree below `message_length`; systematic
+//! encoding makes the first `message_length` symbols the message. Decoding
+//! interpolates in the monomial basis and costs O(n^2) field operations in
+//! the number of known symbols, against O(n log n) for encoding.
+use crate::{ReedSolomon, encode};
+use binary_fields::BinaryFieldElement;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Errors returned by the decoders
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// Received word does not have the block length
+    WrongLength { got: usize, expected: usize },
+
+    /// Fewer known symbols than the message length
+    NotEnoughSymbols { got: usize, need: usize },
+
+    /// Known symbols do not lie on a single codeword
+    Inconsistent,
+
+    /// More corrupt symbols than the code can correct
+    TooManyErrors,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WrongLength { got, expected } => {
+                write!(f, "received {} symbols, block length is {}", got, expected)
+            }
+            Self::NotEnoughSymbols { got, need } => {
+                write!(f, "not enough symbols: got {}, need {}", got, need)
+            }
+            Self::Inconsistent => write!(f, "symbols are not a codeword"),
+            Self::TooManyErrors => write!(f, "too many errors to correct"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
+
+/// Output of [`decode`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decoded<F> {
+    pub message: Vec<F>,
+    /// Positions (0-based) whose received symbol was corrupt, ascending
+    pub error_positions: Vec<usize>,
+}
+
+/// Recover the message from a received word with erasures (`None`)
+///
+/// Needs at least `message_length` known symbols, all correct. Known symbols
+/// beyond those used for interpolation are checked against the result.
+pub fn decode_erasures<F: BinaryFieldElement>(
+    rs: &ReedSolomon<F>,
+    received: &[Option<F>],
+) -> Result<Vec<F>, DecodeError> {
+    let known = known_symbols(rs, received)?;
+    let k = rs.message_length();
+
+    // systematic positions all present: nothing to interpolate
+    let message: Vec<F> = match received[..k].iter().copied().collect::<Option<Vec<F>>>() {
+        Some(message) => message,
+        None => evaluate_message(&interpolate(&known[..k]), k),
+    };
+
+    if known.len() > k {
+        let codeword = encode(rs, &message);
+        if known.iter().any(|&(i, y)| codeword[i] != y) {
+            return Err(DecodeError::Inconsistent);
+        }
+    }
+
+    Ok(message)
+}
+
+/// Correct errors and erasures (`None`) with Gao's algorithm
+///
+/// With `n` known symbols, up to `(n - message_length) / 2` of them may be
+/// corrupt. Returns the message and the positions that were corrupt.
+pub fn decode<F: BinaryFieldElement>(
+    rs: &ReedSolomon<F>,
+    received: &[Option<F>],
+) -> Result<Decoded<F>, DecodeError> {
+    let known = known_symbols(rs, received)?;
+    let k = rs.message_length();
+    let n = known.len();
+
+    // g0 vanishes on the known positions, g1 interpolates the received symbols
+    let points: Vec<F> = known.iter().map(|&(i, _)| point(i)).collect();
+    let mut r_prev = vanishing(&points);
+    let mut r = interpolate(&known);
+    let mut v_prev: Vec<F> = Vec::new();
+    let mut v = vec![F::one()];
+
+    // partial extended Euclid: stop once deg r < (n + k) / 2
+    while !r.is_empty() && 2 * (r.len() - 1) >= n + k {
+        let (q, rem) = div_rem(&r_prev, &r);
+        let v_next = add(&v_prev, &mul(&q, &v));
+        r_prev = core::mem::replace(&mut r, rem);
+        v_prev = core::mem::replace(&mut v, v_next);
+    }
+
+    // the message polynomial is r / v, where v locates the errors
+    let (f, rem) = div_rem(&r, &v);
+    if !rem.is_empty() || f.len() > k {
+        return Err(DecodeError::TooManyErrors);
+    }
+
+    let message = evaluate_message(&f, k);
+    let codeword = encode(rs, &message);
+    let error_positions: Vec<usize> = known.iter()
+        .filter(|&&(i, y)| codeword[i] != y)
+        .map(|&(i, _)| i)
+        .collect();
+
+    if 2 * error_positions.len() > n - k {
+        return Err(DecodeError::TooManyErrors);
+    }
+
+    Ok(Decoded { message, error_positions })
+}
+
+fn known_symbols<F: BinaryFieldElement>(
+    rs: &ReedSolomon<F>,
+    received: &[Option<F>],
+) -> Result<Vec<(usize, F)>, DecodeError> {
+    if received.len() != rs.block_length() {
+        return Err(DecodeError::WrongLength {
+            got: received.len(),
+            expected: rs.block_length(),
+        });
+    }
+
+    let known: Vec<(usize, F)> = received.iter()
+        .enumerate()
+        .filter_map(|(i, y)| y.map(|y| (i, y)))
+        .collect();
+
+    if known.len() < rs.message_length() {
+        return Err(DecodeError::NotEnoughSymbols {
+            got: known.len(),
+            need: rs.message_length(),
+        });
+    }
+
+    Ok(known)
+}
+
+/// Evaluation point of codeword position `i`
+#[inline]
+fn point<F: BinaryFieldElement>(i: usize) -> F {
+    F::from_bits(i as u64)
+}
+
+/// Systematic message of a polynomial: its values at the first `k` points
+fn evaluate_message<F: BinaryFieldElement>(poly: &[F], k: usize) -> Vec<F> {
+    (0..k).map(|i| evaluate(poly, point(i))).collect()
+}
+
+// Dense polynomials over F, lowest coefficient first, without leading zeros.
+// Characteristic 2, so subtraction is addition.
+
+fn trim<F: BinaryFieldElement>(mut p: Vec<F>) -> Vec<F> {
+    while p.last() == Some(&F::zero()) {
+        p.pop();
+    }
+    p
+}
+
+fn evaluate<F: BinaryFieldElement>(p: &[F], x: F) -> F {
+    p.iter().rev().fold(F::zero(), |acc, c| acc.mul(&x).add(c))
+}
+
+fn add<F: BinaryFieldElement>(a: &[F], b: &[F]) -> Vec<F> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut out = long.to_vec();
+    for (o, s) in out.iter_mut().zip(short.iter()) {
+        *o = o.add(s);
+    }
+    trim(out)
+}
+
+fn mul<F: BinaryFieldElement>(a: &[F], b: &[F]) -> Vec<F> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut out = vec![F::zero(); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            out[i + j] = out[i + j].add(&x.mul(y));
+        }
+    }
+    trim(out)
+}
+
+fn div_rem<F: BinaryFieldElement>(a: &[F], b: &[F]) -> (Vec<F>, Vec<F>) {
+    assert!(!b.is_empty(), "division by zero polynomial");
+    if a.len() < b.len() {
+        return (Vec::new(), a.to_vec());
+    }
+
+    let lead_inv = b[b.len() - 1].inv();
+    let mut rem = a.to_vec();
+    let mut quot = vec![F::zero(); a.len() - b.len() + 1];
+    for i in (0..quot.len()).rev() {
+        let c = rem[i + b.len() - 1].mul(&lead_inv);
+        quot[i] = c;
+        for (j, bj) in b.iter().enumerate() {
+            rem[i + j] = rem[i + j].add(&c.mul(bj));
+        }
+    }
+    rem.truncate(b.len() - 1);
+    (trim(quot), trim(rem))
+}
+
+/// prod (X - x) over `points`
+fn vanishing<F: BinaryFieldElement>(points: &[F]) -> Vec<F> {
+    let mut z = vec![F::one()];
+    for x in points {
+        z.push(F::zero());
+        for j in (1..z.len()).rev() {
+            z[j] = z[j - 1].add(&x.mul(&z[j]));
+        }
+        z[0] = x.mul(&z[0]);
+    }
+    z
+}
+
+/// Lagrange interpolation through `(position, value)` pairs
+///
+/// With Z the vanishing polynomial, the basis polynomial of x_i is
+/// Z / (X - x_i) scaled by 1 / Z'(x_i).
+fn interpolate<F: BinaryFieldElement>(known: &[(usize, F)]) -> Vec<F> {
+    let points: Vec<F> = known.iter().map(|&(i, _)| point(i)).collect();
+    let z = vanishing(&points);
+
+    // formal derivative: only odd powers survive in characteristic 2
+    let dz: Vec<F> = (1..z.len())
+        .map(|j| if j % 2 == 1 { z[j] } else { F::zero() })
+        .collect();
+
+    let mut out = vec![F::zero(); points.len()];
+    let mut q = vec![F::zero(); points.len()];
+    for (&x, &(_, y)) in points.iter().zip(known.iter()) {
+        // q = Z / (X - x) by synthetic division
+        let mut carry = F::zero();
+        for j in (0..q.len()).rev() {
+            carry = z[j + 1].add(&x.mul(&carry));
+            q[j] = carry;
+        }
+
+        let scale = y.mul(&evaluate(&dz, x).inv());
+        for (o, c) in out.iter_mut().zip(q.iter()) {
+            *o = o.add(&scale.mul(c));
+        }
+    }
+    trim(out)
+}
@@ -1,6 +1,8 @@
 // reed-solomon/src/encode.rs
 use crate::{ReedSolomon, fft, short_from_long_twiddles};
 use binary_fields::BinaryFieldElement;
+use alloc::vec;
+use alloc::vec::Vec;
 
 /// Encode a message using Reed-Solomon
 pub fn encode<F: BinaryFieldElement>(rs: &ReedSolomon<F>, message: &[F]) -> Vec<F> {
@@ -1,5 +1,7 @@
 // reed-solomon/src/fft.rs
 use binary_fields::BinaryFieldElement;
+use alloc::vec;
+use alloc::vec::Vec;
 
 /// Compute twiddle factors for FFT
 pub fn compute_twiddles<F: BinaryFieldElement>(log_n: usize, beta: F) -> Vec<F> {
@@ -94,11 +96,8 @@ fn fft_mul<F: BinaryFieldElement>(v: &mut [F], lambda: F) {
     let mid = v.len() / 2;
     let (u, w) = v.split_at_mut(mid);
     
-    for i in 0..mid {
-        let lambda_w = lambda.mul(&w[i]);
-        u[i] = u[i].add(&lambda_w);
-        w[i] = w[i].add(&u[i]); // Uses updated u[i]
-    }
+    // u += lambda * w, then w += u (uses updated u), vectorized per field
+    F::fft_butterfly(u, w, lambda);
 }
 
 /// In-place FFT with twiddle factors
@@ -136,11 +135,8 @@ fn ifft_mul<F: BinaryFieldElement>(v: &mut [F], lambda: F) {
     let mid = v.len() / 2;
     let (lo, hi) = v.split_at_mut(mid);
     
-    for i in 0..mid {
-        hi[i] = hi[i].add(&lo[i]);
-        let lambda_hi = lambda.mul(&hi[i]);
-        lo[i] = lo[i].add(&lambda_hi);
-    }
+    // hi += lo, then lo += lambda * hi
+    F::ifft_butterfly(lo, hi, lambda);
 }
 
 /// Inverse FFT
@@ -1,8 +1,17 @@
 // reed-solomon/src/lib.rs
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
 mod fft;
 mod encode;
+mod decode;
 
 pub use encode::{encode, encode_in_place, encode_non_systematic};
+pub use decode::{decode, decode_erasures, DecodeError, Decoded};
 pub use fft::{compute_twiddles, fft, ifft};
 
 use binary_fields::BinaryFieldElement;
@@ -46,6 +55,19 @@ impl<F: BinaryFieldElement> ReedSolomon<F> {
     pub fn block_length(&self) -> usize {
         1 << self.log_block_length
     }
+
+    /// The same code over an extension field
+    ///
+    /// `From<F>` must be a field embedding; encoding an embedded message
+    /// then yields the embedded codeword.
+    pub fn embed<U: BinaryFieldElement + From<F>>(&self) -> ReedSolomon<U> {
+        ReedSolomon {
+            log_message_length: self.log_message_length,
+            log_block_length: self.log_block_length,
+            twiddles: self.twiddles.iter().map(|&t| U::from(t)).collect(),
+            pis: self.pis.iter().map(|&p| U::from(p)).collect(),
+        }
+    }
 }
 
 /// Create a Reed-Solomon encoder
@@ -144,6 +166,9 @@ pub fn short_from_long_twiddles<F: BinaryFieldElement>(
 mod tests {
     use super::*;
     use binary_fields::{BinaryElem16, BinaryElem32, BinaryElem128};
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::{Rng, SeedableRng};
 
     #[test]
     fn test_eval_sk_at_vks() {
@@ -360,4 +385,121 @@ mod tests {
         assert_eq!(enc32.len(), 32);
         assert_eq!(enc128.len(), 32);
     }
+
+    fn random_codeword(rs: &ReedSolomon<BinaryElem32>, rng: &mut StdRng) -> (Vec<BinaryElem32>, Vec<BinaryElem32>) {
+        let message: Vec<_> = (0..rs.message_length())
+            .map(|_| BinaryElem32::from(rng.gen::<u32>()))
+            .collect();
+        let codeword = encode(rs, &message);
+        (message, codeword)
+    }
+
+    #[test]
+    fn test_embedded_code_encodes_embedded_messages() {
+        let mut rng = StdRng::seed_from_u64(12);
+        let rs = reed_solomon::<BinaryElem32>(32, 128);
+        let msg: Vec<BinaryElem32> = (0..32).map(|_| rng.gen()).collect();
+
+        let lifted: Vec<BinaryElem128> = msg.iter().map(|&x| BinaryElem128::from(x)).collect();
+        let expected: Vec<BinaryElem128> = encode(&rs, &msg).into_iter().map(BinaryElem128::from).collect();
+        assert_eq!(encode(&rs.embed::<BinaryElem128>(), &lifted), expected);
+    }
+
+    #[test]
+    fn test_erasure_decoding() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let rs = reed_solomon::<BinaryElem32>(8, 32);
+
+        for _ in 0..10 {
+            let (message, codeword) = random_codeword(&rs, &mut rng);
+
+            // keep exactly message_length random positions
+            let mut positions: Vec<usize> = (0..32).collect();
+            positions.shuffle(&mut rng);
+            let mut received = vec![None; 32];
+            for &i in &positions[..8] {
+                received[i] = Some(codeword[i]);
+            }
+
+            assert_eq!(decode_erasures(&rs, &received).unwrap(), message);
+        }
+    }
+
+    #[test]
+    fn test_erasure_decoding_rejects_bad_input() {
+        let mut rng = StdRng::seed_from_u64(8);
+        let rs = reed_solomon::<BinaryElem32>(8, 32);
+        let (_, codeword) = random_codeword(&rs, &mut rng);
+
+        let mut received: Vec<_> = codeword.iter().map(|&y| Some(y)).collect();
+        assert_eq!(
+            decode_erasures(&rs, &received[..31]),
+            Err(DecodeError::WrongLength { got: 31, expected: 32 })
+        );
+
+        received[20] = Some(received[20].unwrap().add(&BinaryElem32::one()));
+        assert_eq!(decode_erasures(&rs, &received), Err(DecodeError::Inconsistent));
+
+        for y in received.iter_mut().skip(7) {
+            *y = None;
+        }
+        assert_eq!(
+            decode_erasures(&rs, &received),
+            Err(DecodeError::NotEnoughSymbols { got: 7, need: 8 })
+        );
+    }
+
+    #[test]
+    fn test_error_decoding_reports_positions() {
+        let mut rng = StdRng::seed_from_u64(9);
+        let rs = reed_solomon::<BinaryElem32>(8, 32);
+
+        for erasures in [0, 6, 12] {
+            let (message, codeword) = random_codeword(&rs, &mut rng);
+            let max_errors = (32 - erasures - 8) / 2;
+
+            let mut positions: Vec<usize> = (0..32).collect();
+            positions.shuffle(&mut rng);
+            let mut received: Vec<_> = codeword.iter().map(|&y| Some(y)).collect();
+            for &i in &positions[..erasures] {
+                received[i] = None;
+            }
+            let mut corrupted = positions[erasures..erasures + max_errors].to_vec();
+            for &i in &corrupted {
+                let noise = BinaryElem32::from(rng.gen::<u32>() | 1);
+                received[i] = Some(codeword[i].add(&noise));
+            }
+            corrupted.sort();
+
+            let decoded = decode(&rs, &received).unwrap();
+            assert_eq!(decoded.message, message);
+            assert_eq!(decoded.error_positions, corrupted);
+        }
+    }
+
+    #[test]
+    fn test_error_decoding_clean_codeword() {
+        let mut rng = StdRng::seed_from_u64(10);
+        let rs = reed_solomon::<BinaryElem16>(4, 16);
+        let message: Vec<_> = (0..4).map(|_| BinaryElem16::from(rng.gen::<u16>())).collect();
+        let received: Vec<_> = encode(&rs, &message).into_iter().map(Some).collect();
+
+        let decoded = decode(&rs, &received).unwrap();
+        assert_eq!(decoded.message, message);
+        assert!(decoded.error_positions.is_empty());
+    }
+
+    #[test]
+    fn test_error_decoding_too_many_errors() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let rs = reed_solomon::<BinaryElem32>(8, 32);
+        let (_, codeword) = random_codeword(&rs, &mut rng);
+
+        // one past the unique decoding radius
+        let mut received: Vec<_> = codeword.iter().map(|&y| Some(y)).collect();
+        for y in received.iter_mut().take(13) {
+            *y = Some(BinaryElem32::from(rng.gen::<u32>()));
+        }
+        assert_eq!(decode(&rs, &received), Err(DecodeError::TooManyErrors));
+    }
 }